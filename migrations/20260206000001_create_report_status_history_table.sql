@@ -0,0 +1,28 @@
+-- Migration: Create report_status_history table
+-- Records every status transition of a report (actor, role, timestamp, note)
+
+CREATE TABLE report_status_history (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    report_id UUID NOT NULL REFERENCES reports(id) ON DELETE CASCADE,
+    from_status report_status,
+    to_status report_status NOT NULL,
+    changed_by VARCHAR(255),
+    changed_by_role VARCHAR(50),
+    note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Index for timeline lookups by report
+CREATE INDEX idx_report_status_history_report_id ON report_status_history(report_id, created_at);
+
+-- Seed initial history entry for existing reports
+INSERT INTO report_status_history (report_id, from_status, to_status, changed_by_role, note, created_at)
+SELECT id, NULL, status, 'system', 'Initial status (backfilled)', created_at
+FROM reports;
+
+COMMENT ON TABLE report_status_history IS 'Audit trail of report status transitions';
+COMMENT ON COLUMN report_status_history.from_status IS 'Previous status (NULL for the initial entry)';
+COMMENT ON COLUMN report_status_history.to_status IS 'New status after the transition';
+COMMENT ON COLUMN report_status_history.changed_by IS 'User ID of the actor (NULL for system changes)';
+COMMENT ON COLUMN report_status_history.changed_by_role IS 'Role of the actor at the time of the change (e.g. super_admin, system)';
+COMMENT ON COLUMN report_status_history.note IS 'Optional note explaining the transition';
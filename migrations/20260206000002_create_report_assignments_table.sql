@@ -0,0 +1,38 @@
+-- Migration: Create report_assignments table
+-- Tracks which official owns a report (claim / admin assign / reassign)
+
+CREATE TYPE report_assignment_type AS ENUM (
+    'claim',     -- Official claimed an unassigned report
+    'assign',    -- Admin curator assigned the report directly
+    'reassign'   -- Handed over from one official to another
+);
+
+CREATE TABLE report_assignments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    report_id UUID NOT NULL REFERENCES reports(id) ON DELETE CASCADE,
+    assignee_id VARCHAR(255) NOT NULL,
+    assigned_by VARCHAR(255) NOT NULL,
+    assignment_type report_assignment_type NOT NULL,
+    note TEXT,
+    assigned_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    released_at TIMESTAMPTZ,
+    released_by VARCHAR(255),
+    release_reason TEXT
+);
+
+-- Only one active (unreleased) assignment per report
+CREATE UNIQUE INDEX idx_report_assignments_active
+    ON report_assignments(report_id) WHERE released_at IS NULL;
+
+-- Index for "my assigned reports" lookups
+CREATE INDEX idx_report_assignments_assignee
+    ON report_assignments(assignee_id) WHERE released_at IS NULL;
+
+-- Index for assignment history per report
+CREATE INDEX idx_report_assignments_report_id ON report_assignments(report_id, assigned_at);
+
+COMMENT ON TABLE report_assignments IS 'Ownership of reports by officials, including released (historical) assignments';
+COMMENT ON COLUMN report_assignments.assignee_id IS 'User ID of the official owning the report';
+COMMENT ON COLUMN report_assignments.assigned_by IS 'User ID of the actor who created the assignment';
+COMMENT ON COLUMN report_assignments.released_at IS 'When the assignment ended (NULL = active)';
+COMMENT ON COLUMN report_assignments.release_reason IS 'Why the assignment ended (release note or reassignment)';
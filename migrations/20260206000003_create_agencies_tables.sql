@@ -0,0 +1,71 @@
+-- Migration: Create agencies subsystem
+-- Agencies are government bodies responsible for reports within a jurisdiction.
+-- A jurisdiction is a set of regions (any administrative level) plus a set of category slugs.
+
+CREATE TABLE agencies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    code VARCHAR(50) NOT NULL UNIQUE,
+    name VARCHAR(200) NOT NULL,
+    description TEXT,
+    contact_email VARCHAR(255),
+    contact_phone VARCHAR(50),
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_agencies_is_active ON agencies(is_active) WHERE is_active = TRUE;
+
+-- Regions covered by an agency. Exactly one region level is set per row.
+CREATE TABLE agency_jurisdiction_regions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    agency_id UUID NOT NULL REFERENCES agencies(id) ON DELETE CASCADE,
+    province_id UUID REFERENCES provinces(id) ON DELETE CASCADE,
+    regency_id UUID REFERENCES regencies(id) ON DELETE CASCADE,
+    district_id UUID REFERENCES districts(id) ON DELETE CASCADE,
+    village_id UUID REFERENCES villages(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT agency_jurisdiction_regions_one_level
+        CHECK (num_nonnulls(province_id, regency_id, district_id, village_id) = 1)
+);
+
+CREATE INDEX idx_agency_jurisdiction_regions_agency_id ON agency_jurisdiction_regions(agency_id);
+CREATE INDEX idx_agency_jurisdiction_regions_province_id ON agency_jurisdiction_regions(province_id) WHERE province_id IS NOT NULL;
+CREATE INDEX idx_agency_jurisdiction_regions_regency_id ON agency_jurisdiction_regions(regency_id) WHERE regency_id IS NOT NULL;
+CREATE INDEX idx_agency_jurisdiction_regions_district_id ON agency_jurisdiction_regions(district_id) WHERE district_id IS NOT NULL;
+CREATE INDEX idx_agency_jurisdiction_regions_village_id ON agency_jurisdiction_regions(village_id) WHERE village_id IS NOT NULL;
+
+-- Categories handled by an agency. An agency without categories handles all categories.
+CREATE TABLE agency_jurisdiction_categories (
+    agency_id UUID NOT NULL REFERENCES agencies(id) ON DELETE CASCADE,
+    category_slug VARCHAR(100) NOT NULL REFERENCES categories(slug) ON UPDATE CASCADE ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (agency_id, category_slug)
+);
+
+CREATE INDEX idx_agency_jurisdiction_categories_slug ON agency_jurisdiction_categories(category_slug);
+
+-- Routing result: the agency a report is attached to
+CREATE TABLE report_agency_routes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    report_id UUID NOT NULL UNIQUE REFERENCES reports(id) ON DELETE CASCADE,
+    agency_id UUID NOT NULL REFERENCES agencies(id) ON DELETE CASCADE,
+    matched_region_level VARCHAR(20) NOT NULL,
+    matched_region_id UUID NOT NULL,
+    category_matched BOOLEAN NOT NULL,
+    routed_by VARCHAR(255) NOT NULL,
+    routed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_report_agency_routes_agency_id ON report_agency_routes(agency_id);
+
+COMMENT ON TABLE agencies IS 'Government agencies responsible for handling reports';
+COMMENT ON COLUMN agencies.code IS 'Unique short code of the agency (e.g. dpupr-kota-bandung)';
+COMMENT ON TABLE agency_jurisdiction_regions IS 'Regions covered by an agency (one administrative level per row)';
+COMMENT ON TABLE agency_jurisdiction_categories IS 'Category slugs handled by an agency (none = all categories)';
+COMMENT ON TABLE report_agency_routes IS 'Agency each report was routed to';
+COMMENT ON COLUMN report_agency_routes.matched_region_level IS 'Region level that matched: village, district, regency or province';
+COMMENT ON COLUMN report_agency_routes.category_matched IS 'TRUE if matched on a specific category, FALSE if the agency handles all categories';
+COMMENT ON COLUMN report_agency_routes.routed_by IS 'User ID of the actor, or system for automatic routing';
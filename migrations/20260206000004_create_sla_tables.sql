@@ -0,0 +1,85 @@
+-- Migration: Create SLA tables
+-- SLA policies define how long a report may take to be verified and resolved,
+-- per category and severity. Deadlines are opened when a report enters a status
+-- and closed when it reaches the target status.
+
+CREATE TABLE sla_policies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(200) NOT NULL,
+    category_id UUID REFERENCES categories(id) ON DELETE CASCADE,
+    severity report_severity,
+    verify_within_hours INTEGER CHECK (verify_within_hours > 0),
+    resolve_within_hours INTEGER CHECK (resolve_within_hours > 0),
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- One active policy per category/severity combination (NULL = any)
+CREATE UNIQUE INDEX idx_sla_policies_scope_active
+    ON sla_policies (COALESCE(category_id, '00000000-0000-0000-0000-000000000000'::uuid), severity)
+    WHERE is_active = TRUE AND severity IS NOT NULL;
+
+CREATE UNIQUE INDEX idx_sla_policies_scope_any_severity_active
+    ON sla_policies (COALESCE(category_id, '00000000-0000-0000-0000-000000000000'::uuid))
+    WHERE is_active = TRUE AND severity IS NULL;
+
+CREATE TABLE report_sla_deadlines (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    report_id UUID NOT NULL REFERENCES reports(id) ON DELETE CASCADE,
+    policy_id UUID REFERENCES sla_policies(id) ON DELETE SET NULL,
+    target_status report_status NOT NULL,
+    started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    due_at TIMESTAMPTZ NOT NULL,
+    met_at TIMESTAMPTZ,
+    cancelled_at TIMESTAMPTZ,
+    breached_at TIMESTAMPTZ,
+    escalation_level INTEGER NOT NULL DEFAULT 0,
+    last_escalated_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Only one open deadline per report and target status
+CREATE UNIQUE INDEX idx_report_sla_deadlines_open
+    ON report_sla_deadlines(report_id, target_status)
+    WHERE met_at IS NULL AND cancelled_at IS NULL;
+
+-- Index for the escalation worker scanning open deadlines
+CREATE INDEX idx_report_sla_deadlines_due_at
+    ON report_sla_deadlines(due_at)
+    WHERE met_at IS NULL AND cancelled_at IS NULL;
+
+CREATE TABLE report_sla_escalations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    deadline_id UUID NOT NULL REFERENCES report_sla_deadlines(id) ON DELETE CASCADE,
+    report_id UUID NOT NULL REFERENCES reports(id) ON DELETE CASCADE,
+    escalation_level INTEGER NOT NULL,
+    target_type VARCHAR(50) NOT NULL,
+    agency_id UUID REFERENCES agencies(id) ON DELETE SET NULL,
+    region_level VARCHAR(20),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_report_sla_escalations_report_id ON report_sla_escalations(report_id, created_at);
+
+-- Seed default policies
+INSERT INTO sla_policies (name, category_id, severity, verify_within_hours, resolve_within_hours)
+VALUES ('Default', NULL, NULL, 72, 720);
+
+INSERT INTO sla_policies (name, category_id, severity, verify_within_hours, resolve_within_hours)
+VALUES ('Critical (any category)', NULL, 'critical', 24, 168);
+
+INSERT INTO sla_policies (name, category_id, severity, verify_within_hours, resolve_within_hours)
+SELECT 'Critical infrastructure', id, 'critical', 24, 168
+FROM categories WHERE slug = 'infrastruktur';
+
+COMMENT ON TABLE sla_policies IS 'SLA targets per category and severity (NULL = any)';
+COMMENT ON COLUMN sla_policies.category_id IS 'Category the policy applies to (also applies to its child categories)';
+COMMENT ON COLUMN sla_policies.verify_within_hours IS 'Hours allowed from submission until the report is verified (NULL = no deadline)';
+COMMENT ON COLUMN sla_policies.resolve_within_hours IS 'Hours allowed from verification until the report is resolved (NULL = no deadline)';
+COMMENT ON TABLE report_sla_deadlines IS 'SLA deadlines of reports, opened on status entry and closed when the target status is reached';
+COMMENT ON COLUMN report_sla_deadlines.target_status IS 'Status the report must reach before due_at';
+COMMENT ON COLUMN report_sla_deadlines.breached_at IS 'When the escalation worker flagged the deadline as breached';
+COMMENT ON COLUMN report_sla_deadlines.escalation_level IS 'Number of escalations performed for this deadline';
+COMMENT ON TABLE report_sla_escalations IS 'Escalations of breached SLA deadlines';
+COMMENT ON COLUMN report_sla_escalations.target_type IS 'Escalation target: agency (parent region) or admin_curators';
@@ -0,0 +1,39 @@
+-- Migration: Create report_duplicates table
+-- Links a report to an earlier report it likely duplicates (detected after extraction)
+
+CREATE TYPE report_duplicate_status AS ENUM (
+    'pending',    -- Detected, awaiting admin review
+    'confirmed',  -- Admin confirmed both reports describe the same issue
+    'dismissed',  -- Admin decided the reports are unrelated
+    'merged'      -- Duplicate was merged into the original report
+);
+
+CREATE TABLE report_duplicates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    report_id UUID NOT NULL REFERENCES reports(id) ON DELETE CASCADE,
+    duplicate_of_id UUID NOT NULL REFERENCES reports(id) ON DELETE CASCADE,
+    score DOUBLE PRECISION NOT NULL,
+    text_similarity DOUBLE PRECISION NOT NULL,
+    distance_meters DOUBLE PRECISION,
+    status report_duplicate_status NOT NULL DEFAULT 'pending',
+    detected_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    reviewed_by VARCHAR(255),
+    reviewed_at TIMESTAMPTZ,
+    review_note TEXT,
+
+    UNIQUE(report_id, duplicate_of_id),
+    CHECK (report_id <> duplicate_of_id)
+);
+
+-- Index for looking up links from the original report side
+CREATE INDEX idx_report_duplicates_duplicate_of_id ON report_duplicates(duplicate_of_id);
+
+-- Index for the admin review queue
+CREATE INDEX idx_report_duplicates_status ON report_duplicates(status, score DESC);
+
+COMMENT ON TABLE report_duplicates IS 'Likely duplicate reports (same area and category, similar text and location)';
+COMMENT ON COLUMN report_duplicates.report_id IS 'Newer report that looks like a duplicate';
+COMMENT ON COLUMN report_duplicates.duplicate_of_id IS 'Earlier report it duplicates';
+COMMENT ON COLUMN report_duplicates.score IS 'Combined duplicate score (0-1) from text similarity and distance';
+COMMENT ON COLUMN report_duplicates.text_similarity IS 'Word overlap of title/description (0-1)';
+COMMENT ON COLUMN report_duplicates.distance_meters IS 'Distance between report locations (NULL if either has no coordinates)';
@@ -0,0 +1,30 @@
+-- Migration: Create report_updates table
+-- Public progress updates posted by officials and admins on a report
+
+CREATE TABLE report_updates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    report_id UUID NOT NULL REFERENCES reports(id) ON DELETE CASCADE,
+    author_id VARCHAR(255) NOT NULL,
+    author_role VARCHAR(50) NOT NULL,
+    message TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at TIMESTAMPTZ,
+    deleted_by VARCHAR(255)
+);
+
+-- Index for the update timeline of a report
+CREATE INDEX idx_report_updates_report_id ON report_updates(report_id, created_at)
+    WHERE deleted_at IS NULL;
+
+-- Attachments of an update reuse report_attachments (NULL = citizen evidence)
+ALTER TABLE report_attachments
+    ADD COLUMN report_update_id UUID REFERENCES report_updates(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_report_attachments_report_update_id
+    ON report_attachments(report_update_id) WHERE report_update_id IS NOT NULL;
+
+COMMENT ON TABLE report_updates IS 'Public progress updates on a report, shown to the citizen and on the dashboard';
+COMMENT ON COLUMN report_updates.author_id IS 'User ID of the official or admin who posted the update';
+COMMENT ON COLUMN report_updates.author_role IS 'Role of the author at posting time (shown instead of the user ID)';
+COMMENT ON COLUMN report_updates.deleted_at IS 'Soft delete timestamp (deleted updates are hidden)';
+COMMENT ON COLUMN report_attachments.report_update_id IS 'Update this attachment belongs to (NULL = attached by the citizen)';
@@ -0,0 +1,29 @@
+-- Migration: Create report_endorsements table
+-- Citizens endorse ("me too") a public report instead of filing a duplicate
+
+CREATE TABLE report_endorsements (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    report_id UUID NOT NULL REFERENCES reports(id) ON DELETE CASCADE,
+    user_id VARCHAR(255) NOT NULL,
+    comment VARCHAR(280),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    -- One endorsement per user per report
+    UNIQUE(report_id, user_id)
+);
+
+-- Index for listing endorsements of a report
+CREATE INDEX idx_report_endorsements_report_id ON report_endorsements(report_id, created_at DESC);
+
+-- Index for looking up a user's endorsements
+CREATE INDEX idx_report_endorsements_user_id ON report_endorsements(user_id);
+
+-- Denormalized counter so dashboard listings can sort by it cheaply
+ALTER TABLE reports ADD COLUMN endorsement_count INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX idx_reports_endorsement_count ON reports(endorsement_count DESC, created_at DESC);
+
+COMMENT ON TABLE report_endorsements IS 'Citizen endorsements of public reports (one per user per report)';
+COMMENT ON COLUMN report_endorsements.user_id IS 'User ID of the endorsing citizen';
+COMMENT ON COLUMN report_endorsements.comment IS 'Optional short comment from the endorsing citizen';
+COMMENT ON COLUMN reports.endorsement_count IS 'Number of citizen endorsements (kept in sync by the endorsement service)';
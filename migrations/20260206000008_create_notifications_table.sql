@@ -0,0 +1,29 @@
+-- Migration: Create notifications table
+-- In-app notification inbox for citizens and officials
+
+CREATE TYPE notification_type AS ENUM (
+    'report_processed',
+    'report_status_changed',
+    'report_assigned'
+);
+
+CREATE TABLE notifications (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id VARCHAR(255) NOT NULL,
+    notification_type notification_type NOT NULL,
+    report_id UUID REFERENCES reports(id) ON DELETE CASCADE,
+    payload JSONB NOT NULL,
+    read_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Index for the inbox of a user (newest first)
+CREATE INDEX idx_notifications_user_id ON notifications(user_id, created_at DESC);
+
+-- Index for unread counts
+CREATE INDEX idx_notifications_unread ON notifications(user_id) WHERE read_at IS NULL;
+
+COMMENT ON TABLE notifications IS 'In-app notifications produced by report lifecycle events';
+COMMENT ON COLUMN notifications.user_id IS 'Recipient user ID';
+COMMENT ON COLUMN notifications.payload IS 'Typed payload, tagged by "type" (matches notification_type)';
+COMMENT ON COLUMN notifications.read_at IS 'When the recipient marked the notification as read (NULL = unread)';
@@ -0,0 +1,83 @@
+-- Migration: Create webhook tables
+-- Outbound webhooks for report lifecycle events (subscriptions, outbox, delivery log)
+
+-- ============================================================================
+-- Subscriptions
+-- ============================================================================
+
+CREATE TABLE webhook_subscriptions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(200) NOT NULL,
+    target_url TEXT NOT NULL,
+    secret VARCHAR(255) NOT NULL,
+    event_types TEXT[] NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT true,
+    created_by VARCHAR(255) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT webhook_subscriptions_event_types_not_empty CHECK (cardinality(event_types) > 0)
+);
+
+-- Index for fanning out events to active subscribers
+CREATE INDEX idx_webhook_subscriptions_event_types ON webhook_subscriptions USING GIN (event_types)
+    WHERE is_active = true;
+
+COMMENT ON TABLE webhook_subscriptions IS 'Partner endpoints receiving report lifecycle events';
+COMMENT ON COLUMN webhook_subscriptions.secret IS 'Shared secret used to sign deliveries with HMAC-SHA256';
+COMMENT ON COLUMN webhook_subscriptions.event_types IS 'Subscribed event types (e.g. report.submitted, report.status_changed)';
+
+-- ============================================================================
+-- Deliveries (outbox)
+-- ============================================================================
+
+CREATE TYPE webhook_delivery_status AS ENUM ('pending', 'succeeded', 'failed');
+
+CREATE TABLE webhook_deliveries (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    subscription_id UUID NOT NULL REFERENCES webhook_subscriptions(id) ON DELETE CASCADE,
+    event_id UUID NOT NULL,
+    event_type VARCHAR(100) NOT NULL,
+    payload JSONB NOT NULL,
+    status webhook_delivery_status NOT NULL DEFAULT 'pending',
+    attempt_count INTEGER NOT NULL DEFAULT 0,
+    max_attempts INTEGER NOT NULL DEFAULT 8,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_attempt_at TIMESTAMPTZ,
+    last_status_code INTEGER,
+    last_error TEXT,
+    delivered_at TIMESTAMPTZ,
+    redelivery_of UUID REFERENCES webhook_deliveries(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Index for the dispatcher picking due deliveries
+CREATE INDEX idx_webhook_deliveries_due ON webhook_deliveries(next_attempt_at)
+    WHERE status = 'pending';
+
+-- Index for the delivery log of a subscription
+CREATE INDEX idx_webhook_deliveries_subscription_id ON webhook_deliveries(subscription_id, created_at DESC);
+
+COMMENT ON TABLE webhook_deliveries IS 'Outbox of webhook deliveries, one row per event per subscription';
+COMMENT ON COLUMN webhook_deliveries.event_id IS 'Event ID shared by all deliveries of the same event (idempotency key for receivers)';
+COMMENT ON COLUMN webhook_deliveries.next_attempt_at IS 'Earliest time of the next attempt (also used as a lease while sending)';
+COMMENT ON COLUMN webhook_deliveries.redelivery_of IS 'Original delivery when this row is a manual redelivery';
+
+-- ============================================================================
+-- Delivery attempts (log)
+-- ============================================================================
+
+CREATE TABLE webhook_delivery_attempts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    delivery_id UUID NOT NULL REFERENCES webhook_deliveries(id) ON DELETE CASCADE,
+    attempt_number INTEGER NOT NULL,
+    status_code INTEGER,
+    error TEXT,
+    duration_ms INTEGER NOT NULL,
+    attempted_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_webhook_delivery_attempts_delivery_id ON webhook_delivery_attempts(delivery_id, attempt_number);
+
+COMMENT ON TABLE webhook_delivery_attempts IS 'Log of every HTTP attempt made for a webhook delivery';
+COMMENT ON COLUMN webhook_delivery_attempts.status_code IS 'HTTP status code returned by the receiver (NULL = network error or timeout)';
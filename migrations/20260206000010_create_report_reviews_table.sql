@@ -0,0 +1,48 @@
+-- Migration: Create report review queue
+-- Low-confidence submissions wait for a curator instead of being rejected automatically
+
+CREATE TYPE report_review_status AS ENUM (
+    'pending',         -- Waiting for a curator decision
+    'info_requested',  -- Curator asked the citizen for more information
+    'approved',        -- Curator approved; a report job was queued for extraction
+    'rejected'         -- Curator rejected the report
+);
+
+CREATE TABLE report_reviews (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    report_id UUID NOT NULL UNIQUE REFERENCES reports(id) ON DELETE CASCADE,
+    confidence_score DOUBLE PRECISION,
+    confidence_threshold DOUBLE PRECISION NOT NULL,
+    status report_review_status NOT NULL DEFAULT 'pending',
+    info_request TEXT,
+    info_requested_at TIMESTAMPTZ,
+    reviewed_by VARCHAR(255),
+    reviewed_at TIMESTAMPTZ,
+    review_note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Index for the curator queue (oldest first)
+CREATE INDEX idx_report_reviews_status ON report_reviews(status, created_at);
+
+-- Runtime settings of the review queue (single row)
+CREATE TABLE report_review_settings (
+    id BOOLEAN PRIMARY KEY DEFAULT true CHECK (id),
+    confidence_threshold DOUBLE PRECISION NOT NULL
+        CHECK (confidence_threshold >= 0 AND confidence_threshold <= 1),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_by VARCHAR(255)
+);
+
+INSERT INTO report_review_settings (confidence_threshold) VALUES (0.7);
+
+-- Citizens are notified when a curator needs more information
+ALTER TYPE notification_type ADD VALUE 'report_info_requested';
+
+COMMENT ON TABLE report_reviews IS 'Curator review queue for submissions below the confidence threshold';
+COMMENT ON COLUMN report_reviews.confidence_score IS 'Agent confidence score of the submission (0-1)';
+COMMENT ON COLUMN report_reviews.confidence_threshold IS 'Threshold in effect when the report was queued';
+COMMENT ON COLUMN report_reviews.info_request IS 'Latest message sent to the citizen asking for more information';
+COMMENT ON TABLE report_review_settings IS 'Runtime settings of the report review queue';
+COMMENT ON COLUMN report_review_settings.confidence_threshold IS 'Submissions below this confidence go to the review queue';
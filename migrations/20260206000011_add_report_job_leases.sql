@@ -0,0 +1,29 @@
+-- Migration: Add leases and wakeup notifications to the report job queue
+-- Lets several processor replicas share the queue without double-processing jobs
+
+ALTER TABLE report_jobs
+ADD COLUMN locked_by VARCHAR(255),
+ADD COLUMN locked_until TIMESTAMPTZ,
+ADD COLUMN heartbeat_at TIMESTAMPTZ;
+
+-- Index for finding processing jobs whose lease expired
+CREATE INDEX idx_report_jobs_lease ON report_jobs(locked_until)
+WHERE status = 'processing';
+
+-- Wake up processors as soon as a job is queued
+CREATE OR REPLACE FUNCTION notify_report_job_inserted() RETURNS trigger AS $$
+BEGIN
+    PERFORM pg_notify('report_jobs', NEW.id::text);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_report_jobs_notify
+AFTER INSERT ON report_jobs
+FOR EACH ROW
+WHEN (NEW.status = 'submitted')
+EXECUTE FUNCTION notify_report_job_inserted();
+
+COMMENT ON COLUMN report_jobs.locked_by IS 'Processor instance holding the job while it is processing';
+COMMENT ON COLUMN report_jobs.locked_until IS 'Lease expiry; processing jobs past it are recovered by other processors';
+COMMENT ON COLUMN report_jobs.heartbeat_at IS 'Last time the processing worker extended its lease';
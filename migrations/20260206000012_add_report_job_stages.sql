@@ -0,0 +1,37 @@
+-- Migration: Staged, resumable report processing
+-- Jobs record the last completed pipeline stage so a retry resumes where it failed,
+-- retries are delayed with exponential backoff, and stage failures are logged
+
+CREATE TYPE report_job_stage AS ENUM (
+    'extract',      -- LLM extraction of the conversation
+    'content',      -- Extracted title/description written to the report
+    'classify',     -- Categories and tags assigned
+    'locate',       -- Geocoding, region resolution and duplicate detection
+    'attachments',  -- Thread attachments linked to the report
+    'finalize'      -- Agency routing and notifications
+);
+
+ALTER TABLE report_jobs
+ADD COLUMN stage report_job_stage NOT NULL DEFAULT 'extract',
+ADD COLUMN extracted_data JSONB,
+ADD COLUMN next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+
+-- Claimable jobs ordered by due time
+CREATE INDEX idx_report_jobs_due ON report_jobs(next_attempt_at)
+WHERE status = 'submitted';
+
+CREATE TABLE report_job_errors (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    job_id UUID NOT NULL REFERENCES report_jobs(id) ON DELETE CASCADE,
+    stage report_job_stage NOT NULL,
+    attempt INT NOT NULL,
+    error TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_report_job_errors_job_id ON report_job_errors(job_id, created_at);
+
+COMMENT ON COLUMN report_jobs.stage IS 'Next pipeline stage to run (earlier stages are checkpointed)';
+COMMENT ON COLUMN report_jobs.extracted_data IS 'Checkpointed LLM extraction result, reused by retries';
+COMMENT ON COLUMN report_jobs.next_attempt_at IS 'Earliest time the job may be claimed (retry backoff)';
+COMMENT ON TABLE report_job_errors IS 'Failed attempts of report jobs with the stage that failed';
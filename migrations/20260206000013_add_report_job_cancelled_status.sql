@@ -0,0 +1,8 @@
+-- Migration: Cancelled report jobs
+-- Admins can cancel queued or failed jobs from the job console; cancelled jobs are never
+-- claimed by the processor but can be requeued
+
+ALTER TYPE report_job_status ADD VALUE IF NOT EXISTS 'cancelled';
+
+-- Queue metrics (depth by status, oldest pending job)
+CREATE INDEX idx_report_jobs_status_submitted_at ON report_jobs(status, submitted_at);
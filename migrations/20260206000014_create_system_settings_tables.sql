@@ -0,0 +1,35 @@
+-- Migration: Runtime system settings
+-- Operational knobs (report processor, review queue, attachments, agent) that admins can
+-- change without a redeploy. Keys and their types/ranges are defined in code; a row only
+-- exists when a value overrides the built-in default.
+
+CREATE TABLE system_settings (
+    key VARCHAR(100) PRIMARY KEY,
+    value JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_by VARCHAR(255)
+);
+
+-- Every change, including resets to the default (new_value NULL)
+CREATE TABLE system_setting_audits (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    key VARCHAR(100) NOT NULL,
+    old_value JSONB,
+    new_value JSONB,
+    changed_by VARCHAR(255) NOT NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_system_setting_audits_key ON system_setting_audits(key, changed_at DESC);
+CREATE INDEX idx_system_setting_audits_changed_at ON system_setting_audits(changed_at DESC);
+
+-- The review queue threshold moves into the registry
+INSERT INTO system_settings (key, value, updated_at, updated_by)
+SELECT 'report_review.confidence_threshold', to_jsonb(confidence_threshold), updated_at, updated_by
+FROM report_review_settings;
+
+DROP TABLE report_review_settings;
+
+COMMENT ON TABLE system_settings IS 'Runtime setting overrides; missing keys use the built-in default';
+COMMENT ON TABLE system_setting_audits IS 'Audit log of runtime setting changes';
+COMMENT ON COLUMN system_setting_audits.new_value IS 'New value (NULL when reset to the default)';
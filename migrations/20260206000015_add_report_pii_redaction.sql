@@ -0,0 +1,20 @@
+-- Migration: PII redaction of report content
+-- The processor stores a redacted copy of the extracted title and description; public
+-- endpoints only serve the redacted copy while admins keep access to the original
+
+ALTER TYPE report_job_stage ADD VALUE IF NOT EXISTS 'redact' AFTER 'content';
+
+ALTER TABLE reports
+ADD COLUMN redacted_title TEXT,
+ADD COLUMN redacted_description TEXT,
+ADD COLUMN pii_types TEXT[] NOT NULL DEFAULT '{}',
+ADD COLUMN redacted_at TIMESTAMPTZ;
+
+-- Processed reports still waiting for redaction (backfill)
+CREATE INDEX idx_reports_unredacted ON reports(created_at)
+WHERE redacted_at IS NULL AND title IS NOT NULL;
+
+COMMENT ON COLUMN reports.redacted_title IS 'Title with personal data replaced by placeholders (served publicly)';
+COMMENT ON COLUMN reports.redacted_description IS 'Description with personal data replaced by placeholders (served publicly)';
+COMMENT ON COLUMN reports.pii_types IS 'Kinds of personal data found in the original content (nik, phone, email, plate, name)';
+COMMENT ON COLUMN reports.redacted_at IS 'When the redacted copy was produced (NULL = not redacted yet)';
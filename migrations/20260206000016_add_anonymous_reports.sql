@@ -0,0 +1,21 @@
+-- Migration: Anonymous reporting
+-- Citizens can submit a report anonymously; the reporter and the conversation are hidden
+-- from officials and admins and only revealed to super admins through an audited break-glass
+
+ALTER TABLE reports
+ADD COLUMN is_anonymous BOOLEAN NOT NULL DEFAULT false;
+
+CREATE TABLE report_identity_reveals (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    report_id UUID NOT NULL REFERENCES reports(id) ON DELETE CASCADE,
+    revealed_by VARCHAR(255) NOT NULL,
+    reason TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_report_identity_reveals_report_id ON report_identity_reveals(report_id, created_at);
+CREATE INDEX idx_report_identity_reveals_created_at ON report_identity_reveals(created_at);
+
+COMMENT ON COLUMN reports.is_anonymous IS 'Reporter chose anonymity: user_id and conversation are hidden outside the break-glass endpoint';
+COMMENT ON TABLE report_identity_reveals IS 'Audit log of super admins revealing the reporter of an anonymous report';
+COMMENT ON COLUMN report_identity_reveals.reason IS 'Justification given by the super admin for the reveal';
@@ -0,0 +1,78 @@
+-- Migration: Content moderation
+-- Citizen messages and extracted reports are classified for spam, abuse, off-topic content
+-- and test submissions; flagged reports wait in a moderation queue and flagged users
+-- accumulate abuse counters that the chat rate limiter acts on
+
+ALTER TYPE report_job_stage ADD VALUE IF NOT EXISTS 'moderate' AFTER 'redact';
+
+CREATE TYPE moderation_category AS ENUM (
+    'clean',
+    'spam',        -- Advertising, gibberish, repeated or mass-posted content
+    'abuse',       -- Harassment, threats, hate speech or insults
+    'off_topic',   -- Not a public service issue
+    'test'         -- Test submissions ("tes", "coba", "asdf")
+);
+
+CREATE TYPE moderation_subject AS ENUM (
+    'message',     -- A citizen chat message
+    'report'       -- Extracted report content
+);
+
+CREATE TYPE moderation_source AS ENUM (
+    'keyword',     -- Matched the keyword lists
+    'llm'          -- LLM classifier
+);
+
+CREATE TYPE report_moderation_status AS ENUM (
+    'pending',     -- Processing is held until a moderator decides
+    'approved',    -- Moderator cleared the report; processing resumed
+    'rejected'     -- Moderator rejected the report
+);
+
+CREATE TABLE moderation_verdicts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    subject moderation_subject NOT NULL,
+    report_id UUID REFERENCES reports(id) ON DELETE CASCADE,
+    thread_id UUID,
+    user_id VARCHAR(255),
+    category moderation_category NOT NULL,
+    source moderation_source NOT NULL,
+    confidence DOUBLE PRECISION,
+    matched_terms TEXT[] NOT NULL DEFAULT '{}',
+    reason TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_moderation_verdicts_report_id ON moderation_verdicts(report_id);
+CREATE INDEX idx_moderation_verdicts_user_id ON moderation_verdicts(user_id, created_at);
+CREATE INDEX idx_moderation_verdicts_category ON moderation_verdicts(category, created_at);
+
+CREATE TABLE report_moderations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    report_id UUID NOT NULL UNIQUE REFERENCES reports(id) ON DELETE CASCADE,
+    verdict_id UUID NOT NULL REFERENCES moderation_verdicts(id) ON DELETE CASCADE,
+    status report_moderation_status NOT NULL DEFAULT 'pending',
+    reviewed_by VARCHAR(255),
+    reviewed_at TIMESTAMPTZ,
+    review_note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Index for the moderation queue (oldest first)
+CREATE INDEX idx_report_moderations_status ON report_moderations(status, created_at);
+
+CREATE TABLE user_abuse_counters (
+    user_id VARCHAR(255) PRIMARY KEY,
+    flagged_messages INTEGER NOT NULL DEFAULT 0,
+    flagged_reports INTEGER NOT NULL DEFAULT 0,
+    last_category moderation_category,
+    last_flagged_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+COMMENT ON TABLE moderation_verdicts IS 'Moderation classifications of citizen messages (flagged only) and extracted reports';
+COMMENT ON COLUMN moderation_verdicts.matched_terms IS 'Keyword list entries that matched (empty for LLM verdicts)';
+COMMENT ON COLUMN moderation_verdicts.user_id IS 'Sender of the content; kept for anonymous reports but never exposed with them';
+COMMENT ON TABLE report_moderations IS 'Moderation queue of flagged reports; processing is held while pending';
+COMMENT ON TABLE user_abuse_counters IS 'Spam and abuse flags per user, checked by the chat rate limiter';
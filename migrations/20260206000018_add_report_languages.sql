@@ -0,0 +1,34 @@
+-- Migration: Report languages
+-- Citizens write in regional languages and mixed slang; extraction records the detected
+-- language on the report, keeps the original text in reports.title/description and stores
+-- a normalized Bahasa Indonesia version as a translation for officials
+
+CREATE TYPE report_language AS ENUM (
+    'id',          -- Bahasa Indonesia
+    'jv',          -- Javanese
+    'su',          -- Sundanese
+    'min',         -- Minangkabau
+    'ban',         -- Balinese
+    'en',          -- English
+    'other'        -- Any other language
+);
+
+ALTER TABLE reports ADD COLUMN language report_language;
+
+CREATE TABLE report_translations (
+    report_id UUID NOT NULL REFERENCES reports(id) ON DELETE CASCADE,
+    language report_language NOT NULL,
+    title TEXT,
+    description TEXT,
+    redacted_title TEXT,
+    redacted_description TEXT,
+    redacted_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (report_id, language)
+);
+
+COMMENT ON COLUMN reports.language IS 'Language the citizen wrote in, detected during extraction (NULL for reports processed before detection existed)';
+COMMENT ON TABLE report_translations IS 'Translated title and description of a report; the normalized Bahasa Indonesia version is stored with language id';
+COMMENT ON COLUMN report_translations.redacted_title IS 'Title with personal data replaced by placeholders (public display)';
+COMMENT ON COLUMN report_translations.redacted_description IS 'Description with personal data replaced by placeholders (public display)';
@@ -0,0 +1,34 @@
+-- Migration: Report revisions
+-- Every change to a report's content, categories, tags or location is stored as a revision
+-- with its editor, reason and a per-field diff, from the raw extraction to curator edits
+
+CREATE TYPE report_revision_source AS ENUM (
+    'extraction',  -- Written by the report processor from the conversation
+    'admin'        -- Edited by a curator
+);
+
+CREATE TYPE report_revision_section AS ENUM (
+    'content',     -- Title, description, timeline, impact and the normalized translation
+    'categories',
+    'tags',
+    'location'
+);
+
+CREATE TABLE report_revisions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    report_id UUID NOT NULL REFERENCES reports(id) ON DELETE CASCADE,
+    revision_number INTEGER NOT NULL,
+    source report_revision_source NOT NULL,
+    section report_revision_section NOT NULL,
+    changes JSONB NOT NULL,
+    reason TEXT,
+    edited_by VARCHAR(255),
+    editor_role VARCHAR(50) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (report_id, revision_number)
+);
+
+COMMENT ON TABLE report_revisions IS 'Change history of report content, categories, tags and location';
+COMMENT ON COLUMN report_revisions.revision_number IS 'Sequence number per report, starting at 1';
+COMMENT ON COLUMN report_revisions.changes IS 'Changed fields as {"field": {"old": ..., "new": ...}}';
+COMMENT ON COLUMN report_revisions.edited_by IS 'Curator who made the change (NULL for extraction)';
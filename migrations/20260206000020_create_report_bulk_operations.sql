@@ -0,0 +1,53 @@
+-- Migration: Bulk report operations
+-- Curators can save report filters and apply one action to a list of reports or to every
+-- report matching a saved filter; each batch is stored with its per-report results
+
+CREATE TABLE report_saved_filters (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(100) NOT NULL,
+    filter JSONB NOT NULL,
+    created_by VARCHAR(255) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_report_saved_filters_name ON report_saved_filters(name);
+
+CREATE TYPE report_bulk_action AS ENUM (
+    'update_status',
+    'reject',
+    'set_categories',
+    'add_tags',
+    'remove_tags',
+    'assign'
+);
+
+CREATE TABLE report_bulk_operations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    action report_bulk_action NOT NULL,
+    params JSONB NOT NULL,
+    report_ids UUID[],
+    filter_id UUID REFERENCES report_saved_filters(id) ON DELETE SET NULL,
+    filter JSONB,
+    reason TEXT NOT NULL,
+    performed_by VARCHAR(255) NOT NULL,
+    performer_role VARCHAR(50) NOT NULL,
+    total INTEGER NOT NULL,
+    succeeded INTEGER NOT NULL DEFAULT 0,
+    unchanged INTEGER NOT NULL DEFAULT 0,
+    failed INTEGER NOT NULL DEFAULT 0,
+    results JSONB NOT NULL DEFAULT '[]',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_report_bulk_operations_created_at ON report_bulk_operations(created_at);
+
+COMMENT ON TABLE report_saved_filters IS 'Named report filters shared between curators';
+COMMENT ON COLUMN report_saved_filters.filter IS 'Same fields as the admin report list filter';
+COMMENT ON TABLE report_bulk_operations IS 'Audit log of bulk actions applied to reports';
+COMMENT ON COLUMN report_bulk_operations.params IS 'Action parameters as sent by the curator';
+COMMENT ON COLUMN report_bulk_operations.report_ids IS 'Requested reports (NULL when run against a saved filter)';
+COMMENT ON COLUMN report_bulk_operations.filter IS 'Snapshot of the saved filter at the time of the run';
+COMMENT ON COLUMN report_bulk_operations.results IS 'Per-report outcome as [{"report_id", "outcome", "error"}]';
+COMMENT ON COLUMN report_bulk_operations.completed_at IS 'NULL while the batch is running or if it was interrupted';
@@ -0,0 +1,39 @@
+-- Migration: Report exports
+-- Super admins export filtered reports as CSV, XLSX or GeoJSON; large exports run as
+-- background jobs that upload the file to object storage and notify the requester
+
+CREATE TYPE report_export_format AS ENUM ('csv', 'xlsx', 'geojson');
+
+CREATE TYPE report_export_status AS ENUM (
+    'pending',    -- Waiting for the export worker
+    'running',    -- Claimed by the export worker
+    'completed',  -- File uploaded
+    'failed'
+);
+
+CREATE TABLE report_exports (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    format report_export_format NOT NULL,
+    filter JSONB NOT NULL,
+    status report_export_status NOT NULL DEFAULT 'pending',
+    row_count INTEGER,
+    file_key TEXT,
+    error TEXT,
+    requested_by VARCHAR(255) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    started_at TIMESTAMPTZ,
+    completed_at TIMESTAMPTZ
+);
+
+-- Index for the worker queue
+CREATE INDEX idx_report_exports_queue ON report_exports(created_at)
+    WHERE status IN ('pending', 'running');
+
+CREATE INDEX idx_report_exports_requested_by ON report_exports(requested_by, created_at DESC);
+
+ALTER TYPE notification_type ADD VALUE 'report_export_ready';
+
+COMMENT ON TABLE report_exports IS 'Background report export jobs';
+COMMENT ON COLUMN report_exports.filter IS 'Same fields as the admin report list filter';
+COMMENT ON COLUMN report_exports.file_key IS 'Object key of the uploaded file in the private prefix';
+COMMENT ON COLUMN report_exports.started_at IS 'When the worker claimed the job; stale running jobs are claimed again';
@@ -0,0 +1,27 @@
+-- Migration: Open data feed
+-- Anonymized verified reports are published through a versioned public API and as daily
+-- snapshot files in the public storage prefix
+
+CREATE TYPE open_data_format AS ENUM ('csv', 'geojson');
+
+CREATE TABLE open_data_snapshots (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    snapshot_date DATE NOT NULL,
+    format open_data_format NOT NULL,
+    file_key TEXT NOT NULL,
+    row_count INTEGER NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    -- One file per format per day
+    UNIQUE(snapshot_date, format)
+);
+
+CREATE INDEX idx_open_data_snapshots_date ON open_data_snapshots(snapshot_date DESC);
+
+-- Keyset for the incremental feed (`since` cursors)
+CREATE INDEX idx_reports_updated_at_id ON reports(updated_at, id);
+
+COMMENT ON TABLE open_data_snapshots IS 'Daily snapshot files of the open dataset';
+COMMENT ON COLUMN open_data_snapshots.snapshot_date IS 'Snapshot day in Asia/Jakarta time';
+COMMENT ON COLUMN open_data_snapshots.file_key IS 'Object key of the snapshot file in the public prefix';
@@ -0,0 +1,94 @@
+-- Migration: Full-text search over reports
+-- Reports keep two search vectors over their text and location using Indonesian stemming:
+-- admins search the original text, the public dashboard only the redacted copy
+
+-- Own configuration so stop words or synonyms can be tuned without touching the queries
+CREATE TEXT SEARCH CONFIGURATION report_search (COPY = pg_catalog.indonesian);
+
+ALTER TABLE reports
+ADD COLUMN search_vector TSVECTOR NOT NULL DEFAULT ''::tsvector,
+ADD COLUMN public_search_vector TSVECTOR NOT NULL DEFAULT ''::tsvector;
+
+-- Weighted vector: title (A), description and location (B), timeline and impact (C)
+CREATE FUNCTION report_search_vector(
+    title TEXT,
+    description TEXT,
+    timeline TEXT,
+    impact TEXT,
+    location TEXT
+) RETURNS TSVECTOR LANGUAGE sql IMMUTABLE AS $$
+    SELECT setweight(to_tsvector('report_search', COALESCE(title, '')), 'A')
+        || setweight(to_tsvector('report_search', COALESCE(description, '')), 'B')
+        || setweight(to_tsvector('report_search', COALESCE(location, '')), 'B')
+        || setweight(to_tsvector('report_search', concat_ws(' ', timeline, impact)), 'C')
+$$;
+
+-- Location text of a report as typed by the citizen and as geocoded
+CREATE FUNCTION report_location_text(p_report_id UUID) RETURNS TEXT LANGUAGE sql STABLE AS $$
+    SELECT concat_ws(' ', raw_input, display_name)
+    FROM report_locations
+    WHERE report_id = p_report_id
+$$;
+
+CREATE FUNCTION reports_search_vector_trigger() RETURNS TRIGGER LANGUAGE plpgsql AS $$
+DECLARE
+    location TEXT := report_location_text(NEW.id);
+BEGIN
+    NEW.search_vector := report_search_vector(
+        NEW.title, NEW.description, NEW.timeline, NEW.impact, location
+    );
+    NEW.public_search_vector := report_search_vector(
+        NEW.redacted_title, NEW.redacted_description, NEW.timeline, NEW.impact, location
+    );
+    RETURN NEW;
+END;
+$$;
+
+CREATE TRIGGER trg_reports_search_vector
+    BEFORE INSERT OR UPDATE OF title, description, timeline, impact,
+        redacted_title, redacted_description
+    ON reports
+    FOR EACH ROW EXECUTE FUNCTION reports_search_vector_trigger();
+
+CREATE FUNCTION report_locations_search_vector_trigger() RETURNS TRIGGER LANGUAGE plpgsql AS $$
+DECLARE
+    target_report_id UUID;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        target_report_id := OLD.report_id;
+    ELSE
+        target_report_id := NEW.report_id;
+    END IF;
+
+    UPDATE reports
+    SET search_vector = report_search_vector(
+            title, description, timeline, impact, report_location_text(id)
+        ),
+        public_search_vector = report_search_vector(
+            redacted_title, redacted_description, timeline, impact, report_location_text(id)
+        )
+    WHERE id = target_report_id;
+
+    RETURN NULL;
+END;
+$$;
+
+CREATE TRIGGER trg_report_locations_search_vector
+    AFTER INSERT OR DELETE OR UPDATE OF raw_input, display_name
+    ON report_locations
+    FOR EACH ROW EXECUTE FUNCTION report_locations_search_vector_trigger();
+
+-- Backfill existing reports
+UPDATE reports
+SET search_vector = report_search_vector(
+        title, description, timeline, impact, report_location_text(id)
+    ),
+    public_search_vector = report_search_vector(
+        redacted_title, redacted_description, timeline, impact, report_location_text(id)
+    );
+
+CREATE INDEX idx_reports_search_vector ON reports USING GIN (search_vector);
+CREATE INDEX idx_reports_public_search_vector ON reports USING GIN (public_search_vector);
+
+COMMENT ON COLUMN reports.search_vector IS 'Full-text vector over the original text and location (admin search)';
+COMMENT ON COLUMN reports.public_search_vector IS 'Full-text vector over the redacted text and location (public search)';
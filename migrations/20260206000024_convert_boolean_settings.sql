@@ -0,0 +1,8 @@
+-- Migration: Boolean runtime settings
+-- On/off settings were stored as the integers 0 and 1 and are JSON booleans now.
+-- The audit log keeps the values as they were written.
+
+UPDATE system_settings
+SET value = to_jsonb((value #>> '{}')::numeric <> 0)
+WHERE key IN ('redaction.redact_names', 'moderation.llm_enabled')
+  AND jsonb_typeof(value) = 'number';
@@ -0,0 +1,26 @@
+-- Migration: Track when reports cleared moderation
+-- Extracted reports become public only once they are redacted and cleared by the moderate
+-- stage (clean verdict or moderator approval); new content has to clear it again
+
+ALTER TABLE reports
+ADD COLUMN moderated_at TIMESTAMPTZ;
+
+-- Reports processed before moderation existed, or already past the moderate stage,
+-- count as cleared unless they are still held or were rejected in moderation
+UPDATE reports r
+SET moderated_at = r.updated_at
+WHERE r.title IS NOT NULL
+  AND NOT EXISTS (
+      SELECT 1 FROM report_moderations rm
+      WHERE rm.report_id = r.id AND rm.status <> 'approved'
+  )
+  AND (
+      r.status IN ('verified', 'in_progress', 'resolved')
+      OR EXISTS (
+          SELECT 1 FROM report_jobs j
+          WHERE j.report_id = r.id
+            AND (j.status = 'completed' OR j.stage IN ('classify', 'locate', 'attachments', 'finalize'))
+      )
+  );
+
+COMMENT ON COLUMN reports.moderated_at IS 'When the current content cleared moderation (NULL = not moderated yet or held)';
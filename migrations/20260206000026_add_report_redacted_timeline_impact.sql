@@ -0,0 +1,79 @@
+-- Migration: Redact report timeline and impact
+-- Timeline and impact are served publicly alongside the title and description, so they
+-- get a redacted copy too; the public search vector only covers redacted text and the
+-- geocoded location
+
+ALTER TABLE reports
+ADD COLUMN redacted_timeline TEXT,
+ADD COLUMN redacted_impact TEXT;
+
+-- Geocoded location of a report (the citizen's own wording is left out of public search)
+CREATE FUNCTION report_public_location_text(p_report_id UUID) RETURNS TEXT LANGUAGE sql STABLE AS $$
+    SELECT display_name
+    FROM report_locations
+    WHERE report_id = p_report_id
+$$;
+
+CREATE OR REPLACE FUNCTION reports_search_vector_trigger() RETURNS TRIGGER LANGUAGE plpgsql AS $$
+BEGIN
+    NEW.search_vector := report_search_vector(
+        NEW.title, NEW.description, NEW.timeline, NEW.impact, report_location_text(NEW.id)
+    );
+    NEW.public_search_vector := report_search_vector(
+        NEW.redacted_title, NEW.redacted_description, NEW.redacted_timeline,
+        NEW.redacted_impact, report_public_location_text(NEW.id)
+    );
+    RETURN NEW;
+END;
+$$;
+
+DROP TRIGGER trg_reports_search_vector ON reports;
+
+CREATE TRIGGER trg_reports_search_vector
+    BEFORE INSERT OR UPDATE OF title, description, timeline, impact,
+        redacted_title, redacted_description, redacted_timeline, redacted_impact
+    ON reports
+    FOR EACH ROW EXECUTE FUNCTION reports_search_vector_trigger();
+
+CREATE OR REPLACE FUNCTION report_locations_search_vector_trigger() RETURNS TRIGGER LANGUAGE plpgsql AS $$
+DECLARE
+    target_report_id UUID;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        target_report_id := OLD.report_id;
+    ELSE
+        target_report_id := NEW.report_id;
+    END IF;
+
+    UPDATE reports
+    SET search_vector = report_search_vector(
+            title, description, timeline, impact, report_location_text(id)
+        ),
+        public_search_vector = report_search_vector(
+            redacted_title, redacted_description, redacted_timeline, redacted_impact,
+            report_public_location_text(id)
+        )
+    WHERE id = target_report_id;
+
+    RETURN NULL;
+END;
+$$;
+
+-- Drop the unredacted timeline, impact and typed location from public search; the report
+-- processor redacts the timeline and impact of existing reports on its next runs
+UPDATE reports
+SET public_search_vector = report_search_vector(
+    redacted_title, redacted_description, NULL, NULL, report_public_location_text(id)
+);
+
+DROP INDEX idx_reports_unredacted;
+
+CREATE INDEX idx_reports_unredacted ON reports(created_at)
+WHERE title IS NOT NULL AND (
+    redacted_at IS NULL
+    OR (timeline IS NOT NULL AND redacted_timeline IS NULL)
+    OR (impact IS NOT NULL AND redacted_impact IS NULL)
+);
+
+COMMENT ON COLUMN reports.redacted_timeline IS 'Timeline with personal data replaced by placeholders (served publicly)';
+COMMENT ON COLUMN reports.redacted_impact IS 'Impact with personal data replaced by placeholders (served publicly)';
@@ -22,6 +22,8 @@ pub struct AppConfig {
     pub cors_allowed_origins: Vec<String>,
     pub max_request_body_size: usize,
     pub frontend_url: String,
+    /// Read client IPs from `X-Real-IP`/`X-Forwarded-For` (only behind a reverse proxy)
+    pub trust_proxy_headers: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -164,12 +166,18 @@ impl AppConfig {
         let frontend_url =
             env::var("FRONTEND_URL").unwrap_or_else(|_| "http://localhost:3000".to_string());
 
+        let trust_proxy_headers = env::var("TRUST_PROXY_HEADERS")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse::<bool>()
+            .map_err(|_| "TRUST_PROXY_HEADERS must be true or false".to_string())?;
+
         Ok(Self {
             host,
             port,
             cors_allowed_origins,
             max_request_body_size,
             frontend_url,
+            trust_proxy_headers,
         })
     }
 
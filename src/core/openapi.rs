@@ -1,7 +1,12 @@
 use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
 use utoipa::{Modify, OpenApi};
 
-use crate::features::admin::{dtos as admin_dtos, handlers as admin_handlers};
+use crate::features::admin::{
+    dtos as admin_dtos, handlers as admin_handlers, models as admin_models,
+};
+use crate::features::agencies::{
+    dtos as agencies_dtos, handlers as agencies_handlers, models as agencies_models,
+};
 use crate::features::auth;
 use crate::features::categories::{dtos as categories_dtos, handlers as categories_handlers};
 use crate::features::citizen_report_agent::{
@@ -11,14 +16,31 @@ use crate::features::contributors::{dtos as contributors_dtos, handlers as contr
 use crate::features::dashboard::{dtos as dashboard_dtos, handlers as dashboard_handlers};
 use crate::features::expectations::{dtos as expectations_dtos, handlers as expectations_handlers};
 use crate::features::files::{dtos as files_dtos, handlers as files_handlers};
+use crate::features::moderation::{
+    dtos as moderation_dtos, handlers as moderation_handlers, models as moderation_models,
+};
+use crate::features::notifications::{
+    dtos as notifications_dtos, handlers as notifications_handlers, models as notifications_models,
+};
+use crate::features::open_data::{
+    dtos as open_data_dtos, handlers as open_data_handlers, models as open_data_models,
+};
 use crate::features::prompts::{dtos as prompts_dtos, handlers as prompts_handlers};
 use crate::features::rate_limits::{dtos as rate_limits_dtos, handlers as rate_limits_handlers};
 use crate::features::regions::{dtos as regions_dtos, handlers as regions_handlers};
 use crate::features::reports::{
     dtos as reports_dtos, handlers as reports_handlers, models as reports_models,
 };
+use crate::features::settings::{
+    dtos as settings_dtos, handlers as settings_handlers, models as settings_models,
+};
+use crate::features::sla::{dtos as sla_dtos, handlers as sla_handlers};
+use crate::features::tracking::{dtos as tracking_dtos, handlers as tracking_handlers};
 use crate::features::users::{dtos as users_dtos, handlers::profile_handler};
-use crate::shared::types::{ApiResponse, Meta};
+use crate::features::webhooks::{
+    dtos as webhooks_dtos, handlers as webhooks_handlers, models as webhooks_models,
+};
+use crate::shared::types::{ApiResponse, Meta, SearchMatch, SortDirection};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -84,10 +106,96 @@ use crate::shared::types::{ApiResponse, Meta};
         admin_handlers::get_report,
         admin_handlers::list_contributors,
         admin_handlers::get_contributor,
+        admin_handlers::list_report_filters,
+        admin_handlers::create_report_filter,
+        admin_handlers::delete_report_filter,
+        admin_handlers::run_bulk_operation,
+        admin_handlers::list_bulk_operations,
+        admin_handlers::get_bulk_operation,
+        admin_handlers::export_reports,
+        admin_handlers::create_report_export,
+        admin_handlers::list_report_exports,
+        admin_handlers::get_report_export,
+        admin_handlers::download_report_export,
+        // Agencies (Super Admin)
+        agencies_handlers::agency_handler::create_agency,
+        agencies_handlers::agency_handler::list_agencies,
+        agencies_handlers::agency_handler::get_agency,
+        agencies_handlers::agency_handler::update_agency,
+        agencies_handlers::agency_handler::delete_agency,
+        agencies_handlers::agency_handler::get_jurisdiction,
+        agencies_handlers::agency_handler::update_jurisdiction,
+        agencies_handlers::agency_handler::routing_dry_run,
+        // SLA
+        sla_handlers::sla_handler::list_sla_policies,
+        sla_handlers::sla_handler::create_sla_policy,
+        sla_handlers::sla_handler::update_sla_policy,
+        sla_handlers::sla_handler::delete_sla_policy,
+        sla_handlers::sla_handler::get_sla_overdue_summary,
+        sla_handlers::sla_handler::get_report_sla,
+        // Notifications
+        notifications_handlers::list_notifications,
+        notifications_handlers::get_unread_count,
+        notifications_handlers::mark_notification_read,
+        notifications_handlers::mark_all_notifications_read,
+        // Webhooks
+        webhooks_handlers::list_webhooks,
+        webhooks_handlers::create_webhook,
+        webhooks_handlers::get_webhook,
+        webhooks_handlers::update_webhook,
+        webhooks_handlers::delete_webhook,
+        webhooks_handlers::rotate_webhook_secret,
+        webhooks_handlers::list_webhook_deliveries,
+        webhooks_handlers::get_webhook_delivery,
+        webhooks_handlers::redeliver_webhook_delivery,
+        // Runtime settings (super admin)
+        settings_handlers::list_settings,
+        settings_handlers::list_setting_audit,
+        settings_handlers::get_setting,
+        settings_handlers::update_setting,
+        settings_handlers::reset_setting,
         // Reports
         reports_handlers::report_handler::list_reports,
         reports_handlers::report_handler::get_report,
         reports_handlers::report_handler::update_report_status,
+        reports_handlers::report_handler::get_report_status_history,
+        reports_handlers::assignment_handler::list_assigned_reports,
+        reports_handlers::assignment_handler::claim_report,
+        reports_handlers::assignment_handler::release_report,
+        reports_handlers::assignment_handler::reassign_report,
+        reports_handlers::assignment_handler::assign_report,
+        reports_handlers::assignment_handler::list_report_assignments,
+        reports_handlers::duplicate_handler::list_report_duplicates,
+        reports_handlers::duplicate_handler::list_duplicates_for_report,
+        reports_handlers::update_handler::create_report_update,
+        reports_handlers::update_handler::list_report_updates,
+        reports_handlers::update_handler::delete_report_update,
+        reports_handlers::endorsement_handler::endorse_report,
+        reports_handlers::endorsement_handler::withdraw_endorsement,
+        reports_handlers::endorsement_handler::list_report_endorsements,
+        reports_handlers::duplicate_handler::confirm_report_duplicate,
+        reports_handlers::duplicate_handler::dismiss_report_duplicate,
+        reports_handlers::duplicate_handler::merge_report_duplicate,
+        reports_handlers::review_handler::list_report_reviews,
+        reports_handlers::review_handler::get_report_review,
+        reports_handlers::review_handler::approve_report_review,
+        reports_handlers::review_handler::request_report_info,
+        reports_handlers::review_handler::reject_report_review,
+        reports_handlers::revision_handler::list_report_revisions,
+        reports_handlers::revision_handler::update_report_content,
+        reports_handlers::revision_handler::update_report_categories,
+        reports_handlers::revision_handler::update_report_tags,
+        reports_handlers::revision_handler::update_report_location,
+        reports_handlers::job_handler::list_report_jobs,
+        reports_handlers::job_handler::get_report_job_metrics,
+        reports_handlers::job_handler::get_report_job,
+        reports_handlers::job_handler::requeue_report_job,
+        reports_handlers::job_handler::requeue_failed_report_jobs,
+        reports_handlers::job_handler::cancel_report_job,
+        reports_handlers::job_handler::reextract_report_job,
+        // Anonymous reporter break-glass (super admin)
+        reports_handlers::identity_handler::reveal_report_identity,
+        reports_handlers::identity_handler::list_identity_reveals,
         // Dashboard (public)
         dashboard_handlers::dashboard_handler::get_summary,
         dashboard_handlers::dashboard_handler::list_reports,
@@ -98,11 +206,27 @@ use crate::shared::types::{ApiResponse, Meta};
         dashboard_handlers::dashboard_handler::get_recent,
         dashboard_handlers::dashboard_handler::get_map,
         dashboard_handlers::dashboard_handler::get_map_data,
+        // Report tracking (public)
+        tracking_handlers::track_report,
+        // Open data (public)
+        open_data_handlers::open_data_handler::get_reports,
+        open_data_handlers::open_data_handler::get_dictionary,
+        open_data_handlers::open_data_handler::get_license,
+        open_data_handlers::open_data_handler::list_snapshots,
+        // Moderation (admin curator)
+        moderation_handlers::list_report_moderations,
+        moderation_handlers::get_report_moderation,
+        moderation_handlers::approve_report_moderation,
+        moderation_handlers::reject_report_moderation,
+        moderation_handlers::list_moderation_verdicts,
+        moderation_handlers::list_abuse_counters,
+        moderation_handlers::reset_abuse_counter,
     ),
     components(
         schemas(
             // Shared
             Meta,
+            SearchMatch,
             // Auth
             auth::dto::MeResponseDto,
             auth::model::AuthenticatedUser,
@@ -194,6 +318,7 @@ use crate::shared::types::{ApiResponse, Meta};
             reports_models::ReportStatus,
             reports_models::ReportSeverity,
             reports_models::ReportTagType,
+            reports_models::ReportLanguage,
             reports_models::GeocodingSource,
             reports_dtos::ReportCategoryDto,
             reports_dtos::ReportTagDto,
@@ -201,13 +326,78 @@ use crate::shared::types::{ApiResponse, Meta};
             reports_dtos::ReportDetailResponseDto,
             reports_dtos::ReportLocationResponseDto,
             reports_dtos::UpdateReportStatusDto,
+            reports_dtos::ReportStatusHistoryDto,
             ApiResponse<Vec<reports_dtos::ReportResponseDto>>,
             ApiResponse<reports_dtos::ReportDetailResponseDto>,
             ApiResponse<reports_dtos::ReportResponseDto>,
+            ApiResponse<Vec<reports_dtos::ReportStatusHistoryDto>>,
+            reports_models::ReportAssignmentType,
+            reports_dtos::ReportAssignmentDto,
+            reports_dtos::ClaimReportDto,
+            reports_dtos::ReleaseReportDto,
+            reports_dtos::AssignReportDto,
+            reports_dtos::AssignedReportDto,
+            ApiResponse<reports_dtos::ReportAssignmentDto>,
+            ApiResponse<Vec<reports_dtos::ReportAssignmentDto>>,
+            ApiResponse<Vec<reports_dtos::AssignedReportDto>>,
+            reports_models::ReportDuplicateStatus,
+            reports_dtos::DuplicateReportSummaryDto,
+            reports_dtos::ReportDuplicateDto,
+            reports_dtos::ReviewDuplicateDto,
+            ApiResponse<reports_dtos::ReportDuplicateDto>,
+            ApiResponse<Vec<reports_dtos::ReportDuplicateDto>>,
+            reports_dtos::CreateReportUpdateDto,
+            reports_dtos::ReportUpdateAttachmentDto,
+            reports_dtos::ReportUpdateDto,
+            ApiResponse<reports_dtos::ReportUpdateDto>,
+            ApiResponse<Vec<reports_dtos::ReportUpdateDto>>,
+            reports_dtos::EndorseReportDto,
+            reports_dtos::ReportEndorsementDto,
+            ApiResponse<reports_dtos::ReportEndorsementDto>,
+            ApiResponse<Vec<reports_dtos::ReportEndorsementDto>>,
+            reports_models::ReportReviewStatus,
+            reports_dtos::ReportReviewDto,
+            reports_dtos::ApproveReportReviewDto,
+            reports_dtos::RequestReportInfoDto,
+            reports_dtos::RejectReportReviewDto,
+            ApiResponse<reports_dtos::ReportReviewDto>,
+            ApiResponse<Vec<reports_dtos::ReportReviewDto>>,
+            reports_models::ReportRevisionSource,
+            reports_models::ReportRevisionSection,
+            reports_dtos::ReportRevisionDto,
+            reports_dtos::UpdateReportContentDto,
+            reports_dtos::ReportCategoryInputDto,
+            reports_dtos::UpdateReportCategoriesDto,
+            reports_dtos::UpdateReportTagsDto,
+            reports_dtos::UpdateReportLocationDto,
+            ApiResponse<reports_dtos::ReportRevisionDto>,
+            ApiResponse<Vec<reports_dtos::ReportRevisionDto>>,
+            reports_models::ReportJobStatus,
+            reports_models::ReportJobStage,
+            reports_dtos::ReportJobDto,
+            reports_dtos::ReportJobErrorDto,
+            reports_dtos::ReportJobReportDto,
+            reports_dtos::ReportJobDetailDto,
+            reports_dtos::ReportJobMetricsDto,
+            reports_dtos::RequeueReportJobsResultDto,
+            reports_dtos::CancelReportJobDto,
+            ApiResponse<reports_dtos::ReportJobDto>,
+            ApiResponse<Vec<reports_dtos::ReportJobDto>>,
+            ApiResponse<reports_dtos::ReportJobDetailDto>,
+            ApiResponse<reports_dtos::ReportJobMetricsDto>,
+            ApiResponse<reports_dtos::RequeueReportJobsResultDto>,
+            reports_dtos::RevealReportIdentityDto,
+            reports_dtos::ReportIdentityRevealDto,
+            reports_dtos::ReportIdentityDto,
+            ApiResponse<reports_dtos::ReportIdentityDto>,
+            ApiResponse<Vec<reports_dtos::ReportIdentityRevealDto>>,
             // Dashboard (public)
             dashboard_dtos::PaginationMeta,
             dashboard_dtos::ReportCategoryInfo,
             dashboard_dtos::ReportLocationInfo,
+            dashboard_dtos::ReportUpdateAttachmentInfo,
+            dashboard_dtos::ReportUpdateInfo,
+            dashboard_dtos::DashboardReportSort,
             dashboard_dtos::DashboardReportDto,
             dashboard_dtos::DashboardReportDetailDto,
             dashboard_dtos::ProvinceReportSummary,
@@ -233,9 +423,39 @@ use crate::shared::types::{ApiResponse, Meta};
             dashboard_dtos::MapPointDto,
             dashboard_dtos::DashboardMapDataDto,
             ApiResponse<dashboard_dtos::DashboardMapDataDto>,
+            // Report tracking (public)
+            tracking_dtos::ReportTrackingDto,
+            tracking_dtos::TrackingCategoryDto,
+            tracking_dtos::TrackingRegionDto,
+            tracking_dtos::TrackingStatusChangeDto,
+            tracking_dtos::TrackingUpdateDto,
+            ApiResponse<tracking_dtos::ReportTrackingDto>,
+            // Open data (public)
+            open_data_models::OpenDataFormat,
+            open_data_dtos::OpenDataLicenseDto,
+            open_data_dtos::OpenDataFieldDto,
+            open_data_dtos::OpenDataDictionaryDto,
+            open_data_dtos::OpenDataSnapshotDto,
+            ApiResponse<open_data_dtos::OpenDataDictionaryDto>,
+            ApiResponse<open_data_dtos::OpenDataLicenseDto>,
+            ApiResponse<Vec<open_data_dtos::OpenDataSnapshotDto>>,
+            // Moderation
+            moderation_models::ModerationCategory,
+            moderation_models::ModerationSubject,
+            moderation_models::ModerationSource,
+            moderation_models::ReportModerationStatus,
+            moderation_dtos::ReportModerationDto,
+            moderation_dtos::ModerationVerdictDto,
+            moderation_dtos::UserAbuseCounterDto,
+            moderation_dtos::ApproveReportModerationDto,
+            moderation_dtos::RejectReportModerationDto,
+            ApiResponse<moderation_dtos::ReportModerationDto>,
+            ApiResponse<Vec<moderation_dtos::ReportModerationDto>>,
+            ApiResponse<Vec<moderation_dtos::ModerationVerdictDto>>,
+            ApiResponse<Vec<moderation_dtos::UserAbuseCounterDto>>,
             // Admin
-            admin_dtos::SortDirection,
-            admin_dtos::ReportSortBy,
+            SortDirection,
+            reports_dtos::ReportSortBy,
             admin_dtos::AdminExpectationDto,
             admin_dtos::AdminReportDto,
             admin_dtos::AdminReportDetailDto,
@@ -250,6 +470,91 @@ use crate::shared::types::{ApiResponse, Meta};
             ApiResponse<admin_dtos::AdminReportDetailDto>,
             ApiResponse<Vec<admin_dtos::AdminContributorDto>>,
             ApiResponse<admin_dtos::AdminContributorDetailDto>,
+            reports_dtos::ReportFilter,
+            admin_dtos::ReportSavedFilterDto,
+            admin_dtos::CreateReportSavedFilterDto,
+            admin_dtos::BulkReportActionDto,
+            admin_dtos::CreateReportBulkOperationDto,
+            admin_dtos::BulkItemOutcome,
+            admin_dtos::BulkReportItemResultDto,
+            admin_dtos::ReportBulkOperationDto,
+            admin_models::ReportBulkAction,
+            ApiResponse<Vec<admin_dtos::ReportSavedFilterDto>>,
+            ApiResponse<admin_dtos::ReportSavedFilterDto>,
+            ApiResponse<admin_dtos::ReportBulkOperationDto>,
+            ApiResponse<Vec<admin_dtos::ReportBulkOperationDto>>,
+            admin_models::ReportExportFormat,
+            admin_models::ReportExportStatus,
+            admin_dtos::CreateReportExportDto,
+            admin_dtos::ReportExportDto,
+            ApiResponse<admin_dtos::ReportExportDto>,
+            ApiResponse<Vec<admin_dtos::ReportExportDto>>,
+            // Agencies
+            agencies_models::RegionLevel,
+            agencies_dtos::AgencyQueryParams,
+            agencies_dtos::CreateAgencyDto,
+            agencies_dtos::UpdateAgencyDto,
+            agencies_dtos::AgencyResponseDto,
+            agencies_dtos::AgencyDetailDto,
+            agencies_dtos::JurisdictionRegionDto,
+            agencies_dtos::JurisdictionDto,
+            agencies_dtos::UpdateJurisdictionDto,
+            agencies_dtos::ReportAgencyRouteDto,
+            agencies_dtos::RoutingDryRunDto,
+            agencies_dtos::RoutingCandidateDto,
+            agencies_dtos::RoutingDryRunResponseDto,
+            ApiResponse<agencies_dtos::AgencyResponseDto>,
+            ApiResponse<Vec<agencies_dtos::AgencyResponseDto>>,
+            ApiResponse<agencies_dtos::AgencyDetailDto>,
+            ApiResponse<agencies_dtos::JurisdictionDto>,
+            ApiResponse<agencies_dtos::RoutingDryRunResponseDto>,
+            // SLA
+            sla_dtos::SlaPolicyQueryParams,
+            sla_dtos::CreateSlaPolicyDto,
+            sla_dtos::UpdateSlaPolicyDto,
+            sla_dtos::SlaPolicyResponseDto,
+            sla_dtos::ReportSlaDeadlineDto,
+            sla_dtos::ReportSlaEscalationDto,
+            sla_dtos::ReportSlaDto,
+            sla_dtos::SlaOverdueSummaryDto,
+            ApiResponse<sla_dtos::SlaPolicyResponseDto>,
+            ApiResponse<Vec<sla_dtos::SlaPolicyResponseDto>>,
+            ApiResponse<sla_dtos::ReportSlaDto>,
+            ApiResponse<sla_dtos::SlaOverdueSummaryDto>,
+            // Notifications
+            notifications_models::NotificationType,
+            notifications_models::NotificationPayload,
+            notifications_dtos::NotificationDto,
+            notifications_dtos::UnreadCountDto,
+            notifications_dtos::MarkAllReadDto,
+            ApiResponse<Vec<notifications_dtos::NotificationDto>>,
+            ApiResponse<notifications_dtos::NotificationDto>,
+            ApiResponse<notifications_dtos::UnreadCountDto>,
+            ApiResponse<notifications_dtos::MarkAllReadDto>,
+            // Webhooks
+            webhooks_models::WebhookEventType,
+            webhooks_models::WebhookDeliveryStatus,
+            webhooks_dtos::CreateWebhookSubscriptionDto,
+            webhooks_dtos::UpdateWebhookSubscriptionDto,
+            webhooks_dtos::WebhookSubscriptionDto,
+            webhooks_dtos::WebhookSubscriptionSecretDto,
+            webhooks_dtos::WebhookDeliveryDto,
+            webhooks_dtos::WebhookDeliveryAttemptDto,
+            webhooks_dtos::WebhookDeliveryDetailDto,
+            ApiResponse<Vec<webhooks_dtos::WebhookSubscriptionDto>>,
+            ApiResponse<webhooks_dtos::WebhookSubscriptionDto>,
+            ApiResponse<webhooks_dtos::WebhookSubscriptionSecretDto>,
+            ApiResponse<Vec<webhooks_dtos::WebhookDeliveryDto>>,
+            ApiResponse<webhooks_dtos::WebhookDeliveryDto>,
+            ApiResponse<webhooks_dtos::WebhookDeliveryDetailDto>,
+            // Runtime settings
+            settings_models::SettingKind,
+            settings_dtos::SystemSettingDto,
+            settings_dtos::UpdateSystemSettingDto,
+            settings_dtos::SystemSettingAuditDto,
+            ApiResponse<settings_dtos::SystemSettingDto>,
+            ApiResponse<Vec<settings_dtos::SystemSettingDto>>,
+            ApiResponse<Vec<settings_dtos::SystemSettingAuditDto>>,
         )
     ),
     tags(
@@ -265,6 +570,14 @@ use crate::shared::types::{ApiResponse, Meta};
         (name = "Dashboard", description = "Public dashboard for viewing reports"),
         (name = "rate-limits", description = "Rate limit configuration (admin only)"),
         (name = "admin", description = "Admin endpoints (super admin only)"),
+        (name = "agencies", description = "Agencies, jurisdictions and report routing (super admin only)"),
+        (name = "sla", description = "SLA policies, report deadlines and escalations"),
+        (name = "notifications", description = "In-app notification inbox"),
+        (name = "webhooks", description = "Outbound webhook subscriptions and delivery log (super admin only)"),
+        (name = "settings", description = "Runtime system settings and their audit log (super admin only)"),
+        (name = "tracking", description = "Public report tracking by reference number (rate limited)"),
+        (name = "open-data", description = "Versioned open data feed of anonymized verified reports (public)"),
+        (name = "moderation", description = "Spam and abuse moderation queue, verdicts and abuse counters (admin curator)"),
     ),
     modifiers(&SecurityAddon),
     info(
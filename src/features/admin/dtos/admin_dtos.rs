@@ -3,30 +3,12 @@ use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::features::reports::models::{ReportSeverity, ReportStatus, ReportTagType};
+use crate::features::reports::dtos::{ReportFilter, ReportSort, ReportSortBy};
+use crate::features::reports::models::{
+    ReportLanguage, ReportSeverity, ReportStatus, ReportTagType,
+};
 use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
-
-// =============================================================================
-// COMMON SORT ENUM
-// =============================================================================
-
-/// Sort direction
-#[derive(Debug, Clone, Copy, Default, Deserialize, ToSchema)]
-#[serde(rename_all = "lowercase")]
-pub enum SortDirection {
-    #[default]
-    Desc,
-    Asc,
-}
-
-impl SortDirection {
-    pub fn as_sql(&self) -> &'static str {
-        match self {
-            SortDirection::Asc => "ASC",
-            SortDirection::Desc => "DESC",
-        }
-    }
-}
+use crate::shared::types::{deserialize_list, search_text, SearchMatch, SortDirection};
 
 // =============================================================================
 // EXPECTATION DTOs
@@ -82,7 +64,7 @@ pub struct AdminExpectationDto {
 /// Query params for listing reports
 #[derive(Debug, Clone, Deserialize, IntoParams)]
 pub struct ReportQueryParams {
-    /// Page number (1-indexed)
+    /// Page number (1-indexed, ignored with `cursor`)
     #[serde(default = "default_page")]
     #[param(minimum = 1)]
     pub page: i64,
@@ -90,50 +72,62 @@ pub struct ReportQueryParams {
     #[serde(default = "default_page_size")]
     #[param(minimum = 1, maximum = 100)]
     pub page_size: i64,
-    /// Filter by status
-    pub status: Option<ReportStatus>,
-    /// Filter from date (YYYY-MM-DD)
+    /// Cursor from `meta.next_cursor` of the previous page (keyset pagination)
+    pub cursor: Option<String>,
+    /// Filter by status (comma-separated, any of)
+    #[serde(default, deserialize_with = "deserialize_list")]
+    #[param(style = Form, explode = false)]
+    pub status: Vec<ReportStatus>,
+    /// Created from date (YYYY-MM-DD)
     pub from_date: Option<NaiveDate>,
-    /// Filter to date (YYYY-MM-DD)
+    /// Created to date, inclusive (YYYY-MM-DD)
     pub to_date: Option<NaiveDate>,
+    /// Verified from date (YYYY-MM-DD)
+    pub verified_from: Option<NaiveDate>,
+    /// Verified to date, inclusive (YYYY-MM-DD)
+    pub verified_to: Option<NaiveDate>,
+    /// Resolved from date (YYYY-MM-DD)
+    pub resolved_from: Option<NaiveDate>,
+    /// Resolved to date, inclusive (YYYY-MM-DD)
+    pub resolved_to: Option<NaiveDate>,
     /// Search in reference_number or title
     pub search: Option<String>,
-    /// Filter by user_id
+    /// Full-text search over title, description, timeline, impact and location
+    /// (Indonesian stemming, web search syntax); results are ranked by relevance
+    pub q: Option<String>,
+    /// Filter by user_id (anonymous reports are excluded)
     pub user_id: Option<String>,
     /// Filter by platform
     pub platform: Option<String>,
-    /// Filter reports with attachments only
+    /// Filter reports with or without attachments
     pub has_attachments: Option<bool>,
-    /// Sort by field (default: created_at)
-    #[serde(default)]
-    pub sort_by: ReportSortBy,
+    /// Filter by severity of any assigned category (comma-separated, any of)
+    #[serde(default, deserialize_with = "deserialize_list")]
+    #[param(style = Form, explode = false)]
+    pub severity: Vec<ReportSeverity>,
+    /// Filter by assigned category including child categories (comma-separated, any of)
+    #[serde(default, deserialize_with = "deserialize_list")]
+    #[param(style = Form, explode = false)]
+    pub category_id: Vec<Uuid>,
+    /// Filter by tag (comma-separated, any of)
+    #[serde(default, deserialize_with = "deserialize_list")]
+    #[param(style = Form, explode = false)]
+    pub tag_type: Vec<ReportTagType>,
+    /// Filter by province of the report location
+    pub province_id: Option<Uuid>,
+    /// Filter by regency of the report location
+    pub regency_id: Option<Uuid>,
+    /// Filter by district of the report location
+    pub district_id: Option<Uuid>,
+    /// Filter by village of the report location
+    pub village_id: Option<Uuid>,
+    /// Sort by field (default: relevance with `q`, otherwise created_at)
+    pub sort_by: Option<ReportSortBy>,
     /// Sort direction (default: desc)
     #[serde(default)]
     pub sort: SortDirection,
 }
 
-/// Sort fields for reports
-#[derive(Debug, Clone, Copy, Default, Deserialize, ToSchema)]
-#[serde(rename_all = "snake_case")]
-pub enum ReportSortBy {
-    #[default]
-    CreatedAt,
-    UpdatedAt,
-    Status,
-    ReferenceNumber,
-}
-
-impl ReportSortBy {
-    pub fn as_sql(&self) -> &'static str {
-        match self {
-            ReportSortBy::CreatedAt => "created_at",
-            ReportSortBy::UpdatedAt => "updated_at",
-            ReportSortBy::Status => "status",
-            ReportSortBy::ReferenceNumber => "reference_number",
-        }
-    }
-}
-
 impl ReportQueryParams {
     pub fn offset(&self) -> i64 {
         (self.page.max(1) - 1) * self.limit()
@@ -141,6 +135,40 @@ impl ReportQueryParams {
     pub fn limit(&self) -> i64 {
         self.page_size.clamp(1, MAX_PAGE_SIZE)
     }
+
+    /// Filter part of the query
+    pub fn filter(&self) -> ReportFilter {
+        ReportFilter {
+            status: self.status.clone(),
+            from_date: self.from_date,
+            to_date: self.to_date,
+            verified_from: self.verified_from,
+            verified_to: self.verified_to,
+            resolved_from: self.resolved_from,
+            resolved_to: self.resolved_to,
+            search: self.search.clone(),
+            q: self.q.clone(),
+            user_id: self.user_id.clone(),
+            platform: self.platform.clone(),
+            has_attachments: self.has_attachments,
+            severity: self.severity.clone(),
+            category_id: self.category_id.clone(),
+            tag_type: self.tag_type.clone(),
+            province_id: self.province_id,
+            regency_id: self.regency_id,
+            district_id: self.district_id,
+            village_id: self.village_id,
+        }
+    }
+
+    /// Sort of the query
+    pub fn sort(&self) -> ReportSort {
+        ReportSort::new(
+            self.sort_by,
+            self.sort,
+            search_text(self.q.as_deref()).is_some(),
+        )
+    }
 }
 
 /// Admin view of report (list) - optimized with summary fields
@@ -149,8 +177,14 @@ pub struct AdminReportDto {
     pub id: Uuid,
     pub reference_number: Option<String>,
     pub title: Option<String>,
+    /// Language the citizen wrote in (None for reports processed before detection)
+    pub language: Option<ReportLanguage>,
+    /// Title in Bahasa Indonesia when the citizen wrote in another language
+    pub normalized_title: Option<String>,
     pub status: ReportStatus,
+    /// Citizen who submitted the report (None for anonymous reports)
     pub user_id: Option<String>,
+    pub is_anonymous: bool,
     pub platform: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -162,6 +196,8 @@ pub struct AdminReportDto {
     pub location_summary: Option<String>,
     /// Number of attachments
     pub attachment_count: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<SearchMatch>,
 }
 
 /// Admin view of report detail (single)
@@ -173,10 +209,29 @@ pub struct AdminReportDetailDto {
     pub description: Option<String>,
     pub timeline: Option<String>,
     pub impact: Option<String>,
+    /// Language the citizen wrote in (None for reports processed before detection)
+    pub language: Option<ReportLanguage>,
+    /// Title in Bahasa Indonesia when the citizen wrote in another language
+    pub normalized_title: Option<String>,
+    /// Description in Bahasa Indonesia when the citizen wrote in another language
+    pub normalized_description: Option<String>,
+    /// Public title with personal data redacted (None until redacted)
+    pub redacted_title: Option<String>,
+    /// Public description with personal data redacted (None until redacted)
+    pub redacted_description: Option<String>,
+    /// Public timeline with personal data redacted (None until redacted)
+    pub redacted_timeline: Option<String>,
+    /// Public impact with personal data redacted (None until redacted)
+    pub redacted_impact: Option<String>,
+    /// Kinds of personal data found in the original content (nik, phone, email, plate, name)
+    pub pii_types: Vec<String>,
     pub status: ReportStatus,
+    /// Citizen who submitted the report (None for anonymous reports, see the identity endpoint)
     pub user_id: Option<String>,
     pub platform: Option<String>,
+    /// Conversation thread (None for anonymous reports, see the identity endpoint)
     pub adk_thread_id: Option<Uuid>,
+    pub is_anonymous: bool,
     pub verified_at: Option<DateTime<Utc>>,
     pub verified_by: Option<String>,
     pub resolved_at: Option<DateTime<Utc>>,
@@ -0,0 +1,267 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::features::admin::models::{ReportBulkAction, ReportBulkOperation, ReportSavedFilter};
+use crate::features::reports::dtos::ReportCategoryInputDto;
+use crate::features::reports::dtos::ReportFilter;
+use crate::features::reports::models::{ReportStatus, ReportTagType};
+use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+// =============================================================================
+// SAVED FILTER DTOs
+// =============================================================================
+
+/// Response DTO for a saved report filter
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReportSavedFilterDto {
+    pub id: Uuid,
+    pub name: String,
+    pub filter: ReportFilter,
+    /// Curator who saved the filter
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<ReportSavedFilter> for ReportSavedFilterDto {
+    fn from(f: ReportSavedFilter) -> Self {
+        Self {
+            id: f.id,
+            name: f.name,
+            filter: serde_json::from_value(f.filter).unwrap_or_default(),
+            created_by: f.created_by,
+            created_at: f.created_at,
+            updated_at: f.updated_at,
+        }
+    }
+}
+
+/// Request DTO for saving a report filter
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct CreateReportSavedFilterDto {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    pub filter: ReportFilter,
+}
+
+// =============================================================================
+// BULK OPERATION DTOs
+// =============================================================================
+
+/// Action applied to every report of a bulk operation
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BulkReportActionDto {
+    /// Move reports to a status (the lifecycle is checked per report)
+    UpdateStatus {
+        status: ReportStatus,
+        resolution_notes: Option<String>,
+    },
+    /// Reject reports
+    Reject { resolution_notes: Option<String> },
+    /// Replace the categories of reports
+    SetCategories {
+        categories: Vec<ReportCategoryInputDto>,
+    },
+    /// Add tags, keeping the existing ones
+    AddTags { tags: Vec<ReportTagType> },
+    /// Remove tags
+    RemoveTags { tags: Vec<ReportTagType> },
+    /// Assign reports to an official
+    Assign {
+        assignee_id: String,
+        note: Option<String>,
+    },
+}
+
+impl BulkReportActionDto {
+    pub fn kind(&self) -> ReportBulkAction {
+        match self {
+            BulkReportActionDto::UpdateStatus { .. } => ReportBulkAction::UpdateStatus,
+            BulkReportActionDto::Reject { .. } => ReportBulkAction::Reject,
+            BulkReportActionDto::SetCategories { .. } => ReportBulkAction::SetCategories,
+            BulkReportActionDto::AddTags { .. } => ReportBulkAction::AddTags,
+            BulkReportActionDto::RemoveTags { .. } => ReportBulkAction::RemoveTags,
+            BulkReportActionDto::Assign { .. } => ReportBulkAction::Assign,
+        }
+    }
+
+    /// Check the action parameters (same limits as the single-report endpoints)
+    pub fn check(&self) -> Result<(), String> {
+        match self {
+            BulkReportActionDto::UpdateStatus {
+                resolution_notes, ..
+            }
+            | BulkReportActionDto::Reject { resolution_notes } => {
+                if resolution_notes.as_ref().is_some_and(|n| n.len() > 2000) {
+                    return Err("resolution_notes must be at most 2000 characters".to_string());
+                }
+            }
+            BulkReportActionDto::SetCategories { categories } => {
+                if categories.is_empty() || categories.len() > 10 {
+                    return Err("categories must contain 1 to 10 entries".to_string());
+                }
+            }
+            BulkReportActionDto::AddTags { tags } | BulkReportActionDto::RemoveTags { tags } => {
+                if tags.is_empty() || tags.len() > 5 {
+                    return Err("tags must contain 1 to 5 entries".to_string());
+                }
+            }
+            BulkReportActionDto::Assign { assignee_id, note } => {
+                if assignee_id.trim().is_empty() {
+                    return Err("assignee_id must not be empty".to_string());
+                }
+                if note.as_ref().is_some_and(|n| n.len() > 2000) {
+                    return Err("note must be at most 2000 characters".to_string());
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Request DTO for a bulk operation
+///
+/// Exactly one of `report_ids` and `filter_id` must be given.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct CreateReportBulkOperationDto {
+    pub action: BulkReportActionDto,
+    /// Reports to change
+    #[validate(length(min = 1, max = 1000))]
+    pub report_ids: Option<Vec<Uuid>>,
+    /// Saved filter selecting the reports to change
+    pub filter_id: Option<Uuid>,
+    /// Why the batch is applied (stored in the audit log and the report histories)
+    #[validate(length(min = 1, max = 2000))]
+    pub reason: String,
+}
+
+/// Outcome of a bulk operation for one report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkItemOutcome {
+    /// The action changed the report
+    Applied,
+    /// The report was already in the requested state
+    Unchanged,
+    /// The action was refused for this report; see `error`
+    Failed,
+}
+
+/// Result of a bulk operation for one report
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkReportItemResultDto {
+    pub report_id: Uuid,
+    pub outcome: BulkItemOutcome,
+    pub error: Option<String>,
+}
+
+/// Response DTO for a bulk operation
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReportBulkOperationDto {
+    pub id: Uuid,
+    pub action: ReportBulkAction,
+    /// Action parameters as sent by the curator
+    pub params: Value,
+    /// Requested reports (null when run against a saved filter)
+    pub report_ids: Option<Vec<Uuid>>,
+    pub filter_id: Option<Uuid>,
+    /// Filter as it was when the batch ran
+    pub filter: Option<ReportFilter>,
+    pub reason: String,
+    pub performed_by: String,
+    pub performer_role: String,
+    pub total: i32,
+    pub succeeded: i32,
+    pub unchanged: i32,
+    pub failed: i32,
+    /// Per-report results (omitted in listings)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub results: Option<Vec<BulkReportItemResultDto>>,
+    pub created_at: DateTime<Utc>,
+    /// Null while the batch is running or if it was interrupted
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl From<ReportBulkOperation> for ReportBulkOperationDto {
+    fn from(o: ReportBulkOperation) -> Self {
+        Self {
+            id: o.id,
+            action: o.action,
+            params: o.params,
+            report_ids: o.report_ids,
+            filter_id: o.filter_id,
+            filter: o.filter.and_then(|f| serde_json::from_value(f).ok()),
+            reason: o.reason,
+            performed_by: o.performed_by,
+            performer_role: o.performer_role,
+            total: o.total,
+            succeeded: o.succeeded,
+            unchanged: o.unchanged,
+            failed: o.failed,
+            results: serde_json::from_value(o.results).ok(),
+            created_at: o.created_at,
+            completed_at: o.completed_at,
+        }
+    }
+}
+
+/// Query parameters for listing bulk operations
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct ReportBulkOperationQueryParams {
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl ReportBulkOperationQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bulk_actions() {
+        let dto: CreateReportBulkOperationDto = serde_json::from_value(serde_json::json!({
+            "action": { "type": "add_tags", "tags": ["complaint"] },
+            "report_ids": ["7f1c7a55-2a29-4c0f-9a54-6c2f3bbd9a11"],
+            "reason": "Flood reports are complaints"
+        }))
+        .unwrap();
+        assert_eq!(dto.action.kind(), ReportBulkAction::AddTags);
+        assert!(dto.action.check().is_ok());
+
+        let reject: BulkReportActionDto =
+            serde_json::from_value(serde_json::json!({ "type": "reject" })).unwrap();
+        assert_eq!(reject.kind(), ReportBulkAction::Reject);
+
+        let empty: BulkReportActionDto =
+            serde_json::from_value(serde_json::json!({ "type": "remove_tags", "tags": [] }))
+                .unwrap();
+        assert!(empty.check().is_err());
+    }
+}
@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::features::admin::models::{ReportExport, ReportExportFormat, ReportExportStatus};
+use crate::features::reports::dtos::ReportFilter;
+use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+/// Query param selecting the export format
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct ReportExportFormatParams {
+    /// File format
+    pub format: ReportExportFormat,
+}
+
+/// Request DTO for a background report export
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateReportExportDto {
+    pub format: ReportExportFormat,
+    #[serde(default)]
+    pub filter: ReportFilter,
+}
+
+/// Response DTO for a background report export
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReportExportDto {
+    pub id: Uuid,
+    pub format: ReportExportFormat,
+    pub filter: ReportFilter,
+    pub status: ReportExportStatus,
+    /// Number of exported reports (set once completed)
+    pub row_count: Option<i32>,
+    pub error: Option<String>,
+    pub requested_by: String,
+    /// Endpoint redirecting to a fresh download link (set once completed)
+    pub download_path: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl From<ReportExport> for ReportExportDto {
+    fn from(e: ReportExport) -> Self {
+        let download_path = (e.status == ReportExportStatus::Completed)
+            .then(|| format!("/api/admin/report-exports/{}/download", e.id));
+
+        Self {
+            id: e.id,
+            format: e.format,
+            filter: serde_json::from_value(e.filter).unwrap_or_default(),
+            status: e.status,
+            row_count: e.row_count,
+            error: e.error,
+            requested_by: e.requested_by,
+            download_path,
+            created_at: e.created_at,
+            started_at: e.started_at,
+            completed_at: e.completed_at,
+        }
+    }
+}
+
+/// Query parameters for listing background exports
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct ReportExportQueryParams {
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl ReportExportQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
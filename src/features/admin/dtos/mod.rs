@@ -1,3 +1,7 @@
 mod admin_dtos;
+mod bulk_dtos;
+mod export_dtos;
 
 pub use admin_dtos::*;
+pub use bulk_dtos::*;
+pub use export_dtos::*;
@@ -41,7 +41,7 @@ pub async fn list_expectations(
     Ok(Json(ApiResponse::success(
         Some(items),
         None,
-        Some(Meta { total }),
+        Some(Meta::new(total)),
     )))
 }
 
@@ -83,6 +83,7 @@ pub async fn get_expectation(
     params(ReportQueryParams),
     responses(
         (status = 200, description = "List of reports with attachments", body = ApiResponse<Vec<AdminReportDto>>),
+        (status = 400, description = "Invalid cursor"),
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden - Super admin access required")
     ),
@@ -96,12 +97,12 @@ pub async fn list_reports(
     State(service): State<Arc<AdminService>>,
     Query(params): Query<ReportQueryParams>,
 ) -> Result<Json<ApiResponse<Vec<AdminReportDto>>>> {
-    let (items, total) = service.list_reports(&params).await?;
+    let (items, total, next_cursor) = service.list_reports(&params).await?;
 
     Ok(Json(ApiResponse::success(
         Some(items),
         None,
-        Some(Meta { total }),
+        Some(Meta::with_cursor(total, next_cursor)),
     )))
 }
 
@@ -161,7 +162,7 @@ pub async fn list_contributors(
     Ok(Json(ApiResponse::success(
         Some(items),
         None,
-        Some(Meta { total }),
+        Some(Meta::new(total)),
     )))
 }
 
@@ -0,0 +1,218 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::core::error::{AppError, Result};
+use crate::core::extractor::AppJson;
+use crate::features::admin::dtos::*;
+use crate::features::admin::services::ReportBulkService;
+use crate::features::auth::guards::RequireAdminCurator;
+use crate::shared::types::{ApiResponse, Meta};
+
+// =============================================================================
+// SAVED FILTER HANDLERS
+// =============================================================================
+
+/// List saved report filters (admin curator)
+#[utoipa::path(
+    get,
+    path = "/api/admin/report-filters",
+    responses(
+        (status = 200, description = "Saved report filters", body = ApiResponse<Vec<ReportSavedFilterDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_report_filters(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<ReportBulkService>>,
+) -> Result<Json<ApiResponse<Vec<ReportSavedFilterDto>>>> {
+    let filters = service.list_filters().await?;
+    let total = filters.len() as i64;
+    let dtos = filters
+        .into_iter()
+        .map(ReportSavedFilterDto::from)
+        .collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Save a report filter for bulk operations (admin curator)
+#[utoipa::path(
+    post,
+    path = "/api/admin/report-filters",
+    request_body = CreateReportSavedFilterDto,
+    responses(
+        (status = 200, description = "Filter saved", body = ApiResponse<ReportSavedFilterDto>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_report_filter(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ReportBulkService>>,
+    AppJson(dto): AppJson<CreateReportSavedFilterDto>,
+) -> Result<Json<ApiResponse<ReportSavedFilterDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let filter = service.create_filter(&dto, &user.sub).await?;
+    Ok(Json(ApiResponse::success(
+        Some(filter.into()),
+        Some("Filter saved".to_string()),
+        None,
+    )))
+}
+
+/// Delete a saved report filter (its creator or a super admin)
+#[utoipa::path(
+    delete,
+    path = "/api/admin/report-filters/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Saved filter ID")
+    ),
+    responses(
+        (status = 200, description = "Filter deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Only the creator or a super admin"),
+        (status = 404, description = "Filter not found")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_report_filter(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ReportBulkService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>> {
+    service
+        .delete_filter(id, &user.sub, user.is_super_admin())
+        .await?;
+    Ok(Json(ApiResponse::success(None, None, None)))
+}
+
+// =============================================================================
+// BULK OPERATION HANDLERS
+// =============================================================================
+
+/// Apply one action to many reports (admin curator)
+///
+/// Targets either `report_ids` or every report matching a saved filter (at most 1000).
+/// Reports are changed in transactional chunks; a report refused by the lifecycle or
+/// validation is reported as failed without undoing the others. The batch is stored in
+/// the bulk operation audit log.
+#[utoipa::path(
+    post,
+    path = "/api/admin/reports/bulk",
+    request_body = CreateReportBulkOperationDto,
+    responses(
+        (status = 200, description = "Batch completed with per-report results", body = ApiResponse<ReportBulkOperationDto>),
+        (status = 400, description = "Validation error, or the filter matches no or too many reports"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Saved filter not found")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn run_bulk_operation(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ReportBulkService>>,
+    AppJson(dto): AppJson<CreateReportBulkOperationDto>,
+) -> Result<Json<ApiResponse<ReportBulkOperationDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let operation = service.run(&dto, &user.sub, user.primary_role()).await?;
+    let message = format!(
+        "{} applied, {} unchanged, {} failed",
+        operation.succeeded, operation.unchanged, operation.failed
+    );
+
+    Ok(Json(ApiResponse::success(
+        Some(operation.into()),
+        Some(message),
+        None,
+    )))
+}
+
+/// List the bulk operation audit log, newest first (admin curator)
+///
+/// Per-report results are only included in the detail endpoint.
+#[utoipa::path(
+    get,
+    path = "/api/admin/report-bulk-operations",
+    params(ReportBulkOperationQueryParams),
+    responses(
+        (status = 200, description = "Bulk operations", body = ApiResponse<Vec<ReportBulkOperationDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_bulk_operations(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<ReportBulkService>>,
+    Query(params): Query<ReportBulkOperationQueryParams>,
+) -> Result<Json<ApiResponse<Vec<ReportBulkOperationDto>>>> {
+    let (items, total) = service
+        .list_operations(params.limit(), params.offset())
+        .await?;
+    let dtos = items
+        .into_iter()
+        .map(|o| ReportBulkOperationDto {
+            results: None,
+            ..o.into()
+        })
+        .collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Get a bulk operation with its per-report results (admin curator)
+#[utoipa::path(
+    get,
+    path = "/api/admin/report-bulk-operations/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Bulk operation ID")
+    ),
+    responses(
+        (status = 200, description = "Bulk operation", body = ApiResponse<ReportBulkOperationDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Bulk operation not found")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_bulk_operation(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<ReportBulkService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ReportBulkOperationDto>>> {
+    let operation = service.get_operation(id).await?;
+    Ok(Json(ApiResponse::success(
+        Some(operation.into()),
+        None,
+        None,
+    )))
+}
@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::{IntoResponse, Redirect, Response},
+    Json,
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::core::error::Result;
+use crate::core::extractor::AppJson;
+use crate::features::admin::dtos::*;
+use crate::features::admin::services::ReportExportService;
+use crate::features::auth::guards::RequireSuperAdmin;
+use crate::shared::types::{ApiResponse, Meta};
+
+/// Download the reports matching the admin report filters
+///
+/// Takes the same filters as `GET /api/admin/reports` (paging and sort are ignored).
+/// Categories are flattened with their severities and attachments as URLs. Exports
+/// above the direct limit must use `POST /api/admin/report-exports`.
+#[utoipa::path(
+    get,
+    path = "/api/admin/reports/export",
+    params(ReportExportFormatParams, ReportQueryParams),
+    responses(
+        (status = 200, description = "Export file (CSV, XLSX or GeoJSON)", content_type = "application/octet-stream", body = Vec<u8>),
+        (status = 400, description = "Too many reports for a direct export"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn export_reports(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<ReportExportService>>,
+    Query(format): Query<ReportExportFormatParams>,
+    Query(params): Query<ReportQueryParams>,
+) -> Result<Response> {
+    let format = format.format;
+    let data = service.export(&params.filter(), format).await?;
+    let filename = format!(
+        "reports-{}.{}",
+        Utc::now().format("%Y%m%d-%H%M%S"),
+        format.extension()
+    );
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            ),
+        ],
+        data,
+    )
+        .into_response())
+}
+
+/// Queue a background export (super admin)
+///
+/// For exports above the direct limit. The file is uploaded to storage and the
+/// requester gets a notification with the download path when it is ready.
+#[utoipa::path(
+    post,
+    path = "/api/admin/report-exports",
+    request_body = CreateReportExportDto,
+    responses(
+        (status = 200, description = "Export queued", body = ApiResponse<ReportExportDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn create_report_export(
+    RequireSuperAdmin(user): RequireSuperAdmin,
+    State(service): State<Arc<ReportExportService>>,
+    AppJson(dto): AppJson<CreateReportExportDto>,
+) -> Result<Json<ApiResponse<ReportExportDto>>> {
+    let job = service
+        .create_job(&dto.filter, dto.format, &user.sub)
+        .await?;
+    Ok(Json(ApiResponse::success(
+        Some(job.into()),
+        Some("Export queued".to_string()),
+        None,
+    )))
+}
+
+/// List background exports, newest first (super admin)
+#[utoipa::path(
+    get,
+    path = "/api/admin/report-exports",
+    params(ReportExportQueryParams),
+    responses(
+        (status = 200, description = "Report exports", body = ApiResponse<Vec<ReportExportDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_report_exports(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<ReportExportService>>,
+    Query(params): Query<ReportExportQueryParams>,
+) -> Result<Json<ApiResponse<Vec<ReportExportDto>>>> {
+    let (items, total) = service.list_jobs(params.limit(), params.offset()).await?;
+    let dtos = items.into_iter().map(ReportExportDto::from).collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Get a background export (super admin)
+#[utoipa::path(
+    get,
+    path = "/api/admin/report-exports/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Export ID")
+    ),
+    responses(
+        (status = 200, description = "Report export", body = ApiResponse<ReportExportDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required"),
+        (status = 404, description = "Export not found")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_report_export(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<ReportExportService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ReportExportDto>>> {
+    let job = service.get_job(id).await?;
+    Ok(Json(ApiResponse::success(Some(job.into()), None, None)))
+}
+
+/// Download a completed background export (super admin)
+///
+/// Redirects to a short-lived presigned storage URL.
+#[utoipa::path(
+    get,
+    path = "/api/admin/report-exports/{id}/download",
+    params(
+        ("id" = Uuid, Path, description = "Export ID")
+    ),
+    responses(
+        (status = 307, description = "Redirect to the export file"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required"),
+        (status = 404, description = "Export not found"),
+        (status = 409, description = "Export is not ready")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn download_report_export(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<ReportExportService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Redirect> {
+    let url = service.download_url(id).await?;
+    Ok(Redirect::temporary(&url))
+}
@@ -1,3 +1,7 @@
 mod admin_handlers;
+mod bulk_handlers;
+mod export_handlers;
 
 pub use admin_handlers::*;
+pub use bulk_handlers::*;
+pub use export_handlers::*;
@@ -0,0 +1,7 @@
+mod report_bulk_operation;
+mod report_export;
+mod report_saved_filter;
+
+pub use report_bulk_operation::{ReportBulkAction, ReportBulkOperation};
+pub use report_export::{ReportExport, ReportExportFormat, ReportExportRow, ReportExportStatus};
+pub use report_saved_filter::ReportSavedFilter;
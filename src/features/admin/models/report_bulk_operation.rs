@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Action applied by a bulk report operation, matching database enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "report_bulk_action", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportBulkAction {
+    UpdateStatus,
+    Reject,
+    SetCategories,
+    AddTags,
+    RemoveTags,
+    Assign,
+}
+
+impl std::fmt::Display for ReportBulkAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportBulkAction::UpdateStatus => write!(f, "update_status"),
+            ReportBulkAction::Reject => write!(f, "reject"),
+            ReportBulkAction::SetCategories => write!(f, "set_categories"),
+            ReportBulkAction::AddTags => write!(f, "add_tags"),
+            ReportBulkAction::RemoveTags => write!(f, "remove_tags"),
+            ReportBulkAction::Assign => write!(f, "assign"),
+        }
+    }
+}
+
+/// Database model for a bulk report operation (audit record of the batch)
+#[derive(Debug, Clone, FromRow)]
+pub struct ReportBulkOperation {
+    pub id: Uuid,
+    pub action: ReportBulkAction,
+    /// Action parameters as sent by the curator
+    pub params: serde_json::Value,
+    /// Requested reports (None when run against a saved filter)
+    pub report_ids: Option<Vec<Uuid>>,
+    pub filter_id: Option<Uuid>,
+    /// Snapshot of the saved filter at the time of the run
+    pub filter: Option<serde_json::Value>,
+    pub reason: String,
+    pub performed_by: String,
+    pub performer_role: String,
+    pub total: i32,
+    pub succeeded: i32,
+    pub unchanged: i32,
+    pub failed: i32,
+    /// Per-report outcomes
+    pub results: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::features::reports::models::{ReportLanguage, ReportStatus};
+
+/// File format of a report export, matching database enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "report_export_format", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ReportExportFormat {
+    Csv,
+    Xlsx,
+    Geojson,
+}
+
+impl ReportExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ReportExportFormat::Csv => "text/csv; charset=utf-8",
+            ReportExportFormat::Xlsx => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+            ReportExportFormat::Geojson => "application/geo+json",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReportExportFormat::Csv => "csv",
+            ReportExportFormat::Xlsx => "xlsx",
+            ReportExportFormat::Geojson => "geojson",
+        }
+    }
+}
+
+impl std::fmt::Display for ReportExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.extension())
+    }
+}
+
+/// Status of a background report export, matching database enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "report_export_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportExportStatus {
+    /// Waiting for the export worker
+    Pending,
+    /// Claimed by the export worker
+    Running,
+    /// File uploaded
+    Completed,
+    Failed,
+}
+
+/// Database model for a background report export
+#[derive(Debug, Clone, FromRow)]
+pub struct ReportExport {
+    pub id: Uuid,
+    pub format: ReportExportFormat,
+    /// Serialized `ReportFilter`
+    pub filter: serde_json::Value,
+    pub status: ReportExportStatus,
+    pub row_count: Option<i32>,
+    /// Object key of the uploaded file
+    pub file_key: Option<String>,
+    pub error: Option<String>,
+    pub requested_by: String,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// One exported report, flattened for spreadsheets and map layers
+#[derive(Debug, Clone, FromRow)]
+pub struct ReportExportRow {
+    pub id: Uuid,
+    pub reference_number: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub status: ReportStatus,
+    pub language: Option<ReportLanguage>,
+    pub platform: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    /// Categories as "Name (severity)"
+    pub categories: Vec<String>,
+    pub tags: Vec<String>,
+    pub location: Option<String>,
+    pub lat: Option<f64>,
+    pub lon: Option<f64>,
+    pub province: Option<String>,
+    pub regency: Option<String>,
+    pub district: Option<String>,
+    pub village: Option<String>,
+    pub attachment_urls: Vec<String>,
+}
@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for a saved report filter
+#[derive(Debug, Clone, FromRow)]
+pub struct ReportSavedFilter {
+    pub id: Uuid,
+    pub name: String,
+    /// Serialized `ReportFilter`
+    pub filter: serde_json::Value,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
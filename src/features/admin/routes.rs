@@ -1,9 +1,12 @@
 use std::sync::Arc;
 
-use axum::{routing::get, Router};
+use axum::{
+    routing::{delete, get, post},
+    Router,
+};
 
 use crate::features::admin::handlers;
-use crate::features::admin::services::AdminService;
+use crate::features::admin::services::{AdminService, ReportBulkService, ReportExportService};
 
 /// Create admin routes (all require super admin access)
 pub fn routes(admin_service: Arc<AdminService>) -> Router {
@@ -19,3 +22,48 @@ pub fn routes(admin_service: Arc<AdminService>) -> Router {
         .route("/contributors/{id}", get(handlers::get_contributor))
         .with_state(admin_service)
 }
+
+/// Create the saved filter and bulk operation routes (admin curator access)
+pub fn bulk_routes(bulk_service: Arc<ReportBulkService>) -> Router {
+    Router::new()
+        .route(
+            "/api/admin/report-filters",
+            get(handlers::list_report_filters).post(handlers::create_report_filter),
+        )
+        .route(
+            "/api/admin/report-filters/{id}",
+            delete(handlers::delete_report_filter),
+        )
+        .route(
+            "/api/admin/reports/bulk",
+            post(handlers::run_bulk_operation),
+        )
+        .route(
+            "/api/admin/report-bulk-operations",
+            get(handlers::list_bulk_operations),
+        )
+        .route(
+            "/api/admin/report-bulk-operations/{id}",
+            get(handlers::get_bulk_operation),
+        )
+        .with_state(bulk_service)
+}
+
+/// Create the report export routes (super admin access)
+pub fn export_routes(export_service: Arc<ReportExportService>) -> Router {
+    Router::new()
+        .route("/api/admin/reports/export", get(handlers::export_reports))
+        .route(
+            "/api/admin/report-exports",
+            get(handlers::list_report_exports).post(handlers::create_report_export),
+        )
+        .route(
+            "/api/admin/report-exports/{id}",
+            get(handlers::get_report_export),
+        )
+        .route(
+            "/api/admin/report-exports/{id}/download",
+            get(handlers::download_report_export),
+        )
+        .with_state(export_service)
+}
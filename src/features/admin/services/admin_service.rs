@@ -3,7 +3,12 @@ use uuid::Uuid;
 
 use crate::core::error::{AppError, Result};
 use crate::features::admin::dtos::*;
-use crate::features::reports::models::{ReportSeverity, ReportStatus, ReportTagType};
+use crate::features::reports::dtos::ReportFilterScope;
+use crate::features::reports::models::{
+    ReportLanguage, ReportSeverity, ReportStatus, ReportTagType,
+};
+use crate::shared::constants::SEARCH_HEADLINE_OPTIONS;
+use crate::shared::types::{KeysetCursor, SearchMatch};
 
 /// Service for admin queries
 pub struct AdminService {
@@ -157,77 +162,54 @@ impl AdminService {
     // =========================================================================
 
     /// List reports with pagination and filters (optimized single query)
+    ///
+    /// Returns the page, the total count and the cursor for the next page. A `cursor`
+    /// continues after the previous page (keyset pagination) instead of using `page`.
     pub async fn list_reports(
         &self,
         params: &ReportQueryParams,
-    ) -> Result<(Vec<AdminReportDto>, i64)> {
-        let offset = params.offset();
+    ) -> Result<(Vec<AdminReportDto>, i64, Option<String>)> {
         let limit = params.limit();
-        let sort_by = params.sort_by.as_sql();
-        let sort_dir = params.sort.as_sql();
-
-        // Build WHERE clause dynamically
-        let mut conditions = Vec::new();
-        let mut args: Vec<String> = Vec::new();
-
-        if let Some(ref status) = params.status {
-            args.push(status.to_string());
-            conditions.push(format!("r.status = ${}::report_status", args.len()));
-        }
-
-        if let Some(from_date) = params.from_date {
-            args.push(from_date.to_string());
-            conditions.push(format!("r.created_at >= ${}::date", args.len()));
-        }
-
-        if let Some(to_date) = params.to_date {
-            args.push(to_date.to_string());
-            conditions.push(format!(
-                "r.created_at < (${}::date + interval '1 day')",
-                args.len()
-            ));
-        }
+        let sort = params.sort();
+        let cursor = params
+            .cursor
+            .as_deref()
+            .map(|c| KeysetCursor::decode(c, &sort.name()))
+            .transpose()?;
+        let offset = if cursor.is_some() { 0 } else { params.offset() };
 
-        if let Some(ref search) = params.search {
-            args.push(format!("%{}%", search.to_lowercase()));
-            conditions.push(format!(
-                "(LOWER(r.reference_number) LIKE ${0} OR LOWER(r.title) LIKE ${0})",
-                args.len()
-            ));
-        }
-
-        if let Some(ref user_id) = params.user_id {
-            args.push(user_id.clone());
-            conditions.push(format!("r.user_id = ${}", args.len()));
-        }
+        let filter = params.filter();
+        let (where_clause, mut args) = filter.where_clause(ReportFilterScope::Admin);
 
-        if let Some(ref platform) = params.platform {
-            args.push(platform.clone());
-            conditions.push(format!("r.platform = ${}", args.len()));
-        }
+        // Get total count
+        let count_query = format!(r#"SELECT COUNT(*) FROM reports r {}"#, where_clause);
+        let total: i64 = self.execute_count_query(&count_query, &args).await?;
 
-        if let Some(has_attachments) = params.has_attachments {
-            if has_attachments {
-                conditions.push(
-                    "EXISTS (SELECT 1 FROM report_attachments WHERE report_id = r.id)".to_string(),
-                );
-            } else {
-                conditions.push(
-                    "NOT EXISTS (SELECT 1 FROM report_attachments WHERE report_id = r.id)"
-                        .to_string(),
+        // Rank and highlight full-text matches
+        let (rank, search_columns) = match filter.search_text() {
+            Some(q) => {
+                args.push(q.to_string());
+                let tsquery = format!("websearch_to_tsquery('report_search', ${})", args.len());
+                args.push(SEARCH_HEADLINE_OPTIONS.to_string());
+                let rank = format!("ts_rank_cd(r.search_vector, {})", tsquery);
+                let columns = format!(
+                    "{} as rank, \
+                     ts_headline('report_search', concat_ws(' … ', r.title, r.description, rl.raw_input, r.timeline, r.impact), {}, ${}) as snippet",
+                    rank,
+                    tsquery,
+                    args.len()
                 );
+                (Some(rank), columns)
             }
-        }
-
-        let where_clause = if conditions.is_empty() {
-            String::new()
-        } else {
-            format!("WHERE {}", conditions.join(" AND "))
+            None => (
+                None,
+                "NULL::real as rank, NULL::text as snippet".to_string(),
+            ),
         };
 
-        // Get total count
-        let count_query = format!(r#"SELECT COUNT(*) FROM reports r {}"#, where_clause);
-        let total: i64 = self.execute_count_query(&count_query, &args).await?;
+        let (sort_key, _) = sort.key(rank.as_deref());
+        let order_by = sort.order_by(rank.as_deref());
+        let where_clause = sort.after(where_clause, cursor.as_ref(), rank.as_deref(), &mut args);
 
         // Optimized single query with LEFT JOINs and subqueries for aggregated data
         let data_query = format!(
@@ -236,18 +218,24 @@ impl AdminService {
                 r.id,
                 r.reference_number,
                 r.title,
+                r.language,
+                tr.title as normalized_title,
                 r.status,
-                r.user_id,
+                CASE WHEN r.is_anonymous THEN NULL ELSE r.user_id END as user_id,
+                r.is_anonymous,
                 r.platform,
                 r.created_at,
                 r.updated_at,
                 COALESCE(cat_agg.category_count, 0) as category_count,
                 cat_agg.primary_category,
                 COALESCE(rl.city, rg.name, rl.display_name) as location_summary,
-                COALESCE(att_agg.attachment_count, 0) as attachment_count
+                COALESCE(att_agg.attachment_count, 0) as attachment_count,
+                {},
+                ({})::text as sort_key
             FROM reports r
             LEFT JOIN report_locations rl ON rl.report_id = r.id
             LEFT JOIN regencies rg ON rg.id = rl.regency_id
+            LEFT JOIN report_translations tr ON tr.report_id = r.id AND tr.language = 'id'
             LEFT JOIN LATERAL (
                 SELECT
                     COUNT(*) as category_count,
@@ -261,42 +249,35 @@ impl AdminService {
             LEFT JOIN LATERAL (
                 SELECT COUNT(*) as attachment_count
                 FROM report_attachments ra
-                WHERE ra.report_id = r.id
+                WHERE ra.report_id = r.id AND ra.report_update_id IS NULL
             ) att_agg ON true
             {}
-            ORDER BY r.{} {}
+            ORDER BY {}
             OFFSET {} LIMIT {}
             "#,
-            where_clause, sort_by, sort_dir, offset, limit
+            search_columns, sort_key, where_clause, order_by, offset, limit
         );
 
-        let items = self.execute_reports_list_query(&data_query, &args).await?;
-
-        Ok((items, total))
-    }
+        let rows = self.execute_reports_list_query(&data_query, &args).await?;
 
-    async fn execute_reports_list_query(
-        &self,
-        query: &str,
-        args: &[String],
-    ) -> Result<Vec<AdminReportDto>> {
-        let mut sqlx_query = sqlx::query_as::<_, ReportListRow>(query);
-        for arg in args {
-            sqlx_query = sqlx_query.bind(arg);
-        }
-        let rows = sqlx_query.fetch_all(&self.pool).await.map_err(|e| {
-            tracing::error!("Failed to execute reports list query: {:?}", e);
-            AppError::Database(e)
-        })?;
+        // A full page may have more rows after it
+        let next_cursor = rows
+            .last()
+            .filter(|_| rows.len() as i64 == limit)
+            .map(|r| KeysetCursor::new(sort.name(), r.sort_key.clone(), r.id).encode())
+            .transpose()?;
 
-        Ok(rows
+        let items = rows
             .into_iter()
             .map(|r| AdminReportDto {
                 id: r.id,
                 reference_number: r.reference_number,
                 title: r.title,
+                language: r.language,
+                normalized_title: r.normalized_title,
                 status: r.status,
                 user_id: r.user_id,
+                is_anonymous: r.is_anonymous,
                 platform: r.platform,
                 created_at: r.created_at,
                 updated_at: r.updated_at,
@@ -304,8 +285,26 @@ impl AdminService {
                 primary_category: r.primary_category,
                 location_summary: r.location_summary,
                 attachment_count: r.attachment_count,
+                search: SearchMatch::from_columns(r.rank, r.snippet),
             })
-            .collect())
+            .collect();
+
+        Ok((items, total, next_cursor))
+    }
+
+    async fn execute_reports_list_query(
+        &self,
+        query: &str,
+        args: &[String],
+    ) -> Result<Vec<ReportListRow>> {
+        let mut sqlx_query = sqlx::query_as::<_, ReportListRow>(query);
+        for arg in args {
+            sqlx_query = sqlx_query.bind(arg);
+        }
+        sqlx_query.fetch_all(&self.pool).await.map_err(|e| {
+            tracing::error!("Failed to execute reports list query: {:?}", e);
+            AppError::Database(e)
+        })
     }
 
     /// Get a single report by ID with full details
@@ -313,15 +312,21 @@ impl AdminService {
         let row = sqlx::query!(
             r#"
             SELECT
-                id, reference_number,
-                title, description, timeline, impact,
-                status as "status: ReportStatus",
-                user_id, platform, adk_thread_id,
-                verified_at, verified_by,
-                resolved_at, resolved_by, resolution_notes,
-                created_at, updated_at
-            FROM reports
-            WHERE id = $1
+                r.id, r.reference_number,
+                r.title, r.description, r.timeline, r.impact,
+                r.language as "language: ReportLanguage",
+                tr.title as "normalized_title?",
+                tr.description as "normalized_description?",
+                r.redacted_title, r.redacted_description,
+                r.redacted_timeline, r.redacted_impact, r.pii_types,
+                r.status as "status: ReportStatus",
+                r.user_id, r.platform, r.adk_thread_id, r.is_anonymous,
+                r.verified_at, r.verified_by,
+                r.resolved_at, r.resolved_by, r.resolution_notes,
+                r.created_at, r.updated_at
+            FROM reports r
+            LEFT JOIN report_translations tr ON tr.report_id = r.id AND tr.language = 'id'
+            WHERE r.id = $1
             "#,
             id
         )
@@ -345,10 +350,19 @@ impl AdminService {
             description: row.description,
             timeline: row.timeline,
             impact: row.impact,
+            language: row.language,
+            normalized_title: row.normalized_title,
+            normalized_description: row.normalized_description,
+            redacted_title: row.redacted_title,
+            redacted_description: row.redacted_description,
+            redacted_timeline: row.redacted_timeline,
+            redacted_impact: row.redacted_impact,
+            pii_types: row.pii_types,
             status: row.status,
-            user_id: row.user_id,
+            user_id: row.user_id.filter(|_| !row.is_anonymous),
             platform: row.platform,
-            adk_thread_id: row.adk_thread_id,
+            adk_thread_id: row.adk_thread_id.filter(|_| !row.is_anonymous),
+            is_anonymous: row.is_anonymous,
             verified_at: row.verified_at,
             verified_by: row.verified_by,
             resolved_at: row.resolved_at,
@@ -472,7 +486,7 @@ impl AdminService {
                 f.url
             FROM report_attachments ra
             JOIN files f ON f.id = ra.file_id
-            WHERE ra.report_id = $1
+            WHERE ra.report_id = $1 AND ra.report_update_id IS NULL
             ORDER BY ra.created_at
             "#,
             report_id
@@ -668,8 +682,11 @@ struct ReportListRow {
     id: Uuid,
     reference_number: Option<String>,
     title: Option<String>,
+    language: Option<ReportLanguage>,
+    normalized_title: Option<String>,
     status: ReportStatus,
     user_id: Option<String>,
+    is_anonymous: bool,
     platform: Option<String>,
     created_at: chrono::DateTime<chrono::Utc>,
     updated_at: chrono::DateTime<chrono::Utc>,
@@ -677,6 +694,9 @@ struct ReportListRow {
     primary_category: Option<String>,
     location_summary: Option<String>,
     attachment_count: i64,
+    rank: Option<f32>,
+    snippet: Option<String>,
+    sort_key: String,
 }
 
 #[derive(sqlx::FromRow)]
@@ -1,3 +1,8 @@
 mod admin_service;
+mod report_bulk_service;
+mod report_export_service;
+mod report_export_writer;
 
 pub use admin_service::AdminService;
+pub use report_bulk_service::ReportBulkService;
+pub use report_export_service::ReportExportService;
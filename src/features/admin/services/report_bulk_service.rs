@@ -0,0 +1,551 @@
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::admin::dtos::{
+    BulkItemOutcome, BulkReportActionDto, BulkReportItemResultDto, CreateReportBulkOperationDto,
+    CreateReportSavedFilterDto,
+};
+use crate::features::admin::models::{ReportBulkAction, ReportBulkOperation, ReportSavedFilter};
+use crate::features::reports::dtos::{ReportFilter, ReportFilterScope, UpdateReportStatusDto};
+use crate::features::reports::models::{ReportStatus, ReportTagType};
+use crate::features::reports::services::{
+    ReportAssignmentService, ReportRevisionService, ReportService, RevisionAuthor,
+};
+
+/// Maximum number of reports a single bulk operation may touch
+const MAX_BULK_REPORTS: i64 = 1000;
+
+/// Reports applied per transaction
+const BULK_CHUNK_SIZE: usize = 50;
+
+/// Curator running a bulk operation
+struct BulkActor<'a> {
+    user_id: &'a str,
+    role: &'a str,
+    reason: &'a str,
+}
+
+/// Service for saved report filters and bulk report operations
+///
+/// A batch runs in chunks of `BULK_CHUNK_SIZE` reports, one transaction per chunk. Each
+/// report is applied in its own savepoint so a refused report does not undo the others.
+pub struct ReportBulkService {
+    pool: PgPool,
+}
+
+impl ReportBulkService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // ===== Saved Filters =====
+
+    /// List saved filters by name
+    pub async fn list_filters(&self) -> Result<Vec<ReportSavedFilter>> {
+        sqlx::query_as!(
+            ReportSavedFilter,
+            r#"
+            SELECT id, name, filter, created_by, created_at, updated_at
+            FROM report_saved_filters
+            ORDER BY name ASC, created_at ASC
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list saved report filters: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Save a report filter
+    pub async fn create_filter(
+        &self,
+        dto: &CreateReportSavedFilterDto,
+        created_by: &str,
+    ) -> Result<ReportSavedFilter> {
+        let filter = serde_json::to_value(&dto.filter)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize filter: {}", e)))?;
+
+        let saved = sqlx::query_as!(
+            ReportSavedFilter,
+            r#"
+            INSERT INTO report_saved_filters (name, filter, created_by)
+            VALUES ($1, $2, $3)
+            RETURNING id, name, filter, created_by, created_at, updated_at
+            "#,
+            dto.name.trim(),
+            filter,
+            created_by
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to save report filter: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!("Report filter {} saved by {}", saved.id, created_by);
+        Ok(saved)
+    }
+
+    /// Delete a saved filter (its creator or a super admin)
+    pub async fn delete_filter(&self, id: Uuid, user_id: &str, is_super_admin: bool) -> Result<()> {
+        let filter = self.get_filter(id).await?;
+        if filter.created_by != user_id && !is_super_admin {
+            return Err(AppError::Forbidden(
+                "Only the creator can delete this filter".to_string(),
+            ));
+        }
+
+        sqlx::query!("DELETE FROM report_saved_filters WHERE id = $1", id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to delete report filter: {:?}", e);
+                AppError::Database(e)
+            })?;
+
+        tracing::info!("Report filter {} deleted by {}", id, user_id);
+        Ok(())
+    }
+
+    async fn get_filter(&self, id: Uuid) -> Result<ReportSavedFilter> {
+        sqlx::query_as!(
+            ReportSavedFilter,
+            r#"
+            SELECT id, name, filter, created_by, created_at, updated_at
+            FROM report_saved_filters
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report filter: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report filter {} not found", id)))
+    }
+
+    // ===== Bulk Operations =====
+
+    /// Apply an action to a list of reports or to the reports matching a saved filter
+    ///
+    /// The batch is recorded before it starts and completed with the per-report results.
+    pub async fn run(
+        &self,
+        dto: &CreateReportBulkOperationDto,
+        user_id: &str,
+        role: &str,
+    ) -> Result<ReportBulkOperation> {
+        dto.action.check().map_err(AppError::Validation)?;
+
+        let (report_ids, filter) = match (&dto.report_ids, dto.filter_id) {
+            (Some(ids), None) => {
+                let mut unique = Vec::with_capacity(ids.len());
+                for id in ids {
+                    if !unique.contains(id) {
+                        unique.push(*id);
+                    }
+                }
+                (unique, None)
+            }
+            (None, Some(filter_id)) => {
+                let saved = self.get_filter(filter_id).await?;
+                let filter: ReportFilter =
+                    serde_json::from_value(saved.filter.clone()).map_err(|e| {
+                        AppError::Internal(format!("Saved filter {} is invalid: {}", filter_id, e))
+                    })?;
+                (self.resolve_filter(&filter).await?, Some(saved.filter))
+            }
+            _ => {
+                return Err(AppError::BadRequest(
+                    "Provide either report_ids or filter_id".to_string(),
+                ))
+            }
+        };
+
+        let params = serde_json::to_value(&dto.action)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize action: {}", e)))?;
+
+        let operation = sqlx::query_as!(
+            ReportBulkOperation,
+            r#"
+            INSERT INTO report_bulk_operations (
+                action, params, report_ids, filter_id, filter, reason,
+                performed_by, performer_role, total
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING
+                id, action as "action: ReportBulkAction", params, report_ids, filter_id, filter,
+                reason, performed_by, performer_role, total, succeeded, unchanged, failed,
+                results, created_at, completed_at
+            "#,
+            dto.action.kind() as ReportBulkAction,
+            params,
+            dto.report_ids.as_ref().map(|_| report_ids.as_slice()),
+            dto.filter_id,
+            filter,
+            dto.reason.trim(),
+            user_id,
+            role,
+            report_ids.len() as i32
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to record bulk report operation: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let actor = BulkActor {
+            user_id,
+            role,
+            reason: dto.reason.trim(),
+        };
+
+        let mut results = Vec::with_capacity(report_ids.len());
+        for chunk in report_ids.chunks(BULK_CHUNK_SIZE) {
+            results.extend(self.apply_chunk(chunk, &dto.action, &actor).await);
+        }
+
+        let count = |outcome| results.iter().filter(|r| r.outcome == outcome).count() as i32;
+        let (succeeded, unchanged, failed) = (
+            count(BulkItemOutcome::Applied),
+            count(BulkItemOutcome::Unchanged),
+            count(BulkItemOutcome::Failed),
+        );
+        let results_json = serde_json::to_value(&results)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize results: {}", e)))?;
+
+        let operation = sqlx::query_as!(
+            ReportBulkOperation,
+            r#"
+            UPDATE report_bulk_operations
+            SET succeeded = $2, unchanged = $3, failed = $4, results = $5, completed_at = NOW()
+            WHERE id = $1
+            RETURNING
+                id, action as "action: ReportBulkAction", params, report_ids, filter_id, filter,
+                reason, performed_by, performer_role, total, succeeded, unchanged, failed,
+                results, created_at, completed_at
+            "#,
+            operation.id,
+            succeeded,
+            unchanged,
+            failed,
+            results_json
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to complete bulk report operation: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!(
+            "Bulk {} {} by {}: {} applied, {} unchanged, {} failed",
+            operation.action,
+            operation.id,
+            user_id,
+            succeeded,
+            unchanged,
+            failed
+        );
+        Ok(operation)
+    }
+
+    /// List bulk operations, newest first
+    pub async fn list_operations(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<ReportBulkOperation>, i64)> {
+        let items = sqlx::query_as!(
+            ReportBulkOperation,
+            r#"
+            SELECT
+                id, action as "action: ReportBulkAction", params, report_ids, filter_id, filter,
+                reason, performed_by, performer_role, total, succeeded, unchanged, failed,
+                results, created_at, completed_at
+            FROM report_bulk_operations
+            ORDER BY created_at DESC
+            LIMIT $1 OFFSET $2
+            "#,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list bulk report operations: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let total =
+            sqlx::query_scalar!(r#"SELECT COUNT(*) as "count!" FROM report_bulk_operations"#)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to count bulk report operations: {:?}", e);
+                    AppError::Database(e)
+                })?;
+
+        Ok((items, total))
+    }
+
+    /// Get a bulk operation with its per-report results
+    pub async fn get_operation(&self, id: Uuid) -> Result<ReportBulkOperation> {
+        sqlx::query_as!(
+            ReportBulkOperation,
+            r#"
+            SELECT
+                id, action as "action: ReportBulkAction", params, report_ids, filter_id, filter,
+                reason, performed_by, performer_role, total, succeeded, unchanged, failed,
+                results, created_at, completed_at
+            FROM report_bulk_operations
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get bulk report operation: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Bulk operation {} not found", id)))
+    }
+
+    /// IDs of the reports matching a filter, oldest first
+    ///
+    /// Fails if the filter matches more than `MAX_BULK_REPORTS` reports.
+    async fn resolve_filter(&self, filter: &ReportFilter) -> Result<Vec<Uuid>> {
+        let (where_clause, args) = filter.where_clause(ReportFilterScope::Admin);
+        let query = format!(
+            "SELECT r.id FROM reports r {} ORDER BY r.created_at ASC LIMIT {}",
+            where_clause,
+            MAX_BULK_REPORTS + 1
+        );
+
+        let mut sqlx_query = sqlx::query_scalar::<_, Uuid>(&query);
+        for arg in &args {
+            sqlx_query = sqlx_query.bind(arg);
+        }
+        let ids = sqlx_query.fetch_all(&self.pool).await.map_err(|e| {
+            tracing::error!("Failed to resolve report filter: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        if ids.len() as i64 > MAX_BULK_REPORTS {
+            return Err(AppError::BadRequest(format!(
+                "Filter matches more than {} reports; narrow it down",
+                MAX_BULK_REPORTS
+            )));
+        }
+        if ids.is_empty() {
+            return Err(AppError::BadRequest(
+                "Filter matches no reports".to_string(),
+            ));
+        }
+        Ok(ids)
+    }
+
+    /// Apply the action to one chunk in a single transaction
+    async fn apply_chunk(
+        &self,
+        report_ids: &[Uuid],
+        action: &BulkReportActionDto,
+        actor: &BulkActor<'_>,
+    ) -> Vec<BulkReportItemResultDto> {
+        let failed_all = |message: String| {
+            report_ids
+                .iter()
+                .map(|&report_id| BulkReportItemResultDto {
+                    report_id,
+                    outcome: BulkItemOutcome::Failed,
+                    error: Some(message.clone()),
+                })
+                .collect()
+        };
+
+        let mut tx = match self.pool.begin().await {
+            Ok(tx) => tx,
+            Err(e) => {
+                tracing::error!("Failed to begin bulk chunk transaction: {:?}", e);
+                return failed_all("Database unavailable".to_string());
+            }
+        };
+
+        let mut results = Vec::with_capacity(report_ids.len());
+        for &report_id in report_ids {
+            let outcome = match Self::apply_one(&mut tx, report_id, action, actor).await {
+                Ok(true) => BulkItemOutcome::Applied,
+                Ok(false) => BulkItemOutcome::Unchanged,
+                Err(e) => {
+                    results.push(BulkReportItemResultDto {
+                        report_id,
+                        outcome: BulkItemOutcome::Failed,
+                        error: Some(Self::describe(&e)),
+                    });
+                    continue;
+                }
+            };
+            results.push(BulkReportItemResultDto {
+                report_id,
+                outcome,
+                error: None,
+            });
+        }
+
+        if let Err(e) = tx.commit().await {
+            tracing::error!("Failed to commit bulk chunk: {:?}", e);
+            return failed_all("Chunk could not be committed".to_string());
+        }
+        results
+    }
+
+    /// Apply the action to one report inside a savepoint (true if it changed the report)
+    async fn apply_one(
+        tx: &mut Transaction<'_, Postgres>,
+        report_id: Uuid,
+        action: &BulkReportActionDto,
+        actor: &BulkActor<'_>,
+    ) -> Result<bool> {
+        let mut sp = sqlx::Connection::begin(&mut **tx).await.map_err(|e| {
+            tracing::error!("Failed to create savepoint: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let changed = match Self::apply_action(&mut sp, report_id, action, actor).await {
+            Ok(changed) => changed,
+            Err(e) => {
+                if let Err(rollback) = sp.rollback().await {
+                    tracing::error!("Failed to roll back savepoint: {:?}", rollback);
+                }
+                return Err(e);
+            }
+        };
+
+        sp.commit().await.map_err(|e| {
+            tracing::error!("Failed to release savepoint: {:?}", e);
+            AppError::Database(e)
+        })?;
+        Ok(changed)
+    }
+
+    async fn apply_action(
+        tx: &mut Transaction<'_, Postgres>,
+        report_id: Uuid,
+        action: &BulkReportActionDto,
+        actor: &BulkActor<'_>,
+    ) -> Result<bool> {
+        let author = RevisionAuthor {
+            edited_by: actor.user_id,
+            role: actor.role,
+            reason: actor.reason,
+        };
+
+        match action {
+            BulkReportActionDto::UpdateStatus {
+                status,
+                resolution_notes,
+            } => Self::change_status(tx, report_id, *status, resolution_notes, actor).await,
+            BulkReportActionDto::Reject { resolution_notes } => {
+                Self::change_status(
+                    tx,
+                    report_id,
+                    ReportStatus::Rejected,
+                    resolution_notes,
+                    actor,
+                )
+                .await
+            }
+            BulkReportActionDto::SetCategories { categories } => {
+                let revision = ReportRevisionService::replace_categories_in_tx(
+                    tx, report_id, categories, &author,
+                )
+                .await?;
+                Ok(revision.is_some())
+            }
+            BulkReportActionDto::AddTags { tags } | BulkReportActionDto::RemoveTags { tags } => {
+                ReportService::lock_status(tx, report_id).await?;
+                let current = ReportRevisionService::current_tags(tx, report_id).await?;
+                let next: Vec<ReportTagType> = match action {
+                    BulkReportActionDto::AddTags { .. } => current
+                        .iter()
+                        .chain(tags.iter().filter(|t| !current.contains(t)))
+                        .copied()
+                        .collect(),
+                    _ => current.into_iter().filter(|t| !tags.contains(t)).collect(),
+                };
+                let revision =
+                    ReportRevisionService::replace_tags_in_tx(tx, report_id, &next, &author)
+                        .await?;
+                Ok(revision.is_some())
+            }
+            BulkReportActionDto::Assign { assignee_id, note } => {
+                ReportService::lock_status(tx, report_id).await?;
+                let active = sqlx::query_scalar!(
+                    r#"
+                    SELECT assignee_id
+                    FROM report_assignments
+                    WHERE report_id = $1 AND released_at IS NULL
+                    "#,
+                    report_id
+                )
+                .fetch_optional(&mut **tx)
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to get active report assignment: {:?}", e);
+                    AppError::Database(e)
+                })?;
+                if active.as_deref() == Some(assignee_id.trim()) {
+                    return Ok(false);
+                }
+
+                let note = note.as_deref().unwrap_or(actor.reason);
+                ReportAssignmentService::assign_in_tx(
+                    tx,
+                    report_id,
+                    actor.user_id,
+                    assignee_id.trim(),
+                    Some(note),
+                )
+                .await?;
+                Ok(true)
+            }
+        }
+    }
+
+    async fn change_status(
+        tx: &mut Transaction<'_, Postgres>,
+        report_id: Uuid,
+        status: ReportStatus,
+        resolution_notes: &Option<String>,
+        actor: &BulkActor<'_>,
+    ) -> Result<bool> {
+        let current = ReportService::lock_status(tx, report_id).await?;
+        if current == status {
+            return Ok(false);
+        }
+
+        let dto = UpdateReportStatusDto {
+            status,
+            resolution_notes: resolution_notes.clone(),
+            note: Some(actor.reason.to_string()),
+        };
+        ReportService::update_status_in_tx(tx, report_id, &dto, actor.user_id, actor.role).await?;
+        Ok(true)
+    }
+
+    /// Message for a refused report (database details stay in the logs)
+    fn describe(error: &AppError) -> String {
+        match error {
+            AppError::Database(_) | AppError::Internal(_) => "Internal error".to_string(),
+            other => other.to_string(),
+        }
+    }
+}
@@ -0,0 +1,370 @@
+use std::sync::Arc;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::report_export_writer::render;
+use crate::core::error::{AppError, Result};
+use crate::features::admin::models::{
+    ReportExport, ReportExportFormat, ReportExportRow, ReportExportStatus,
+};
+use crate::features::notifications::models::NotificationPayload;
+use crate::features::notifications::NotificationService;
+use crate::features::reports::dtos::{ReportFilter, ReportFilterScope};
+use crate::modules::storage::{FileVisibility, MinIOClient};
+
+/// Largest export served directly; bigger exports must run as a background job
+pub const MAX_DIRECT_EXPORT_ROWS: i64 = 5_000;
+
+/// Largest export a background job will write
+const MAX_JOB_EXPORT_ROWS: i64 = 200_000;
+
+/// Running jobs older than this are assumed lost and claimed again
+const STALE_JOB_SECS: f64 = 1800.0;
+
+/// Service for report exports (direct downloads and background jobs)
+pub struct ReportExportService {
+    pool: PgPool,
+    minio_client: Arc<MinIOClient>,
+    notification_service: Arc<NotificationService>,
+}
+
+impl ReportExportService {
+    pub fn new(
+        pool: PgPool,
+        minio_client: Arc<MinIOClient>,
+        notification_service: Arc<NotificationService>,
+    ) -> Self {
+        Self {
+            pool,
+            minio_client,
+            notification_service,
+        }
+    }
+
+    // ===== Direct Exports =====
+
+    /// Render the reports matching a filter
+    ///
+    /// Returns BadRequest if more than `MAX_DIRECT_EXPORT_ROWS` reports match.
+    pub async fn export(
+        &self,
+        filter: &ReportFilter,
+        format: ReportExportFormat,
+    ) -> Result<Vec<u8>> {
+        let total = self.count(filter).await?;
+        if total > MAX_DIRECT_EXPORT_ROWS {
+            return Err(AppError::BadRequest(format!(
+                "Export matches {} reports (limit {}); create a background export instead",
+                total, MAX_DIRECT_EXPORT_ROWS
+            )));
+        }
+
+        let rows = self.fetch_rows(filter, MAX_DIRECT_EXPORT_ROWS).await?;
+        render(format, &rows)
+    }
+
+    async fn count(&self, filter: &ReportFilter) -> Result<i64> {
+        let (where_clause, args) = filter.where_clause(ReportFilterScope::Admin);
+        let query = format!("SELECT COUNT(*) FROM reports r {}", where_clause);
+
+        let mut sqlx_query = sqlx::query_scalar::<_, i64>(&query);
+        for arg in &args {
+            sqlx_query = sqlx_query.bind(arg);
+        }
+        sqlx_query.fetch_one(&self.pool).await.map_err(|e| {
+            tracing::error!("Failed to count exported reports: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Flattened reports matching a filter, oldest first
+    async fn fetch_rows(&self, filter: &ReportFilter, limit: i64) -> Result<Vec<ReportExportRow>> {
+        let (where_clause, args) = filter.where_clause(ReportFilterScope::Admin);
+        let query = format!(
+            r#"
+            SELECT
+                r.id, r.reference_number, r.title, r.description,
+                r.status, r.language, r.platform,
+                r.created_at, r.verified_at, r.resolved_at,
+                COALESCE(cat.categories, '{{}}') as categories,
+                COALESCE(tag.tags, '{{}}') as tags,
+                rl.raw_input as location, rl.lat, rl.lon,
+                p.name as province, rg.name as regency, d.name as district, v.name as village,
+                COALESCE(att.urls, '{{}}') as attachment_urls
+            FROM reports r
+            LEFT JOIN report_locations rl ON rl.report_id = r.id
+            LEFT JOIN provinces p ON p.id = rl.province_id
+            LEFT JOIN regencies rg ON rg.id = rl.regency_id
+            LEFT JOIN districts d ON d.id = rl.district_id
+            LEFT JOIN villages v ON v.id = rl.village_id
+            LEFT JOIN LATERAL (
+                SELECT array_agg(c.name || ' (' || rc.severity::text || ')' ORDER BY c.name) as categories
+                FROM report_categories rc
+                JOIN categories c ON c.id = rc.category_id
+                WHERE rc.report_id = r.id
+            ) cat ON true
+            LEFT JOIN LATERAL (
+                SELECT array_agg(rt.tag_type::text ORDER BY rt.tag_type::text) as tags
+                FROM report_tags rt
+                WHERE rt.report_id = r.id
+            ) tag ON true
+            LEFT JOIN LATERAL (
+                SELECT array_agg(f.url ORDER BY ra.created_at) as urls
+                FROM report_attachments ra
+                JOIN files f ON f.id = ra.file_id
+                WHERE ra.report_id = r.id AND ra.report_update_id IS NULL
+            ) att ON true
+            {}
+            ORDER BY r.created_at ASC
+            LIMIT {}
+            "#,
+            where_clause, limit
+        );
+
+        let mut sqlx_query = sqlx::query_as::<_, ReportExportRow>(&query);
+        for arg in &args {
+            sqlx_query = sqlx_query.bind(arg);
+        }
+        sqlx_query.fetch_all(&self.pool).await.map_err(|e| {
+            tracing::error!("Failed to fetch exported reports: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    // ===== Background Jobs =====
+
+    /// Queue a background export
+    pub async fn create_job(
+        &self,
+        filter: &ReportFilter,
+        format: ReportExportFormat,
+        requested_by: &str,
+    ) -> Result<ReportExport> {
+        let filter = serde_json::to_value(filter)
+            .map_err(|e| AppError::Internal(format!("Failed to serialize filter: {}", e)))?;
+
+        let job = sqlx::query_as!(
+            ReportExport,
+            r#"
+            INSERT INTO report_exports (format, filter, requested_by)
+            VALUES ($1, $2, $3)
+            RETURNING
+                id, format as "format: ReportExportFormat", filter,
+                status as "status: ReportExportStatus",
+                row_count, file_key, error, requested_by, created_at, started_at, completed_at
+            "#,
+            format as ReportExportFormat,
+            filter,
+            requested_by
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to create report export: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!(
+            "Report export {} ({}) queued by {}",
+            job.id,
+            format,
+            requested_by
+        );
+        Ok(job)
+    }
+
+    /// List background exports, newest first
+    pub async fn list_jobs(&self, limit: i64, offset: i64) -> Result<(Vec<ReportExport>, i64)> {
+        let items = sqlx::query_as!(
+            ReportExport,
+            r#"
+            SELECT
+                id, format as "format: ReportExportFormat", filter,
+                status as "status: ReportExportStatus",
+                row_count, file_key, error, requested_by, created_at, started_at, completed_at
+            FROM report_exports
+            ORDER BY created_at DESC
+            LIMIT $1 OFFSET $2
+            "#,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list report exports: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let total = sqlx::query_scalar!(r#"SELECT COUNT(*) as "count!" FROM report_exports"#)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to count report exports: {:?}", e);
+                AppError::Database(e)
+            })?;
+
+        Ok((items, total))
+    }
+
+    pub async fn get_job(&self, id: Uuid) -> Result<ReportExport> {
+        sqlx::query_as!(
+            ReportExport,
+            r#"
+            SELECT
+                id, format as "format: ReportExportFormat", filter,
+                status as "status: ReportExportStatus",
+                row_count, file_key, error, requested_by, created_at, started_at, completed_at
+            FROM report_exports
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report export: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report export {} not found", id)))
+    }
+
+    /// Presigned download URL of a completed export
+    pub async fn download_url(&self, id: Uuid) -> Result<String> {
+        let job = self.get_job(id).await?;
+        match (job.status, job.file_key) {
+            (ReportExportStatus::Completed, Some(key)) => {
+                self.minio_client.get_presigned_url(&key).await
+            }
+            (status, _) => Err(AppError::Conflict(format!(
+                "Report export {} is not ready (status: {:?})",
+                id, status
+            ))),
+        }
+    }
+
+    /// Claim the oldest pending export (or a stale running one) for the worker
+    pub async fn claim_next(&self) -> Result<Option<ReportExport>> {
+        sqlx::query_as!(
+            ReportExport,
+            r#"
+            UPDATE report_exports
+            SET status = 'running', started_at = NOW()
+            WHERE id = (
+                SELECT id FROM report_exports
+                WHERE status = 'pending'
+                   OR (status = 'running' AND started_at < NOW() - make_interval(secs => $1))
+                ORDER BY created_at
+                LIMIT 1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING
+                id, format as "format: ReportExportFormat", filter,
+                status as "status: ReportExportStatus",
+                row_count, file_key, error, requested_by, created_at, started_at, completed_at
+            "#,
+            STALE_JOB_SECS
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to claim report export: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Write and upload a claimed export, then notify the requester
+    pub async fn run_job(&self, job: &ReportExport) -> Result<()> {
+        match self.write_job(job).await {
+            Ok((file_key, row_count)) => {
+                sqlx::query!(
+                    r#"
+                    UPDATE report_exports
+                    SET status = 'completed', file_key = $2, row_count = $3, error = NULL,
+                        completed_at = NOW()
+                    WHERE id = $1
+                    "#,
+                    job.id,
+                    file_key,
+                    row_count
+                )
+                .execute(&self.pool)
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to complete report export: {:?}", e);
+                    AppError::Database(e)
+                })?;
+
+                let payload = NotificationPayload::ReportExportReady {
+                    export_id: job.id,
+                    format: job.format,
+                    row_count,
+                    download_path: format!("/api/admin/report-exports/{}/download", job.id),
+                };
+                if let Err(e) = self
+                    .notification_service
+                    .send(&job.requested_by, payload)
+                    .await
+                {
+                    tracing::warn!("Failed to notify about report export {}: {:?}", job.id, e);
+                }
+
+                tracing::info!("Report export {} completed ({} rows)", job.id, row_count);
+                Ok(())
+            }
+            Err(e) => {
+                tracing::error!("Report export {} failed: {:?}", job.id, e);
+                sqlx::query!(
+                    r#"
+                    UPDATE report_exports
+                    SET status = 'failed', error = $2, completed_at = NOW()
+                    WHERE id = $1
+                    "#,
+                    job.id,
+                    e.to_string()
+                )
+                .execute(&self.pool)
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to mark report export as failed: {:?}", e);
+                    AppError::Database(e)
+                })?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Render and upload an export, returning (file key, row count)
+    async fn write_job(&self, job: &ReportExport) -> Result<(String, i32)> {
+        let filter: ReportFilter = serde_json::from_value(job.filter.clone())
+            .map_err(|e| AppError::Internal(format!("Invalid export filter: {}", e)))?;
+
+        let total = self.count(&filter).await?;
+        if total > MAX_JOB_EXPORT_ROWS {
+            return Err(AppError::BadRequest(format!(
+                "Export matches {} reports (limit {}); narrow the filter",
+                total, MAX_JOB_EXPORT_ROWS
+            )));
+        }
+
+        let rows = self.fetch_rows(&filter, MAX_JOB_EXPORT_ROWS).await?;
+        let data = render(job.format, &rows)?;
+
+        let path = format!(
+            "exports/reports-{}-{}.{}",
+            job.created_at.format("%Y%m%d-%H%M%S"),
+            job.id,
+            job.format.extension()
+        );
+        let key = self
+            .minio_client
+            .generate_key(FileVisibility::Private, &path);
+        let key = self
+            .minio_client
+            .upload(&key, data, job.format.content_type())
+            .await?;
+
+        Ok((key, rows.len() as i32))
+    }
+}
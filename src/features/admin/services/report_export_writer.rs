@@ -0,0 +1,252 @@
+use rust_xlsxwriter::{Format, Workbook};
+use serde_json::{json, Map, Value};
+
+use crate::core::error::{AppError, Result};
+use crate::features::admin::models::{ReportExportFormat, ReportExportRow};
+
+/// Separator for multi-valued columns (categories, tags, attachments) in spreadsheets
+const LIST_SEPARATOR: &str = "; ";
+
+/// Cell of an exported column
+enum Cell {
+    Text(String),
+    Number(f64),
+    Empty,
+}
+
+impl Cell {
+    fn text(value: Option<impl ToString>) -> Self {
+        value.map_or(Cell::Empty, |v| Cell::Text(v.to_string()))
+    }
+
+    fn list(values: &[String]) -> Self {
+        if values.is_empty() {
+            Cell::Empty
+        } else {
+            Cell::Text(values.join(LIST_SEPARATOR))
+        }
+    }
+
+    fn to_csv(&self) -> String {
+        match self {
+            Cell::Text(s) => s.clone(),
+            Cell::Number(n) => n.to_string(),
+            Cell::Empty => String::new(),
+        }
+    }
+}
+
+/// Exported columns, in order
+const COLUMNS: [&str; 20] = [
+    "id",
+    "reference_number",
+    "title",
+    "description",
+    "status",
+    "language",
+    "platform",
+    "created_at",
+    "verified_at",
+    "resolved_at",
+    "categories",
+    "tags",
+    "location",
+    "lat",
+    "lon",
+    "province",
+    "regency",
+    "district",
+    "village",
+    "attachment_urls",
+];
+
+fn cells(row: &ReportExportRow) -> [Cell; 20] {
+    [
+        Cell::Text(row.id.to_string()),
+        Cell::text(row.reference_number.as_ref()),
+        Cell::text(row.title.as_ref()),
+        Cell::text(row.description.as_ref()),
+        Cell::Text(row.status.to_string()),
+        Cell::text(row.language),
+        Cell::text(row.platform.as_ref()),
+        Cell::Text(row.created_at.to_rfc3339()),
+        Cell::text(row.verified_at.map(|t| t.to_rfc3339())),
+        Cell::text(row.resolved_at.map(|t| t.to_rfc3339())),
+        Cell::list(&row.categories),
+        Cell::list(&row.tags),
+        Cell::text(row.location.as_ref()),
+        row.lat.map_or(Cell::Empty, Cell::Number),
+        row.lon.map_or(Cell::Empty, Cell::Number),
+        Cell::text(row.province.as_ref()),
+        Cell::text(row.regency.as_ref()),
+        Cell::text(row.district.as_ref()),
+        Cell::text(row.village.as_ref()),
+        Cell::list(&row.attachment_urls),
+    ]
+}
+
+/// Render exported reports in the requested format
+pub fn render(format: ReportExportFormat, rows: &[ReportExportRow]) -> Result<Vec<u8>> {
+    match format {
+        ReportExportFormat::Csv => write_csv(rows),
+        ReportExportFormat::Xlsx => write_xlsx(rows),
+        ReportExportFormat::Geojson => write_geojson(rows),
+    }
+}
+
+fn write_csv(rows: &[ReportExportRow]) -> Result<Vec<u8>> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let csv_error = |e: csv::Error| AppError::Internal(format!("Failed to write CSV: {}", e));
+
+    writer.write_record(COLUMNS).map_err(csv_error)?;
+    for row in rows {
+        writer
+            .write_record(cells(row).iter().map(Cell::to_csv))
+            .map_err(csv_error)?;
+    }
+
+    writer
+        .into_inner()
+        .map_err(|e| AppError::Internal(format!("Failed to write CSV: {}", e)))
+}
+
+fn write_xlsx(rows: &[ReportExportRow]) -> Result<Vec<u8>> {
+    let xlsx_error =
+        |e: rust_xlsxwriter::XlsxError| AppError::Internal(format!("Failed to write XLSX: {}", e));
+
+    let mut workbook = Workbook::new();
+    let header = Format::new().set_bold();
+    let sheet = workbook.add_worksheet();
+    sheet.set_name("Reports").map_err(xlsx_error)?;
+
+    for (col, name) in COLUMNS.iter().enumerate() {
+        sheet
+            .write_string_with_format(0, col as u16, *name, &header)
+            .map_err(xlsx_error)?;
+    }
+    sheet.set_freeze_panes(1, 0).map_err(xlsx_error)?;
+
+    for (i, row) in rows.iter().enumerate() {
+        let r = (i + 1) as u32;
+        for (col, cell) in cells(row).into_iter().enumerate() {
+            match cell {
+                Cell::Text(s) => sheet.write_string(r, col as u16, s),
+                Cell::Number(n) => sheet.write_number(r, col as u16, n),
+                Cell::Empty => continue,
+            }
+            .map_err(xlsx_error)?;
+        }
+    }
+
+    workbook.save_to_buffer().map_err(xlsx_error)
+}
+
+/// GeoJSON FeatureCollection; reports without coordinates get a null geometry
+fn write_geojson(rows: &[ReportExportRow]) -> Result<Vec<u8>> {
+    let features: Vec<Value> = rows
+        .iter()
+        .map(|row| {
+            let geometry = match (row.lon, row.lat) {
+                (Some(lon), Some(lat)) => json!({ "type": "Point", "coordinates": [lon, lat] }),
+                _ => Value::Null,
+            };
+
+            let mut properties = Map::new();
+            for (name, cell) in COLUMNS.iter().zip(cells(row)) {
+                let value = match (*name, cell) {
+                    ("lat" | "lon", _) => continue,
+                    ("categories", _) => json!(row.categories),
+                    ("tags", _) => json!(row.tags),
+                    ("attachment_urls", _) => json!(row.attachment_urls),
+                    (_, Cell::Text(s)) => Value::String(s),
+                    (_, Cell::Number(n)) => json!(n),
+                    (_, Cell::Empty) => Value::Null,
+                };
+                properties.insert(name.to_string(), value);
+            }
+
+            json!({
+                "type": "Feature",
+                "id": row.id,
+                "geometry": geometry,
+                "properties": properties,
+            })
+        })
+        .collect();
+
+    serde_json::to_vec(&json!({ "type": "FeatureCollection", "features": features }))
+        .map_err(|e| AppError::Internal(format!("Failed to write GeoJSON: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::features::reports::models::ReportStatus;
+
+    fn row() -> ReportExportRow {
+        ReportExportRow {
+            id: Uuid::nil(),
+            reference_number: Some("RPT-2026-0000001".to_string()),
+            title: Some("Jembatan rusak, \"bahaya\"".to_string()),
+            description: None,
+            status: ReportStatus::Verified,
+            language: None,
+            platform: Some("web".to_string()),
+            created_at: Utc::now(),
+            verified_at: None,
+            resolved_at: None,
+            categories: vec![
+                "Infrastruktur (high)".to_string(),
+                "Transportasi (medium)".to_string(),
+            ],
+            tags: vec!["complaint".to_string()],
+            location: Some("Cibiru, Bandung".to_string()),
+            lat: Some(-6.93),
+            lon: Some(107.72),
+            province: Some("Jawa Barat".to_string()),
+            regency: Some("Kota Bandung".to_string()),
+            district: None,
+            village: None,
+            attachment_urls: vec![],
+        }
+    }
+
+    #[test]
+    fn test_csv_flattens_lists_and_quotes() {
+        let csv = String::from_utf8(render(ReportExportFormat::Csv, &[row()]).unwrap()).unwrap();
+        let mut lines = csv.lines();
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("id,reference_number,title,"));
+        let line = lines.next().unwrap();
+        assert!(line.contains(r#""Jembatan rusak, ""bahaya""""#));
+        assert!(line.contains("Infrastruktur (high); Transportasi (medium)"));
+        assert!(line.contains(",-6.93,107.72,"));
+    }
+
+    #[test]
+    fn test_geojson_points_and_null_geometry() {
+        let mut without_location = row();
+        without_location.lat = None;
+        without_location.lon = None;
+
+        let bytes = render(ReportExportFormat::Geojson, &[row(), without_location]).unwrap();
+        let value: Value = serde_json::from_slice(&bytes).unwrap();
+        let features = value["features"].as_array().unwrap();
+
+        assert_eq!(
+            features[0]["geometry"]["coordinates"],
+            json!([107.72, -6.93])
+        );
+        assert_eq!(
+            features[0]["properties"]["categories"][1],
+            "Transportasi (medium)"
+        );
+        assert!(features[0]["properties"].get("lat").is_none());
+        assert!(features[1]["geometry"].is_null());
+    }
+}
@@ -0,0 +1,3 @@
+mod report_export_worker;
+
+pub use report_export_worker::ReportExportWorker;
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::interval;
+
+use crate::core::error::Result;
+use crate::features::admin::services::ReportExportService;
+
+/// Delay between queue polls
+const POLL_INTERVAL_SECS: u64 = 15;
+
+/// Report export worker that runs in the background
+/// Writes queued exports one at a time and uploads them to object storage
+pub struct ReportExportWorker {
+    export_service: Arc<ReportExportService>,
+}
+
+impl ReportExportWorker {
+    pub fn new(export_service: Arc<ReportExportService>) -> Self {
+        Self { export_service }
+    }
+
+    /// Run the worker in a background loop
+    pub async fn run(&self) {
+        tracing::info!("Starting report export worker");
+
+        let mut interval = interval(Duration::from_secs(POLL_INTERVAL_SECS));
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = self.drain_queue().await {
+                tracing::error!("Error processing report exports: {:?}", e);
+            }
+        }
+    }
+
+    /// Process queued exports until none are left
+    async fn drain_queue(&self) -> Result<()> {
+        while let Some(job) = self.export_service.claim_next().await? {
+            tracing::debug!("Processing report export {}", job.id);
+            self.export_service.run_job(&job).await?;
+        }
+        Ok(())
+    }
+}
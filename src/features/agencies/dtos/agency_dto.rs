@@ -0,0 +1,254 @@
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::features::agencies::models::{
+    Agency, AgencyJurisdictionRegion, RegionLevel, ReportAgencyRoute,
+};
+use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+/// Regex for agency code format: lowercase kebab-case (e.g. "dpupr-kota-bandung")
+static AGENCY_CODE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*$").unwrap());
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
+
+// =============================================================================
+// AGENCY CRUD
+// =============================================================================
+
+/// Query params for listing agencies
+#[derive(Debug, Clone, Deserialize, IntoParams, ToSchema)]
+pub struct AgencyQueryParams {
+    /// Page number (1-indexed)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+
+    /// Items per page
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+
+    /// Search in code or name
+    pub search: Option<String>,
+
+    /// Filter by active status (true = active, false = inactive, none = all)
+    pub is_active: Option<bool>,
+}
+
+impl AgencyQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+/// Create agency request
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateAgencyDto {
+    /// Unique short code in kebab-case (e.g. "dpupr-kota-bandung")
+    #[validate(length(min = 2, max = 50), regex(path = *AGENCY_CODE_REGEX, message = "code must be lowercase kebab-case (e.g. 'dpupr-kota-bandung')"))]
+    pub code: String,
+
+    #[validate(length(min = 1, max = 200))]
+    pub name: String,
+
+    pub description: Option<String>,
+
+    #[validate(email)]
+    pub contact_email: Option<String>,
+
+    #[validate(length(max = 50))]
+    pub contact_phone: Option<String>,
+}
+
+/// Update agency request
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateAgencyDto {
+    #[validate(length(min = 1, max = 200))]
+    pub name: Option<String>,
+
+    pub description: Option<String>,
+
+    #[validate(email)]
+    pub contact_email: Option<String>,
+
+    #[validate(length(max = 50))]
+    pub contact_phone: Option<String>,
+
+    pub is_active: Option<bool>,
+}
+
+/// Agency response
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AgencyResponseDto {
+    pub id: Uuid,
+    pub code: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub contact_email: Option<String>,
+    pub contact_phone: Option<String>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Agency> for AgencyResponseDto {
+    fn from(a: Agency) -> Self {
+        Self {
+            id: a.id,
+            code: a.code,
+            name: a.name,
+            description: a.description,
+            contact_email: a.contact_email,
+            contact_phone: a.contact_phone,
+            is_active: a.is_active,
+            created_at: a.created_at,
+            updated_at: a.updated_at,
+        }
+    }
+}
+
+/// Agency with its jurisdiction
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AgencyDetailDto {
+    #[serde(flatten)]
+    pub agency: AgencyResponseDto,
+    pub jurisdiction: JurisdictionDto,
+}
+
+// =============================================================================
+// JURISDICTION
+// =============================================================================
+
+/// A region covered by an agency
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct JurisdictionRegionDto {
+    pub id: Uuid,
+    pub level: RegionLevel,
+    /// BPS region code (e.g. "32.73")
+    pub code: String,
+    pub name: String,
+}
+
+impl From<AgencyJurisdictionRegion> for JurisdictionRegionDto {
+    fn from(r: AgencyJurisdictionRegion) -> Self {
+        Self {
+            id: r.id,
+            level: r.level(),
+            code: r.region_code,
+            name: r.region_name,
+        }
+    }
+}
+
+/// Jurisdiction of an agency: covered regions and handled categories
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct JurisdictionDto {
+    pub regions: Vec<JurisdictionRegionDto>,
+    /// Category slugs handled by the agency (empty = all categories)
+    pub category_slugs: Vec<String>,
+}
+
+/// Replace the jurisdiction of an agency
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateJurisdictionDto {
+    /// BPS region codes at any level (e.g. "32", "32.73", "32.73.01", "32.73.01.1001")
+    #[validate(length(max = 500))]
+    pub region_codes: Vec<String>,
+
+    /// Category slugs handled by the agency (empty = all categories)
+    #[serde(default)]
+    #[validate(length(max = 100))]
+    pub category_slugs: Vec<String>,
+}
+
+// =============================================================================
+// ROUTING
+// =============================================================================
+
+/// Agency a report has been routed to
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReportAgencyRouteDto {
+    pub report_id: Uuid,
+    pub agency_id: Uuid,
+    pub agency_code: String,
+    pub agency_name: String,
+    pub matched_region_level: String,
+    pub matched_region_id: Uuid,
+    pub category_matched: bool,
+    /// User ID of the actor, or "system" for automatic routing
+    pub routed_by: String,
+    pub routed_at: DateTime<Utc>,
+}
+
+impl From<ReportAgencyRoute> for ReportAgencyRouteDto {
+    fn from(r: ReportAgencyRoute) -> Self {
+        Self {
+            report_id: r.report_id,
+            agency_id: r.agency_id,
+            agency_code: r.agency_code,
+            agency_name: r.agency_name,
+            matched_region_level: r.matched_region_level,
+            matched_region_id: r.matched_region_id,
+            category_matched: r.category_matched,
+            routed_by: r.routed_by,
+            routed_at: r.routed_at,
+        }
+    }
+}
+
+/// Routing dry-run request
+///
+/// Either `report_id` (route using the report's location and categories)
+/// or `region_code` with optional `category_slugs`.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RoutingDryRunDto {
+    pub report_id: Option<Uuid>,
+
+    /// Most specific BPS region code of the location (e.g. "32.73.01")
+    #[validate(length(min = 1, max = 13))]
+    pub region_code: Option<String>,
+
+    #[serde(default)]
+    pub category_slugs: Vec<String>,
+}
+
+/// An agency eligible for a report, in ranking order
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RoutingCandidateDto {
+    pub agency_id: Uuid,
+    pub agency_code: String,
+    pub agency_name: String,
+    pub matched_region_level: RegionLevel,
+    pub matched_region_id: Uuid,
+    /// TRUE if matched on a specific category, FALSE if the agency handles all categories
+    pub category_matched: bool,
+}
+
+/// Routing dry-run result
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RoutingDryRunResponseDto {
+    /// Agency that would be selected
+    pub selected: Option<RoutingCandidateDto>,
+    /// All eligible agencies, best match first
+    pub candidates: Vec<RoutingCandidateDto>,
+    /// Category slugs used for matching (including parent categories)
+    pub category_slugs: Vec<String>,
+    /// Current routing of the report (only when `report_id` is given)
+    pub current_route: Option<ReportAgencyRouteDto>,
+}
@@ -0,0 +1,3 @@
+mod agency_dto;
+
+pub use agency_dto::*;
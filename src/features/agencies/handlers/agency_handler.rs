@@ -0,0 +1,272 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::core::error::{AppError, Result};
+use crate::core::extractor::AppJson;
+use crate::features::agencies::dtos::{
+    AgencyDetailDto, AgencyQueryParams, AgencyResponseDto, CreateAgencyDto, JurisdictionDto,
+    RoutingDryRunDto, RoutingDryRunResponseDto, UpdateAgencyDto, UpdateJurisdictionDto,
+};
+use crate::features::agencies::services::{AgencyRoutingService, AgencyService};
+use crate::features::auth::guards::RequireSuperAdmin;
+use crate::shared::types::{ApiResponse, Meta};
+
+/// State for agency handlers
+#[derive(Clone)]
+pub struct AgencyState {
+    pub agency_service: Arc<AgencyService>,
+    pub routing_service: Arc<AgencyRoutingService>,
+}
+
+// =============================================================================
+// AGENCY HANDLERS
+// =============================================================================
+
+/// Create a new agency (super admin only)
+#[utoipa::path(
+    post,
+    path = "/api/admin/agencies",
+    request_body = CreateAgencyDto,
+    responses(
+        (status = 200, description = "Agency created", body = ApiResponse<AgencyResponseDto>),
+        (status = 400, description = "Validation error"),
+        (status = 403, description = "Forbidden - super admin only"),
+        (status = 409, description = "Agency code already exists")
+    ),
+    tag = "agencies",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_agency(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(state): State<AgencyState>,
+    AppJson(dto): AppJson<CreateAgencyDto>,
+) -> Result<Json<ApiResponse<AgencyResponseDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let agency = state.agency_service.create(dto).await?;
+    Ok(Json(ApiResponse::success(Some(agency), None, None)))
+}
+
+/// List agencies with pagination and filters (super admin only)
+#[utoipa::path(
+    get,
+    path = "/api/admin/agencies",
+    params(AgencyQueryParams),
+    responses(
+        (status = 200, description = "Agencies retrieved", body = ApiResponse<Vec<AgencyResponseDto>>),
+        (status = 403, description = "Forbidden - super admin only")
+    ),
+    tag = "agencies",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_agencies(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(state): State<AgencyState>,
+    Query(params): Query<AgencyQueryParams>,
+) -> Result<Json<ApiResponse<Vec<AgencyResponseDto>>>> {
+    let (agencies, total) = state.agency_service.list(&params).await?;
+    Ok(Json(ApiResponse::success(
+        Some(agencies),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Get an agency with its jurisdiction (super admin only)
+#[utoipa::path(
+    get,
+    path = "/api/admin/agencies/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Agency ID")
+    ),
+    responses(
+        (status = 200, description = "Agency retrieved", body = ApiResponse<AgencyDetailDto>),
+        (status = 404, description = "Agency not found"),
+        (status = 403, description = "Forbidden - super admin only")
+    ),
+    tag = "agencies",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_agency(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(state): State<AgencyState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<AgencyDetailDto>>> {
+    let agency = state.agency_service.get_by_id(id).await?;
+    Ok(Json(ApiResponse::success(Some(agency), None, None)))
+}
+
+/// Update an agency (super admin only)
+#[utoipa::path(
+    put,
+    path = "/api/admin/agencies/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Agency ID")
+    ),
+    request_body = UpdateAgencyDto,
+    responses(
+        (status = 200, description = "Agency updated", body = ApiResponse<AgencyResponseDto>),
+        (status = 400, description = "Validation error"),
+        (status = 404, description = "Agency not found"),
+        (status = 403, description = "Forbidden - super admin only")
+    ),
+    tag = "agencies",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_agency(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(state): State<AgencyState>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<UpdateAgencyDto>,
+) -> Result<Json<ApiResponse<AgencyResponseDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let agency = state.agency_service.update(id, dto).await?;
+    Ok(Json(ApiResponse::success(Some(agency), None, None)))
+}
+
+/// Deactivate an agency (soft delete, super admin only)
+#[utoipa::path(
+    delete,
+    path = "/api/admin/agencies/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Agency ID")
+    ),
+    responses(
+        (status = 200, description = "Agency deactivated"),
+        (status = 404, description = "Agency not found"),
+        (status = 403, description = "Forbidden - super admin only")
+    ),
+    tag = "agencies",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_agency(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(state): State<AgencyState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>> {
+    state.agency_service.delete(id).await?;
+    Ok(Json(ApiResponse::success(None, None, None)))
+}
+
+// =============================================================================
+// JURISDICTION HANDLERS
+// =============================================================================
+
+/// Get the jurisdiction of an agency (super admin only)
+#[utoipa::path(
+    get,
+    path = "/api/admin/agencies/{id}/jurisdiction",
+    params(
+        ("id" = Uuid, Path, description = "Agency ID")
+    ),
+    responses(
+        (status = 200, description = "Jurisdiction retrieved", body = ApiResponse<JurisdictionDto>),
+        (status = 404, description = "Agency not found"),
+        (status = 403, description = "Forbidden - super admin only")
+    ),
+    tag = "agencies",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_jurisdiction(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(state): State<AgencyState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<JurisdictionDto>>> {
+    let agency = state.agency_service.get_by_id(id).await?;
+    Ok(Json(ApiResponse::success(
+        Some(agency.jurisdiction),
+        None,
+        None,
+    )))
+}
+
+/// Replace the jurisdiction of an agency (super admin only)
+///
+/// Region codes can be at any level (province, regency, district, village).
+/// An empty category list means the agency handles all categories.
+#[utoipa::path(
+    put,
+    path = "/api/admin/agencies/{id}/jurisdiction",
+    params(
+        ("id" = Uuid, Path, description = "Agency ID")
+    ),
+    request_body = UpdateJurisdictionDto,
+    responses(
+        (status = 200, description = "Jurisdiction updated", body = ApiResponse<JurisdictionDto>),
+        (status = 400, description = "Validation error (unknown region code or category)"),
+        (status = 404, description = "Agency not found"),
+        (status = 403, description = "Forbidden - super admin only")
+    ),
+    tag = "agencies",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_jurisdiction(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(state): State<AgencyState>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<UpdateJurisdictionDto>,
+) -> Result<Json<ApiResponse<JurisdictionDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let jurisdiction = state.agency_service.replace_jurisdiction(id, dto).await?;
+    Ok(Json(ApiResponse::success(Some(jurisdiction), None, None)))
+}
+
+// =============================================================================
+// ROUTING HANDLERS
+// =============================================================================
+
+/// Dry-run agency routing without persisting anything (super admin only)
+///
+/// Pass `report_id` to route using an existing report's location and categories,
+/// or `region_code` and `category_slugs` to test a hypothetical report.
+#[utoipa::path(
+    post,
+    path = "/api/admin/agencies/routing/dry-run",
+    request_body = RoutingDryRunDto,
+    responses(
+        (status = 200, description = "Routing decision", body = ApiResponse<RoutingDryRunResponseDto>),
+        (status = 400, description = "Validation error"),
+        (status = 404, description = "Report or region not found"),
+        (status = 403, description = "Forbidden - super admin only")
+    ),
+    tag = "agencies",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn routing_dry_run(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(state): State<AgencyState>,
+    AppJson(dto): AppJson<RoutingDryRunDto>,
+) -> Result<Json<ApiResponse<RoutingDryRunResponseDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let result = state.routing_service.dry_run(&dto).await?;
+    Ok(Json(ApiResponse::success(Some(result), None, None)))
+}
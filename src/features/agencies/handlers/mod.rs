@@ -0,0 +1,3 @@
+pub mod agency_handler;
+
+pub use agency_handler::*;
@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Administrative region level (most specific last)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RegionLevel {
+    Province,
+    Regency,
+    District,
+    Village,
+}
+
+impl RegionLevel {
+    /// Infer the level from a BPS region code
+    ///
+    /// Codes are dot-separated: `32` (province), `32.73` (regency),
+    /// `32.73.01` (district), `32.73.01.1001` (village).
+    pub fn from_code(code: &str) -> Option<Self> {
+        if code.is_empty() {
+            return None;
+        }
+        match code.split('.').count() {
+            1 => Some(RegionLevel::Province),
+            2 => Some(RegionLevel::Regency),
+            3 => Some(RegionLevel::District),
+            4 => Some(RegionLevel::Village),
+            _ => None,
+        }
+    }
+
+    /// Parse a level name as stored in routing/escalation records
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "province" => Some(RegionLevel::Province),
+            "regency" => Some(RegionLevel::Regency),
+            "district" => Some(RegionLevel::District),
+            "village" => Some(RegionLevel::Village),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RegionLevel::Province => "province",
+            RegionLevel::Regency => "regency",
+            RegionLevel::District => "district",
+            RegionLevel::Village => "village",
+        }
+    }
+}
+
+impl std::fmt::Display for RegionLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Database model for a region covered by an agency (with resolved code/name)
+#[derive(Debug, Clone, FromRow)]
+#[allow(dead_code)]
+pub struct AgencyJurisdictionRegion {
+    pub id: Uuid,
+    pub agency_id: Uuid,
+    pub province_id: Option<Uuid>,
+    pub regency_id: Option<Uuid>,
+    pub district_id: Option<Uuid>,
+    pub village_id: Option<Uuid>,
+    pub region_code: String,
+    pub region_name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AgencyJurisdictionRegion {
+    /// Level of the region this row covers
+    pub fn level(&self) -> RegionLevel {
+        if self.village_id.is_some() {
+            RegionLevel::Village
+        } else if self.district_id.is_some() {
+            RegionLevel::District
+        } else if self.regency_id.is_some() {
+            RegionLevel::Regency
+        } else {
+            RegionLevel::Province
+        }
+    }
+}
+
+/// Full region chain of a location (each level resolved from its child when missing)
+#[derive(Debug, Clone, Default)]
+pub struct RegionChain {
+    pub province_id: Option<Uuid>,
+    pub regency_id: Option<Uuid>,
+    pub district_id: Option<Uuid>,
+    pub village_id: Option<Uuid>,
+}
+
+impl RegionChain {
+    pub fn is_empty(&self) -> bool {
+        self.province_id.is_none()
+            && self.regency_id.is_none()
+            && self.district_id.is_none()
+            && self.village_id.is_none()
+    }
+}
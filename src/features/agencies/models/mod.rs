@@ -0,0 +1,7 @@
+mod agency;
+mod jurisdiction;
+mod report_agency_route;
+
+pub use agency::Agency;
+pub use jurisdiction::{AgencyJurisdictionRegion, RegionChain, RegionLevel};
+pub use report_agency_route::ReportAgencyRoute;
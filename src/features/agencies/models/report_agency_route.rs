@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for the agency a report was routed to
+#[derive(Debug, Clone, FromRow)]
+#[allow(dead_code)]
+pub struct ReportAgencyRoute {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub agency_id: Uuid,
+    pub agency_code: String,
+    pub agency_name: String,
+    pub matched_region_level: String,
+    pub matched_region_id: Uuid,
+    pub category_matched: bool,
+    pub routed_by: String,
+    pub routed_at: DateTime<Utc>,
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::features::agencies::handlers::{self, AgencyState};
+use crate::features::agencies::services::{AgencyRoutingService, AgencyService};
+
+/// Create admin routes for agency management and routing (super admin only)
+pub fn admin_routes(
+    agency_service: Arc<AgencyService>,
+    routing_service: Arc<AgencyRoutingService>,
+) -> Router {
+    let state = AgencyState {
+        agency_service,
+        routing_service,
+    };
+
+    Router::new()
+        .route(
+            "/api/admin/agencies",
+            get(handlers::list_agencies).post(handlers::create_agency),
+        )
+        .route(
+            "/api/admin/agencies/routing/dry-run",
+            post(handlers::routing_dry_run),
+        )
+        .route(
+            "/api/admin/agencies/{id}",
+            get(handlers::get_agency)
+                .put(handlers::update_agency)
+                .delete(handlers::delete_agency),
+        )
+        .route(
+            "/api/admin/agencies/{id}/jurisdiction",
+            get(handlers::get_jurisdiction).put(handlers::update_jurisdiction),
+        )
+        .with_state(state)
+}
@@ -0,0 +1,511 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::agencies::dtos::{
+    RoutingCandidateDto, RoutingDryRunDto, RoutingDryRunResponseDto,
+};
+use crate::features::agencies::models::{RegionChain, RegionLevel, ReportAgencyRoute};
+use crate::shared::constants::ROLE_SYSTEM;
+
+/// An agency whose jurisdiction covers a report
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoutingCandidate {
+    pub agency_id: Uuid,
+    pub agency_code: String,
+    pub agency_name: String,
+    pub region_level: RegionLevel,
+    pub region_id: Uuid,
+    /// TRUE if matched on a specific category, FALSE if the agency handles all categories
+    pub category_matched: bool,
+}
+
+impl From<RoutingCandidate> for RoutingCandidateDto {
+    fn from(c: RoutingCandidate) -> Self {
+        Self {
+            agency_id: c.agency_id,
+            agency_code: c.agency_code,
+            agency_name: c.agency_name,
+            matched_region_level: c.region_level,
+            matched_region_id: c.region_id,
+            category_matched: c.category_matched,
+        }
+    }
+}
+
+/// Rank candidates best match first, keeping one entry per agency
+///
+/// Ranking: most specific region level (village > district > regency > province),
+/// then agencies matching a specific category before catch-all agencies,
+/// then agency code for a stable order.
+pub fn rank_candidates(mut candidates: Vec<RoutingCandidate>) -> Vec<RoutingCandidate> {
+    candidates.sort_by(|a, b| {
+        b.region_level
+            .cmp(&a.region_level)
+            .then(b.category_matched.cmp(&a.category_matched))
+            .then(a.agency_code.cmp(&b.agency_code))
+    });
+
+    let mut seen = std::collections::HashSet::new();
+    candidates.retain(|c| seen.insert(c.agency_id));
+    candidates
+}
+
+/// Raw jurisdiction match row
+#[derive(Debug, sqlx::FromRow)]
+struct CandidateRow {
+    agency_id: Uuid,
+    agency_code: String,
+    agency_name: String,
+    province_id: Option<Uuid>,
+    regency_id: Option<Uuid>,
+    district_id: Option<Uuid>,
+    village_id: Option<Uuid>,
+    handles_all_categories: bool,
+    category_matched: bool,
+}
+
+impl CandidateRow {
+    /// Convert to a candidate, or None if the agency does not handle the report's categories
+    fn into_candidate(self) -> Option<RoutingCandidate> {
+        if !self.category_matched && !self.handles_all_categories {
+            return None;
+        }
+
+        let (region_level, region_id) = if let Some(id) = self.village_id {
+            (RegionLevel::Village, id)
+        } else if let Some(id) = self.district_id {
+            (RegionLevel::District, id)
+        } else if let Some(id) = self.regency_id {
+            (RegionLevel::Regency, id)
+        } else {
+            (RegionLevel::Province, self.province_id?)
+        };
+
+        Some(RoutingCandidate {
+            agency_id: self.agency_id,
+            agency_code: self.agency_code,
+            agency_name: self.agency_name,
+            region_level,
+            region_id,
+            category_matched: self.category_matched,
+        })
+    }
+}
+
+/// Service for routing reports to the agency responsible for them
+pub struct AgencyRoutingService {
+    pool: PgPool,
+}
+
+impl AgencyRoutingService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Route a report to the best-matching agency (called by ReportProcessor)
+    ///
+    /// Returns the route, or None if the report has no location or no agency matches.
+    /// Any previous route of the report is replaced.
+    pub async fn route_report(&self, report_id: Uuid) -> Result<Option<ReportAgencyRoute>> {
+        let chain = self.get_report_region_chain(report_id).await?;
+        let slugs = self.get_report_category_slugs(report_id).await?;
+
+        let best = match chain {
+            Some(chain) => self
+                .find_candidates(&chain, &slugs)
+                .await?
+                .into_iter()
+                .next(),
+            None => None,
+        };
+
+        let Some(best) = best else {
+            tracing::info!("No agency matched report {}", report_id);
+            return Ok(None);
+        };
+
+        let route = self.save_route(report_id, &best, ROLE_SYSTEM).await?;
+
+        tracing::info!(
+            "Routed report {} to agency {} (level={}, category_matched={})",
+            report_id,
+            best.agency_code,
+            best.region_level,
+            best.category_matched
+        );
+
+        Ok(Some(route))
+    }
+
+    /// Get the agency a report is currently routed to
+    pub async fn get_route(&self, report_id: Uuid) -> Result<Option<ReportAgencyRoute>> {
+        sqlx::query_as!(
+            ReportAgencyRoute,
+            r#"
+            SELECT
+                rr.id, rr.report_id, rr.agency_id,
+                a.code as agency_code, a.name as agency_name,
+                rr.matched_region_level, rr.matched_region_id, rr.category_matched,
+                rr.routed_by, rr.routed_at
+            FROM report_agency_routes rr
+            JOIN agencies a ON a.id = rr.agency_id
+            WHERE rr.report_id = $1
+            "#,
+            report_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report agency route: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// All agencies covering a report, best match first (used for SLA escalation)
+    pub async fn candidates_for_report(&self, report_id: Uuid) -> Result<Vec<RoutingCandidate>> {
+        let Some(chain) = self.get_report_region_chain(report_id).await? else {
+            return Ok(Vec::new());
+        };
+        let slugs = self.get_report_category_slugs(report_id).await?;
+
+        self.find_candidates(&chain, &slugs).await
+    }
+
+    /// Compute routing without persisting anything (for debugging jurisdictions)
+    pub async fn dry_run(&self, dto: &RoutingDryRunDto) -> Result<RoutingDryRunResponseDto> {
+        let (chain, slugs, current_route) = if let Some(report_id) = dto.report_id {
+            let exists = sqlx::query_scalar!(
+                r#"SELECT EXISTS(SELECT 1 FROM reports WHERE id = $1) as "exists!""#,
+                report_id
+            )
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to check report: {:?}", e);
+                AppError::Database(e)
+            })?;
+
+            if !exists {
+                return Err(AppError::NotFound(format!(
+                    "Report {} not found",
+                    report_id
+                )));
+            }
+
+            (
+                self.get_report_region_chain(report_id).await?,
+                self.get_report_category_slugs(report_id).await?,
+                self.get_route(report_id).await?,
+            )
+        } else if let Some(code) = dto.region_code.as_deref() {
+            (
+                Some(self.get_region_chain_by_code(code).await?),
+                self.expand_category_slugs(&dto.category_slugs).await?,
+                None,
+            )
+        } else {
+            return Err(AppError::Validation(
+                "Either report_id or region_code is required".to_string(),
+            ));
+        };
+
+        let candidates = match chain {
+            Some(chain) => self.find_candidates(&chain, &slugs).await?,
+            None => Vec::new(),
+        };
+
+        let candidates: Vec<RoutingCandidateDto> = candidates.into_iter().map(Into::into).collect();
+
+        Ok(RoutingDryRunResponseDto {
+            selected: candidates.first().cloned(),
+            candidates,
+            category_slugs: slugs,
+            current_route: current_route.map(Into::into),
+        })
+    }
+
+    // ===== Helpers =====
+
+    /// Find agencies covering the region chain and categories, best match first
+    async fn find_candidates(
+        &self,
+        chain: &RegionChain,
+        category_slugs: &[String],
+    ) -> Result<Vec<RoutingCandidate>> {
+        if chain.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rows = sqlx::query_as!(
+            CandidateRow,
+            r#"
+            SELECT
+                a.id as agency_id, a.code as agency_code, a.name as agency_name,
+                j.province_id, j.regency_id, j.district_id, j.village_id,
+                NOT EXISTS(
+                    SELECT 1 FROM agency_jurisdiction_categories ac WHERE ac.agency_id = a.id
+                ) as "handles_all_categories!",
+                EXISTS(
+                    SELECT 1 FROM agency_jurisdiction_categories ac
+                    WHERE ac.agency_id = a.id AND ac.category_slug = ANY($5)
+                ) as "category_matched!"
+            FROM agency_jurisdiction_regions j
+            JOIN agencies a ON a.id = j.agency_id
+            WHERE a.is_active = TRUE
+              AND (j.village_id = $1 OR j.district_id = $2 OR j.regency_id = $3 OR j.province_id = $4)
+            "#,
+            chain.village_id,
+            chain.district_id,
+            chain.regency_id,
+            chain.province_id,
+            category_slugs
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to find routing candidates: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(rank_candidates(
+            rows.into_iter()
+                .filter_map(CandidateRow::into_candidate)
+                .collect(),
+        ))
+    }
+
+    /// Resolve the full region chain of a report's location
+    ///
+    /// Missing parent levels are derived from the most specific resolved region.
+    async fn get_report_region_chain(&self, report_id: Uuid) -> Result<Option<RegionChain>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                rl.village_id,
+                COALESCE(rl.district_id, v.district_id) as district_id,
+                COALESCE(rl.regency_id, d.regency_id) as regency_id,
+                COALESCE(rl.province_id, rg.province_id) as province_id
+            FROM report_locations rl
+            LEFT JOIN villages v ON v.id = rl.village_id
+            LEFT JOIN districts d ON d.id = COALESCE(rl.district_id, v.district_id)
+            LEFT JOIN regencies rg ON rg.id = COALESCE(rl.regency_id, d.regency_id)
+            WHERE rl.report_id = $1
+            LIMIT 1
+            "#,
+            report_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report region chain: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(row.map(|r| RegionChain {
+            province_id: r.province_id,
+            regency_id: r.regency_id,
+            district_id: r.district_id,
+            village_id: r.village_id,
+        }))
+    }
+
+    /// Resolve the full region chain of a BPS region code
+    async fn get_region_chain_by_code(&self, code: &str) -> Result<RegionChain> {
+        let level = RegionLevel::from_code(code)
+            .ok_or_else(|| AppError::Validation(format!("Invalid region code '{}'", code)))?;
+
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                v.id as "village_id?",
+                COALESCE(v.district_id, d.id) as district_id,
+                COALESCE(d.regency_id, rg.id) as regency_id,
+                COALESCE(rg.province_id, p.id) as province_id
+            FROM (SELECT 1) AS dummy
+            LEFT JOIN villages v ON v.code = $1
+            LEFT JOIN districts d ON d.id = v.district_id OR d.code = $1
+            LEFT JOIN regencies rg ON rg.id = d.regency_id OR rg.code = $1
+            LEFT JOIN provinces p ON p.id = rg.province_id OR p.code = $1
+            "#,
+            code
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to resolve region code: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let chain = RegionChain {
+            province_id: row.province_id,
+            regency_id: row.regency_id,
+            district_id: row.district_id,
+            village_id: row.village_id,
+        };
+
+        if chain.is_empty() {
+            return Err(AppError::NotFound(format!(
+                "Region {} '{}' not found",
+                level, code
+            )));
+        }
+
+        Ok(chain)
+    }
+
+    /// Category slugs of a report, including parent categories
+    async fn get_report_category_slugs(&self, report_id: Uuid) -> Result<Vec<String>> {
+        let slugs = sqlx::query_scalar!(
+            r#"
+            SELECT c.slug
+            FROM report_categories rc
+            JOIN categories c ON c.id = rc.category_id
+            WHERE rc.report_id = $1
+            "#,
+            report_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report category slugs: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        self.expand_category_slugs(&slugs).await
+    }
+
+    /// Add parent category slugs so agencies handling a parent category also match children
+    async fn expand_category_slugs(&self, slugs: &[String]) -> Result<Vec<String>> {
+        if slugs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        sqlx::query_scalar!(
+            r#"
+            SELECT slug as "slug!" FROM categories WHERE slug = ANY($1)
+            UNION
+            SELECT p.slug FROM categories c
+            JOIN categories p ON p.id = c.parent_id
+            WHERE c.slug = ANY($1)
+            ORDER BY 1
+            "#,
+            slugs
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to expand category slugs: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Insert or replace the route of a report
+    async fn save_route(
+        &self,
+        report_id: Uuid,
+        candidate: &RoutingCandidate,
+        routed_by: &str,
+    ) -> Result<ReportAgencyRoute> {
+        sqlx::query!(
+            r#"
+            INSERT INTO report_agency_routes
+                (report_id, agency_id, matched_region_level, matched_region_id, category_matched, routed_by)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (report_id) DO UPDATE SET
+                agency_id = EXCLUDED.agency_id,
+                matched_region_level = EXCLUDED.matched_region_level,
+                matched_region_id = EXCLUDED.matched_region_id,
+                category_matched = EXCLUDED.category_matched,
+                routed_by = EXCLUDED.routed_by,
+                routed_at = NOW()
+            "#,
+            report_id,
+            candidate.agency_id,
+            candidate.region_level.as_str(),
+            candidate.region_id,
+            candidate.category_matched,
+            routed_by
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to save report agency route: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        self.get_route(report_id).await?.ok_or_else(|| {
+            AppError::Internal(format!("Route for report {} was not saved", report_id))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(code: &str, level: RegionLevel, category_matched: bool) -> RoutingCandidate {
+        // Stable ID per agency code so duplicates collapse
+        let id = code.bytes().fold(0u128, |acc, b| acc * 31 + b as u128);
+        RoutingCandidate {
+            agency_id: Uuid::from_u128(id),
+            agency_code: code.to_string(),
+            agency_name: code.to_uppercase(),
+            region_level: level,
+            region_id: Uuid::nil(),
+            category_matched,
+        }
+    }
+
+    #[test]
+    fn test_most_specific_region_wins() {
+        let ranked = rank_candidates(vec![
+            candidate("province-agency", RegionLevel::Province, true),
+            candidate("district-agency", RegionLevel::District, false),
+            candidate("regency-agency", RegionLevel::Regency, true),
+        ]);
+
+        let codes: Vec<&str> = ranked.iter().map(|c| c.agency_code.as_str()).collect();
+        assert_eq!(
+            codes,
+            vec!["district-agency", "regency-agency", "province-agency"]
+        );
+    }
+
+    #[test]
+    fn test_category_match_beats_catch_all_at_same_level() {
+        let ranked = rank_candidates(vec![
+            candidate("generic", RegionLevel::Regency, false),
+            candidate("roads", RegionLevel::Regency, true),
+        ]);
+
+        assert_eq!(ranked[0].agency_code, "roads");
+    }
+
+    #[test]
+    fn test_one_entry_per_agency_keeps_best_level() {
+        let ranked = rank_candidates(vec![
+            candidate("dinas", RegionLevel::Province, true),
+            candidate("dinas", RegionLevel::Village, true),
+        ]);
+
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].region_level, RegionLevel::Village);
+    }
+
+    #[test]
+    fn test_region_level_from_code() {
+        assert_eq!(RegionLevel::from_code("32"), Some(RegionLevel::Province));
+        assert_eq!(RegionLevel::from_code("32.73"), Some(RegionLevel::Regency));
+        assert_eq!(
+            RegionLevel::from_code("32.73.01"),
+            Some(RegionLevel::District)
+        );
+        assert_eq!(
+            RegionLevel::from_code("32.73.01.1001"),
+            Some(RegionLevel::Village)
+        );
+        assert_eq!(RegionLevel::from_code(""), None);
+        assert_eq!(RegionLevel::from_code("32.73.01.1001.9"), None);
+    }
+}
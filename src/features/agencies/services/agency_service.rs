@@ -0,0 +1,374 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::agencies::dtos::{
+    AgencyDetailDto, AgencyQueryParams, AgencyResponseDto, CreateAgencyDto, JurisdictionDto,
+    UpdateAgencyDto, UpdateJurisdictionDto,
+};
+use crate::features::agencies::models::{Agency, AgencyJurisdictionRegion, RegionLevel};
+
+/// Convert database error to more specific AppError with user-friendly messages
+fn handle_db_error(e: sqlx::Error) -> AppError {
+    if let sqlx::Error::Database(db_err) = &e {
+        // Unique constraint violation
+        if db_err.code() == Some(std::borrow::Cow::Borrowed("23505")) {
+            return AppError::Conflict("An agency with this code already exists.".to_string());
+        }
+        // Foreign key violation
+        if db_err.code() == Some(std::borrow::Cow::Borrowed("23503")) {
+            return AppError::BadRequest("Referenced record does not exist.".to_string());
+        }
+    }
+    AppError::Database(e)
+}
+
+/// Service for agency and jurisdiction management
+pub struct AgencyService {
+    pool: PgPool,
+}
+
+impl AgencyService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Create a new agency (without jurisdiction)
+    pub async fn create(&self, dto: CreateAgencyDto) -> Result<AgencyResponseDto> {
+        let agency = sqlx::query_as!(
+            Agency,
+            r#"
+            INSERT INTO agencies (code, name, description, contact_email, contact_phone)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#,
+            dto.code,
+            dto.name,
+            dto.description,
+            dto.contact_email,
+            dto.contact_phone
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(handle_db_error)?;
+
+        tracing::info!("Created agency {} ({})", agency.code, agency.id);
+        Ok(agency.into())
+    }
+
+    /// Get an agency with its jurisdiction
+    pub async fn get_by_id(&self, id: Uuid) -> Result<AgencyDetailDto> {
+        let agency = self.find(id).await?;
+        let jurisdiction = self.get_jurisdiction(id).await?;
+
+        Ok(AgencyDetailDto {
+            agency: agency.into(),
+            jurisdiction,
+        })
+    }
+
+    /// List agencies with pagination and filters
+    pub async fn list(&self, params: &AgencyQueryParams) -> Result<(Vec<AgencyResponseDto>, i64)> {
+        let search = params.search.as_ref().map(|s| format!("%{}%", s));
+
+        let agencies = sqlx::query_as!(
+            Agency,
+            r#"
+            SELECT * FROM agencies
+            WHERE ($1::text IS NULL OR code ILIKE $1 OR name ILIKE $1)
+              AND ($2::bool IS NULL OR is_active = $2)
+            ORDER BY name ASC
+            LIMIT $3 OFFSET $4
+            "#,
+            search,
+            params.is_active,
+            params.limit(),
+            params.offset()
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list agencies: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let total = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!" FROM agencies
+            WHERE ($1::text IS NULL OR code ILIKE $1 OR name ILIKE $1)
+              AND ($2::bool IS NULL OR is_active = $2)
+            "#,
+            search,
+            params.is_active
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to count agencies: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok((agencies.into_iter().map(Into::into).collect(), total))
+    }
+
+    /// Update an agency
+    pub async fn update(&self, id: Uuid, dto: UpdateAgencyDto) -> Result<AgencyResponseDto> {
+        let agency = sqlx::query_as!(
+            Agency,
+            r#"
+            UPDATE agencies
+            SET
+                name = COALESCE($2, name),
+                description = COALESCE($3, description),
+                contact_email = COALESCE($4, contact_email),
+                contact_phone = COALESCE($5, contact_phone),
+                is_active = COALESCE($6, is_active),
+                updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+            id,
+            dto.name,
+            dto.description,
+            dto.contact_email,
+            dto.contact_phone,
+            dto.is_active
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(handle_db_error)?
+        .ok_or_else(|| AppError::NotFound(format!("Agency {} not found", id)))?;
+
+        tracing::info!("Updated agency {} ({})", agency.code, agency.id);
+        Ok(agency.into())
+    }
+
+    /// Deactivate an agency (soft delete)
+    ///
+    /// Inactive agencies are ignored by routing; existing routes are kept.
+    pub async fn delete(&self, id: Uuid) -> Result<()> {
+        let result = sqlx::query!(
+            r#"UPDATE agencies SET is_active = FALSE, updated_at = NOW() WHERE id = $1"#,
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to deactivate agency: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("Agency {} not found", id)));
+        }
+
+        tracing::info!("Deactivated agency {}", id);
+        Ok(())
+    }
+
+    /// Get the jurisdiction (regions + categories) of an agency
+    pub async fn get_jurisdiction(&self, agency_id: Uuid) -> Result<JurisdictionDto> {
+        let regions = sqlx::query_as!(
+            AgencyJurisdictionRegion,
+            r#"
+            SELECT
+                j.id, j.agency_id,
+                j.province_id, j.regency_id, j.district_id, j.village_id,
+                COALESCE(v.code, d.code, r.code, p.code) as "region_code!",
+                COALESCE(v.name, d.name, r.name, p.name) as "region_name!",
+                j.created_at
+            FROM agency_jurisdiction_regions j
+            LEFT JOIN provinces p ON p.id = j.province_id
+            LEFT JOIN regencies r ON r.id = j.regency_id
+            LEFT JOIN districts d ON d.id = j.district_id
+            LEFT JOIN villages v ON v.id = j.village_id
+            WHERE j.agency_id = $1
+            ORDER BY COALESCE(v.code, d.code, r.code, p.code)
+            "#,
+            agency_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get agency regions: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let category_slugs = sqlx::query_scalar!(
+            r#"
+            SELECT category_slug FROM agency_jurisdiction_categories
+            WHERE agency_id = $1
+            ORDER BY category_slug
+            "#,
+            agency_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get agency categories: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(JurisdictionDto {
+            regions: regions.into_iter().map(Into::into).collect(),
+            category_slugs,
+        })
+    }
+
+    /// Replace the jurisdiction of an agency
+    ///
+    /// Region codes may be at any level; the level is inferred from the code format.
+    pub async fn replace_jurisdiction(
+        &self,
+        agency_id: Uuid,
+        dto: UpdateJurisdictionDto,
+    ) -> Result<JurisdictionDto> {
+        self.find(agency_id).await?;
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        sqlx::query!(
+            "DELETE FROM agency_jurisdiction_regions WHERE agency_id = $1",
+            agency_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to clear agency regions: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        sqlx::query!(
+            "DELETE FROM agency_jurisdiction_categories WHERE agency_id = $1",
+            agency_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to clear agency categories: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let mut region_codes = dto.region_codes.clone();
+        region_codes.sort();
+        region_codes.dedup();
+
+        for code in &region_codes {
+            let level = RegionLevel::from_code(code)
+                .ok_or_else(|| AppError::Validation(format!("Invalid region code '{}'", code)))?;
+
+            let inserted = match level {
+                RegionLevel::Province => {
+                    sqlx::query!(
+                        r#"
+                        INSERT INTO agency_jurisdiction_regions (agency_id, province_id)
+                        SELECT $1, id FROM provinces WHERE code = $2
+                        "#,
+                        agency_id,
+                        code
+                    )
+                    .execute(&mut *tx)
+                    .await
+                }
+                RegionLevel::Regency => {
+                    sqlx::query!(
+                        r#"
+                        INSERT INTO agency_jurisdiction_regions (agency_id, regency_id)
+                        SELECT $1, id FROM regencies WHERE code = $2
+                        "#,
+                        agency_id,
+                        code
+                    )
+                    .execute(&mut *tx)
+                    .await
+                }
+                RegionLevel::District => {
+                    sqlx::query!(
+                        r#"
+                        INSERT INTO agency_jurisdiction_regions (agency_id, district_id)
+                        SELECT $1, id FROM districts WHERE code = $2
+                        "#,
+                        agency_id,
+                        code
+                    )
+                    .execute(&mut *tx)
+                    .await
+                }
+                RegionLevel::Village => {
+                    sqlx::query!(
+                        r#"
+                        INSERT INTO agency_jurisdiction_regions (agency_id, village_id)
+                        SELECT $1, id FROM villages WHERE code = $2
+                        "#,
+                        agency_id,
+                        code
+                    )
+                    .execute(&mut *tx)
+                    .await
+                }
+            }
+            .map_err(|e| {
+                tracing::error!("Failed to add agency region: {:?}", e);
+                AppError::Database(e)
+            })?;
+
+            if inserted.rows_affected() == 0 {
+                return Err(AppError::Validation(format!(
+                    "Region {} '{}' not found",
+                    level, code
+                )));
+            }
+        }
+
+        let mut category_slugs = dto.category_slugs.clone();
+        category_slugs.sort();
+        category_slugs.dedup();
+
+        for slug in &category_slugs {
+            sqlx::query!(
+                r#"
+                INSERT INTO agency_jurisdiction_categories (agency_id, category_slug)
+                VALUES ($1, $2)
+                "#,
+                agency_id,
+                slug
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| match handle_db_error(e) {
+                AppError::BadRequest(_) => {
+                    AppError::Validation(format!("Category '{}' not found", slug))
+                }
+                other => other,
+            })?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit agency jurisdiction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!(
+            "Replaced jurisdiction of agency {}: {} regions, {} categories",
+            agency_id,
+            region_codes.len(),
+            category_slugs.len()
+        );
+
+        self.get_jurisdiction(agency_id).await
+    }
+
+    async fn find(&self, id: Uuid) -> Result<Agency> {
+        sqlx::query_as!(Agency, "SELECT * FROM agencies WHERE id = $1", id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to get agency: {:?}", e);
+                AppError::Database(e)
+            })?
+            .ok_or_else(|| AppError::NotFound(format!("Agency {} not found", id)))
+    }
+}
@@ -0,0 +1,5 @@
+mod agency_routing_service;
+mod agency_service;
+
+pub use agency_routing_service::{AgencyRoutingService, RoutingCandidate};
+pub use agency_service::AgencyService;
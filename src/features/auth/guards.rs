@@ -59,7 +59,6 @@ where
 /// ```ignore
 /// pub async fn handler(RequireAdminCurator(user): RequireAdminCurator) { ... }
 /// ```
-#[allow(dead_code)]
 pub struct RequireAdminCurator(pub AuthenticatedUser);
 
 impl<S> FromRequestParts<S> for RequireAdminCurator
@@ -93,7 +92,6 @@ where
 /// ```ignore
 /// pub async fn handler(RequireOfficial(user): RequireOfficial) { ... }
 /// ```
-#[allow(dead_code)]
 pub struct RequireOfficial(pub AuthenticatedUser);
 
 impl<S> FromRequestParts<S> for RequireOfficial
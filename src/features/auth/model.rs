@@ -23,7 +23,6 @@ impl AuthenticatedUser {
     }
 
     /// Check if user is admin curator (can curate citizen reports, manage marketplace)
-    #[allow(dead_code)]
     pub fn is_admin_curator(&self) -> bool {
         self.has_role("admin_curator")
     }
@@ -35,23 +34,33 @@ impl AuthenticatedUser {
     }
 
     /// Check if user is official (can claim and resolve problems)
-    #[allow(dead_code)]
     pub fn is_official(&self) -> bool {
         self.has_role("official")
     }
 
     /// Check if user has admin-level access (super_admin or admin_curator)
-    #[allow(dead_code)]
     pub fn has_admin_access(&self) -> bool {
         self.is_super_admin() || self.is_admin_curator()
     }
 
     /// Check if user has official-level access (super_admin, admin_curator, or official)
-    #[allow(dead_code)]
     pub fn has_official_access(&self) -> bool {
         self.has_admin_access() || self.is_official()
     }
 
+    /// Highest-privilege role of the user, recorded on audit trails
+    pub fn primary_role(&self) -> &'static str {
+        if self.is_super_admin() {
+            "super_admin"
+        } else if self.is_admin_curator() {
+            "admin_curator"
+        } else if self.is_official() {
+            "official"
+        } else {
+            "citizen"
+        }
+    }
+
     /// Check if user has citizen-level access (any authenticated user)
     /// All roles can access citizen features
     #[allow(dead_code)]
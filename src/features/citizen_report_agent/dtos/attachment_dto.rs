@@ -3,12 +3,6 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-/// Maximum number of attachments allowed per thread
-pub const MAX_ATTACHMENTS_PER_THREAD: i64 = 5;
-
-/// Maximum file size in bytes (20MB)
-pub const MAX_ATTACHMENT_SIZE: usize = 20 * 1024 * 1024;
-
 /// Allowed MIME types for thread attachments
 pub const ALLOWED_ATTACHMENT_MIME_TYPES: &[&str] = &[
     // Images
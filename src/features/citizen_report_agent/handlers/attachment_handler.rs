@@ -13,7 +13,6 @@ use crate::features::auth::model::AuthenticatedUser;
 use crate::features::citizen_report_agent::dtos::{
     is_attachment_mime_type_allowed, AttachmentCountDto, DeleteAttachmentResponseDto,
     ThreadAttachmentResponseDto, UploadAttachmentDto, ALLOWED_ATTACHMENT_MIME_TYPES,
-    MAX_ATTACHMENT_SIZE,
 };
 use crate::features::citizen_report_agent::services::ThreadAttachmentService;
 use crate::shared::types::ApiResponse;
@@ -101,15 +100,6 @@ pub async fn upload_attachment(
     let content_type =
         content_type.ok_or_else(|| AppError::BadRequest("Content type is required".to_string()))?;
 
-    // Validate file size
-    if file_data.len() > MAX_ATTACHMENT_SIZE {
-        return Err(AppError::BadRequest(format!(
-            "File too large. Maximum size is {} bytes ({} MB)",
-            MAX_ATTACHMENT_SIZE,
-            MAX_ATTACHMENT_SIZE / 1024 / 1024
-        )));
-    }
-
     // Validate MIME type
     if !is_attachment_mime_type_allowed(&content_type) {
         return Err(AppError::BadRequest(format!(
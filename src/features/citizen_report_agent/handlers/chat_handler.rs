@@ -17,6 +17,7 @@ use crate::shared::types::ApiResponse;
 
 use super::super::dtos::{ChatRequestDto, ChatResponseDto, ContentBlockInput, MessageContentInput};
 use super::super::services::{AgentRuntimeService, ThreadAttachmentService};
+use crate::features::moderation::ModerationService;
 use crate::features::rate_limits::services::RateLimitService;
 
 /// State for chat handlers
@@ -25,6 +26,59 @@ pub struct ChatState {
     pub agent_runtime: Arc<AgentRuntimeService>,
     pub attachment_service: Arc<ThreadAttachmentService>,
     pub rate_limit_service: Arc<RateLimitService>,
+    pub moderation_service: Arc<ModerationService>,
+}
+
+/// Check the user's rate limits: the daily report limit and the spam/abuse block
+async fn check_limits(state: &ChatState, user_id: &str) -> Result<()> {
+    if !state.rate_limit_service.can_user_chat(user_id).await? {
+        return Err(AppError::RateLimitExceeded(
+            "You have reached your daily report limit. Please try again tomorrow after 00:00 WIB."
+                .to_string(),
+        ));
+    }
+
+    if state.rate_limit_service.is_user_blocked(user_id).await? {
+        return Err(AppError::RateLimitExceeded(
+            "Your account is temporarily restricted after repeated spam or abusive messages."
+                .to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Text of a message (text blocks only), for moderation
+fn message_text(input: &MessageContentInput) -> String {
+    match input {
+        MessageContentInput::Text(text) => text.clone(),
+        MessageContentInput::Blocks(blocks) => blocks
+            .iter()
+            .filter_map(|b| match b {
+                ContentBlockInput::Text { text } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+/// Record a moderation verdict for the message in the background (best effort - never
+/// blocks or delays the message, even when the LLM classifier is consulted)
+fn moderate_message(state: &ChatState, user_id: &str, dto: &ChatRequestDto) {
+    let moderation_service = Arc::clone(&state.moderation_service);
+    let user_id = user_id.to_string();
+    let thread_id = dto.thread_id;
+    let text = message_text(&dto.content);
+
+    tokio::spawn(async move {
+        if let Err(e) = moderation_service
+            .moderate_message(&user_id, thread_id, &text)
+            .await
+        {
+            tracing::warn!("Failed to moderate message from {}: {:?}", user_id, e);
+        }
+    });
 }
 
 /// Convert MessageContentInput (DTO) to MessageContent (ADK)
@@ -83,7 +137,7 @@ fn parse_raw_sse(raw: &str) -> (Option<String>, String) {
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden - Thread belongs to another user"),
         (status = 404, description = "Thread not found"),
-        (status = 429, description = "Rate limit exceeded - daily ticket limit reached")
+        (status = 429, description = "Rate limit exceeded - daily ticket limit reached or blocked for abuse")
     ),
     tag = "citizen-report-agent",
     security(
@@ -95,22 +149,16 @@ pub async fn chat_stream(
     State(state): State<ChatState>,
     Json(dto): Json<ChatRequestDto>,
 ) -> Result<Response> {
-    // Check rate limit FIRST
-    if !state
-        .rate_limit_service
-        .can_user_chat(&user.account_id)
-        .await?
-    {
-        return Err(AppError::RateLimitExceeded(
-            "You have reached your daily report limit. Please try again tomorrow after 00:00 WIB."
-                .to_string(),
-        ));
-    }
+    // Check rate limits FIRST
+    check_limits(&state, &user.account_id).await?;
 
     // Validate request
     dto.validate()
         .map_err(|e| AppError::Validation(format!("Invalid request: {}", e)))?;
 
+    // Flag spam and abuse; the agent still answers and decides whether to close
+    moderate_message(&state, &user.account_id, &dto);
+
     // Convert DTO content to ADK MessageContent
     let content = convert_content(dto.content);
 
@@ -183,7 +231,7 @@ pub async fn chat_stream(
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Forbidden - Thread belongs to another user"),
         (status = 404, description = "Thread not found"),
-        (status = 429, description = "Rate limit exceeded - daily ticket limit reached"),
+        (status = 429, description = "Rate limit exceeded - daily ticket limit reached or blocked for abuse"),
         (status = 502, description = "AI service error")
     ),
     tag = "citizen-report-agent",
@@ -196,22 +244,16 @@ pub async fn chat_sync(
     State(state): State<ChatState>,
     Json(dto): Json<ChatRequestDto>,
 ) -> Result<Json<ApiResponse<ChatResponseDto>>> {
-    // Check rate limit FIRST
-    if !state
-        .rate_limit_service
-        .can_user_chat(&user.account_id)
-        .await?
-    {
-        return Err(AppError::RateLimitExceeded(
-            "You have reached your daily report limit. Please try again tomorrow after 00:00 WIB."
-                .to_string(),
-        ));
-    }
+    // Check rate limits FIRST
+    check_limits(&state, &user.account_id).await?;
 
     // Validate request
     dto.validate()
         .map_err(|e| AppError::Validation(format!("Invalid request: {}", e)))?;
 
+    // Flag spam and abuse; the agent still answers and decides whether to close
+    moderate_message(&state, &user.account_id, &dto);
+
     // Convert DTO content to ADK MessageContent
     let content = convert_content(dto.content);
 
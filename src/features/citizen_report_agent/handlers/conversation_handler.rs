@@ -39,7 +39,7 @@ pub async fn list_threads(
     Ok(Json(ApiResponse::success(
         Some(threads),
         None,
-        Some(Meta { total }),
+        Some(Meta::new(total)),
     )))
 }
 
@@ -101,6 +101,6 @@ pub async fn list_messages(
     Ok(Json(ApiResponse::success(
         Some(messages),
         None,
-        Some(Meta { total }),
+        Some(Meta::new(total)),
     )))
 }
@@ -15,10 +15,15 @@ use super::handlers::{
     rate_limit_handler::get_user_rate_limit,
 };
 use super::services::{AgentRuntimeService, ConversationService, ThreadAttachmentService};
+use crate::features::moderation::ModerationService;
 use crate::features::rate_limits::services::RateLimitService;
+use crate::features::settings::models::ATTACHMENT_MAX_SIZE_UPPER_BOUND;
 
-/// Maximum body size for attachment uploads (21MB to account for multipart overhead)
-const ATTACHMENT_BODY_LIMIT: usize = 21 * 1024 * 1024;
+/// Maximum body size for attachment uploads
+///
+/// Covers the largest configurable attachment size plus 1MB of multipart overhead; the
+/// configured limit itself is enforced by the attachment service.
+const ATTACHMENT_BODY_LIMIT: usize = ATTACHMENT_MAX_SIZE_UPPER_BOUND + 1024 * 1024;
 
 /// Create routes for the citizen report agent feature
 pub fn routes(
@@ -26,11 +31,13 @@ pub fn routes(
     conversation_service: Arc<ConversationService>,
     attachment_service: Arc<ThreadAttachmentService>,
     rate_limit_service: Arc<RateLimitService>,
+    moderation_service: Arc<ModerationService>,
 ) -> Router {
     let chat_state = ChatState {
         agent_runtime: agent_runtime_service,
         attachment_service: Arc::clone(&attachment_service),
         rate_limit_service: Arc::clone(&rate_limit_service),
+        moderation_service,
     };
 
     let attachment_state = AttachmentState { attachment_service };
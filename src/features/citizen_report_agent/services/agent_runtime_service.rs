@@ -9,6 +9,7 @@ use tokio::sync::mpsc;
 use uuid::Uuid;
 
 use crate::core::error::{AppError, Result};
+use crate::features::settings::{SettingKey, SettingsService};
 use crate::shared::prompts::render_citizen_report_agent_prompt;
 
 /// Service for managing agent runtime and chat operations
@@ -18,6 +19,8 @@ pub struct AgentRuntimeService {
     openai_api_key: String,
     model_name: String,
     tools: ToolRegistry,
+    /// Agent iteration limit
+    settings_service: Arc<SettingsService>,
 }
 
 impl AgentRuntimeService {
@@ -28,6 +31,7 @@ impl AgentRuntimeService {
         storage: Arc<PostgresStorage>,
         openai_api_key: String,
         model_name: String,
+        settings_service: Arc<SettingsService>,
     ) -> Self {
         Self {
             tensorzero_client,
@@ -35,6 +39,7 @@ impl AgentRuntimeService {
             openai_api_key,
             model_name,
             tools: ToolRegistry::new(),
+            settings_service,
         }
     }
 
@@ -45,6 +50,7 @@ impl AgentRuntimeService {
         openai_api_key: String,
         model_name: String,
         tools: ToolRegistry,
+        settings_service: Arc<SettingsService>,
     ) -> Self {
         Self {
             tensorzero_client,
@@ -52,6 +58,7 @@ impl AgentRuntimeService {
             openai_api_key,
             model_name,
             tools,
+            settings_service,
         }
     }
 
@@ -79,6 +86,11 @@ impl AgentRuntimeService {
             .await
             .map_err(|e| AppError::Internal(format!("Failed to render prompt template: {}", e)))?;
 
+        let max_iterations = self
+            .settings_service
+            .get_i64(SettingKey::AgentMaxIterations)
+            .await;
+
         AgentBuilder::new()
             .tensorzero_client(self.tensorzero_client.clone())
             .storage(Arc::clone(&self.storage))
@@ -88,7 +100,7 @@ impl AgentRuntimeService {
             }))
             .system_prompt(&system_prompt)
             .tools(self.tools.clone())
-            .max_iterations(10)
+            .max_iterations(max_iterations as usize)
             .build()
             .map_err(|e| AppError::Internal(format!("Failed to build agent: {}", e)))
     }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use balungpisah_adk::{MessageStorage, PostgresStorage, ThreadStorage};
+use balungpisah_adk::{Message, MessageStorage, PostgresStorage, ThreadStorage};
 use uuid::Uuid;
 
 use crate::core::error::{AppError, Result};
@@ -129,16 +129,31 @@ impl ConversationService {
             .into_iter()
             .skip(offset)
             .take(limit)
-            .map(|m| MessageResponseDto {
-                id: m.id,
-                thread_id: m.thread_id,
-                role: format!("{:?}", m.role).to_lowercase(),
-                content: serde_json::to_value(&m.content).unwrap_or_default(),
-                episode_id: m.episode_id,
-                created_at: m.created_at,
-            })
+            .map(to_message_dto)
             .collect();
 
         Ok((paginated, total))
     }
+
+    /// List all messages of a thread without an ownership check (admin tooling only)
+    pub async fn list_thread_messages(&self, thread_id: Uuid) -> Result<Vec<MessageResponseDto>> {
+        let messages = self
+            .storage
+            .get_thread_messages(thread_id)
+            .await
+            .map_err(|e| AppError::Internal(format!("Failed to get messages: {}", e)))?;
+
+        Ok(messages.into_iter().map(to_message_dto).collect())
+    }
+}
+
+fn to_message_dto(m: Message) -> MessageResponseDto {
+    MessageResponseDto {
+        id: m.id,
+        thread_id: m.thread_id,
+        role: format!("{:?}", m.role).to_lowercase(),
+        content: serde_json::to_value(&m.content).unwrap_or_default(),
+        episode_id: m.episode_id,
+        created_at: m.created_at,
+    }
 }
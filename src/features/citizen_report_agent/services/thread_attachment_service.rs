@@ -7,11 +7,11 @@ use uuid::Uuid;
 
 use crate::core::error::{AppError, Result};
 use crate::features::citizen_report_agent::dtos::{
-    AttachmentCountDto, ThreadAttachmentResponseDto, MAX_ATTACHMENTS_PER_THREAD,
-    MAX_ATTACHMENT_SIZE,
+    AttachmentCountDto, ThreadAttachmentResponseDto,
 };
 use crate::features::citizen_report_agent::models::ThreadAttachment;
 use crate::features::files::dtos::get_extension_from_content_type;
+use crate::features::settings::{SettingKey, SettingsService};
 use crate::modules::storage::{FileVisibility, MinIOClient};
 
 /// Service for managing thread attachments
@@ -19,6 +19,8 @@ pub struct ThreadAttachmentService {
     pool: PgPool,
     minio_client: Arc<MinIOClient>,
     adk_storage: Arc<PostgresStorage>,
+    /// Attachment count and size limits
+    settings_service: Arc<SettingsService>,
 }
 
 impl ThreadAttachmentService {
@@ -26,11 +28,13 @@ impl ThreadAttachmentService {
         pool: PgPool,
         minio_client: Arc<MinIOClient>,
         adk_storage: Arc<PostgresStorage>,
+        settings_service: Arc<SettingsService>,
     ) -> Self {
         Self {
             pool,
             minio_client,
             adk_storage,
+            settings_service,
         }
     }
 
@@ -70,10 +74,15 @@ impl ThreadAttachmentService {
         .fetch_one(&self.pool)
         .await?;
 
+        let max_allowed = self
+            .settings_service
+            .get_i64(SettingKey::AttachmentsMaxPerThread)
+            .await;
+
         Ok(AttachmentCountDto {
             count,
-            max_allowed: MAX_ATTACHMENTS_PER_THREAD,
-            can_upload: count < MAX_ATTACHMENTS_PER_THREAD,
+            max_allowed,
+            can_upload: count < max_allowed,
         })
     }
 
@@ -90,11 +99,15 @@ impl ThreadAttachmentService {
         self.verify_thread_ownership(thread_id, owner_id).await?;
 
         // Check file size
-        if data.len() > MAX_ATTACHMENT_SIZE {
+        let max_size = self
+            .settings_service
+            .get_i64(SettingKey::AttachmentsMaxSizeBytes)
+            .await as usize;
+        if data.len() > max_size {
             return Err(AppError::BadRequest(format!(
                 "File too large. Maximum size is {} bytes ({} MB)",
-                MAX_ATTACHMENT_SIZE,
-                MAX_ATTACHMENT_SIZE / 1024 / 1024
+                max_size,
+                max_size / 1024 / 1024
             )));
         }
 
@@ -103,7 +116,7 @@ impl ThreadAttachmentService {
         if !count_info.can_upload {
             return Err(AppError::BadRequest(format!(
                 "Maximum number of attachments ({}) reached for this thread",
-                MAX_ATTACHMENTS_PER_THREAD
+                count_info.max_allowed
             )));
         }
 
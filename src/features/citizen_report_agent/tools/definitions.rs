@@ -14,8 +14,15 @@ pub fn create_report_tool() -> ToolDefinition {
         )
         .number_param(
             "confidence",
-            "Your confidence level (0.0-1.0). For 'submit': how certain this is a complete, actionable report (>=0.7 to process). \
+            "Your confidence level (0.0-1.0). For 'submit': how certain this is a complete, actionable report (low scores are reviewed by a curator before processing). \
              For 'close': how certain there is no valid report.",
         )
+        .string_param(
+            "visibility",
+            "Reporter identity for 'submit': 'public' (default) or 'anonymous'. \
+             Use 'anonymous' only when the citizen asked to report anonymously, e.g. when reporting \
+             illegal levies or abuse by officials. Anonymous reports hide the citizen's identity and \
+             conversation from officials; the citizen can still track the report.",
+        )
         .build()
 }
@@ -3,15 +3,19 @@ use serde_json::{json, Value};
 use sqlx::PgPool;
 
 use crate::features::reports::models::CreateReportJob;
-use crate::features::reports::services::{ReportJobService, ReportService};
-
-/// Minimum confidence score required for a report to be processed
-const MIN_CONFIDENCE_FOR_PROCESSING: f64 = 0.7;
+use crate::features::reports::services::{ReportJobService, ReportReviewService, ReportService};
+use crate::features::settings::{SettingKey, SettingsService};
 
 /// Handle the `create_report` tool call
-/// Creates a report submission and queues it for background processing,
-/// or closes the conversation without creating a report
-pub async fn handle_create_report(args: Value, ctx: ToolContext, pool: &PgPool) -> ToolResult {
+/// Creates a report submission and queues it for background processing (or for curator
+/// review when the confidence is below the threshold), or closes the conversation without
+/// creating a report
+pub async fn handle_create_report(
+    args: Value,
+    ctx: ToolContext,
+    pool: &PgPool,
+    settings_service: &SettingsService,
+) -> ToolResult {
     // Extract action and confidence from arguments
     let action = args
         .get("action")
@@ -23,6 +27,11 @@ pub async fn handle_create_report(args: Value, ctx: ToolContext, pool: &PgPool)
         .and_then(|v| v.as_f64())
         .unwrap_or(0.5);
 
+    let is_anonymous = args
+        .get("visibility")
+        .and_then(|v| v.as_str())
+        .is_some_and(|v| v.eq_ignore_ascii_case("anonymous"));
+
     // Handle "close" action - end conversation without creating a report
     if action == "close" {
         tracing::info!(
@@ -51,7 +60,7 @@ pub async fn handle_create_report(args: Value, ctx: ToolContext, pool: &PgPool)
 
     // Create report submission with auto-generated reference number
     let report = match report_service
-        .create_submission_auto_ref(thread_id, user_id, Some("web"))
+        .create_submission_auto_ref(thread_id, user_id, Some("web"), is_anonymous)
         .await
     {
         Ok(r) => r,
@@ -67,8 +76,12 @@ pub async fn handle_create_report(args: Value, ctx: ToolContext, pool: &PgPool)
 
     let reference_number = report.reference_number.as_deref().unwrap_or("UNKNOWN");
 
+    let threshold = settings_service
+        .get_f64(SettingKey::ReportReviewConfidenceThreshold)
+        .await;
+
     // Check if report meets criteria for processing
-    if confidence >= MIN_CONFIDENCE_FOR_PROCESSING {
+    if confidence >= threshold {
         // High confidence - create job for background processing
         let job_data = CreateReportJob {
             report_id: report.id,
@@ -99,6 +112,7 @@ pub async fn handle_create_report(args: Value, ctx: ToolContext, pool: &PgPool)
                 "action": "submitted",
                 "reference_number": reference_number,
                 "report_id": report.id,
+                "anonymous": is_anonymous,
                 "will_be_processed": true,
                 "message": format!(
                     "Laporan berhasil dibuat dengan nomor referensi {}. \
@@ -108,18 +122,19 @@ pub async fn handle_create_report(args: Value, ctx: ToolContext, pool: &PgPool)
             }),
         )
     } else {
-        // Low confidence - reject the report without creating a job
-        let reject_reason = format!(
-            "Low confidence score: {:.2} (minimum required: {:.2})",
-            confidence, MIN_CONFIDENCE_FOR_PROCESSING
-        );
-
-        if let Err(e) = report_service.reject(report.id, Some(&reject_reason)).await {
-            tracing::error!("Failed to reject report: {:?}", e);
+        // Low confidence - queue the report for curator review instead of processing it
+        if let Err(e) =
+            ReportReviewService::enqueue(pool, report.id, Some(confidence), threshold).await
+        {
+            tracing::error!("Failed to queue report for review: {:?}", e);
+            tracing::warn!(
+                "Report {} created but not queued for review. Manual review may be needed.",
+                report.id
+            );
         }
 
         tracing::info!(
-            "Report rejected due to low confidence: id={}, ref={}, user={}, confidence={}",
+            "Report queued for review due to low confidence: id={}, ref={}, user={}, confidence={}",
             report.id,
             reference_number,
             user_id,
@@ -134,10 +149,13 @@ pub async fn handle_create_report(args: Value, ctx: ToolContext, pool: &PgPool)
                 "action": "submitted",
                 "reference_number": reference_number,
                 "report_id": report.id,
+                "anonymous": is_anonymous,
                 "will_be_processed": false,
+                "needs_review": true,
                 "message": format!(
                     "Laporan berhasil dibuat dengan nomor referensi {}. \
-                     Laporan Anda telah dicatat namun memerlukan informasi tambahan untuk dapat diproses lebih lanjut.",
+                     Laporan Anda akan ditinjau terlebih dahulu oleh petugas kami. \
+                     Kami akan menghubungi Anda jika memerlukan informasi tambahan.",
                     reference_number
                 )
             }),
@@ -3,11 +3,16 @@ use serde_json::Value;
 use sqlx::PgPool;
 use std::sync::Arc;
 
+use crate::features::settings::SettingsService;
+
 use super::definitions::create_report_tool;
 use super::handlers::handle_create_report;
 
 /// Create a tool registry with all citizen report agent tools
-pub fn create_tool_registry(pool: Arc<PgPool>) -> ToolRegistry {
+pub fn create_tool_registry(
+    pool: Arc<PgPool>,
+    settings_service: Arc<SettingsService>,
+) -> ToolRegistry {
     let mut registry = ToolRegistry::new();
 
     // Register create_report tool (new workflow)
@@ -16,7 +21,8 @@ pub fn create_tool_registry(pool: Arc<PgPool>) -> ToolRegistry {
         create_report_tool(),
         move |args: Value, ctx: ToolContext| {
             let pool = Arc::clone(&pool_clone);
-            async move { handle_create_report(args, ctx, &pool).await }
+            let settings_service = Arc::clone(&settings_service);
+            async move { handle_create_report(args, ctx, &pool, &settings_service).await }
         },
     ));
 
@@ -1,10 +1,14 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::features::reports::models::{ReportSeverity, ReportStatus, ReportTagType};
+use crate::features::reports::dtos::{ReportFilter, ReportSort, ReportSortBy};
+use crate::features::reports::models::{
+    ReportLanguage, ReportSeverity, ReportStatus, ReportTagType,
+};
 use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+use crate::shared::types::{deserialize_list, search_text, SearchMatch, SortDirection};
 
 // ============================================================================
 // Pagination
@@ -40,10 +44,35 @@ fn default_page_size() -> i64 {
     DEFAULT_PAGE_SIZE
 }
 
+/// Sort order for dashboard report listings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DashboardReportSort {
+    /// Newest reports first
+    #[default]
+    Newest,
+    /// Most endorsed reports first (ties broken by newest)
+    MostEndorsed,
+}
+
+impl DashboardReportSort {
+    pub fn by_endorsements(&self) -> bool {
+        matches!(self, DashboardReportSort::MostEndorsed)
+    }
+
+    /// Sort field of the preset (always descending)
+    pub fn sort_by(&self) -> ReportSortBy {
+        match self {
+            DashboardReportSort::Newest => ReportSortBy::CreatedAt,
+            DashboardReportSort::MostEndorsed => ReportSortBy::Endorsements,
+        }
+    }
+}
+
 /// Standard pagination query parameters
 #[derive(Debug, Clone, Deserialize, IntoParams)]
 pub struct PaginationParams {
-    /// Page number (1-indexed)
+    /// Page number (1-indexed, ignored with `cursor`)
     #[serde(default = "default_page")]
     #[param(minimum = 1)]
     pub page: i64,
@@ -52,6 +81,60 @@ pub struct PaginationParams {
     #[serde(default = "default_page_size")]
     #[param(minimum = 1, maximum = 100)]
     pub page_size: i64,
+
+    /// Cursor from `meta.next_cursor` of the previous page (keyset pagination)
+    pub cursor: Option<String>,
+    /// Sort preset (default: newest); `sort_by` takes precedence
+    #[serde(default)]
+    pub sort: DashboardReportSort,
+    /// Sort by field (default: relevance with `q`, otherwise the `sort` preset)
+    pub sort_by: Option<ReportSortBy>,
+    /// Direction of `sort_by` (default: desc)
+    #[serde(default)]
+    pub sort_dir: SortDirection,
+    /// Return title and description translated to this language where a translation exists
+    pub lang: Option<ReportLanguage>,
+    /// Full-text search over the redacted title, description, timeline and impact and the
+    /// geocoded location (Indonesian stemming, web search syntax); results are ranked by relevance
+    pub q: Option<String>,
+    /// Filter by status (comma-separated, any of)
+    #[serde(default, deserialize_with = "deserialize_list")]
+    #[param(style = Form, explode = false)]
+    pub status: Vec<ReportStatus>,
+    /// Created from date (YYYY-MM-DD)
+    pub from_date: Option<NaiveDate>,
+    /// Created to date, inclusive (YYYY-MM-DD)
+    pub to_date: Option<NaiveDate>,
+    /// Verified from date (YYYY-MM-DD)
+    pub verified_from: Option<NaiveDate>,
+    /// Verified to date, inclusive (YYYY-MM-DD)
+    pub verified_to: Option<NaiveDate>,
+    /// Resolved from date (YYYY-MM-DD)
+    pub resolved_from: Option<NaiveDate>,
+    /// Resolved to date, inclusive (YYYY-MM-DD)
+    pub resolved_to: Option<NaiveDate>,
+    /// Filter reports with or without attachments
+    pub has_attachments: Option<bool>,
+    /// Filter by severity of any category (comma-separated, any of)
+    #[serde(default, deserialize_with = "deserialize_list")]
+    #[param(style = Form, explode = false)]
+    pub severity: Vec<ReportSeverity>,
+    /// Filter by category including child categories (comma-separated, any of)
+    #[serde(default, deserialize_with = "deserialize_list")]
+    #[param(style = Form, explode = false)]
+    pub category_id: Vec<Uuid>,
+    /// Filter by tag (comma-separated, any of)
+    #[serde(default, deserialize_with = "deserialize_list")]
+    #[param(style = Form, explode = false)]
+    pub tag_type: Vec<ReportTagType>,
+    /// Filter by province
+    pub province_id: Option<Uuid>,
+    /// Filter by regency
+    pub regency_id: Option<Uuid>,
+    /// Filter by district
+    pub district_id: Option<Uuid>,
+    /// Filter by village
+    pub village_id: Option<Uuid>,
 }
 
 impl PaginationParams {
@@ -62,6 +145,39 @@ impl PaginationParams {
     pub fn limit(&self) -> i64 {
         self.page_size.clamp(1, MAX_PAGE_SIZE)
     }
+
+    /// Filter part of the query (public filters only)
+    pub fn filter(&self) -> ReportFilter {
+        ReportFilter {
+            status: self.status.clone(),
+            from_date: self.from_date,
+            to_date: self.to_date,
+            verified_from: self.verified_from,
+            verified_to: self.verified_to,
+            resolved_from: self.resolved_from,
+            resolved_to: self.resolved_to,
+            q: self.q.clone(),
+            has_attachments: self.has_attachments,
+            severity: self.severity.clone(),
+            category_id: self.category_id.clone(),
+            tag_type: self.tag_type.clone(),
+            province_id: self.province_id,
+            regency_id: self.regency_id,
+            district_id: self.district_id,
+            village_id: self.village_id,
+            ..Default::default()
+        }
+    }
+
+    /// Sort of the query
+    pub fn sort(&self) -> ReportSort {
+        let searching = search_text(self.q.as_deref()).is_some();
+        if searching || self.sort_by.is_some() {
+            ReportSort::new(self.sort_by, self.sort_dir, searching)
+        } else {
+            ReportSort::new(Some(self.sort.sort_by()), SortDirection::Desc, false)
+        }
+    }
 }
 
 // ============================================================================
@@ -95,21 +211,49 @@ pub struct ReportLocationInfo {
     pub regency_name: Option<String>,
 }
 
+/// File attached to a progress update
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportUpdateAttachmentInfo {
+    pub file_id: Uuid,
+    pub original_filename: String,
+    pub content_type: String,
+    pub url: String,
+}
+
+/// Public progress update posted by an official
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportUpdateInfo {
+    pub id: Uuid,
+    /// Role of the author (official, admin_curator, super_admin)
+    pub author_role: String,
+    pub message: String,
+    pub created_at: DateTime<Utc>,
+    pub attachments: Vec<ReportUpdateAttachmentInfo>,
+}
+
 /// Report item for listing
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DashboardReportDto {
     pub id: Uuid,
-    /// Report title (may be None for unprocessed reports)
+    /// Report title with personal data redacted (None until processed)
     pub title: Option<String>,
-    /// Report description (may be None for unprocessed reports)
+    /// Language of the returned title and description
+    pub language: Option<ReportLanguage>,
+    /// Report description with personal data redacted (None until processed)
     pub description: Option<String>,
     pub status: ReportStatus,
     pub tag_type: Option<ReportTagType>,
+    /// Timeline with personal data redacted
     pub timeline: Option<String>,
+    /// Impact with personal data redacted
     pub impact: Option<String>,
+    /// Number of citizens who endorsed the report
+    pub endorsement_count: i32,
     pub created_at: DateTime<Utc>,
     pub categories: Vec<ReportCategoryInfo>,
     pub location: Option<ReportLocationInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<SearchMatch>,
 }
 
 /// Report detail with full information
@@ -118,21 +262,36 @@ pub struct DashboardReportDetailDto {
     pub id: Uuid,
     /// Report reference number
     pub reference_number: Option<String>,
-    /// Report title (may be None for unprocessed reports)
+    /// Report title with personal data redacted (None until processed)
     pub title: Option<String>,
-    /// Report description (may be None for unprocessed reports)
+    /// Language of the returned title and description
+    pub language: Option<ReportLanguage>,
+    /// Report description with personal data redacted (None until processed)
     pub description: Option<String>,
     pub status: ReportStatus,
     pub tag_type: Option<ReportTagType>,
+    /// Timeline with personal data redacted
     pub timeline: Option<String>,
+    /// Impact with personal data redacted
     pub impact: Option<String>,
     pub verified_at: Option<DateTime<Utc>>,
     pub resolved_at: Option<DateTime<Utc>>,
     pub resolution_notes: Option<String>,
+    /// Number of citizens who endorsed the report
+    pub endorsement_count: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub categories: Vec<ReportCategoryInfo>,
     pub location: Option<ReportLocationInfo>,
+    /// Public progress updates (oldest first)
+    pub updates: Vec<ReportUpdateInfo>,
+}
+
+/// Query params for report detail
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct ReportDetailQueryParams {
+    /// Return title and description translated to this language where a translation exists
+    pub lang: Option<ReportLanguage>,
 }
 
 // ============================================================================
@@ -154,6 +313,11 @@ pub struct LocationQueryParams {
     #[serde(default = "default_page_size")]
     #[param(minimum = 1, maximum = 100)]
     pub page_size: i64,
+    /// Sort order for the report listing (default: newest)
+    #[serde(default)]
+    pub sort: DashboardReportSort,
+    /// Return title and description translated to this language where a translation exists
+    pub lang: Option<ReportLanguage>,
 }
 
 impl LocationQueryParams {
@@ -217,6 +381,11 @@ pub struct CategoryQueryParams {
     #[serde(default = "default_page_size")]
     #[param(minimum = 1, maximum = 100)]
     pub page_size: i64,
+    /// Sort order for the report listing (default: newest)
+    #[serde(default)]
+    pub sort: DashboardReportSort,
+    /// Return title and description translated to this language where a translation exists
+    pub lang: Option<ReportLanguage>,
 }
 
 impl CategoryQueryParams {
@@ -267,6 +436,11 @@ pub struct TagQueryParams {
     #[serde(default = "default_page_size")]
     #[param(minimum = 1, maximum = 100)]
     pub page_size: i64,
+    /// Sort order for the report listing (default: newest)
+    #[serde(default)]
+    pub sort: DashboardReportSort,
+    /// Return title and description translated to this language where a translation exists
+    pub lang: Option<ReportLanguage>,
 }
 
 impl TagQueryParams {
@@ -309,6 +483,8 @@ pub struct RecentQueryParams {
     /// Maximum reports to return
     #[serde(default = "default_limit")]
     pub limit: i64,
+    /// Return title and description translated to this language where a translation exists
+    pub lang: Option<ReportLanguage>,
 }
 
 fn default_days() -> i32 {
@@ -335,13 +511,16 @@ pub struct DashboardRecentDto {
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct MapReportMarker {
     pub id: Uuid,
-    /// Report title (may be None for unprocessed reports)
+    /// Report title with personal data redacted (None until processed)
     pub title: Option<String>,
+    /// Language of the returned title and description
+    pub language: Option<ReportLanguage>,
     pub lat: f64,
     pub lon: f64,
     pub status: ReportStatus,
     pub category_slug: Option<String>,
     pub category_color: Option<String>,
+    pub endorsement_count: i32,
     pub created_at: DateTime<Utc>,
 }
 
@@ -368,6 +547,8 @@ pub struct MapQueryParams {
     /// Maximum markers to return
     #[serde(default = "default_map_limit")]
     pub limit: i64,
+    /// Return title and description translated to this language where a translation exists
+    pub lang: Option<ReportLanguage>,
 }
 
 fn default_map_limit() -> i64 {
@@ -386,6 +567,8 @@ pub struct DashboardSummaryDto {
     pub resolved_count: i64,
     pub reports_this_week: i64,
     pub reports_this_month: i64,
+    /// Reports past their SLA deadline (verification or resolution)
+    pub overdue_count: i64,
 }
 
 // Query Data for map
@@ -35,7 +35,7 @@ pub async fn get_summary(
 // Reports List
 // ============================================================================
 
-/// List all reports with pagination
+/// List published reports with filters, paginated by page or by cursor
 #[utoipa::path(
     get,
     path = "/api/dashboard/reports",
@@ -43,6 +43,7 @@ pub async fn get_summary(
     params(PaginationParams),
     responses(
         (status = 200, description = "Paginated reports list", body = ApiResponse<Vec<DashboardReportDto>>),
+        (status = 400, description = "Invalid cursor"),
         (status = 500, description = "Internal server error")
     )
 )]
@@ -50,11 +51,11 @@ pub async fn list_reports(
     State(service): State<Arc<DashboardService>>,
     Query(params): Query<PaginationParams>,
 ) -> Result<Json<ApiResponse<Vec<DashboardReportDto>>>, AppError> {
-    let (reports, total) = service.list_reports(&params).await?;
+    let (reports, total, next_cursor) = service.list_reports(&params).await?;
     Ok(Json(ApiResponse::success(
         Some(reports),
         None,
-        Some(Meta { total }),
+        Some(Meta::with_cursor(total, next_cursor)),
     )))
 }
 
@@ -64,7 +65,8 @@ pub async fn list_reports(
     path = "/api/dashboard/reports/{id}",
     tag = "Dashboard",
     params(
-        ("id" = Uuid, Path, description = "Report ID")
+        ("id" = Uuid, Path, description = "Report ID"),
+        ReportDetailQueryParams
     ),
     responses(
         (status = 200, description = "Report detail", body = ApiResponse<DashboardReportDetailDto>),
@@ -75,8 +77,9 @@ pub async fn list_reports(
 pub async fn get_report(
     State(service): State<Arc<DashboardService>>,
     Path(id): Path<Uuid>,
+    Query(params): Query<ReportDetailQueryParams>,
 ) -> Result<Json<ApiResponse<DashboardReportDetailDto>>, AppError> {
-    let data = service.get_report(id).await?;
+    let data = service.get_report(id, params.lang).await?;
     Ok(Json(ApiResponse::success(Some(data), None, None)))
 }
 
@@ -3,7 +3,12 @@ use uuid::Uuid;
 
 use crate::core::error::{AppError, Result};
 use crate::features::dashboard::dtos::*;
-use crate::features::reports::models::{ReportSeverity, ReportStatus, ReportTagType};
+use crate::features::reports::dtos::ReportFilterScope;
+use crate::features::reports::models::{
+    ReportLanguage, ReportSeverity, ReportStatus, ReportTagType,
+};
+use crate::shared::constants::SEARCH_HEADLINE_OPTIONS;
+use crate::shared::types::{KeysetCursor, SearchMatch};
 
 /// Service for public dashboard queries
 pub struct DashboardService {
@@ -28,9 +33,16 @@ impl DashboardService {
                 COUNT(*) FILTER (WHERE status = 'pending') as "pending_count!",
                 COUNT(*) FILTER (WHERE status = 'resolved') as "resolved_count!",
                 COUNT(*) FILTER (WHERE created_at >= date_trunc('week', CURRENT_DATE)) as "reports_this_week!",
-                COUNT(*) FILTER (WHERE created_at >= date_trunc('month', CURRENT_DATE)) as "reports_this_month!"
+                COUNT(*) FILTER (WHERE created_at >= date_trunc('month', CURRENT_DATE)) as "reports_this_month!",
+                COUNT(*) FILTER (WHERE EXISTS(
+                    SELECT 1 FROM report_sla_deadlines d
+                    WHERE d.report_id = reports.id
+                      AND d.met_at IS NULL AND d.cancelled_at IS NULL
+                      AND d.due_at < NOW()
+                )) as "overdue_count!"
             FROM reports
             WHERE status NOT IN ('pending', 'rejected')
+              AND redacted_at IS NOT NULL AND moderated_at IS NOT NULL
             "#
         )
         .fetch_one(&self.pool)
@@ -46,6 +58,7 @@ impl DashboardService {
             resolved_count: counts.resolved_count,
             reports_this_week: counts.reports_this_week,
             reports_this_month: counts.reports_this_month,
+            overdue_count: counts.overdue_count,
         })
     }
 
@@ -53,52 +66,111 @@ impl DashboardService {
     // List Reports (paginated)
     // ========================================================================
 
-    /// List all reports with pagination
-    /// Returns (reports, total_count)
+    /// List all reports with pagination and filters
+    /// Returns (reports, total_count, next_cursor); a `cursor` continues after the
+    /// previous page (keyset pagination) instead of using `page`
     pub async fn list_reports(
         &self,
         params: &PaginationParams,
-    ) -> Result<(Vec<DashboardReportDto>, i64)> {
-        let offset = params.offset();
+    ) -> Result<(Vec<DashboardReportDto>, i64, Option<String>)> {
         let limit = params.limit();
+        let sort = params.sort();
+        let cursor = params
+            .cursor
+            .as_deref()
+            .map(|c| KeysetCursor::decode(c, &sort.name()))
+            .transpose()?;
+        let offset = if cursor.is_some() { 0 } else { params.offset() };
+
+        let filter = params.filter();
+        let (where_clause, mut args) = filter.where_clause(ReportFilterScope::Public);
 
         // Get total count
-        let total = sqlx::query_scalar!(
-            r#"SELECT COUNT(*) as "count!" FROM reports WHERE status NOT IN ('pending', 'rejected')"#
-        )
-        .fetch_one(&self.pool)
-        .await
-        .map_err(|e| {
+        let count_query = format!("SELECT COUNT(*) FROM reports r {}", where_clause);
+        let mut count_query = sqlx::query_scalar::<_, i64>(&count_query);
+        for arg in &args {
+            count_query = count_query.bind(arg);
+        }
+        let total = count_query.fetch_one(&self.pool).await.map_err(|e| {
             tracing::error!("Failed to count reports: {:?}", e);
             AppError::Database(e)
         })?;
 
-        // Get reports
-        let rows = sqlx::query!(
+        // Full-text matches are ranked by relevance and highlighted
+        let (rank, search_columns) = match filter.search_text() {
+            Some(q) => {
+                args.push(q.to_string());
+                let tsquery = format!("websearch_to_tsquery('report_search', ${})", args.len());
+                args.push(SEARCH_HEADLINE_OPTIONS.to_string());
+                let rank = format!("ts_rank_cd(r.public_search_vector, {})", tsquery);
+                let columns = format!(
+                    "{} as rank, \
+                     ts_headline('report_search', concat_ws(' … ', r.redacted_title, r.redacted_description, rl.display_name, r.redacted_timeline, r.redacted_impact), {}, ${}) as snippet",
+                    rank,
+                    tsquery,
+                    args.len()
+                );
+                (Some(rank), columns)
+            }
+            None => (
+                None,
+                "NULL::real as rank, NULL::text as snippet".to_string(),
+            ),
+        };
+
+        let translation = match params.lang {
+            Some(lang) => {
+                args.push(lang.to_string());
+                format!("t.language = ${}::report_language", args.len())
+            }
+            None => "false".to_string(),
+        };
+
+        let (sort_key, _) = sort.key(rank.as_deref());
+        let order_by = sort.order_by(rank.as_deref());
+        let where_clause = sort.after(where_clause, cursor.as_ref(), rank.as_deref(), &mut args);
+
+        let data_query = format!(
             r#"
             SELECT
                 r.id,
-                r.title,
-                r.description,
-                r.status as "status: ReportStatus",
-                r.timeline,
-                r.impact,
-                r.created_at
+                COALESCE(t.redacted_title, r.redacted_title) as title,
+                COALESCE(t.redacted_description, r.redacted_description) as description,
+                COALESCE(t.language, r.language) as language,
+                r.status,
+                r.redacted_timeline as timeline,
+                r.redacted_impact as impact,
+                r.endorsement_count,
+                r.created_at,
+                {},
+                ({})::text as sort_key
             FROM reports r
-            WHERE r.status NOT IN ('pending', 'rejected')
-            ORDER BY r.created_at DESC
-            OFFSET $1 LIMIT $2
+            LEFT JOIN report_translations t
+                ON t.report_id = r.id AND {} AND t.redacted_at IS NOT NULL
+            LEFT JOIN report_locations rl ON rl.report_id = r.id
+            {}
+            ORDER BY {}
+            OFFSET {} LIMIT {}
             "#,
-            offset,
-            limit
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| {
+            search_columns, sort_key, translation, where_clause, order_by, offset, limit
+        );
+
+        let mut data_query = sqlx::query_as::<_, DashboardReportRow>(&data_query);
+        for arg in &args {
+            data_query = data_query.bind(arg);
+        }
+        let rows = data_query.fetch_all(&self.pool).await.map_err(|e| {
             tracing::error!("Failed to fetch reports: {:?}", e);
             AppError::Database(e)
         })?;
 
+        // A full page may have more rows after it
+        let next_cursor = rows
+            .last()
+            .filter(|_| rows.len() as i64 == limit)
+            .map(|r| KeysetCursor::new(sort.name(), r.sort_key.clone(), r.id).encode())
+            .transpose()?;
+
         let mut reports = Vec::with_capacity(rows.len());
         for row in rows {
             let categories = self.get_report_categories(row.id).await?;
@@ -108,41 +180,54 @@ impl DashboardService {
             reports.push(DashboardReportDto {
                 id: row.id,
                 title: row.title,
+                language: row.language,
                 description: row.description,
                 status: row.status,
                 tag_type,
                 timeline: row.timeline,
                 impact: row.impact,
+                endorsement_count: row.endorsement_count,
                 created_at: row.created_at,
                 categories,
                 location,
+                search: SearchMatch::from_columns(row.rank, row.snippet),
             });
         }
 
-        Ok((reports, total))
+        Ok((reports, total, next_cursor))
     }
 
     /// Get single report detail
-    pub async fn get_report(&self, id: Uuid) -> Result<DashboardReportDetailDto> {
+    pub async fn get_report(
+        &self,
+        id: Uuid,
+        lang: Option<ReportLanguage>,
+    ) -> Result<DashboardReportDetailDto> {
         let row = sqlx::query!(
             r#"
             SELECT
                 r.id,
                 r.reference_number,
-                r.title,
-                r.description,
+                COALESCE(t.redacted_title, r.redacted_title) as title,
+                COALESCE(t.redacted_description, r.redacted_description) as description,
+                COALESCE(t.language, r.language) as "language: ReportLanguage",
                 r.status as "status: ReportStatus",
-                r.timeline,
-                r.impact,
+                r.redacted_timeline as timeline,
+                r.redacted_impact as impact,
                 r.verified_at,
                 r.resolved_at,
                 r.resolution_notes,
+                r.endorsement_count,
                 r.created_at,
                 r.updated_at
             FROM reports r
+            LEFT JOIN report_translations t
+                ON t.report_id = r.id AND t.language = $2 AND t.redacted_at IS NOT NULL
             WHERE r.id = $1
+              AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
             "#,
-            id
+            id,
+            lang as Option<ReportLanguage>
         )
         .fetch_optional(&self.pool)
         .await
@@ -155,11 +240,13 @@ impl DashboardService {
         let categories = self.get_report_categories(row.id).await?;
         let location = self.get_report_location(row.id).await?;
         let tag_type = self.get_report_tag(row.id).await?;
+        let updates = self.get_report_updates(row.id).await?;
 
         Ok(DashboardReportDetailDto {
             id: row.id,
             reference_number: row.reference_number,
             title: row.title,
+            language: row.language,
             description: row.description,
             status: row.status,
             tag_type,
@@ -168,10 +255,12 @@ impl DashboardService {
             verified_at: row.verified_at,
             resolved_at: row.resolved_at,
             resolution_notes: row.resolution_notes,
+            endorsement_count: row.endorsement_count,
             created_at: row.created_at,
             updated_at: row.updated_at,
             categories,
             location,
+            updates,
         })
     }
 
@@ -206,6 +295,7 @@ impl DashboardService {
                 JOIN report_locations rl ON rl.report_id = r.id
                 WHERE rl.regency_id = $1
                   AND r.status NOT IN ('pending', 'rejected')
+                  AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
                 "#,
                 regency_id
             )
@@ -217,7 +307,7 @@ impl DashboardService {
             })?;
 
             let reports = self
-                .get_reports_by_regency(regency_id, offset, limit)
+                .get_reports_by_regency(regency_id, params.sort, offset, limit, params.lang)
                 .await?;
             let pagination = PaginationMeta::new(params.page, params.page_size, total);
             (Some(reports), Some(pagination))
@@ -242,13 +332,15 @@ impl DashboardService {
                 p.code,
                 p.lat,
                 p.lng,
-                COUNT(rl.id) as "report_count!"
+                COUNT(r.id) as "report_count!"
             FROM provinces p
             LEFT JOIN report_locations rl ON rl.province_id = p.id
-            LEFT JOIN reports r ON r.id = rl.report_id AND r.status NOT IN ('pending', 'rejected')
+            LEFT JOIN reports r ON r.id = rl.report_id
+                AND r.status NOT IN ('pending', 'rejected')
+                AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
             GROUP BY p.id, p.name, p.code, p.lat, p.lng
-            HAVING COUNT(rl.id) > 0
-            ORDER BY COUNT(rl.id) DESC, p.name ASC
+            HAVING COUNT(r.id) > 0
+            ORDER BY COUNT(r.id) DESC, p.name ASC
             "#
         )
         .fetch_all(&self.pool)
@@ -281,14 +373,16 @@ impl DashboardService {
                 rg.code,
                 rg.lat,
                 rg.lng,
-                COUNT(rl.id) as "report_count!"
+                COUNT(r.id) as "report_count!"
             FROM regencies rg
             LEFT JOIN report_locations rl ON rl.regency_id = rg.id
-            LEFT JOIN reports r ON r.id = rl.report_id AND r.status NOT IN ('pending', 'rejected')
+            LEFT JOIN reports r ON r.id = rl.report_id
+                AND r.status NOT IN ('pending', 'rejected')
+                AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
             WHERE rg.province_id = $1
             GROUP BY rg.id, rg.province_id, rg.name, rg.code, rg.lat, rg.lng
-            HAVING COUNT(rl.id) > 0
-            ORDER BY COUNT(rl.id) DESC, rg.name ASC
+            HAVING COUNT(r.id) > 0
+            ORDER BY COUNT(r.id) DESC, rg.name ASC
             "#,
             province_id
         )
@@ -316,29 +410,40 @@ impl DashboardService {
     async fn get_reports_by_regency(
         &self,
         regency_id: Uuid,
+        sort: DashboardReportSort,
         offset: i64,
         limit: i64,
+        lang: Option<ReportLanguage>,
     ) -> Result<Vec<DashboardReportDto>> {
         let rows = sqlx::query!(
             r#"
             SELECT
                 r.id,
-                r.title,
-                r.description,
+                COALESCE(t.redacted_title, r.redacted_title) as title,
+                COALESCE(t.redacted_description, r.redacted_description) as description,
+                COALESCE(t.language, r.language) as "language: ReportLanguage",
                 r.status as "status: ReportStatus",
-                r.timeline,
-                r.impact,
+                r.redacted_timeline as timeline,
+                r.redacted_impact as impact,
+                r.endorsement_count,
                 r.created_at
             FROM reports r
+            LEFT JOIN report_translations t
+                ON t.report_id = r.id AND t.language = $5 AND t.redacted_at IS NOT NULL
             JOIN report_locations rl ON rl.report_id = r.id
             WHERE rl.regency_id = $1
               AND r.status NOT IN ('pending', 'rejected')
-            ORDER BY r.created_at DESC
+              AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
+            ORDER BY
+                CASE WHEN $4 THEN r.endorsement_count ELSE 0 END DESC,
+                r.created_at DESC
             OFFSET $2 LIMIT $3
             "#,
             regency_id,
             offset,
-            limit
+            limit,
+            sort.by_endorsements(),
+            lang as Option<ReportLanguage>
         )
         .fetch_all(&self.pool)
         .await
@@ -356,14 +461,17 @@ impl DashboardService {
             reports.push(DashboardReportDto {
                 id: row.id,
                 title: row.title,
+                language: row.language,
                 description: row.description,
                 status: row.status,
                 tag_type,
                 timeline: row.timeline,
                 impact: row.impact,
+                endorsement_count: row.endorsement_count,
                 created_at: row.created_at,
                 categories,
                 location,
+                search: None,
             });
         }
 
@@ -395,6 +503,7 @@ impl DashboardService {
                 JOIN categories c ON c.id = rc.category_id
                 WHERE c.slug = $1
                   AND r.status NOT IN ('pending', 'rejected')
+                  AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
                 "#,
                 slug
             )
@@ -405,7 +514,9 @@ impl DashboardService {
                 AppError::Database(e)
             })?;
 
-            let reports = self.get_reports_by_category(slug, offset, limit).await?;
+            let reports = self
+                .get_reports_by_category(slug, params.sort, offset, limit, params.lang)
+                .await?;
             let pagination = PaginationMeta::new(params.page, params.page_size, total);
             (Some(reports), Some(pagination))
         } else {
@@ -429,13 +540,15 @@ impl DashboardService {
                 c.description,
                 c.color,
                 c.icon,
-                COUNT(DISTINCT rc.report_id) as "report_count!"
+                COUNT(DISTINCT r.id) as "report_count!"
             FROM categories c
             LEFT JOIN report_categories rc ON rc.category_id = c.id
-            LEFT JOIN reports r ON r.id = rc.report_id AND r.status NOT IN ('pending', 'rejected')
+            LEFT JOIN reports r ON r.id = rc.report_id
+                AND r.status NOT IN ('pending', 'rejected')
+                AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
             WHERE c.is_active = true
             GROUP BY c.id, c.name, c.slug, c.description, c.color, c.icon, c.display_order
-            ORDER BY COUNT(DISTINCT rc.report_id) DESC, c.display_order ASC
+            ORDER BY COUNT(DISTINCT r.id) DESC, c.display_order ASC
             "#
         )
         .fetch_all(&self.pool)
@@ -462,30 +575,44 @@ impl DashboardService {
     async fn get_reports_by_category(
         &self,
         slug: &str,
+        sort: DashboardReportSort,
         offset: i64,
         limit: i64,
+        lang: Option<ReportLanguage>,
     ) -> Result<Vec<DashboardReportDto>> {
         let rows = sqlx::query!(
             r#"
-            SELECT DISTINCT
+            SELECT
                 r.id,
-                r.title,
-                r.description,
+                COALESCE(t.redacted_title, r.redacted_title) as title,
+                COALESCE(t.redacted_description, r.redacted_description) as description,
+                COALESCE(t.language, r.language) as "language: ReportLanguage",
                 r.status as "status: ReportStatus",
-                r.timeline,
-                r.impact,
+                r.redacted_timeline as timeline,
+                r.redacted_impact as impact,
+                r.endorsement_count,
                 r.created_at
             FROM reports r
-            JOIN report_categories rc ON rc.report_id = r.id
-            JOIN categories c ON c.id = rc.category_id
-            WHERE c.slug = $1
+            LEFT JOIN report_translations t
+                ON t.report_id = r.id AND t.language = $5 AND t.redacted_at IS NOT NULL
+            WHERE EXISTS (
+                SELECT 1
+                FROM report_categories rc
+                JOIN categories c ON c.id = rc.category_id
+                WHERE rc.report_id = r.id AND c.slug = $1
+            )
               AND r.status NOT IN ('pending', 'rejected')
-            ORDER BY r.created_at DESC
+              AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
+            ORDER BY
+                CASE WHEN $4 THEN r.endorsement_count ELSE 0 END DESC,
+                r.created_at DESC
             OFFSET $2 LIMIT $3
             "#,
             slug,
             offset,
-            limit
+            limit,
+            sort.by_endorsements(),
+            lang as Option<ReportLanguage>
         )
         .fetch_all(&self.pool)
         .await
@@ -503,14 +630,17 @@ impl DashboardService {
             reports.push(DashboardReportDto {
                 id: row.id,
                 title: row.title,
+                language: row.language,
                 description: row.description,
                 status: row.status,
                 tag_type,
                 timeline: row.timeline,
                 impact: row.impact,
+                endorsement_count: row.endorsement_count,
                 created_at: row.created_at,
                 categories,
                 location,
+                search: None,
             });
         }
 
@@ -542,6 +672,7 @@ impl DashboardService {
                 JOIN report_tags rt ON rt.report_id = r.id
                 WHERE rt.tag_type = $1
                   AND r.status NOT IN ('pending', 'rejected')
+                  AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
                 "#,
                 tag_type as &ReportTagType
             )
@@ -552,7 +683,9 @@ impl DashboardService {
                 AppError::Database(e)
             })?;
 
-            let reports = self.get_reports_by_tag(tag_type, offset, limit).await?;
+            let reports = self
+                .get_reports_by_tag(tag_type, params.sort, offset, limit, params.lang)
+                .await?;
             let pagination = PaginationMeta::new(params.page, params.page_size, total);
             (Some(reports), Some(pagination))
         } else {
@@ -575,6 +708,7 @@ impl DashboardService {
             FROM report_tags rt
             JOIN reports r ON r.id = rt.report_id
             WHERE r.status NOT IN ('pending', 'rejected')
+              AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
             GROUP BY rt.tag_type
             ORDER BY COUNT(*) DESC
             "#
@@ -612,6 +746,7 @@ impl DashboardService {
             JOIN reports r ON r.id = rt.report_id
             WHERE ($1::TEXT IS NULL OR rt.tag_type::TEXT = $1::TEXT)
             AND r.status NOT IN ('pending', 'rejected')
+            AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
             GROUP BY rt.tag_type
             "#,
             filter_str // This is $1
@@ -637,29 +772,40 @@ impl DashboardService {
     async fn get_reports_by_tag(
         &self,
         tag_type: &ReportTagType,
+        sort: DashboardReportSort,
         offset: i64,
         limit: i64,
+        lang: Option<ReportLanguage>,
     ) -> Result<Vec<DashboardReportDto>> {
         let rows = sqlx::query!(
             r#"
             SELECT
                 r.id,
-                r.title,
-                r.description,
+                COALESCE(t.redacted_title, r.redacted_title) as title,
+                COALESCE(t.redacted_description, r.redacted_description) as description,
+                COALESCE(t.language, r.language) as "language: ReportLanguage",
                 r.status as "status: ReportStatus",
-                r.timeline,
-                r.impact,
+                r.redacted_timeline as timeline,
+                r.redacted_impact as impact,
+                r.endorsement_count,
                 r.created_at
             FROM reports r
+            LEFT JOIN report_translations t
+                ON t.report_id = r.id AND t.language = $5 AND t.redacted_at IS NOT NULL
             JOIN report_tags rt ON rt.report_id = r.id
             WHERE rt.tag_type::TEXT = $1::TEXT
               AND r.status NOT IN ('pending', 'rejected')
-            ORDER BY r.created_at DESC
+              AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
+            ORDER BY
+                CASE WHEN $4 THEN r.endorsement_count ELSE 0 END DESC,
+                r.created_at DESC
             OFFSET $2 LIMIT $3
             "#,
             tag_type as &ReportTagType,
             offset,
-            limit
+            limit,
+            sort.by_endorsements(),
+            lang as Option<ReportLanguage>
         )
         .fetch_all(&self.pool)
         .await
@@ -677,14 +823,17 @@ impl DashboardService {
             reports.push(DashboardReportDto {
                 id: row.id,
                 title: row.title,
+                language: row.language,
                 description: row.description,
                 status: row.status,
                 tag_type: tag,
                 timeline: row.timeline,
                 impact: row.impact,
+                endorsement_count: row.endorsement_count,
                 created_at: row.created_at,
                 categories,
                 location,
+                search: None,
             });
         }
 
@@ -706,6 +855,7 @@ impl DashboardService {
             FROM reports
             WHERE created_at >= CURRENT_DATE - $1::int
               AND status NOT IN ('pending', 'rejected')
+              AND redacted_at IS NOT NULL AND moderated_at IS NOT NULL
             "#,
             days
         )
@@ -720,20 +870,26 @@ impl DashboardService {
             r#"
             SELECT
                 r.id,
-                r.title,
-                r.description,
+                COALESCE(t.redacted_title, r.redacted_title) as title,
+                COALESCE(t.redacted_description, r.redacted_description) as description,
+                COALESCE(t.language, r.language) as "language: ReportLanguage",
                 r.status as "status: ReportStatus",
-                r.timeline,
-                r.impact,
+                r.redacted_timeline as timeline,
+                r.redacted_impact as impact,
+                r.endorsement_count,
                 r.created_at
             FROM reports r
+            LEFT JOIN report_translations t
+                ON t.report_id = r.id AND t.language = $3 AND t.redacted_at IS NOT NULL
             WHERE r.created_at >= CURRENT_DATE - $1::int
               AND r.status NOT IN ('pending', 'rejected')
+              AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
             ORDER BY r.created_at DESC
             LIMIT $2
             "#,
             days,
-            limit
+            limit,
+            params.lang as Option<ReportLanguage>
         )
         .fetch_all(&self.pool)
         .await
@@ -751,14 +907,17 @@ impl DashboardService {
             reports.push(DashboardReportDto {
                 id: row.id,
                 title: row.title,
+                language: row.language,
                 description: row.description,
                 status: row.status,
                 tag_type,
                 timeline: row.timeline,
                 impact: row.impact,
+                endorsement_count: row.endorsement_count,
                 created_at: row.created_at,
                 categories,
                 location,
+                search: None,
             });
         }
 
@@ -781,20 +940,25 @@ impl DashboardService {
             r#"
             SELECT
                 r.id,
-                r.title,
+                COALESCE(t.redacted_title, r.redacted_title) as title,
+                COALESCE(t.language, r.language) as "language: ReportLanguage",
                 r.status as "status: ReportStatus",
+                r.endorsement_count,
                 r.created_at,
                 rl.lat as "lat!",
                 rl.lon as "lon!",
                 c.slug as "category_slug?",
                 c.color as "category_color?"
             FROM reports r
+            LEFT JOIN report_translations t
+                ON t.report_id = r.id AND t.language = $6 AND t.redacted_at IS NOT NULL
             JOIN report_locations rl ON rl.report_id = r.id
             LEFT JOIN report_categories rc ON rc.report_id = r.id
             LEFT JOIN categories c ON c.id = rc.category_id
             WHERE rl.lat IS NOT NULL
               AND rl.lon IS NOT NULL
               AND r.status NOT IN ('pending', 'rejected')
+              AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
               AND ($1::uuid IS NULL OR rl.province_id = $1)
               AND ($2::uuid IS NULL OR rl.regency_id = $2)
               AND ($3::text IS NULL OR c.slug = $3)
@@ -806,7 +970,8 @@ impl DashboardService {
             params.regency_id,
             params.category.as_deref(),
             params.status.as_ref() as Option<&ReportStatus>,
-            limit
+            limit,
+            params.lang as Option<ReportLanguage>
         )
         .fetch_all(&self.pool)
         .await
@@ -822,11 +987,13 @@ impl DashboardService {
             .map(|r| MapReportMarker {
                 id: r.id,
                 title: r.title,
+                language: r.language,
                 lat: r.lat,
                 lon: r.lon,
                 status: r.status,
                 category_slug: r.category_slug,
                 category_color: r.category_color,
+                endorsement_count: r.endorsement_count,
                 created_at: r.created_at,
             })
             .collect();
@@ -881,6 +1048,7 @@ impl DashboardService {
             WHERE rl.lat IS NOT NULL 
               AND rl.lon IS NOT NULL
               AND r.status NOT IN ('pending', 'rejected')
+              AND r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL
               AND ($1::uuid IS NULL OR rl.province_id = $1)
               AND ($2::uuid IS NULL OR rl.regency_id = $2)
             LIMIT 5000
@@ -1007,6 +1175,63 @@ impl DashboardService {
 
         Ok(row)
     }
+
+    /// Get visible progress updates for a report with their attachments
+    async fn get_report_updates(&self, report_id: Uuid) -> Result<Vec<ReportUpdateInfo>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                u.id,
+                u.author_role,
+                u.message,
+                u.created_at,
+                f.id as "file_id?",
+                f.original_filename as "original_filename?",
+                f.content_type as "content_type?",
+                f.url as "url?"
+            FROM report_updates u
+            LEFT JOIN report_attachments ra ON ra.report_update_id = u.id
+            LEFT JOIN files f ON f.id = ra.file_id AND f.is_active = true
+            WHERE u.report_id = $1 AND u.deleted_at IS NULL
+            ORDER BY u.created_at, u.id, ra.created_at
+            "#,
+            report_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch report updates: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let mut updates: Vec<ReportUpdateInfo> = Vec::new();
+        for r in rows {
+            if updates.last().map(|u| u.id) != Some(r.id) {
+                updates.push(ReportUpdateInfo {
+                    id: r.id,
+                    author_role: r.author_role,
+                    message: r.message,
+                    created_at: r.created_at,
+                    attachments: Vec::new(),
+                });
+            }
+
+            if let (Some(file_id), Some(original_filename), Some(content_type), Some(url)) =
+                (r.file_id, r.original_filename, r.content_type, r.url)
+            {
+                if let Some(update) = updates.last_mut() {
+                    update.attachments.push(ReportUpdateAttachmentInfo {
+                        file_id,
+                        original_filename,
+                        content_type,
+                        url,
+                    });
+                }
+            }
+        }
+
+        Ok(updates)
+    }
 }
 
 // ============================================================================
@@ -1022,3 +1247,23 @@ fn tag_label(tag_type: &ReportTagType) -> String {
         ReportTagType::Appreciation => "Apresiasi".to_string(),
     }
 }
+
+// ============================================================================
+// Row types for dynamic queries
+// ============================================================================
+
+#[derive(sqlx::FromRow)]
+struct DashboardReportRow {
+    id: Uuid,
+    title: Option<String>,
+    description: Option<String>,
+    language: Option<ReportLanguage>,
+    status: ReportStatus,
+    timeline: Option<String>,
+    impact: Option<String>,
+    endorsement_count: i32,
+    created_at: chrono::DateTime<chrono::Utc>,
+    rank: Option<f32>,
+    snippet: Option<String>,
+    sort_key: String,
+}
@@ -1,4 +1,5 @@
 pub mod admin;
+pub mod agencies;
 pub mod auth;
 pub mod categories;
 pub mod citizen_report_agent;
@@ -7,8 +8,15 @@ pub mod dashboard;
 pub mod expectations;
 pub mod files;
 pub mod logto;
+pub mod moderation;
+pub mod notifications;
+pub mod open_data;
 pub mod prompts;
 pub mod rate_limits;
 pub mod regions;
 pub mod reports;
+pub mod settings;
+pub mod sla;
+pub mod tracking;
 pub mod users;
+pub mod webhooks;
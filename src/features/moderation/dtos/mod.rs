@@ -0,0 +1,3 @@
+mod moderation_dto;
+
+pub use moderation_dto::*;
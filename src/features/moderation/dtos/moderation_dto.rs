@@ -0,0 +1,231 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::features::moderation::models::{
+    ModerationCategory, ModerationSource, ModerationSubject, ModerationVerdict, ReportModeration,
+    ReportModerationStatus, UserAbuseCounter,
+};
+use crate::features::reports::models::ReportStatus;
+use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+/// Response DTO for a moderation queue entry
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportModerationDto {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub reference_number: Option<String>,
+    pub report_status: ReportStatus,
+    /// Extracted title (redacted for anonymous reports)
+    pub title: Option<String>,
+    /// Extracted description (redacted for anonymous reports)
+    pub description: Option<String>,
+    /// Citizen who submitted the report (None for anonymous reports)
+    pub user_id: Option<String>,
+    pub is_anonymous: bool,
+    pub verdict_id: Uuid,
+    pub category: ModerationCategory,
+    pub source: ModerationSource,
+    /// LLM confidence in the category (0-1, None for keyword verdicts)
+    pub confidence: Option<f64>,
+    /// Keyword list entries that matched
+    pub matched_terms: Vec<String>,
+    /// LLM explanation of the classification
+    pub reason: Option<String>,
+    pub status: ReportModerationStatus,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ReportModeration> for ReportModerationDto {
+    fn from(m: ReportModeration) -> Self {
+        Self {
+            id: m.id,
+            report_id: m.report_id,
+            reference_number: m.reference_number,
+            report_status: m.report_status,
+            title: m.title,
+            description: m.description,
+            user_id: m.user_id.filter(|_| !m.is_anonymous),
+            is_anonymous: m.is_anonymous,
+            verdict_id: m.verdict_id,
+            category: m.category,
+            source: m.source,
+            confidence: m.confidence,
+            matched_terms: m.matched_terms,
+            reason: m.reason,
+            status: m.status,
+            reviewed_by: m.reviewed_by,
+            reviewed_at: m.reviewed_at,
+            review_note: m.review_note,
+            created_at: m.created_at,
+        }
+    }
+}
+
+/// Response DTO for a moderation verdict
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ModerationVerdictDto {
+    pub id: Uuid,
+    pub subject: ModerationSubject,
+    pub report_id: Option<Uuid>,
+    pub thread_id: Option<Uuid>,
+    /// Sender of the content (None for anonymous reports)
+    pub user_id: Option<String>,
+    pub category: ModerationCategory,
+    pub source: ModerationSource,
+    pub confidence: Option<f64>,
+    pub matched_terms: Vec<String>,
+    pub reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ModerationVerdict> for ModerationVerdictDto {
+    fn from(v: ModerationVerdict) -> Self {
+        Self {
+            id: v.id,
+            subject: v.subject,
+            report_id: v.report_id,
+            thread_id: v.thread_id,
+            user_id: v.user_id,
+            category: v.category,
+            source: v.source,
+            confidence: v.confidence,
+            matched_terms: v.matched_terms,
+            reason: v.reason,
+            created_at: v.created_at,
+        }
+    }
+}
+
+/// Response DTO for the spam and abuse flags of a user
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserAbuseCounterDto {
+    pub user_id: String,
+    pub flagged_messages: i32,
+    pub flagged_reports: i32,
+    /// Flags across messages and reports, compared to `moderation.abuse_threshold`
+    pub total_flags: i64,
+    pub last_category: Option<ModerationCategory>,
+    pub last_flagged_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<UserAbuseCounter> for UserAbuseCounterDto {
+    fn from(c: UserAbuseCounter) -> Self {
+        Self {
+            total_flags: c.total(),
+            user_id: c.user_id,
+            flagged_messages: c.flagged_messages,
+            flagged_reports: c.flagged_reports,
+            last_category: c.last_category,
+            last_flagged_at: c.last_flagged_at,
+            updated_at: c.updated_at,
+        }
+    }
+}
+
+/// Request DTO for clearing a flagged report
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ApproveReportModerationDto {
+    #[validate(length(max = 2000))]
+    pub note: Option<String>,
+}
+
+/// Request DTO for rejecting a flagged report
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RejectReportModerationDto {
+    /// Reason shown to the citizen
+    #[validate(length(min = 1, max = 2000))]
+    pub reason: String,
+}
+
+/// Query parameters for the moderation queue
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct ReportModerationQueryParams {
+    /// Filter by queue status (default: pending)
+    pub status: Option<ReportModerationStatus>,
+    /// Filter by verdict category
+    pub category: Option<ModerationCategory>,
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl ReportModerationQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+/// Query parameters for the verdict log
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct ModerationVerdictQueryParams {
+    /// Filter by moderated content (message or report)
+    pub subject: Option<ModerationSubject>,
+    /// Filter by category
+    pub category: Option<ModerationCategory>,
+    /// Filter by report
+    pub report_id: Option<Uuid>,
+    /// Filter by sender (verdicts of anonymous reports are excluded)
+    pub user_id: Option<String>,
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl ModerationVerdictQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+/// Query parameters for the abuse counter list
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct AbuseCounterQueryParams {
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl AbuseCounterQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
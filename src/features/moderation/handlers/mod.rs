@@ -0,0 +1,3 @@
+pub mod moderation_handler;
+
+pub use moderation_handler::*;
@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::core::error::{AppError, Result};
+use crate::core::extractor::AppJson;
+use crate::features::auth::guards::RequireAdminCurator;
+use crate::features::moderation::dtos::{
+    AbuseCounterQueryParams, ApproveReportModerationDto, ModerationVerdictDto,
+    ModerationVerdictQueryParams, RejectReportModerationDto, ReportModerationDto,
+    ReportModerationQueryParams, UserAbuseCounterDto,
+};
+use crate::features::moderation::services::{ModerationService, VerdictFilter};
+use crate::shared::types::{ApiResponse, Meta};
+
+// =============================================================================
+// MODERATION QUEUE HANDLERS
+// =============================================================================
+
+/// List flagged reports held for moderation, oldest first (admin curator)
+#[utoipa::path(
+    get,
+    path = "/api/admin/moderation/reports",
+    params(ReportModerationQueryParams),
+    responses(
+        (status = 200, description = "Moderation queue entries", body = ApiResponse<Vec<ReportModerationDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required")
+    ),
+    tag = "moderation",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_report_moderations(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<ModerationService>>,
+    Query(params): Query<ReportModerationQueryParams>,
+) -> Result<Json<ApiResponse<Vec<ReportModerationDto>>>> {
+    let (items, total) = service
+        .list(
+            params.status,
+            params.category,
+            params.limit(),
+            params.offset(),
+        )
+        .await?;
+    let dtos: Vec<ReportModerationDto> = items.into_iter().map(Into::into).collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Get a moderation queue entry (admin curator)
+#[utoipa::path(
+    get,
+    path = "/api/admin/moderation/reports/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Report moderation ID")
+    ),
+    responses(
+        (status = 200, description = "Moderation queue entry", body = ApiResponse<ReportModerationDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Report moderation not found")
+    ),
+    tag = "moderation",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_report_moderation(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<ModerationService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ReportModerationDto>>> {
+    let moderation = service.get(id).await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(moderation.into()),
+        None,
+        None,
+    )))
+}
+
+/// Clear a flagged report and resume its processing (admin curator)
+#[utoipa::path(
+    post,
+    path = "/api/admin/moderation/reports/{id}/approve",
+    params(
+        ("id" = Uuid, Path, description = "Report moderation ID")
+    ),
+    request_body = ApproveReportModerationDto,
+    responses(
+        (status = 200, description = "Report cleared; processing resumed", body = ApiResponse<ReportModerationDto>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Report moderation not found"),
+        (status = 409, description = "Moderation already decided")
+    ),
+    tag = "moderation",
+    security(("bearer_auth" = []))
+)]
+pub async fn approve_report_moderation(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ModerationService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<ApproveReportModerationDto>,
+) -> Result<Json<ApiResponse<ReportModerationDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let note = dto.note.as_deref().map(str::trim).filter(|n| !n.is_empty());
+    let moderation = service.approve(id, &user.sub, note).await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(moderation.into()),
+        Some("Report cleared and processing resumed".to_string()),
+        None,
+    )))
+}
+
+/// Reject a flagged report with a reason (admin curator)
+#[utoipa::path(
+    post,
+    path = "/api/admin/moderation/reports/{id}/reject",
+    params(
+        ("id" = Uuid, Path, description = "Report moderation ID")
+    ),
+    request_body = RejectReportModerationDto,
+    responses(
+        (status = 200, description = "Report rejected", body = ApiResponse<ReportModerationDto>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Report moderation not found"),
+        (status = 409, description = "Moderation already decided or report cannot be rejected")
+    ),
+    tag = "moderation",
+    security(("bearer_auth" = []))
+)]
+pub async fn reject_report_moderation(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ModerationService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<RejectReportModerationDto>,
+) -> Result<Json<ApiResponse<ReportModerationDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let moderation = service
+        .reject(id, &user.sub, user.primary_role(), dto.reason.trim())
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(moderation.into()),
+        Some("Report rejected".to_string()),
+        None,
+    )))
+}
+
+// =============================================================================
+// VERDICT AND ABUSE COUNTER HANDLERS
+// =============================================================================
+
+/// List moderation verdicts of messages and reports, newest first (admin curator)
+///
+/// Only flagged messages are recorded; every processed report has a verdict.
+#[utoipa::path(
+    get,
+    path = "/api/admin/moderation/verdicts",
+    params(ModerationVerdictQueryParams),
+    responses(
+        (status = 200, description = "Moderation verdicts", body = ApiResponse<Vec<ModerationVerdictDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required")
+    ),
+    tag = "moderation",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_moderation_verdicts(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<ModerationService>>,
+    Query(params): Query<ModerationVerdictQueryParams>,
+) -> Result<Json<ApiResponse<Vec<ModerationVerdictDto>>>> {
+    let filter = VerdictFilter {
+        subject: params.subject,
+        category: params.category,
+        report_id: params.report_id,
+        user_id: params.user_id.clone(),
+    };
+    let (items, total) = service
+        .list_verdicts(&filter, params.limit(), params.offset())
+        .await?;
+    let dtos: Vec<ModerationVerdictDto> = items.into_iter().map(Into::into).collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// List users with spam or abuse flags, most recently flagged first (admin curator)
+#[utoipa::path(
+    get,
+    path = "/api/admin/moderation/abuse-counters",
+    params(AbuseCounterQueryParams),
+    responses(
+        (status = 200, description = "User abuse counters", body = ApiResponse<Vec<UserAbuseCounterDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required")
+    ),
+    tag = "moderation",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_abuse_counters(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<ModerationService>>,
+    Query(params): Query<AbuseCounterQueryParams>,
+) -> Result<Json<ApiResponse<Vec<UserAbuseCounterDto>>>> {
+    let (items, total) = service
+        .list_counters(params.limit(), params.offset())
+        .await?;
+    let dtos: Vec<UserAbuseCounterDto> = items.into_iter().map(Into::into).collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Reset the abuse flags of a user, lifting a chat block (admin curator)
+#[utoipa::path(
+    delete,
+    path = "/api/admin/moderation/abuse-counters/{user_id}",
+    params(
+        ("user_id" = String, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Abuse counter reset"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "No abuse flags recorded for the user")
+    ),
+    tag = "moderation",
+    security(("bearer_auth" = []))
+)]
+pub async fn reset_abuse_counter(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ModerationService>>,
+    Path(user_id): Path<String>,
+) -> Result<Json<ApiResponse<()>>> {
+    service.reset_counter(&user_id, &user.sub).await?;
+
+    Ok(Json(ApiResponse::success(
+        None,
+        Some("Abuse counter reset".to_string()),
+        None,
+    )))
+}
@@ -0,0 +1,7 @@
+pub mod dtos;
+pub mod handlers;
+pub mod models;
+pub mod routes;
+pub mod services;
+
+pub use services::{ModerationClassifier, ModerationService};
@@ -0,0 +1,10 @@
+mod moderation_verdict;
+mod report_moderation;
+mod user_abuse_counter;
+
+pub use moderation_verdict::{
+    CreateModerationVerdict, ModerationCategory, ModerationSource, ModerationSubject,
+    ModerationVerdict,
+};
+pub use report_moderation::{ReportModeration, ReportModerationStatus};
+pub use user_abuse_counter::UserAbuseCounter;
@@ -0,0 +1,139 @@
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Moderation classification, matching database enum
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema, JsonSchema, Default,
+)]
+#[sqlx(type_name = "moderation_category", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ModerationCategory {
+    #[default]
+    Clean,
+    Spam,
+    Abuse,
+    OffTopic,
+    Test,
+}
+
+impl std::fmt::Display for ModerationCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModerationCategory::Clean => write!(f, "clean"),
+            ModerationCategory::Spam => write!(f, "spam"),
+            ModerationCategory::Abuse => write!(f, "abuse"),
+            ModerationCategory::OffTopic => write!(f, "off_topic"),
+            ModerationCategory::Test => write!(f, "test"),
+        }
+    }
+}
+
+impl ModerationCategory {
+    /// Whether the content needs a moderator (every category except clean)
+    pub fn is_flagged(&self) -> bool {
+        !matches!(self, ModerationCategory::Clean)
+    }
+
+    /// Whether the flag counts against the sender's abuse counter
+    ///
+    /// Off-topic and test content is held for a moderator but is not misuse in itself.
+    pub fn counts_as_abuse(&self) -> bool {
+        matches!(self, ModerationCategory::Spam | ModerationCategory::Abuse)
+    }
+}
+
+/// Kind of moderated content, matching database enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "moderation_subject", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ModerationSubject {
+    Message,
+    Report,
+}
+
+/// Classifier that produced a verdict, matching database enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "moderation_source", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ModerationSource {
+    Keyword,
+    Llm,
+}
+
+/// Database model for a moderation verdict
+#[derive(Debug, Clone, FromRow)]
+pub struct ModerationVerdict {
+    pub id: Uuid,
+    pub subject: ModerationSubject,
+    pub report_id: Option<Uuid>,
+    pub thread_id: Option<Uuid>,
+    pub user_id: Option<String>,
+    pub category: ModerationCategory,
+    pub source: ModerationSource,
+    pub confidence: Option<f64>,
+    pub matched_terms: Vec<String>,
+    pub reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ModerationVerdict {
+    /// Whether the verdict counts against the sender as soon as it is recorded
+    ///
+    /// Keyword lists also match citizens quoting insults they received, so keyword hits
+    /// only count once the classifier or (for reports) a moderator confirms them.
+    pub fn counts_against_sender(&self) -> bool {
+        self.source == ModerationSource::Llm && self.category.counts_as_abuse()
+    }
+}
+
+/// Data for recording a verdict
+#[derive(Debug, Clone)]
+pub struct CreateModerationVerdict {
+    pub subject: ModerationSubject,
+    pub report_id: Option<Uuid>,
+    pub thread_id: Option<Uuid>,
+    pub user_id: Option<String>,
+    pub category: ModerationCategory,
+    pub source: ModerationSource,
+    pub confidence: Option<f64>,
+    pub matched_terms: Vec<String>,
+    pub reason: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verdict(category: ModerationCategory, source: ModerationSource) -> ModerationVerdict {
+        ModerationVerdict {
+            id: Uuid::nil(),
+            subject: ModerationSubject::Message,
+            report_id: None,
+            thread_id: None,
+            user_id: Some("citizen-1".to_string()),
+            category,
+            source,
+            confidence: None,
+            matched_terms: Vec::new(),
+            reason: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_only_classifier_verdicts_count_against_sender() {
+        use ModerationCategory::*;
+        use ModerationSource::*;
+
+        assert!(verdict(Abuse, Llm).counts_against_sender());
+        assert!(verdict(Spam, Llm).counts_against_sender());
+        assert!(!verdict(OffTopic, Llm).counts_against_sender());
+        // A citizen quoting an insult they received trips the keyword lists
+        assert!(!verdict(Abuse, Keyword).counts_against_sender());
+        assert!(!verdict(Spam, Keyword).counts_against_sender());
+    }
+}
@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{ModerationCategory, ModerationSource};
+use crate::features::reports::models::ReportStatus;
+
+/// Status of a flagged report in the moderation queue, matching database enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "report_moderation_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportModerationStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl std::fmt::Display for ReportModerationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportModerationStatus::Pending => write!(f, "pending"),
+            ReportModerationStatus::Approved => write!(f, "approved"),
+            ReportModerationStatus::Rejected => write!(f, "rejected"),
+        }
+    }
+}
+
+/// Database model for a moderation queue entry, with its verdict and a summary of its report
+#[derive(Debug, Clone, FromRow)]
+pub struct ReportModeration {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub reference_number: Option<String>,
+    pub report_status: ReportStatus,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub user_id: Option<String>,
+    pub is_anonymous: bool,
+    pub verdict_id: Uuid,
+    pub category: ModerationCategory,
+    pub source: ModerationSource,
+    pub confidence: Option<f64>,
+    pub matched_terms: Vec<String>,
+    pub reason: Option<String>,
+    pub status: ReportModerationStatus,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+
+use super::ModerationCategory;
+
+/// Database model for the spam and abuse flags of a user
+#[derive(Debug, Clone, FromRow)]
+pub struct UserAbuseCounter {
+    pub user_id: String,
+    pub flagged_messages: i32,
+    pub flagged_reports: i32,
+    pub last_category: Option<ModerationCategory>,
+    pub last_flagged_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UserAbuseCounter {
+    /// Flags across messages and reports
+    pub fn total(&self) -> i64 {
+        self.flagged_messages as i64 + self.flagged_reports as i64
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use axum::{
+    routing::{delete, get, post},
+    Router,
+};
+
+use crate::features::moderation::handlers;
+use crate::features::moderation::services::ModerationService;
+
+/// Create admin routes for the moderation queue, verdict log and abuse counters
+/// (admin curator access)
+pub fn admin_routes(moderation_service: Arc<ModerationService>) -> Router {
+    Router::new()
+        .route(
+            "/api/admin/moderation/reports",
+            get(handlers::list_report_moderations),
+        )
+        .route(
+            "/api/admin/moderation/reports/{id}",
+            get(handlers::get_report_moderation),
+        )
+        .route(
+            "/api/admin/moderation/reports/{id}/approve",
+            post(handlers::approve_report_moderation),
+        )
+        .route(
+            "/api/admin/moderation/reports/{id}/reject",
+            post(handlers::reject_report_moderation),
+        )
+        .route(
+            "/api/admin/moderation/verdicts",
+            get(handlers::list_moderation_verdicts),
+        )
+        .route(
+            "/api/admin/moderation/abuse-counters",
+            get(handlers::list_abuse_counters),
+        )
+        .route(
+            "/api/admin/moderation/abuse-counters/{user_id}",
+            delete(handlers::reset_abuse_counter),
+        )
+        .with_state(moderation_service)
+}
@@ -0,0 +1,135 @@
+use std::collections::BTreeSet;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::features::moderation::models::ModerationCategory;
+
+/// Insults, slurs and threats (whole words, case-insensitive)
+///
+/// Animal words like "anjing" or "babi" are left out on purpose: citizens report stray dogs
+/// and pig farms, and the LLM classifier handles them as insults in context.
+static ABUSE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b(?:bangsat|bajingan|brengsek|keparat|kampret|goblok|goblog|tolol|bego|jancok|jancuk|kontol|memek|ngentot|pepek|lonte|fuck|fucking|bitch|bastard|kubunuh|bunuh kau|bunuh kamu|mampus kau|mampus kamu)\b",
+    )
+    .unwrap()
+});
+
+/// Online gambling and advertising phrases, link shorteners and chat invites
+static SPAM_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b(?:slot gacor|situs slot|judi online|judol|togel|maxwin|bonus deposit|link alternatif|daftar sekarang|jasa seo|bit\.ly|s\.id|wa\.me|t\.me)\b",
+    )
+    .unwrap()
+});
+
+/// Messages that consist of nothing but a test phrase ("tes", "coba 123", "asdf")
+static TEST_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)^\s*(?:tes|test|testing|tes tes|test test|coba|coba coba|cobain|percobaan|asdf|asdfgh|qwerty|lorem ipsum)(?:\s*\d+)?[\s.!?]*$",
+    )
+    .unwrap()
+});
+
+/// Runs of the same character this long are treated as keyboard spam
+const REPEATED_CHAR_RUN: usize = 12;
+
+/// Category and matching terms of a keyword hit
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeywordMatch {
+    pub category: ModerationCategory,
+    pub matched_terms: Vec<String>,
+}
+
+/// Classify `text` against the keyword lists
+///
+/// Returns None when nothing matched. Abuse takes precedence over spam, spam over test.
+pub fn classify_keywords(text: &str) -> Option<KeywordMatch> {
+    for (category, regex) in [
+        (ModerationCategory::Abuse, &*ABUSE_REGEX),
+        (ModerationCategory::Spam, &*SPAM_REGEX),
+    ] {
+        let terms: BTreeSet<String> = regex
+            .find_iter(text)
+            .map(|m| m.as_str().to_lowercase())
+            .collect();
+        if !terms.is_empty() {
+            return Some(KeywordMatch {
+                category,
+                matched_terms: terms.into_iter().collect(),
+            });
+        }
+    }
+
+    if let Some(run) = longest_repeated_run(text).filter(|r| r.len() >= REPEATED_CHAR_RUN) {
+        return Some(KeywordMatch {
+            category: ModerationCategory::Spam,
+            matched_terms: vec![run],
+        });
+    }
+
+    if TEST_REGEX.is_match(text) {
+        return Some(KeywordMatch {
+            category: ModerationCategory::Test,
+            matched_terms: vec![text.trim().to_lowercase()],
+        });
+    }
+
+    None
+}
+
+/// Longest run of one repeated non-whitespace character
+fn longest_repeated_run(text: &str) -> Option<String> {
+    let mut best: Option<(char, usize)> = None;
+    let mut current: Option<(char, usize)> = None;
+
+    for c in text.chars() {
+        current = match current {
+            Some((prev, len)) if prev == c => Some((c, len + 1)),
+            _ if c.is_whitespace() => None,
+            _ => Some((c, 1)),
+        };
+        if let Some((c, len)) = current {
+            if best.is_none_or(|(_, best_len)| len > best_len) {
+                best = Some((c, len));
+            }
+        }
+    }
+
+    best.map(|(c, len)| std::iter::repeat_n(c, len).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classifies_abuse_spam_and_test() {
+        let abuse = classify_keywords("Dasar GOBLOK, petugasnya tolol semua").unwrap();
+        assert_eq!(abuse.category, ModerationCategory::Abuse);
+        assert_eq!(abuse.matched_terms, vec!["goblok", "tolol"]);
+
+        let spam = classify_keywords("Main di situs slot gacor, daftar di bit.ly/abc").unwrap();
+        assert_eq!(spam.category, ModerationCategory::Spam);
+
+        let keyboard = classify_keywords("aaaaaaaaaaaaaaaa").unwrap();
+        assert_eq!(keyboard.category, ModerationCategory::Spam);
+
+        assert_eq!(
+            classify_keywords("  Tes 123 ").unwrap().category,
+            ModerationCategory::Test
+        );
+    }
+
+    #[test]
+    fn test_leaves_legitimate_reports_alone() {
+        for text in [
+            "Anjing liar menggigit dua warga di RT 05, mohon ditangani",
+            "Hasil tes PCR di puskesmas belum keluar sudah seminggu",
+            "Jalan berlubang di depan pasar, sudah dicoba lapor ke kelurahan",
+        ] {
+            assert_eq!(classify_keywords(text), None, "{}", text);
+        }
+    }
+}
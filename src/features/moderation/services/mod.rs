@@ -0,0 +1,7 @@
+mod keyword_classifier;
+mod moderation_classifier;
+mod moderation_service;
+
+pub use keyword_classifier::classify_keywords;
+pub use moderation_classifier::ModerationClassifier;
+pub use moderation_service::{ModerationService, VerdictFilter};
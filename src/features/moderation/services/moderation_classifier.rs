@@ -0,0 +1,136 @@
+use balungpisah_tensorzero::{InferenceRequestBuilder, InputMessage, TensorZeroClient};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::core::error::{AppError, Result};
+use crate::features::moderation::models::ModerationCategory;
+use crate::shared::llm::{parse_with_fallback, LlmResponse};
+use crate::shared::prompts::render_moderation_prompt;
+
+fn default_true() -> bool {
+    true
+}
+
+/// Classification of a piece of content by the LLM
+#[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
+#[schemars(title = "ModerationClassification")]
+pub struct ModerationClassification {
+    #[schemars(description = "One of: clean, spam, abuse, off_topic, test")]
+    pub category: ModerationCategory,
+
+    #[schemars(description = "Certainty in the chosen category, from 0 to 1")]
+    #[serde(default)]
+    pub confidence: f64,
+
+    #[schemars(description = "One short sentence explaining the classification")]
+    #[serde(default)]
+    pub reason: String,
+
+    /// Whether the LLM classification was successful
+    #[serde(default = "default_true")]
+    #[schemars(skip)]
+    pub is_llm_success: bool,
+
+    /// Error message if LLM classification failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(skip)]
+    pub llm_error_message: Option<String>,
+}
+
+impl LlmResponse for ModerationClassification {
+    fn mark_as_fallback(&mut self, error_message: String) {
+        self.is_llm_success = false;
+        self.llm_error_message = Some(error_message);
+        // Fail open: an unreadable answer must not hold a report
+        self.category = ModerationCategory::Clean;
+    }
+
+    fn is_success(&self) -> bool {
+        self.is_llm_success
+    }
+}
+
+/// LLM moderation classifier using TensorZero
+pub struct ModerationClassifier {
+    client: TensorZeroClient,
+    openai_api_key: String,
+    model_name: String,
+}
+
+impl ModerationClassifier {
+    pub fn new(tensorzero_url: &str, openai_api_key: String, model_name: String) -> Result<Self> {
+        let client = TensorZeroClient::new(tensorzero_url).map_err(|e| {
+            tracing::error!("Failed to create TensorZero client: {:?}", e);
+            AppError::Internal(format!("Failed to create TensorZero client: {}", e))
+        })?;
+
+        Ok(Self {
+            client,
+            openai_api_key,
+            model_name,
+        })
+    }
+
+    /// Classify content
+    ///
+    /// Uses graceful fallback parsing: an unparseable answer is returned as clean with
+    /// `is_llm_success = false`.
+    pub async fn classify(&self, content: &str) -> Result<ModerationClassification> {
+        let system_prompt =
+            render_moderation_prompt(&ModerationClassification::json_schema_string())
+                .await
+                .map_err(|e| {
+                    AppError::Internal(format!("Failed to render moderation prompt: {}", e))
+                })?;
+
+        let request = InferenceRequestBuilder::new()
+            .model(self.model_name.clone())
+            .system(system_prompt)
+            .message(InputMessage::user(format!(
+                "Classify this content:\n\n{}",
+                content
+            )))
+            .credentials(serde_json::json!({
+                "system_api_key": self.openai_api_key
+            }))
+            .build()
+            .map_err(|e| {
+                tracing::error!("Failed to build inference request: {:?}", e);
+                AppError::Internal(format!("Failed to build inference request: {}", e))
+            })?;
+
+        let response = self.client.inference(request).await.map_err(|e| {
+            tracing::error!("TensorZero inference failed: {:?}", e);
+            AppError::ExternalServiceError(format!("LLM moderation failed: {}", e))
+        })?;
+
+        let classification: ModerationClassification = parse_with_fallback(&response.text());
+
+        if !classification.is_success() {
+            tracing::warn!(
+                "LLM moderation used fallback: {:?}",
+                classification.llm_error_message
+            );
+        }
+
+        Ok(classification)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_classification() {
+        let result: ModerationClassification = parse_with_fallback(
+            r#"{"category": "off_topic", "confidence": 0.9, "reason": "Asks for homework help"}"#,
+        );
+        assert!(result.is_success());
+        assert_eq!(result.category, ModerationCategory::OffTopic);
+
+        let fallback: ModerationClassification = parse_with_fallback("not json");
+        assert!(!fallback.is_success());
+        assert_eq!(fallback.category, ModerationCategory::Clean);
+    }
+}
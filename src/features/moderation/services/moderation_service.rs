@@ -0,0 +1,812 @@
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::moderation::models::{
+    CreateModerationVerdict, ModerationCategory, ModerationSource, ModerationSubject,
+    ModerationVerdict, ReportModeration, ReportModerationStatus, UserAbuseCounter,
+};
+use crate::features::moderation::services::{classify_keywords, ModerationClassifier};
+use crate::features::reports::models::ReportStatus;
+use crate::features::reports::services::{ReportJobService, ReportService};
+
+/// Filters for the verdict log
+#[derive(Debug, Clone, Default)]
+pub struct VerdictFilter {
+    pub subject: Option<ModerationSubject>,
+    pub category: Option<ModerationCategory>,
+    pub report_id: Option<Uuid>,
+    pub user_id: Option<String>,
+}
+
+/// Service for content moderation: classification, verdicts, the moderation queue of
+/// flagged reports and per-user abuse counters
+pub struct ModerationService {
+    pool: PgPool,
+    /// LLM classifier (None if TensorZero is not available; keyword lists only)
+    classifier: Option<ModerationClassifier>,
+}
+
+impl ModerationService {
+    pub fn new(pool: PgPool, classifier: Option<ModerationClassifier>) -> Self {
+        Self { pool, classifier }
+    }
+
+    // ===== Classification =====
+
+    /// Check a citizen chat message against the keyword lists
+    ///
+    /// Only flagged messages are recorded. Spam and abuse hits are checked again by the LLM
+    /// classifier (when available) and count against the sender only if it confirms them.
+    pub async fn moderate_message(
+        &self,
+        user_id: &str,
+        thread_id: Option<Uuid>,
+        text: &str,
+    ) -> Result<ModerationCategory> {
+        let Some(hit) = classify_keywords(text) else {
+            return Ok(ModerationCategory::Clean);
+        };
+
+        let mut data = CreateModerationVerdict {
+            subject: ModerationSubject::Message,
+            report_id: None,
+            thread_id,
+            user_id: Some(user_id.to_string()),
+            category: hit.category,
+            source: ModerationSource::Keyword,
+            confidence: None,
+            matched_terms: hit.matched_terms,
+            reason: None,
+        };
+
+        if hit.category.counts_as_abuse() {
+            self.classify_llm(&mut data, text).await;
+        }
+
+        let mut tx = self.begin().await?;
+
+        let verdict = Self::record(&mut tx, &data).await?;
+
+        if verdict.counts_against_sender() {
+            Self::increment_counter(
+                &mut tx,
+                user_id,
+                ModerationSubject::Message,
+                verdict.category,
+            )
+            .await?;
+        }
+
+        self.commit(tx).await?;
+
+        tracing::info!(
+            "Moderated flagged message from {} as {} ({:?}, thread {:?})",
+            user_id,
+            verdict.category,
+            verdict.source,
+            thread_id
+        );
+        Ok(verdict.category)
+    }
+
+    /// Classify extracted report content and queue the report if it is flagged
+    ///
+    /// Keyword hits are final; otherwise the LLM classifier runs when `use_llm` is set and
+    /// available. LLM failures fail open (the report is treated as clean). Clean reports
+    /// are marked as moderated, which the public dashboard requires. Keyword hits count
+    /// against the sender once a moderator rejects the report.
+    pub async fn moderate_report(
+        &self,
+        report_id: Uuid,
+        user_id: Option<&str>,
+        content: &str,
+        use_llm: bool,
+    ) -> Result<ModerationVerdict> {
+        let mut data = CreateModerationVerdict {
+            subject: ModerationSubject::Report,
+            report_id: Some(report_id),
+            thread_id: None,
+            user_id: user_id.map(str::to_string),
+            category: ModerationCategory::Clean,
+            source: ModerationSource::Keyword,
+            confidence: None,
+            matched_terms: Vec::new(),
+            reason: None,
+        };
+
+        if let Some(hit) = classify_keywords(content) {
+            data.category = hit.category;
+            data.matched_terms = hit.matched_terms;
+        } else if use_llm {
+            self.classify_llm(&mut data, content).await;
+        }
+
+        let mut tx = self.begin().await?;
+
+        let verdict = Self::record(&mut tx, &data).await?;
+
+        if verdict.category.is_flagged() {
+            Self::enqueue(&mut tx, report_id, verdict.id).await?;
+
+            if let Some(user_id) = user_id.filter(|_| verdict.counts_against_sender()) {
+                Self::increment_counter(
+                    &mut tx,
+                    user_id,
+                    ModerationSubject::Report,
+                    verdict.category,
+                )
+                .await?;
+            }
+        } else {
+            Self::mark_moderated(&mut tx, report_id).await?;
+        }
+
+        self.commit(tx).await?;
+
+        if verdict.category.is_flagged() {
+            tracing::info!(
+                "Report {} flagged as {} ({:?}) and queued for moderation",
+                report_id,
+                verdict.category,
+                verdict.source
+            );
+        }
+        Ok(verdict)
+    }
+
+    // ===== Producers =====
+
+    /// Whether a moderator cleared the report for processing; if so the report is marked
+    /// as moderated
+    pub async fn clear_if_approved(pool: &PgPool, report_id: Uuid) -> Result<bool> {
+        let cleared = sqlx::query_scalar!(
+            r#"
+            UPDATE reports
+            SET moderated_at = COALESCE(moderated_at, NOW())
+            WHERE id = $1
+              AND EXISTS (
+                  SELECT 1 FROM report_moderations
+                  WHERE report_id = $1 AND status = 'approved'
+              )
+            RETURNING id
+            "#,
+            report_id
+        )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check report moderation: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(cleared.is_some())
+    }
+
+    /// Whether a user has reached the abuse threshold and flagged again within the cooldown
+    pub async fn is_user_blocked(
+        pool: &PgPool,
+        user_id: &str,
+        threshold: i64,
+        cooldown_hours: i64,
+    ) -> Result<bool> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM user_abuse_counters
+                WHERE user_id = $1
+                  AND (flagged_messages + flagged_reports)::bigint >= $2
+                  AND last_flagged_at > NOW() - make_interval(hours => $3::int)
+            ) as "exists!"
+            "#,
+            user_id,
+            threshold,
+            cooldown_hours as i32
+        )
+        .fetch_one(pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check user abuse counter: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    // ===== Queue =====
+
+    /// Get a moderation queue entry
+    ///
+    /// Anonymous reports are shown with their redacted content.
+    pub async fn get(&self, id: Uuid) -> Result<ReportModeration> {
+        sqlx::query_as!(
+            ReportModeration,
+            r#"
+            SELECT
+                m.id, m.report_id, r.reference_number,
+                r.status as "report_status: ReportStatus",
+                CASE WHEN r.is_anonymous THEN r.redacted_title ELSE r.title END as title,
+                CASE WHEN r.is_anonymous THEN r.redacted_description ELSE r.description END
+                    as description,
+                r.user_id, r.is_anonymous,
+                m.verdict_id, v.category as "category: ModerationCategory",
+                v.source as "source: ModerationSource",
+                v.confidence, v.matched_terms, v.reason,
+                m.status as "status: ReportModerationStatus",
+                m.reviewed_by, m.reviewed_at, m.review_note, m.created_at
+            FROM report_moderations m
+            JOIN reports r ON r.id = m.report_id
+            JOIN moderation_verdicts v ON v.id = m.verdict_id
+            WHERE m.id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report moderation: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report moderation {} not found", id)))
+    }
+
+    /// List moderation queue entries (oldest first)
+    ///
+    /// Without a status filter only pending entries are returned.
+    pub async fn list(
+        &self,
+        status: Option<ReportModerationStatus>,
+        category: Option<ModerationCategory>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<ReportModeration>, i64)> {
+        let status = status.unwrap_or(ReportModerationStatus::Pending);
+
+        let items = sqlx::query_as!(
+            ReportModeration,
+            r#"
+            SELECT
+                m.id, m.report_id, r.reference_number,
+                r.status as "report_status: ReportStatus",
+                CASE WHEN r.is_anonymous THEN r.redacted_title ELSE r.title END as title,
+                CASE WHEN r.is_anonymous THEN r.redacted_description ELSE r.description END
+                    as description,
+                r.user_id, r.is_anonymous,
+                m.verdict_id, v.category as "category: ModerationCategory",
+                v.source as "source: ModerationSource",
+                v.confidence, v.matched_terms, v.reason,
+                m.status as "status: ReportModerationStatus",
+                m.reviewed_by, m.reviewed_at, m.review_note, m.created_at
+            FROM report_moderations m
+            JOIN reports r ON r.id = m.report_id
+            JOIN moderation_verdicts v ON v.id = m.verdict_id
+            WHERE m.status = $1
+              AND ($2::moderation_category IS NULL OR v.category = $2)
+            ORDER BY m.created_at ASC
+            LIMIT $3 OFFSET $4
+            "#,
+            status as ReportModerationStatus,
+            category as Option<ModerationCategory>,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list report moderations: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let total = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM report_moderations m
+            JOIN moderation_verdicts v ON v.id = m.verdict_id
+            WHERE m.status = $1
+              AND ($2::moderation_category IS NULL OR v.category = $2)
+            "#,
+            status as ReportModerationStatus,
+            category as Option<ModerationCategory>
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to count report moderations: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok((items, total))
+    }
+
+    // ===== Decisions =====
+
+    /// Clear a flagged report and resume its processing
+    pub async fn approve(
+        &self,
+        id: Uuid,
+        reviewer_id: &str,
+        note: Option<&str>,
+    ) -> Result<ReportModeration> {
+        let mut tx = self.begin().await?;
+
+        let report_id = Self::lock_pending(&mut tx, id).await?;
+
+        Self::set_status(
+            &mut tx,
+            id,
+            ReportModerationStatus::Approved,
+            reviewer_id,
+            note,
+        )
+        .await?;
+
+        if !ReportJobService::resume_held(&mut tx, report_id).await? {
+            tracing::warn!(
+                "No held report job found for report {} approved in moderation",
+                report_id
+            );
+        }
+
+        self.commit(tx).await?;
+
+        tracing::info!(
+            "Report {} cleared in moderation by {}",
+            report_id,
+            reviewer_id
+        );
+        self.get(id).await
+    }
+
+    /// Reject a flagged report with a reason shown to the citizen
+    pub async fn reject(
+        &self,
+        id: Uuid,
+        reviewer_id: &str,
+        role: &str,
+        reason: &str,
+    ) -> Result<ReportModeration> {
+        let mut tx = self.begin().await?;
+
+        let report_id = Self::lock_pending(&mut tx, id).await?;
+
+        let current = ReportService::lock_status(&mut tx, report_id).await?;
+        ReportService::ensure_transition(report_id, current, ReportStatus::Rejected)?;
+
+        sqlx::query!(
+            r#"
+            UPDATE reports
+            SET status = 'rejected', resolution_notes = $2, updated_at = NOW()
+            WHERE id = $1
+            "#,
+            report_id,
+            reason
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to reject moderated report: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        ReportService::record_status_change(
+            &mut tx,
+            report_id,
+            Some(current),
+            ReportStatus::Rejected,
+            Some(reviewer_id),
+            role,
+            Some(reason),
+        )
+        .await?;
+
+        Self::set_status(
+            &mut tx,
+            id,
+            ReportModerationStatus::Rejected,
+            reviewer_id,
+            Some(reason),
+        )
+        .await?;
+
+        // Keyword hits count against the sender once a moderator confirms them
+        let verdict = Self::queued_verdict(&mut tx, id).await?;
+        if let Some(user_id) = verdict.user_id.as_deref().filter(|_| {
+            verdict.source == ModerationSource::Keyword && verdict.category.counts_as_abuse()
+        }) {
+            Self::increment_counter(
+                &mut tx,
+                user_id,
+                ModerationSubject::Report,
+                verdict.category,
+            )
+            .await?;
+        }
+
+        self.commit(tx).await?;
+
+        tracing::info!(
+            "Report {} rejected in moderation by {}",
+            report_id,
+            reviewer_id
+        );
+        self.get(id).await
+    }
+
+    // ===== Verdicts =====
+
+    /// List verdicts, newest first
+    ///
+    /// The sender of an anonymous report is not returned.
+    pub async fn list_verdicts(
+        &self,
+        filter: &VerdictFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<ModerationVerdict>, i64)> {
+        let items = sqlx::query_as!(
+            ModerationVerdict,
+            r#"
+            SELECT
+                v.id, v.subject as "subject: ModerationSubject", v.report_id, v.thread_id,
+                CASE WHEN r.is_anonymous THEN NULL ELSE v.user_id END as user_id,
+                v.category as "category: ModerationCategory",
+                v.source as "source: ModerationSource",
+                v.confidence, v.matched_terms, v.reason, v.created_at
+            FROM moderation_verdicts v
+            LEFT JOIN reports r ON r.id = v.report_id
+            WHERE ($1::moderation_subject IS NULL OR v.subject = $1)
+              AND ($2::moderation_category IS NULL OR v.category = $2)
+              AND ($3::uuid IS NULL OR v.report_id = $3)
+              AND ($4::text IS NULL OR (v.user_id = $4 AND NOT COALESCE(r.is_anonymous, false)))
+            ORDER BY v.created_at DESC
+            LIMIT $5 OFFSET $6
+            "#,
+            filter.subject as Option<ModerationSubject>,
+            filter.category as Option<ModerationCategory>,
+            filter.report_id,
+            filter.user_id,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list moderation verdicts: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let total = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM moderation_verdicts v
+            LEFT JOIN reports r ON r.id = v.report_id
+            WHERE ($1::moderation_subject IS NULL OR v.subject = $1)
+              AND ($2::moderation_category IS NULL OR v.category = $2)
+              AND ($3::uuid IS NULL OR v.report_id = $3)
+              AND ($4::text IS NULL OR (v.user_id = $4 AND NOT COALESCE(r.is_anonymous, false)))
+            "#,
+            filter.subject as Option<ModerationSubject>,
+            filter.category as Option<ModerationCategory>,
+            filter.report_id,
+            filter.user_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to count moderation verdicts: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok((items, total))
+    }
+
+    // ===== Abuse counters =====
+
+    /// List users with abuse flags, most recently flagged first
+    pub async fn list_counters(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<UserAbuseCounter>, i64)> {
+        let items = sqlx::query_as!(
+            UserAbuseCounter,
+            r#"
+            SELECT
+                user_id, flagged_messages, flagged_reports,
+                last_category as "last_category: ModerationCategory",
+                last_flagged_at, updated_at
+            FROM user_abuse_counters
+            ORDER BY last_flagged_at DESC NULLS LAST, user_id
+            LIMIT $1 OFFSET $2
+            "#,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list user abuse counters: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let total = sqlx::query_scalar!(r#"SELECT COUNT(*) as "count!" FROM user_abuse_counters"#)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to count user abuse counters: {:?}", e);
+                AppError::Database(e)
+            })?;
+
+        Ok((items, total))
+    }
+
+    /// Clear the abuse flags of a user (lifts a chat block)
+    pub async fn reset_counter(&self, user_id: &str, reset_by: &str) -> Result<()> {
+        let deleted = sqlx::query!(
+            "DELETE FROM user_abuse_counters WHERE user_id = $1",
+            user_id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to reset user abuse counter: {:?}", e);
+            AppError::Database(e)
+        })?
+        .rows_affected();
+
+        if deleted == 0 {
+            return Err(AppError::NotFound(format!(
+                "No abuse flags recorded for user {}",
+                user_id
+            )));
+        }
+
+        tracing::info!("Abuse counter of {} reset by {}", user_id, reset_by);
+        Ok(())
+    }
+
+    // ===== Helpers =====
+
+    async fn begin(&self) -> Result<Transaction<'static, Postgres>> {
+        self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    async fn commit(&self, tx: Transaction<'static, Postgres>) -> Result<()> {
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit moderation changes: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Record a verdict
+    async fn record(
+        conn: &mut PgConnection,
+        data: &CreateModerationVerdict,
+    ) -> Result<ModerationVerdict> {
+        sqlx::query_as!(
+            ModerationVerdict,
+            r#"
+            INSERT INTO moderation_verdicts
+                (subject, report_id, thread_id, user_id, category, source,
+                 confidence, matched_terms, reason)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING
+                id, subject as "subject: ModerationSubject", report_id, thread_id, user_id,
+                category as "category: ModerationCategory",
+                source as "source: ModerationSource",
+                confidence, matched_terms, reason, created_at
+            "#,
+            data.subject as ModerationSubject,
+            data.report_id,
+            data.thread_id,
+            data.user_id,
+            data.category as ModerationCategory,
+            data.source as ModerationSource,
+            data.confidence,
+            &data.matched_terms,
+            data.reason
+        )
+        .fetch_one(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to record moderation verdict: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Let the LLM classifier (if available) decide the verdict
+    ///
+    /// Unsuccessful classifications keep the current verdict.
+    async fn classify_llm(&self, data: &mut CreateModerationVerdict, content: &str) {
+        let Some(classifier) = &self.classifier else {
+            return;
+        };
+
+        match classifier.classify(content).await {
+            Ok(result) if result.is_llm_success => {
+                data.category = result.category;
+                data.source = ModerationSource::Llm;
+                data.confidence = Some(result.confidence.clamp(0.0, 1.0));
+                data.reason = Some(result.reason).filter(|r| !r.is_empty());
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!(
+                    "LLM moderation of {:?} (report {:?}) failed, keeping keyword verdict: {:?}",
+                    data.subject,
+                    data.report_id,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Get the verdict a moderation queue entry was created for
+    async fn queued_verdict(conn: &mut PgConnection, id: Uuid) -> Result<ModerationVerdict> {
+        sqlx::query_as!(
+            ModerationVerdict,
+            r#"
+            SELECT
+                v.id, v.subject as "subject: ModerationSubject", v.report_id, v.thread_id,
+                v.user_id,
+                v.category as "category: ModerationCategory",
+                v.source as "source: ModerationSource",
+                v.confidence, v.matched_terms, v.reason, v.created_at
+            FROM report_moderations m
+            JOIN moderation_verdicts v ON v.id = m.verdict_id
+            WHERE m.id = $1
+            "#,
+            id
+        )
+        .fetch_one(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get queued moderation verdict: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Put a flagged report in the moderation queue
+    ///
+    /// No-op if it is already queued; a rejected entry (report reopened since) is queued again
+    /// with the new verdict.
+    async fn enqueue(conn: &mut PgConnection, report_id: Uuid, verdict_id: Uuid) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO report_moderations (report_id, verdict_id)
+            VALUES ($1, $2)
+            ON CONFLICT (report_id) DO UPDATE
+            SET status = 'pending', verdict_id = EXCLUDED.verdict_id,
+                reviewed_by = NULL, reviewed_at = NULL, review_note = NULL, updated_at = NOW()
+            WHERE report_moderations.status = 'rejected'
+            "#,
+            report_id,
+            verdict_id
+        )
+        .execute(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to queue report for moderation: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(())
+    }
+
+    /// Record that the current content of a report cleared moderation
+    async fn mark_moderated(conn: &mut PgConnection, report_id: Uuid) -> Result<()> {
+        sqlx::query!(
+            "UPDATE reports SET moderated_at = NOW() WHERE id = $1",
+            report_id
+        )
+        .execute(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to mark report as moderated: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(())
+    }
+
+    /// Add a spam or abuse flag to a user's counter
+    async fn increment_counter(
+        conn: &mut PgConnection,
+        user_id: &str,
+        subject: ModerationSubject,
+        category: ModerationCategory,
+    ) -> Result<()> {
+        let (messages, reports) = match subject {
+            ModerationSubject::Message => (1, 0),
+            ModerationSubject::Report => (0, 1),
+        };
+
+        sqlx::query!(
+            r#"
+            INSERT INTO user_abuse_counters
+                (user_id, flagged_messages, flagged_reports, last_category, last_flagged_at)
+            VALUES ($1, $2, $3, $4, NOW())
+            ON CONFLICT (user_id) DO UPDATE
+            SET flagged_messages = user_abuse_counters.flagged_messages + EXCLUDED.flagged_messages,
+                flagged_reports = user_abuse_counters.flagged_reports + EXCLUDED.flagged_reports,
+                last_category = EXCLUDED.last_category,
+                last_flagged_at = EXCLUDED.last_flagged_at,
+                updated_at = NOW()
+            "#,
+            user_id,
+            messages,
+            reports,
+            category as ModerationCategory
+        )
+        .execute(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to update user abuse counter: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(())
+    }
+
+    /// Lock an entry and ensure it is still pending
+    ///
+    /// Returns the report ID.
+    async fn lock_pending(tx: &mut Transaction<'_, Postgres>, id: Uuid) -> Result<Uuid> {
+        let row = sqlx::query!(
+            r#"
+            SELECT report_id, status as "status: ReportModerationStatus"
+            FROM report_moderations
+            WHERE id = $1
+            FOR UPDATE
+            "#,
+            id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to lock report moderation: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report moderation {} not found", id)))?;
+
+        if row.status != ReportModerationStatus::Pending {
+            return Err(AppError::Conflict(format!(
+                "Report moderation {} is already {}",
+                id, row.status
+            )));
+        }
+
+        Ok(row.report_id)
+    }
+
+    /// Close an entry with a decision
+    async fn set_status(
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+        status: ReportModerationStatus,
+        reviewer_id: &str,
+        note: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE report_moderations
+            SET status = $2, reviewed_by = $3, reviewed_at = NOW(), review_note = $4,
+                updated_at = NOW()
+            WHERE id = $1
+            "#,
+            id,
+            status as ReportModerationStatus,
+            reviewer_id,
+            note
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to update report moderation: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+mod notification_dto;
+
+pub use notification_dto::*;
@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::features::notifications::models::{Notification, NotificationPayload, NotificationType};
+use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+/// Query parameters for the notification inbox
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct NotificationQueryParams {
+    /// Only return unread notifications (default: false)
+    #[serde(default)]
+    pub unread_only: bool,
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl NotificationQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
+
+/// Response DTO for a notification
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NotificationDto {
+    pub id: Uuid,
+    pub notification_type: NotificationType,
+    /// Short headline derived from the payload
+    pub title: String,
+    pub report_id: Option<Uuid>,
+    pub payload: NotificationPayload,
+    pub is_read: bool,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Notification> for NotificationDto {
+    fn from(n: Notification) -> Self {
+        let payload = n.payload.0;
+        Self {
+            id: n.id,
+            notification_type: n.notification_type,
+            title: payload.title(),
+            report_id: n.report_id,
+            payload,
+            is_read: n.read_at.is_some(),
+            read_at: n.read_at,
+            created_at: n.created_at,
+        }
+    }
+}
+
+/// Response DTO for the unread notification count
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UnreadCountDto {
+    pub unread_count: i64,
+}
+
+/// Response DTO for mark-all-read
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MarkAllReadDto {
+    /// Number of notifications marked as read
+    pub marked_count: i64,
+}
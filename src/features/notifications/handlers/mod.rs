@@ -0,0 +1,3 @@
+pub mod notification_handler;
+
+pub use notification_handler::*;
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::core::error::Result;
+use crate::features::auth::model::AuthenticatedUser;
+use crate::features::notifications::dtos::{
+    MarkAllReadDto, NotificationDto, NotificationQueryParams, UnreadCountDto,
+};
+use crate::features::notifications::services::NotificationService;
+use crate::shared::types::{ApiResponse, Meta};
+
+/// List notifications of the authenticated user (newest first)
+#[utoipa::path(
+    get,
+    path = "/api/notifications",
+    params(NotificationQueryParams),
+    responses(
+        (status = 200, description = "Notifications retrieved", body = ApiResponse<Vec<NotificationDto>>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "notifications"
+)]
+pub async fn list_notifications(
+    user: AuthenticatedUser,
+    State(service): State<Arc<NotificationService>>,
+    Query(params): Query<NotificationQueryParams>,
+) -> Result<Json<ApiResponse<Vec<NotificationDto>>>> {
+    let (items, total) = service
+        .list(
+            &user.sub,
+            params.unread_only,
+            params.limit(),
+            params.offset(),
+        )
+        .await?;
+    let dtos: Vec<NotificationDto> = items.into_iter().map(Into::into).collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Get the number of unread notifications of the authenticated user
+#[utoipa::path(
+    get,
+    path = "/api/notifications/unread-count",
+    responses(
+        (status = 200, description = "Unread count", body = ApiResponse<UnreadCountDto>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "notifications"
+)]
+pub async fn get_unread_count(
+    user: AuthenticatedUser,
+    State(service): State<Arc<NotificationService>>,
+) -> Result<Json<ApiResponse<UnreadCountDto>>> {
+    let unread_count = service.unread_count(&user.sub).await?;
+    Ok(Json(ApiResponse::success(
+        Some(UnreadCountDto { unread_count }),
+        None,
+        None,
+    )))
+}
+
+/// Mark a notification as read
+#[utoipa::path(
+    post,
+    path = "/api/notifications/{id}/read",
+    params(
+        ("id" = Uuid, Path, description = "Notification ID")
+    ),
+    responses(
+        (status = 200, description = "Notification marked as read", body = ApiResponse<NotificationDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Notification not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "notifications"
+)]
+pub async fn mark_notification_read(
+    user: AuthenticatedUser,
+    State(service): State<Arc<NotificationService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<NotificationDto>>> {
+    let notification = service.mark_read(&user.sub, id).await?;
+    Ok(Json(ApiResponse::success(
+        Some(notification.into()),
+        None,
+        None,
+    )))
+}
+
+/// Mark all notifications of the authenticated user as read
+#[utoipa::path(
+    post,
+    path = "/api/notifications/read-all",
+    responses(
+        (status = 200, description = "Notifications marked as read", body = ApiResponse<MarkAllReadDto>),
+        (status = 401, description = "Unauthorized")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "notifications"
+)]
+pub async fn mark_all_notifications_read(
+    user: AuthenticatedUser,
+    State(service): State<Arc<NotificationService>>,
+) -> Result<Json<ApiResponse<MarkAllReadDto>>> {
+    let marked_count = service.mark_all_read(&user.sub).await?;
+    Ok(Json(ApiResponse::success(
+        Some(MarkAllReadDto { marked_count }),
+        None,
+        None,
+    )))
+}
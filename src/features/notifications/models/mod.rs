@@ -0,0 +1,3 @@
+mod notification;
+
+pub use notification::{Notification, NotificationPayload, NotificationType};
@@ -0,0 +1,194 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{types::Json, FromRow, Type};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::features::admin::models::ReportExportFormat;
+use crate::features::reports::models::ReportStatus;
+
+/// Notification type, matching database enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "notification_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::enum_variant_names)]
+pub enum NotificationType {
+    ReportProcessed,
+    ReportStatusChanged,
+    ReportAssigned,
+    ReportInfoRequested,
+    ReportExportReady,
+}
+
+/// Typed notification payload, stored as JSONB and tagged by `type`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[allow(clippy::enum_variant_names)]
+pub enum NotificationPayload {
+    /// The citizen's submission was processed and is waiting for verification
+    ReportProcessed {
+        report_id: Uuid,
+        reference_number: Option<String>,
+        title: Option<String>,
+    },
+    /// A report owned by the recipient changed status
+    ReportStatusChanged {
+        report_id: Uuid,
+        reference_number: Option<String>,
+        old_status: ReportStatus,
+        new_status: ReportStatus,
+        message: Option<String>,
+    },
+    /// A report was assigned to the recipient official
+    ReportAssigned {
+        report_id: Uuid,
+        reference_number: Option<String>,
+        assigned_by: String,
+        message: Option<String>,
+    },
+    /// A curator needs more information before the submission can be processed
+    ReportInfoRequested {
+        report_id: Uuid,
+        reference_number: Option<String>,
+        message: String,
+    },
+    /// A background report export requested by the recipient is ready to download
+    ReportExportReady {
+        export_id: Uuid,
+        format: ReportExportFormat,
+        row_count: i32,
+        /// Endpoint redirecting to a fresh download link
+        download_path: String,
+    },
+}
+
+impl NotificationPayload {
+    pub fn notification_type(&self) -> NotificationType {
+        match self {
+            NotificationPayload::ReportProcessed { .. } => NotificationType::ReportProcessed,
+            NotificationPayload::ReportStatusChanged { .. } => {
+                NotificationType::ReportStatusChanged
+            }
+            NotificationPayload::ReportAssigned { .. } => NotificationType::ReportAssigned,
+            NotificationPayload::ReportInfoRequested { .. } => {
+                NotificationType::ReportInfoRequested
+            }
+            NotificationPayload::ReportExportReady { .. } => NotificationType::ReportExportReady,
+        }
+    }
+
+    /// Report the notification is about (None for exports)
+    pub fn report_id(&self) -> Option<Uuid> {
+        match self {
+            NotificationPayload::ReportProcessed { report_id, .. }
+            | NotificationPayload::ReportStatusChanged { report_id, .. }
+            | NotificationPayload::ReportAssigned { report_id, .. }
+            | NotificationPayload::ReportInfoRequested { report_id, .. } => Some(*report_id),
+            NotificationPayload::ReportExportReady { .. } => None,
+        }
+    }
+
+    /// Short human readable headline for the inbox
+    pub fn title(&self) -> String {
+        match self {
+            NotificationPayload::ReportProcessed {
+                reference_number, ..
+            } => format!("Report {} received", reference_label(reference_number)),
+            NotificationPayload::ReportStatusChanged {
+                reference_number,
+                new_status,
+                ..
+            } => format!(
+                "Report {} is now {}",
+                reference_label(reference_number),
+                new_status.to_string().replace('_', " ")
+            ),
+            NotificationPayload::ReportAssigned {
+                reference_number, ..
+            } => format!(
+                "Report {} assigned to you",
+                reference_label(reference_number)
+            ),
+            NotificationPayload::ReportInfoRequested {
+                reference_number, ..
+            } => format!(
+                "More information needed for report {}",
+                reference_label(reference_number)
+            ),
+            NotificationPayload::ReportExportReady {
+                format, row_count, ..
+            } => format!(
+                "Report export ready ({} reports, {})",
+                row_count,
+                format.extension().to_uppercase()
+            ),
+        }
+    }
+}
+
+fn reference_label(reference_number: &Option<String>) -> &str {
+    reference_number.as_deref().unwrap_or("(no reference)")
+}
+
+/// Database model for a notification
+#[derive(Debug, Clone, FromRow)]
+#[allow(dead_code)]
+pub struct Notification {
+    pub id: Uuid,
+    pub user_id: String,
+    pub notification_type: NotificationType,
+    pub report_id: Option<Uuid>,
+    pub payload: Json<NotificationPayload>,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payload_is_tagged_by_type() {
+        let payload = NotificationPayload::ReportStatusChanged {
+            report_id: Uuid::nil(),
+            reference_number: Some("RPT-2026-0000001".to_string()),
+            old_status: ReportStatus::Verified,
+            new_status: ReportStatus::InProgress,
+            message: None,
+        };
+
+        let json = serde_json::to_value(&payload).unwrap();
+        assert_eq!(json["type"], "report_status_changed");
+        assert_eq!(json["new_status"], "in_progress");
+        assert_eq!(
+            serde_json::from_value::<NotificationPayload>(json).unwrap(),
+            payload
+        );
+        assert_eq!(
+            payload.notification_type(),
+            NotificationType::ReportStatusChanged
+        );
+    }
+
+    #[test]
+    fn test_title() {
+        let payload = NotificationPayload::ReportStatusChanged {
+            report_id: Uuid::nil(),
+            reference_number: Some("RPT-2026-0000001".to_string()),
+            old_status: ReportStatus::Verified,
+            new_status: ReportStatus::InProgress,
+            message: None,
+        };
+        assert_eq!(
+            payload.title(),
+            "Report RPT-2026-0000001 is now in progress"
+        );
+
+        let payload = NotificationPayload::ReportProcessed {
+            report_id: Uuid::nil(),
+            reference_number: None,
+            title: None,
+        };
+        assert_eq!(payload.title(), "Report (no reference) received");
+    }
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::features::notifications::handlers;
+use crate::features::notifications::services::NotificationService;
+
+/// Create routes for the notification inbox (authenticated users)
+pub fn routes(notification_service: Arc<NotificationService>) -> Router {
+    Router::new()
+        .route("/api/notifications", get(handlers::list_notifications))
+        .route(
+            "/api/notifications/unread-count",
+            get(handlers::get_unread_count),
+        )
+        .route(
+            "/api/notifications/read-all",
+            post(handlers::mark_all_notifications_read),
+        )
+        .route(
+            "/api/notifications/{id}/read",
+            post(handlers::mark_notification_read),
+        )
+        .with_state(notification_service)
+}
@@ -0,0 +1,3 @@
+mod notification_service;
+
+pub use notification_service::NotificationService;
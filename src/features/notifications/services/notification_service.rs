@@ -0,0 +1,250 @@
+use sqlx::{types::Json, PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::notifications::models::{Notification, NotificationPayload, NotificationType};
+use crate::features::reports::models::ReportStatus;
+
+/// Service for the in-app notification inbox
+pub struct NotificationService {
+    pool: PgPool,
+}
+
+impl NotificationService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // ===== Producers =====
+
+    /// Queue a notification for a user outside of any transaction
+    pub async fn send(&self, user_id: &str, payload: NotificationPayload) -> Result<()> {
+        let mut conn = self.pool.acquire().await.map_err(|e| {
+            tracing::error!("Failed to acquire connection: {:?}", e);
+            AppError::Database(e)
+        })?;
+        Self::notify(&mut conn, user_id, &payload).await
+    }
+
+    /// Insert a notification using the caller's connection or transaction
+    pub async fn notify(
+        conn: &mut PgConnection,
+        user_id: &str,
+        payload: &NotificationPayload,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO notifications (user_id, notification_type, report_id, payload)
+            VALUES ($1, $2, $3, $4)
+            "#,
+            user_id,
+            payload.notification_type() as NotificationType,
+            payload.report_id(),
+            Json(payload) as _
+        )
+        .execute(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to create notification: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(())
+    }
+
+    /// Notify the report owner about a status change
+    ///
+    /// Called for every recorded transition. Creation and the internal
+    /// pending -> draft extraction step are skipped; the processor sends its own
+    /// "report processed" notification instead.
+    pub async fn on_status_changed(
+        conn: &mut PgConnection,
+        report_id: Uuid,
+        from: Option<ReportStatus>,
+        to: ReportStatus,
+        note: Option<&str>,
+    ) -> Result<()> {
+        let Some(from) = from else {
+            return Ok(());
+        };
+        if to == ReportStatus::Draft {
+            return Ok(());
+        }
+
+        let report = sqlx::query!(
+            "SELECT user_id, reference_number FROM reports WHERE id = $1",
+            report_id
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report owner: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let Some(user_id) = report.user_id else {
+            return Ok(());
+        };
+
+        let payload = NotificationPayload::ReportStatusChanged {
+            report_id,
+            reference_number: report.reference_number,
+            old_status: from,
+            new_status: to,
+            message: note.map(String::from),
+        };
+
+        Self::notify(conn, &user_id, &payload).await
+    }
+
+    /// Notify an official that a report was assigned to them by someone else
+    pub async fn on_report_assigned(
+        conn: &mut PgConnection,
+        report_id: Uuid,
+        assignee_id: &str,
+        assigned_by: &str,
+        note: Option<&str>,
+    ) -> Result<()> {
+        let reference_number = sqlx::query_scalar!(
+            "SELECT reference_number FROM reports WHERE id = $1",
+            report_id
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report reference: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let payload = NotificationPayload::ReportAssigned {
+            report_id,
+            reference_number,
+            assigned_by: assigned_by.to_string(),
+            message: note.map(String::from),
+        };
+
+        Self::notify(conn, assignee_id, &payload).await
+    }
+
+    // ===== Inbox =====
+
+    /// List notifications of a user (newest first)
+    /// Returns (notifications, total_count)
+    pub async fn list(
+        &self,
+        user_id: &str,
+        unread_only: bool,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<Notification>, i64)> {
+        let total = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM notifications
+            WHERE user_id = $1 AND (NOT $2 OR read_at IS NULL)
+            "#,
+            user_id,
+            unread_only
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to count notifications: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let notifications = sqlx::query_as!(
+            Notification,
+            r#"
+            SELECT
+                id, user_id,
+                notification_type as "notification_type: NotificationType",
+                report_id,
+                payload as "payload: Json<NotificationPayload>",
+                read_at, created_at
+            FROM notifications
+            WHERE user_id = $1 AND (NOT $2 OR read_at IS NULL)
+            ORDER BY created_at DESC
+            LIMIT $3 OFFSET $4
+            "#,
+            user_id,
+            unread_only,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list notifications: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok((notifications, total))
+    }
+
+    /// Count unread notifications of a user
+    pub async fn unread_count(&self, user_id: &str) -> Result<i64> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM notifications
+            WHERE user_id = $1 AND read_at IS NULL
+            "#,
+            user_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to count unread notifications: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Mark a single notification as read (idempotent)
+    pub async fn mark_read(&self, user_id: &str, id: Uuid) -> Result<Notification> {
+        sqlx::query_as!(
+            Notification,
+            r#"
+            UPDATE notifications
+            SET read_at = COALESCE(read_at, NOW())
+            WHERE id = $1 AND user_id = $2
+            RETURNING
+                id, user_id,
+                notification_type as "notification_type: NotificationType",
+                report_id,
+                payload as "payload: Json<NotificationPayload>",
+                read_at, created_at
+            "#,
+            id,
+            user_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to mark notification as read: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Notification {} not found", id)))
+    }
+
+    /// Mark all unread notifications of a user as read
+    /// Returns the number of notifications marked
+    pub async fn mark_all_read(&self, user_id: &str) -> Result<i64> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE notifications
+            SET read_at = NOW()
+            WHERE user_id = $1 AND read_at IS NULL
+            "#,
+            user_id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to mark notifications as read: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(result.rows_affected() as i64)
+    }
+}
@@ -0,0 +1,3 @@
+mod open_data_dto;
+
+pub use open_data_dto::*;
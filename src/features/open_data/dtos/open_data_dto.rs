@@ -0,0 +1,209 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::open_data::models::{OpenDataFormat, OpenDataSnapshot};
+use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+/// Default number of records per feed page
+const DEFAULT_FEED_LIMIT: i64 = 1_000;
+
+/// Maximum number of records per feed page
+const MAX_FEED_LIMIT: i64 = 10_000;
+
+// ============================================================================
+// Feed
+// ============================================================================
+
+/// Query parameters for the open data feed
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct OpenDataQueryParams {
+    /// File format
+    pub format: OpenDataFormat,
+    /// Only records changed after this point: a `next_cursor` from a previous page,
+    /// or an RFC 3339 timestamp for the first incremental sync
+    pub since: Option<String>,
+    /// Records per page (default: 1000, max: 10000)
+    #[param(minimum = 1, maximum = 10000)]
+    pub limit: Option<i64>,
+}
+
+impl OpenDataQueryParams {
+    pub fn limit(&self) -> i64 {
+        self.limit
+            .unwrap_or(DEFAULT_FEED_LIMIT)
+            .clamp(1, MAX_FEED_LIMIT)
+    }
+
+    pub fn cursor(&self) -> Result<Option<OpenDataCursor>> {
+        self.since.as_deref().map(OpenDataCursor::parse).transpose()
+    }
+}
+
+/// Keyset position in the feed, ordered by (updated_at, id)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpenDataCursor {
+    pub updated_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl OpenDataCursor {
+    /// Opaque cursor string handed out as `next_cursor`
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!(
+            "{}|{}",
+            self.updated_at.timestamp_micros(),
+            self.id
+        ))
+    }
+
+    /// Parse a `since` value: an encoded cursor or an RFC 3339 timestamp
+    pub fn parse(since: &str) -> Result<Self> {
+        if let Ok(ts) = DateTime::parse_from_rfc3339(since) {
+            // Sorts after every id, so the page starts strictly after the timestamp
+            return Ok(Self {
+                updated_at: ts.with_timezone(&Utc),
+                id: Uuid::from_u128(u128::MAX),
+            });
+        }
+
+        let invalid = || AppError::BadRequest("Invalid since cursor".to_string());
+        let decoded = URL_SAFE_NO_PAD.decode(since).map_err(|_| invalid())?;
+        let decoded = String::from_utf8(decoded).map_err(|_| invalid())?;
+        let (micros, id) = decoded.split_once('|').ok_or_else(invalid)?;
+
+        Ok(Self {
+            updated_at: micros
+                .parse()
+                .ok()
+                .and_then(DateTime::from_timestamp_micros)
+                .ok_or_else(invalid)?,
+            id: id.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+// ============================================================================
+// Data Dictionary and License
+// ============================================================================
+
+/// License of the open dataset
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OpenDataLicenseDto {
+    pub name: String,
+    /// SPDX license identifier
+    pub spdx_id: String,
+    pub url: String,
+    /// Attribution required when redistributing the data
+    pub attribution: String,
+    pub notice: String,
+}
+
+/// Field of an open data record
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OpenDataFieldDto {
+    pub name: String,
+    /// Value type (string, date, datetime, number, list)
+    #[serde(rename = "type")]
+    pub field_type: String,
+    pub nullable: bool,
+    pub description: String,
+}
+
+/// Data dictionary of the open dataset
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OpenDataDictionaryDto {
+    /// API version the dictionary describes
+    pub version: String,
+    pub description: String,
+    pub formats: Vec<OpenDataFormat>,
+    pub fields: Vec<OpenDataFieldDto>,
+    pub license: OpenDataLicenseDto,
+}
+
+// ============================================================================
+// Snapshots
+// ============================================================================
+
+/// Query parameters for listing daily snapshots
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct OpenDataSnapshotQueryParams {
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl OpenDataSnapshotQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
+
+/// Daily snapshot file of the open dataset
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OpenDataSnapshotDto {
+    /// Snapshot day (Asia/Jakarta)
+    pub snapshot_date: NaiveDate,
+    pub format: OpenDataFormat,
+    /// Public download URL
+    pub url: String,
+    pub row_count: i32,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OpenDataSnapshotDto {
+    pub fn new(snapshot: OpenDataSnapshot, url: String) -> Self {
+        Self {
+            snapshot_date: snapshot.snapshot_date,
+            format: snapshot.format,
+            url,
+            row_count: snapshot.row_count,
+            size_bytes: snapshot.size_bytes,
+            created_at: snapshot.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = OpenDataCursor {
+            updated_at: DateTime::from_timestamp_micros(1_760_000_000_123_456).unwrap(),
+            id: Uuid::new_v4(),
+        };
+        assert_eq!(OpenDataCursor::parse(&cursor.encode()).unwrap(), cursor);
+    }
+
+    #[test]
+    fn test_since_timestamp_and_invalid_cursor() {
+        let cursor = OpenDataCursor::parse("2026-10-01T00:00:00+07:00").unwrap();
+        assert_eq!(cursor.updated_at.to_rfc3339(), "2026-09-30T17:00:00+00:00");
+        assert_eq!(cursor.id, Uuid::from_u128(u128::MAX));
+
+        assert!(OpenDataCursor::parse("not-a-cursor").is_err());
+        assert!(OpenDataCursor::parse(&URL_SAFE_NO_PAD.encode("123|x")).is_err());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod open_data_handler;
+
+pub use open_data_handler::*;
@@ -0,0 +1,117 @@
+use axum::{
+    extract::{Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use std::sync::Arc;
+
+use crate::core::error::AppError;
+use crate::features::open_data::dtos::*;
+use crate::features::open_data::services::OpenDataService;
+use crate::shared::types::{ApiResponse, Meta};
+
+/// Response header carrying the cursor for the next feed page
+const NEXT_CURSOR_HEADER: &str = "x-next-cursor";
+
+/// Get anonymized verified reports (open data feed, v1)
+///
+/// Ordered by last change. Pass the `X-Next-Cursor` header (also `next_cursor` in
+/// GeoJSON) as `since` to fetch the next page; an empty page means the client is up
+/// to date and should keep its cursor for the next sync.
+#[utoipa::path(
+    get,
+    path = "/api/open-data/v1/reports",
+    tag = "open-data",
+    params(OpenDataQueryParams),
+    responses(
+        (status = 200, description = "Open data records (CSV or GeoJSON)", content_type = "application/octet-stream", body = Vec<u8>,
+            headers(("X-Next-Cursor" = String, description = "Cursor to pass as `since` for the next page"))),
+        (status = 400, description = "Invalid since cursor"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_reports(
+    State(service): State<Arc<OpenDataService>>,
+    Query(params): Query<OpenDataQueryParams>,
+) -> Result<Response, AppError> {
+    let page = service.feed(params.cursor()?, params.limit()).await?;
+    let data = service.render(params.format, &page)?;
+
+    let mut response = (
+        [
+            (
+                header::CONTENT_TYPE,
+                params.format.content_type().to_string(),
+            ),
+            (
+                header::LINK,
+                "</api/open-data/v1/license>; rel=\"license\"".to_string(),
+            ),
+        ],
+        data,
+    )
+        .into_response();
+
+    if let Some(value) = page.next_cursor.and_then(|c| c.encode().parse().ok()) {
+        response.headers_mut().insert(NEXT_CURSOR_HEADER, value);
+    }
+
+    Ok(response)
+}
+
+/// Get the data dictionary of the open dataset
+#[utoipa::path(
+    get,
+    path = "/api/open-data/v1/dictionary",
+    tag = "open-data",
+    responses(
+        (status = 200, description = "Fields, formats and license", body = ApiResponse<OpenDataDictionaryDto>)
+    )
+)]
+pub async fn get_dictionary(
+    State(service): State<Arc<OpenDataService>>,
+) -> Json<ApiResponse<OpenDataDictionaryDto>> {
+    Json(ApiResponse::success(Some(service.dictionary()), None, None))
+}
+
+/// Get the license notice of the open dataset
+#[utoipa::path(
+    get,
+    path = "/api/open-data/v1/license",
+    tag = "open-data",
+    responses(
+        (status = 200, description = "License notice", body = ApiResponse<OpenDataLicenseDto>)
+    )
+)]
+pub async fn get_license(
+    State(service): State<Arc<OpenDataService>>,
+) -> Json<ApiResponse<OpenDataLicenseDto>> {
+    Json(ApiResponse::success(Some(service.license()), None, None))
+}
+
+/// List daily snapshot files of the full dataset, newest first
+#[utoipa::path(
+    get,
+    path = "/api/open-data/v1/snapshots",
+    tag = "open-data",
+    params(OpenDataSnapshotQueryParams),
+    responses(
+        (status = 200, description = "Snapshot files with public download URLs", body = ApiResponse<Vec<OpenDataSnapshotDto>>),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn list_snapshots(
+    State(service): State<Arc<OpenDataService>>,
+    Query(params): Query<OpenDataSnapshotQueryParams>,
+) -> Result<Json<ApiResponse<Vec<OpenDataSnapshotDto>>>, AppError> {
+    let (snapshots, total) = service
+        .list_snapshots(params.limit(), params.offset())
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(snapshots),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
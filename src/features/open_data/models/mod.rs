@@ -0,0 +1,3 @@
+mod open_data;
+
+pub use open_data::{OpenDataCategory, OpenDataFormat, OpenDataRecord, OpenDataSnapshot};
@@ -0,0 +1,77 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{types::Json, FromRow, Type};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::features::reports::models::{ReportSeverity, ReportStatus, ReportTagType};
+
+/// File format of the open data feed, matching database enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "open_data_format", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum OpenDataFormat {
+    Csv,
+    Geojson,
+}
+
+impl OpenDataFormat {
+    pub const ALL: [OpenDataFormat; 2] = [OpenDataFormat::Csv, OpenDataFormat::Geojson];
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            OpenDataFormat::Csv => "text/csv; charset=utf-8",
+            OpenDataFormat::Geojson => "application/geo+json",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OpenDataFormat::Csv => "csv",
+            OpenDataFormat::Geojson => "geojson",
+        }
+    }
+}
+
+/// Category of a published report with its severity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenDataCategory {
+    pub slug: String,
+    pub severity: ReportSeverity,
+}
+
+/// One anonymized report of the open dataset
+///
+/// Carries no free text, reporter identity, attachments or exact coordinates:
+/// regions stop at district level and coordinates are rounded to 2 decimals (~1 km).
+#[derive(Debug, Clone, FromRow)]
+pub struct OpenDataRecord {
+    pub id: Uuid,
+    pub status: ReportStatus,
+    pub tag_type: Option<ReportTagType>,
+    pub categories: Json<Vec<OpenDataCategory>>,
+    /// Highest severity across categories
+    pub severity: Option<ReportSeverity>,
+    /// Status dates in Asia/Jakarta time
+    pub reported_on: NaiveDate,
+    pub verified_on: Option<NaiveDate>,
+    pub resolved_on: Option<NaiveDate>,
+    /// Kemendagri region codes
+    pub province_code: Option<String>,
+    pub regency_code: Option<String>,
+    pub district_code: Option<String>,
+    pub lat: Option<f64>,
+    pub lon: Option<f64>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Database model for a daily snapshot file
+#[derive(Debug, Clone, FromRow)]
+pub struct OpenDataSnapshot {
+    pub snapshot_date: NaiveDate,
+    pub format: OpenDataFormat,
+    pub file_key: String,
+    pub row_count: i32,
+    pub size_bytes: i64,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,18 @@
+use axum::{routing::get, Router};
+use std::sync::Arc;
+
+use crate::features::open_data::handlers;
+use crate::features::open_data::services::OpenDataService;
+
+/// Create public open data routes (versioned)
+pub fn routes(open_data_service: Arc<OpenDataService>) -> Router {
+    Router::new()
+        .route("/api/open-data/v1/reports", get(handlers::get_reports))
+        .route(
+            "/api/open-data/v1/dictionary",
+            get(handlers::get_dictionary),
+        )
+        .route("/api/open-data/v1/license", get(handlers::get_license))
+        .route("/api/open-data/v1/snapshots", get(handlers::list_snapshots))
+        .with_state(open_data_service)
+}
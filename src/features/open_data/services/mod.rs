@@ -0,0 +1,4 @@
+mod open_data_service;
+mod open_data_writer;
+
+pub use open_data_service::OpenDataService;
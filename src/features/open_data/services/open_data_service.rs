@@ -0,0 +1,301 @@
+use std::sync::Arc;
+
+use chrono::{FixedOffset, NaiveDate, Utc};
+use sqlx::{types::Json, PgPool};
+
+use super::open_data_writer::{fields, license, render, OPEN_DATA_VERSION};
+use crate::core::error::{AppError, Result};
+use crate::features::open_data::dtos::*;
+use crate::features::open_data::models::{
+    OpenDataCategory, OpenDataFormat, OpenDataRecord, OpenDataSnapshot,
+};
+use crate::features::reports::models::{ReportSeverity, ReportStatus, ReportTagType};
+use crate::modules::storage::{FileVisibility, MinIOClient};
+
+/// Records read per query while writing a snapshot
+const SNAPSHOT_PAGE_SIZE: i64 = 5_000;
+
+/// Page of the open data feed
+pub struct OpenDataPage {
+    pub records: Vec<OpenDataRecord>,
+    /// Cursor to pass as `since` for the next page (the given cursor if the page is empty)
+    pub next_cursor: Option<OpenDataCursor>,
+}
+
+/// Service for the public open data feed and its daily snapshots
+pub struct OpenDataService {
+    pool: PgPool,
+    minio_client: Arc<MinIOClient>,
+}
+
+impl OpenDataService {
+    pub fn new(pool: PgPool, minio_client: Arc<MinIOClient>) -> Self {
+        Self { pool, minio_client }
+    }
+
+    // ===== Feed =====
+
+    /// Published reports changed after the cursor, oldest change first
+    ///
+    /// Only verified, in-progress and resolved reports are published.
+    pub async fn feed(&self, cursor: Option<OpenDataCursor>, limit: i64) -> Result<OpenDataPage> {
+        let records = sqlx::query_as!(
+            OpenDataRecord,
+            r#"
+            SELECT
+                r.id,
+                r.status as "status: ReportStatus",
+                tag.tag_type as "tag_type?: ReportTagType",
+                COALESCE(cat.categories, '[]') as "categories!: Json<Vec<OpenDataCategory>>",
+                cat.severity as "severity?: ReportSeverity",
+                (r.created_at AT TIME ZONE 'Asia/Jakarta')::date as "reported_on!",
+                (r.verified_at AT TIME ZONE 'Asia/Jakarta')::date as verified_on,
+                (r.resolved_at AT TIME ZONE 'Asia/Jakarta')::date as resolved_on,
+                p.code as "province_code?",
+                rg.code as "regency_code?",
+                d.code as "district_code?",
+                ROUND(rl.lat::numeric, 2)::float8 as lat,
+                ROUND(rl.lon::numeric, 2)::float8 as lon,
+                r.updated_at
+            FROM reports r
+            LEFT JOIN report_locations rl ON rl.report_id = r.id
+            LEFT JOIN provinces p ON p.id = rl.province_id
+            LEFT JOIN regencies rg ON rg.id = rl.regency_id
+            LEFT JOIN districts d ON d.id = rl.district_id
+            LEFT JOIN LATERAL (
+                SELECT rt.tag_type
+                FROM report_tags rt
+                WHERE rt.report_id = r.id
+                ORDER BY rt.created_at
+                LIMIT 1
+            ) tag ON true
+            LEFT JOIN LATERAL (
+                SELECT
+                    json_agg(json_build_object('slug', c.slug, 'severity', rc.severity) ORDER BY c.slug) as categories,
+                    MAX(rc.severity) as severity
+                FROM report_categories rc
+                JOIN categories c ON c.id = rc.category_id
+                WHERE rc.report_id = r.id
+            ) cat ON true
+            WHERE r.status IN ('verified', 'in_progress', 'resolved')
+              AND ($1::timestamptz IS NULL OR (r.updated_at, r.id) > ($1, $2::uuid))
+            ORDER BY r.updated_at, r.id
+            LIMIT $3
+            "#,
+            cursor.map(|c| c.updated_at),
+            cursor.map(|c| c.id),
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch open data records: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let next_cursor = records
+            .last()
+            .map(|r| OpenDataCursor {
+                updated_at: r.updated_at,
+                id: r.id,
+            })
+            .or(cursor);
+
+        Ok(OpenDataPage {
+            records,
+            next_cursor,
+        })
+    }
+
+    /// Render a feed page
+    pub fn render(&self, format: OpenDataFormat, page: &OpenDataPage) -> Result<Vec<u8>> {
+        let next_cursor = page.next_cursor.map(|c| c.encode());
+        render(format, &page.records, next_cursor.as_deref())
+    }
+
+    // ===== Data Dictionary =====
+
+    pub fn dictionary(&self) -> OpenDataDictionaryDto {
+        OpenDataDictionaryDto {
+            version: OPEN_DATA_VERSION.to_string(),
+            description: "Anonymized citizen reports that curators verified. Records carry no \
+                          free text, reporter identity or attachments; regions stop at district \
+                          level and coordinates are rounded."
+                .to_string(),
+            formats: OpenDataFormat::ALL.to_vec(),
+            fields: fields(),
+            license: license(),
+        }
+    }
+
+    pub fn license(&self) -> OpenDataLicenseDto {
+        license()
+    }
+
+    // ===== Snapshots =====
+
+    /// List daily snapshot files, newest first
+    pub async fn list_snapshots(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<OpenDataSnapshotDto>, i64)> {
+        let snapshots = sqlx::query_as!(
+            OpenDataSnapshot,
+            r#"
+            SELECT
+                snapshot_date, format as "format: OpenDataFormat",
+                file_key, row_count, size_bytes, created_at
+            FROM open_data_snapshots
+            ORDER BY snapshot_date DESC, format
+            LIMIT $1 OFFSET $2
+            "#,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list open data snapshots: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let total = sqlx::query_scalar!(r#"SELECT COUNT(*) as "count!" FROM open_data_snapshots"#)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to count open data snapshots: {:?}", e);
+                AppError::Database(e)
+            })?;
+
+        let dtos = snapshots
+            .into_iter()
+            .map(|s| {
+                let url = self.minio_client.get_public_url(&s.file_key);
+                OpenDataSnapshotDto::new(s, url)
+            })
+            .collect();
+
+        Ok((dtos, total))
+    }
+
+    /// Today's date in Asia/Jakarta, the day a snapshot is filed under
+    pub fn snapshot_date(&self) -> NaiveDate {
+        let wib = FixedOffset::east_opt(7 * 3600).expect("Invalid WIB offset");
+        Utc::now().with_timezone(&wib).date_naive()
+    }
+
+    /// Formats that have no snapshot for the given day yet
+    pub async fn missing_snapshots(&self, date: NaiveDate) -> Result<Vec<OpenDataFormat>> {
+        let existing = sqlx::query_scalar!(
+            r#"
+            SELECT format as "format: OpenDataFormat"
+            FROM open_data_snapshots
+            WHERE snapshot_date = $1
+            "#,
+            date
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check open data snapshots: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(OpenDataFormat::ALL
+            .into_iter()
+            .filter(|f| !existing.contains(f))
+            .collect())
+    }
+
+    /// Write the full dataset in the given formats and upload it to the public prefix
+    ///
+    /// Each file is stored under its date and also replaces the `latest` file.
+    pub async fn write_snapshots(&self, date: NaiveDate, formats: &[OpenDataFormat]) -> Result<()> {
+        let mut records = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = self.feed(cursor, SNAPSHOT_PAGE_SIZE).await?;
+            let done = (page.records.len() as i64) < SNAPSHOT_PAGE_SIZE;
+            cursor = page.next_cursor;
+            records.extend(page.records);
+            if done {
+                break;
+            }
+        }
+
+        for &format in formats {
+            let data = render(format, &records, None)?;
+            let size_bytes = data.len() as i64;
+
+            let path = format!(
+                "open-data/{}/snapshots/reports-{}.{}",
+                OPEN_DATA_VERSION,
+                date,
+                format.extension()
+            );
+            let key = self
+                .minio_client
+                .generate_key(FileVisibility::Public, &path);
+            let latest_key = self.minio_client.generate_key(
+                FileVisibility::Public,
+                &format!(
+                    "open-data/{}/reports-latest.{}",
+                    OPEN_DATA_VERSION,
+                    format.extension()
+                ),
+            );
+            self.minio_client
+                .upload(&latest_key, data.clone(), format.content_type())
+                .await?;
+            let key = self
+                .minio_client
+                .upload(&key, data, format.content_type())
+                .await?;
+
+            self.record_snapshot(date, format, &key, records.len() as i32, size_bytes)
+                .await?;
+
+            tracing::info!(
+                "Open data snapshot {} ({}) written: {} records",
+                date,
+                format.extension(),
+                records.len()
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn record_snapshot(
+        &self,
+        date: NaiveDate,
+        format: OpenDataFormat,
+        file_key: &str,
+        row_count: i32,
+        size_bytes: i64,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO open_data_snapshots (snapshot_date, format, file_key, row_count, size_bytes)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (snapshot_date, format) DO UPDATE
+            SET file_key = EXCLUDED.file_key, row_count = EXCLUDED.row_count,
+                size_bytes = EXCLUDED.size_bytes, created_at = NOW()
+            "#,
+            date,
+            format as OpenDataFormat,
+            file_key,
+            row_count,
+            size_bytes
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to record open data snapshot: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,238 @@
+use serde_json::{json, Map, Value};
+
+use crate::core::error::{AppError, Result};
+use crate::features::open_data::dtos::{OpenDataFieldDto, OpenDataLicenseDto};
+use crate::features::open_data::models::{OpenDataFormat, OpenDataRecord};
+
+/// Version of the open data API and of the snapshot file layout
+pub const OPEN_DATA_VERSION: &str = "v1";
+
+/// Separator for multi-valued CSV columns
+const LIST_SEPARATOR: &str = "; ";
+
+/// Published field: (name, type, nullable, description)
+type Field = (&'static str, &'static str, bool, &'static str);
+
+/// Published fields, in CSV column order
+const FIELDS: [Field; 14] = [
+    ("id", "string", false, "Report UUID, stable across updates"),
+    ("status", "string", false, "verified, in_progress or resolved"),
+    ("tag_type", "string", true, "Report kind: report, proposal, complaint, inquiry or appreciation"),
+    (
+        "categories",
+        "list",
+        false,
+        "Category slugs with severity (low, medium, high, critical); CSV: \"slug:severity\" separated by \"; \", GeoJSON: array of {slug, severity}",
+    ),
+    ("severity", "string", true, "Highest severity across the categories"),
+    ("reported_on", "date", false, "Day the report was submitted (Asia/Jakarta)"),
+    ("verified_on", "date", true, "Day the report was verified (Asia/Jakarta)"),
+    ("resolved_on", "date", true, "Day the report was resolved (Asia/Jakarta)"),
+    ("province_code", "string", true, "Kemendagri province code"),
+    ("regency_code", "string", true, "Kemendagri regency/city code"),
+    ("district_code", "string", true, "Kemendagri district code"),
+    ("lat", "number", true, "Latitude rounded to 2 decimals (~1 km); GeoJSON: point geometry"),
+    ("lon", "number", true, "Longitude rounded to 2 decimals (~1 km); GeoJSON: point geometry"),
+    ("updated_at", "datetime", false, "Last change to the record (RFC 3339); the feed is ordered by it"),
+];
+
+/// Data dictionary entries of the published fields
+pub fn fields() -> Vec<OpenDataFieldDto> {
+    FIELDS
+        .iter()
+        .map(
+            |(name, field_type, nullable, description)| OpenDataFieldDto {
+                name: name.to_string(),
+                field_type: field_type.to_string(),
+                nullable: *nullable,
+                description: description.to_string(),
+            },
+        )
+        .collect()
+}
+
+/// License notice shipped with the feed, dictionary and snapshots
+pub fn license() -> OpenDataLicenseDto {
+    OpenDataLicenseDto {
+        name: "Creative Commons Attribution 4.0 International".to_string(),
+        spdx_id: "CC-BY-4.0".to_string(),
+        url: "https://creativecommons.org/licenses/by/4.0/".to_string(),
+        attribution: "Balungpisah open data".to_string(),
+        notice: "Anonymized citizen reports verified by Balungpisah curators. You may share and \
+                 adapt the data for any purpose, provided you credit \"Balungpisah open data\", \
+                 link to the license and indicate changes. Reports reflect citizen submissions \
+                 and are provided as is, without warranty."
+            .to_string(),
+    }
+}
+
+fn categories_csv(record: &OpenDataRecord) -> String {
+    record
+        .categories
+        .iter()
+        .map(|c| format!("{}:{}", c.slug, c.severity))
+        .collect::<Vec<_>>()
+        .join(LIST_SEPARATOR)
+}
+
+fn values(record: &OpenDataRecord) -> [Value; 14] {
+    [
+        json!(record.id),
+        json!(record.status),
+        json!(record.tag_type),
+        json!(record.categories.0),
+        json!(record.severity),
+        json!(record.reported_on),
+        json!(record.verified_on),
+        json!(record.resolved_on),
+        json!(record.province_code),
+        json!(record.regency_code),
+        json!(record.district_code),
+        json!(record.lat),
+        json!(record.lon),
+        json!(record.updated_at.to_rfc3339()),
+    ]
+}
+
+/// Render open data records in the requested format
+///
+/// `next_cursor` is embedded in GeoJSON output; CSV callers pass it out of band.
+pub fn render(
+    format: OpenDataFormat,
+    records: &[OpenDataRecord],
+    next_cursor: Option<&str>,
+) -> Result<Vec<u8>> {
+    match format {
+        OpenDataFormat::Csv => write_csv(records),
+        OpenDataFormat::Geojson => write_geojson(records, next_cursor),
+    }
+}
+
+fn write_csv(records: &[OpenDataRecord]) -> Result<Vec<u8>> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let csv_error = |e: csv::Error| AppError::Internal(format!("Failed to write CSV: {}", e));
+
+    writer
+        .write_record(FIELDS.iter().map(|(name, ..)| *name))
+        .map_err(csv_error)?;
+    for record in records {
+        let row = FIELDS
+            .iter()
+            .zip(values(record))
+            .map(|((name, ..), value)| match (*name, value) {
+                ("categories", _) => categories_csv(record),
+                (_, Value::Null) => String::new(),
+                (_, Value::String(s)) => s,
+                (_, v) => v.to_string(),
+            });
+        writer.write_record(row).map_err(csv_error)?;
+    }
+
+    writer
+        .into_inner()
+        .map_err(|e| AppError::Internal(format!("Failed to write CSV: {}", e)))
+}
+
+/// GeoJSON FeatureCollection; records without coordinates get a null geometry
+fn write_geojson(records: &[OpenDataRecord], next_cursor: Option<&str>) -> Result<Vec<u8>> {
+    let features: Vec<Value> = records
+        .iter()
+        .map(|record| {
+            let geometry = match (record.lon, record.lat) {
+                (Some(lon), Some(lat)) => json!({ "type": "Point", "coordinates": [lon, lat] }),
+                _ => Value::Null,
+            };
+
+            let properties: Map<String, Value> = FIELDS
+                .iter()
+                .zip(values(record))
+                .filter(|((name, ..), _)| !matches!(*name, "lat" | "lon"))
+                .map(|((name, ..), value)| (name.to_string(), value))
+                .collect();
+
+            json!({
+                "type": "Feature",
+                "id": record.id,
+                "geometry": geometry,
+                "properties": properties,
+            })
+        })
+        .collect();
+
+    serde_json::to_vec(&json!({
+        "type": "FeatureCollection",
+        "version": OPEN_DATA_VERSION,
+        "license": license(),
+        "next_cursor": next_cursor,
+        "features": features,
+    }))
+    .map_err(|e| AppError::Internal(format!("Failed to write GeoJSON: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Utc};
+    use sqlx::types::Json;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::features::open_data::models::OpenDataCategory;
+    use crate::features::reports::models::{ReportSeverity, ReportStatus, ReportTagType};
+
+    fn record() -> OpenDataRecord {
+        OpenDataRecord {
+            id: Uuid::nil(),
+            status: ReportStatus::InProgress,
+            tag_type: Some(ReportTagType::Complaint),
+            categories: Json(vec![
+                OpenDataCategory {
+                    slug: "infrastruktur".to_string(),
+                    severity: ReportSeverity::High,
+                },
+                OpenDataCategory {
+                    slug: "transportasi".to_string(),
+                    severity: ReportSeverity::Low,
+                },
+            ]),
+            severity: Some(ReportSeverity::High),
+            reported_on: NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+            verified_on: NaiveDate::from_ymd_opt(2026, 10, 2),
+            resolved_on: None,
+            province_code: Some("32".to_string()),
+            regency_code: Some("32.73".to_string()),
+            district_code: None,
+            lat: Some(-6.93),
+            lon: Some(107.72),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_csv_columns_match_dictionary() {
+        let csv =
+            String::from_utf8(render(OpenDataFormat::Csv, &[record()], None).unwrap()).unwrap();
+        let mut lines = csv.lines();
+
+        let header: Vec<_> = lines.next().unwrap().split(',').collect();
+        let names: Vec<_> = fields().into_iter().map(|f| f.name).collect();
+        assert_eq!(header, names);
+
+        let line = lines.next().unwrap();
+        assert!(line.starts_with("00000000-0000-0000-0000-000000000000,in_progress,complaint,"));
+        assert!(line.contains("infrastruktur:high; transportasi:low,high,2026-10-01,2026-10-02,,32,32.73,,-6.93,107.72,"));
+    }
+
+    #[test]
+    fn test_geojson_has_cursor_license_and_coarse_point() {
+        let bytes = render(OpenDataFormat::Geojson, &[record()], Some("abc")).unwrap();
+        let value: Value = serde_json::from_slice(&bytes).unwrap();
+
+        assert_eq!(value["next_cursor"], "abc");
+        assert_eq!(value["license"]["spdx_id"], "CC-BY-4.0");
+
+        let feature = &value["features"][0];
+        assert_eq!(feature["geometry"]["coordinates"], json!([107.72, -6.93]));
+        assert_eq!(feature["properties"]["categories"][0]["severity"], "high");
+        assert!(feature["properties"].get("lat").is_none());
+    }
+}
@@ -0,0 +1,3 @@
+mod snapshot_worker;
+
+pub use snapshot_worker::OpenDataSnapshotWorker;
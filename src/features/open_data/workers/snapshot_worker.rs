@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::interval;
+
+use crate::core::error::Result;
+use crate::features::open_data::services::OpenDataService;
+
+/// Delay between checks for a missing daily snapshot
+const CHECK_INTERVAL_SECS: u64 = 3600;
+
+/// Open data snapshot worker that runs in the background
+/// Writes the daily snapshot files once per day (Asia/Jakarta)
+pub struct OpenDataSnapshotWorker {
+    open_data_service: Arc<OpenDataService>,
+}
+
+impl OpenDataSnapshotWorker {
+    pub fn new(open_data_service: Arc<OpenDataService>) -> Self {
+        Self { open_data_service }
+    }
+
+    /// Run the worker in a background loop
+    pub async fn run(&self) {
+        tracing::info!("Starting open data snapshot worker");
+
+        let mut interval = interval(Duration::from_secs(CHECK_INTERVAL_SECS));
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = self.write_missing().await {
+                tracing::error!("Error writing open data snapshots: {:?}", e);
+            }
+        }
+    }
+
+    /// Write today's snapshot files that do not exist yet
+    async fn write_missing(&self) -> Result<()> {
+        let date = self.open_data_service.snapshot_date();
+        let formats = self.open_data_service.missing_snapshots(date).await?;
+        if formats.is_empty() {
+            return Ok(());
+        }
+
+        tracing::debug!("Writing open data snapshot {} ({:?})", date, formats);
+        self.open_data_service.write_snapshots(date, &formats).await
+    }
+}
@@ -89,7 +89,7 @@ pub async fn list_prompts(
     Ok(Json(ApiResponse::success(
         Some(prompts),
         None,
-        Some(Meta { total }),
+        Some(Meta::new(total)),
     )))
 }
 
@@ -20,6 +20,10 @@ const PROMPT_KEY_REGISTRY: &[PromptKeyDefinition] = &[
         key: "citizen_report_extraction/system",
         description: "System prompt for extracting structured data from citizen reports",
     },
+    PromptKeyDefinition {
+        key: "content_moderation/system",
+        description: "System prompt for classifying messages and reports for spam, abuse, off-topic and test content",
+    },
 ];
 
 /// Check whether the given key is in the registry.
@@ -41,8 +41,10 @@ pub struct UserRateLimitStatusDto {
     pub reports_remaining: i64,
     /// Maximum reports allowed per day
     pub max_reports: i64,
-    /// Whether the user can still chat (hasn't reached the limit)
+    /// Whether the user can still chat (hasn't reached the limit and isn't blocked)
     pub can_chat: bool,
+    /// Whether the user is temporarily blocked for repeated spam or abuse
+    pub abuse_blocked: bool,
     /// When the limit resets (next 00:00 WIB in UTC)
     pub resets_at: DateTime<Utc>,
 
@@ -4,20 +4,29 @@ use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
 use sqlx::PgPool;
 
 use crate::core::error::{AppError, Result};
+use crate::features::moderation::ModerationService;
 use crate::features::rate_limits::dtos::UserRateLimitStatusDto;
 use crate::features::rate_limits::services::RateLimitConfigService;
+use crate::features::settings::{SettingKey, SettingsService};
 
 /// Service for checking and enforcing rate limits
 pub struct RateLimitService {
     pool: PgPool,
     config_service: Arc<RateLimitConfigService>,
+    /// Abuse threshold and cooldown of the moderation block
+    settings_service: Arc<SettingsService>,
 }
 
 impl RateLimitService {
-    pub fn new(pool: PgPool, config_service: Arc<RateLimitConfigService>) -> Self {
+    pub fn new(
+        pool: PgPool,
+        config_service: Arc<RateLimitConfigService>,
+        settings_service: Arc<SettingsService>,
+    ) -> Self {
         Self {
             pool,
             config_service,
+            settings_service,
         }
     }
 
@@ -83,13 +92,31 @@ impl RateLimitService {
         Ok(count < limit as i64)
     }
 
+    /// Check if user is blocked for repeated spam or abuse
+    ///
+    /// A user is blocked once their moderation flags reach `moderation.abuse_threshold`,
+    /// until `moderation.abuse_cooldown_hours` have passed since the latest flag.
+    pub async fn is_user_blocked(&self, user_id: &str) -> Result<bool> {
+        let threshold = self
+            .settings_service
+            .get_i64(SettingKey::ModerationAbuseThreshold)
+            .await;
+        let cooldown_hours = self
+            .settings_service
+            .get_i64(SettingKey::ModerationAbuseCooldownHours)
+            .await;
+
+        ModerationService::is_user_blocked(&self.pool, user_id, threshold, cooldown_hours).await
+    }
+
     /// Get user's rate limit status
     pub async fn get_user_status(&self, user_id: &str) -> Result<UserRateLimitStatusDto> {
         let limit = self.config_service.get_daily_ticket_limit().await?;
         let reports_used = self.count_user_reports_today(user_id).await?;
         let max_reports = limit as i64;
         let reports_remaining = (max_reports - reports_used).max(0);
-        let can_chat = reports_used < max_reports;
+        let abuse_blocked = self.is_user_blocked(user_id).await?;
+        let can_chat = reports_used < max_reports && !abuse_blocked;
         let resets_at = Self::get_next_reset_time();
 
         Ok(UserRateLimitStatusDto {
@@ -97,6 +124,7 @@ impl RateLimitService {
             reports_remaining,
             max_reports,
             can_chat,
+            abuse_blocked,
             resets_at,
             // Backward compatibility fields (same values, different names)
             tickets_used: reports_used,
@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::features::reports::dtos::ReportResponseDto;
+use crate::features::reports::models::{ReportAssignment, ReportAssignmentType, ReportStatus};
+use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+/// Response DTO for a report assignment
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportAssignmentDto {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    /// User ID of the official owning the report
+    pub assignee_id: String,
+    /// User ID of the actor who created the assignment
+    pub assigned_by: String,
+    pub assignment_type: ReportAssignmentType,
+    pub note: Option<String>,
+    pub assigned_at: DateTime<Utc>,
+    /// Null while the assignment is active
+    pub released_at: Option<DateTime<Utc>>,
+    pub released_by: Option<String>,
+    pub release_reason: Option<String>,
+}
+
+impl From<ReportAssignment> for ReportAssignmentDto {
+    fn from(a: ReportAssignment) -> Self {
+        Self {
+            id: a.id,
+            report_id: a.report_id,
+            assignee_id: a.assignee_id,
+            assigned_by: a.assigned_by,
+            assignment_type: a.assignment_type,
+            note: a.note,
+            assigned_at: a.assigned_at,
+            released_at: a.released_at,
+            released_by: a.released_by,
+            release_reason: a.release_reason,
+        }
+    }
+}
+
+/// Request DTO for claiming a report
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ClaimReportDto {
+    #[validate(length(max = 2000))]
+    pub note: Option<String>,
+}
+
+/// Request DTO for releasing a claimed report
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ReleaseReportDto {
+    #[validate(length(max = 2000))]
+    pub reason: Option<String>,
+}
+
+/// Request DTO for reassigning or directly assigning a report to an official
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AssignReportDto {
+    /// User ID of the official who should own the report
+    #[validate(length(min = 1, max = 255))]
+    pub assignee_id: String,
+    #[validate(length(max = 2000))]
+    pub note: Option<String>,
+}
+
+/// Query parameters for listing the caller's assigned reports
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct AssignedReportsQuery {
+    /// Filter by report status
+    pub status: Option<ReportStatus>,
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl AssignedReportsQuery {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
+
+/// Response DTO for a report together with its active assignment
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AssignedReportDto {
+    #[serde(flatten)]
+    pub report: ReportResponseDto,
+    pub assignment: ReportAssignmentDto,
+}
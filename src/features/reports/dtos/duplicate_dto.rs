@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::features::reports::models::{ReportDuplicate, ReportDuplicateStatus, ReportStatus};
+use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+/// Short summary of a report in a duplicate pair
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DuplicateReportSummaryDto {
+    pub id: Uuid,
+    pub reference_number: Option<String>,
+    pub title: Option<String>,
+    pub status: ReportStatus,
+}
+
+/// Response DTO for a duplicate candidate
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportDuplicateDto {
+    pub id: Uuid,
+    /// Newer report that looks like a duplicate
+    pub report: DuplicateReportSummaryDto,
+    /// Earlier report it duplicates (kept when merging)
+    pub duplicate_of: DuplicateReportSummaryDto,
+    /// Combined score (0-1)
+    pub score: f64,
+    /// Word overlap of title/description (0-1)
+    pub text_similarity: f64,
+    /// Distance between the two locations (null if either has no coordinates)
+    pub distance_meters: Option<f64>,
+    pub status: ReportDuplicateStatus,
+    pub detected_at: DateTime<Utc>,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+}
+
+impl From<ReportDuplicate> for ReportDuplicateDto {
+    fn from(d: ReportDuplicate) -> Self {
+        Self {
+            id: d.id,
+            report: DuplicateReportSummaryDto {
+                id: d.report_id,
+                reference_number: d.report_reference,
+                title: d.report_title,
+                status: d.report_status,
+            },
+            duplicate_of: DuplicateReportSummaryDto {
+                id: d.duplicate_of_id,
+                reference_number: d.duplicate_of_reference,
+                title: d.duplicate_of_title,
+                status: d.duplicate_of_status,
+            },
+            score: d.score,
+            text_similarity: d.text_similarity,
+            distance_meters: d.distance_meters,
+            status: d.status,
+            detected_at: d.detected_at,
+            reviewed_by: d.reviewed_by,
+            reviewed_at: d.reviewed_at,
+            review_note: d.review_note,
+        }
+    }
+}
+
+/// Request DTO for confirming, dismissing or merging a duplicate candidate
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ReviewDuplicateDto {
+    #[validate(length(max = 2000))]
+    pub note: Option<String>,
+}
+
+/// Query parameters for the duplicate review queue
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct DuplicateQueryParams {
+    /// Filter by review status (default: all)
+    pub status: Option<ReportDuplicateStatus>,
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl DuplicateQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::features::reports::models::ReportEndorsement;
+use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+/// Request DTO for endorsing a report
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct EndorseReportDto {
+    /// Optional short comment (e.g. how the issue affects you)
+    #[validate(length(max = 280))]
+    pub comment: Option<String>,
+}
+
+/// Response DTO for an endorsement
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportEndorsementDto {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub user_id: String,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ReportEndorsement> for ReportEndorsementDto {
+    fn from(e: ReportEndorsement) -> Self {
+        Self {
+            id: e.id,
+            report_id: e.report_id,
+            user_id: e.user_id,
+            comment: e.comment,
+            created_at: e.created_at,
+        }
+    }
+}
+
+/// Query parameters for listing endorsements of a report
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct EndorsementQueryParams {
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl EndorsementQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::features::citizen_report_agent::dtos::MessageResponseDto;
+use crate::features::reports::models::ReportIdentityReveal;
+use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+/// Request DTO for revealing the reporter of an anonymous report
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RevealReportIdentityDto {
+    /// Justification for the reveal (stored in the audit log)
+    #[validate(length(min = 10, max = 2000))]
+    pub reason: String,
+}
+
+/// Response DTO for an audited identity reveal
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportIdentityRevealDto {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    /// Super admin who revealed the identity
+    pub revealed_by: String,
+    pub reason: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ReportIdentityReveal> for ReportIdentityRevealDto {
+    fn from(r: ReportIdentityReveal) -> Self {
+        Self {
+            id: r.id,
+            report_id: r.report_id,
+            revealed_by: r.revealed_by,
+            reason: r.reason,
+            created_at: r.created_at,
+        }
+    }
+}
+
+/// Response DTO for the revealed identity of an anonymous reporter
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReportIdentityDto {
+    pub report_id: Uuid,
+    pub user_id: Option<String>,
+    pub adk_thread_id: Option<Uuid>,
+    /// Messages of the conversation thread (None if the thread could not be loaded)
+    pub messages: Option<Vec<MessageResponseDto>>,
+    /// Audit record of this reveal
+    pub reveal: ReportIdentityRevealDto,
+}
+
+/// Query parameters for listing identity reveals
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct IdentityRevealQueryParams {
+    /// Filter by report
+    pub report_id: Option<Uuid>,
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl IdentityRevealQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
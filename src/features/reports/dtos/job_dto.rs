@@ -0,0 +1,218 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::features::citizen_report_agent::dtos::MessageResponseDto;
+use crate::features::reports::models::{
+    Report, ReportJob, ReportJobError, ReportJobMetrics, ReportJobStage, ReportJobStatus,
+    ReportStatus,
+};
+use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+/// Response DTO for a report processing job
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportJobDto {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub status: ReportJobStatus,
+    /// Next pipeline stage to run
+    pub stage: ReportJobStage,
+    /// Agent confidence score of the submission (0-1)
+    pub confidence_score: Option<f64>,
+    pub retry_count: i32,
+    /// Latest error or cancellation note
+    pub error_message: Option<String>,
+    /// Worker currently holding the job
+    pub locked_by: Option<String>,
+    pub locked_until: Option<DateTime<Utc>>,
+    /// Earliest time of the next attempt (retry backoff)
+    pub next_attempt_at: DateTime<Utc>,
+    pub submitted_at: DateTime<Utc>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    pub processed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ReportJob> for ReportJobDto {
+    fn from(j: ReportJob) -> Self {
+        Self {
+            id: j.id,
+            report_id: j.report_id,
+            status: j.status,
+            stage: j.stage,
+            confidence_score: j
+                .confidence_score
+                .and_then(|d| d.to_string().parse::<f64>().ok()),
+            retry_count: j.retry_count,
+            error_message: j.error_message,
+            locked_by: j.locked_by,
+            locked_until: j.locked_until,
+            next_attempt_at: j.next_attempt_at,
+            submitted_at: j.submitted_at,
+            last_attempt_at: j.last_attempt_at,
+            processed_at: j.processed_at,
+            created_at: j.created_at,
+        }
+    }
+}
+
+/// Response DTO for a failed attempt of a job
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportJobErrorDto {
+    pub id: Uuid,
+    pub job_id: Uuid,
+    /// Stage that failed
+    pub stage: ReportJobStage,
+    /// Attempt number (1-based)
+    pub attempt: i32,
+    pub error: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ReportJobError> for ReportJobErrorDto {
+    fn from(e: ReportJobError) -> Self {
+        Self {
+            id: e.id,
+            job_id: e.job_id,
+            stage: e.stage,
+            attempt: e.attempt,
+            error: e.error,
+            created_at: e.created_at,
+        }
+    }
+}
+
+/// Summary of the report a job belongs to
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportJobReportDto {
+    pub id: Uuid,
+    pub reference_number: Option<String>,
+    pub title: Option<String>,
+    pub status: ReportStatus,
+    /// Citizen who submitted the report (None for anonymous reports)
+    pub user_id: Option<String>,
+    pub platform: Option<String>,
+    /// Conversation thread the report was extracted from (None for anonymous reports)
+    pub adk_thread_id: Option<Uuid>,
+    pub is_anonymous: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Report> for ReportJobReportDto {
+    fn from(r: Report) -> Self {
+        let shielded = r.is_anonymous;
+        Self {
+            id: r.id,
+            reference_number: r.reference_number,
+            title: r.title,
+            status: r.status,
+            user_id: r.user_id.filter(|_| !shielded),
+            platform: r.platform,
+            adk_thread_id: r.adk_thread_id.filter(|_| !shielded),
+            is_anonymous: r.is_anonymous,
+            created_at: r.created_at,
+        }
+    }
+}
+
+/// Response DTO for a job with its error log, report and conversation
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReportJobDetailDto {
+    pub job: ReportJobDto,
+    /// Failed attempts (oldest first)
+    pub errors: Vec<ReportJobErrorDto>,
+    pub report: ReportJobReportDto,
+    /// Messages of the conversation thread (None if the thread could not be loaded or the
+    /// report is anonymous)
+    pub messages: Option<Vec<MessageResponseDto>>,
+}
+
+/// Response DTO for queue metrics
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportJobMetricsDto {
+    /// Submitted jobs that are due now
+    pub due: i64,
+    /// Submitted jobs waiting for their retry backoff
+    pub scheduled: i64,
+    pub processing: i64,
+    /// Processing jobs whose worker stopped renewing the lease
+    pub expired_leases: i64,
+    pub completed: i64,
+    pub failed: i64,
+    pub cancelled: i64,
+    /// Queue depth (due + scheduled + processing)
+    pub queue_depth: i64,
+    /// Submission time of the oldest unfinished job
+    pub oldest_pending_at: Option<DateTime<Utc>>,
+    /// Age of the oldest unfinished job in seconds
+    pub oldest_pending_age_secs: Option<i64>,
+}
+
+impl From<ReportJobMetrics> for ReportJobMetricsDto {
+    fn from(m: ReportJobMetrics) -> Self {
+        Self {
+            due: m.due,
+            scheduled: m.scheduled,
+            processing: m.processing,
+            expired_leases: m.expired_leases,
+            completed: m.completed,
+            failed: m.failed,
+            cancelled: m.cancelled,
+            queue_depth: m.due + m.scheduled + m.processing,
+            oldest_pending_at: m.oldest_pending_at,
+            oldest_pending_age_secs: m
+                .oldest_pending_at
+                .map(|at| (Utc::now() - at).num_seconds().max(0)),
+        }
+    }
+}
+
+/// Response DTO for requeueing all failed jobs
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RequeueReportJobsResultDto {
+    /// Number of jobs put back in the queue
+    pub requeued: u64,
+}
+
+/// Request DTO for cancelling a job
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CancelReportJobDto {
+    #[validate(length(max = 2000))]
+    pub reason: Option<String>,
+}
+
+/// Query parameters for listing jobs
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct ReportJobQueryParams {
+    /// Filter by job status
+    pub status: Option<ReportJobStatus>,
+    /// Filter by report
+    pub report_id: Option<Uuid>,
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl ReportJobQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
@@ -1,6 +1,40 @@
+mod assignment_dto;
+mod duplicate_dto;
+mod endorsement_dto;
+mod identity_dto;
+mod job_dto;
 mod report_dto;
+mod report_filter;
+mod review_dto;
+mod revision_dto;
+mod update_dto;
 
+pub use assignment_dto::{
+    AssignReportDto, AssignedReportDto, AssignedReportsQuery, ClaimReportDto, ReleaseReportDto,
+    ReportAssignmentDto,
+};
+pub use duplicate_dto::{
+    DuplicateQueryParams, DuplicateReportSummaryDto, ReportDuplicateDto, ReviewDuplicateDto,
+};
+pub use endorsement_dto::{EndorseReportDto, EndorsementQueryParams, ReportEndorsementDto};
+pub use identity_dto::{
+    IdentityRevealQueryParams, ReportIdentityDto, ReportIdentityRevealDto, RevealReportIdentityDto,
+};
+pub use job_dto::{
+    CancelReportJobDto, ReportJobDetailDto, ReportJobDto, ReportJobErrorDto, ReportJobMetricsDto,
+    ReportJobQueryParams, ReportJobReportDto, RequeueReportJobsResultDto,
+};
 pub use report_dto::{
     ReportCategoryDto, ReportDetailResponseDto, ReportLocationResponseDto, ReportResponseDto,
-    ReportTagDto, UpdateReportStatusDto,
+    ReportStatusHistoryDto, ReportTagDto, UpdateReportStatusDto,
+};
+pub use report_filter::{ReportFilter, ReportFilterScope, ReportSort, ReportSortBy};
+pub use review_dto::{
+    ApproveReportReviewDto, RejectReportReviewDto, ReportReviewDto, ReportReviewQueryParams,
+    RequestReportInfoDto,
+};
+pub use revision_dto::{
+    ReportCategoryInputDto, ReportRevisionDto, UpdateReportCategoriesDto, UpdateReportContentDto,
+    UpdateReportLocationDto, UpdateReportTagsDto,
 };
+pub use update_dto::{CreateReportUpdateDto, ReportUpdateAttachmentDto, ReportUpdateDto};
@@ -4,9 +4,10 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::features::reports::dtos::ReportUpdateDto;
 use crate::features::reports::models::{
     GeocodingSource, Report, ReportCategory, ReportLocation, ReportSeverity, ReportStatus,
-    ReportTag, ReportTagType,
+    ReportStatusHistory, ReportTag, ReportTagType,
 };
 
 /// Response DTO for a category assigned to a report
@@ -86,12 +87,14 @@ impl From<Report> for ReportResponseDto {
     }
 }
 
-/// Response DTO for report with location
+/// Response DTO for report with location and progress updates
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ReportDetailResponseDto {
     #[serde(flatten)]
     pub report: ReportResponseDto,
     pub location: Option<ReportLocationResponseDto>,
+    /// Public progress updates posted by officials (oldest first)
+    pub updates: Vec<ReportUpdateDto>,
 }
 
 /// Response DTO for report location
@@ -149,4 +152,35 @@ impl From<ReportLocation> for ReportLocationResponseDto {
 pub struct UpdateReportStatusDto {
     pub status: ReportStatus,
     pub resolution_notes: Option<String>,
+    /// Optional note recorded in the status history
+    #[validate(length(max = 2000))]
+    pub note: Option<String>,
+}
+
+/// Response DTO for a single status transition in a report's timeline
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportStatusHistoryDto {
+    pub id: Uuid,
+    /// Previous status (null for the initial entry)
+    pub from_status: Option<ReportStatus>,
+    pub to_status: ReportStatus,
+    /// User ID of the actor (null for system changes)
+    pub changed_by: Option<String>,
+    pub changed_by_role: Option<String>,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ReportStatusHistory> for ReportStatusHistoryDto {
+    fn from(h: ReportStatusHistory) -> Self {
+        Self {
+            id: h.id,
+            from_status: h.from_status,
+            to_status: h.to_status,
+            changed_by: h.changed_by,
+            changed_by_role: h.changed_by_role,
+            note: h.note,
+            created_at: h.created_at,
+        }
+    }
 }
@@ -0,0 +1,423 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::features::reports::models::{ReportSeverity, ReportStatus, ReportTagType};
+use crate::shared::types::{deserialize_list, search_text, KeysetCursor, SortDirection};
+
+/// Who a report listing is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFilterScope {
+    /// Admin listings, exports and bulk operations: every report, original text
+    Admin,
+    /// Public dashboard: published reports only, redacted text only
+    Public,
+}
+
+impl ReportFilterScope {
+    /// Full-text search vector of the scope
+    pub fn search_vector(&self) -> &'static str {
+        match self {
+            ReportFilterScope::Admin => "r.search_vector",
+            ReportFilterScope::Public => "r.public_search_vector",
+        }
+    }
+}
+
+/// Report filter shared by the admin and dashboard listings, saved filters, exports and
+/// bulk operations
+///
+/// List fields match any of the given values; in query strings they are comma-separated.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ReportFilter {
+    /// Filter by status (any of)
+    #[serde(default, deserialize_with = "deserialize_list")]
+    pub status: Vec<ReportStatus>,
+    /// Created from date (YYYY-MM-DD)
+    pub from_date: Option<NaiveDate>,
+    /// Created to date, inclusive (YYYY-MM-DD)
+    pub to_date: Option<NaiveDate>,
+    /// Verified from date (YYYY-MM-DD)
+    pub verified_from: Option<NaiveDate>,
+    /// Verified to date, inclusive (YYYY-MM-DD)
+    pub verified_to: Option<NaiveDate>,
+    /// Resolved from date (YYYY-MM-DD)
+    pub resolved_from: Option<NaiveDate>,
+    /// Resolved to date, inclusive (YYYY-MM-DD)
+    pub resolved_to: Option<NaiveDate>,
+    /// Search in reference_number or title (admin only)
+    pub search: Option<String>,
+    /// Full-text search over title, description, timeline, impact and location
+    /// (Indonesian stemming, web search syntax); results are ranked by relevance
+    pub q: Option<String>,
+    /// Filter by user_id, anonymous reports are excluded (admin only)
+    pub user_id: Option<String>,
+    /// Filter by platform (admin only)
+    pub platform: Option<String>,
+    /// Filter reports with or without attachments
+    pub has_attachments: Option<bool>,
+    /// Filter by severity of any assigned category (any of)
+    #[serde(default, deserialize_with = "deserialize_list")]
+    pub severity: Vec<ReportSeverity>,
+    /// Filter by assigned category, including its child categories (any of)
+    #[serde(default, deserialize_with = "deserialize_list")]
+    pub category_id: Vec<Uuid>,
+    /// Filter by tag (any of)
+    #[serde(default, deserialize_with = "deserialize_list")]
+    pub tag_type: Vec<ReportTagType>,
+    /// Filter by province of the report location
+    pub province_id: Option<Uuid>,
+    /// Filter by regency of the report location
+    pub regency_id: Option<Uuid>,
+    /// Filter by district of the report location
+    pub district_id: Option<Uuid>,
+    /// Filter by village of the report location
+    pub village_id: Option<Uuid>,
+}
+
+/// Postgres array literal of the given values, bound as text and cast to `type[]`
+fn array_arg<T: ToString>(values: &[T]) -> String {
+    let items: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    format!("{{{}}}", items.join(","))
+}
+
+impl ReportFilter {
+    /// Full-text search text, if any
+    pub fn search_text(&self) -> Option<&str> {
+        search_text(self.q.as_deref())
+    }
+
+    /// WHERE clause over `reports r` and its string arguments ($1, $2, ...)
+    ///
+    /// The public scope only matches published reports that were redacted and cleared by
+    /// moderation, and ignores the admin-only filters, which would match against unredacted data.
+    pub fn where_clause(&self, scope: ReportFilterScope) -> (String, Vec<String>) {
+        let mut conditions = Vec::new();
+        let mut args: Vec<String> = Vec::new();
+
+        if scope == ReportFilterScope::Public {
+            conditions.push("r.status NOT IN ('pending', 'rejected')".to_string());
+            // Extracted content stays hidden until the redact and moderate stages passed
+            conditions.push("r.redacted_at IS NOT NULL".to_string());
+            conditions.push("r.moderated_at IS NOT NULL".to_string());
+        }
+
+        if !self.status.is_empty() {
+            args.push(array_arg(&self.status));
+            conditions.push(format!("r.status = ANY(${}::report_status[])", args.len()));
+        }
+
+        for (column, from, to) in [
+            ("created_at", self.from_date, self.to_date),
+            ("verified_at", self.verified_from, self.verified_to),
+            ("resolved_at", self.resolved_from, self.resolved_to),
+        ] {
+            if let Some(from) = from {
+                args.push(from.to_string());
+                conditions.push(format!("r.{} >= ${}::date", column, args.len()));
+            }
+            if let Some(to) = to {
+                args.push(to.to_string());
+                conditions.push(format!(
+                    "r.{} < (${}::date + interval '1 day')",
+                    column,
+                    args.len()
+                ));
+            }
+        }
+
+        if scope == ReportFilterScope::Admin {
+            if let Some(ref search) = self.search {
+                args.push(format!("%{}%", search.to_lowercase()));
+                conditions.push(format!(
+                    "(LOWER(r.reference_number) LIKE ${0} OR LOWER(r.title) LIKE ${0})",
+                    args.len()
+                ));
+            }
+
+            if let Some(ref user_id) = self.user_id {
+                args.push(user_id.clone());
+                // Anonymous reports must not be attributable by filtering on a user
+                conditions.push(format!(
+                    "r.user_id = ${} AND NOT r.is_anonymous",
+                    args.len()
+                ));
+            }
+
+            if let Some(ref platform) = self.platform {
+                args.push(platform.clone());
+                conditions.push(format!("r.platform = ${}", args.len()));
+            }
+        }
+
+        if let Some(q) = self.search_text() {
+            args.push(q.to_string());
+            conditions.push(format!(
+                "{} @@ websearch_to_tsquery('report_search', ${})",
+                scope.search_vector(),
+                args.len()
+            ));
+        }
+
+        if let Some(has_attachments) = self.has_attachments {
+            if has_attachments {
+                conditions.push(
+                    "EXISTS (SELECT 1 FROM report_attachments WHERE report_id = r.id AND report_update_id IS NULL)".to_string(),
+                );
+            } else {
+                conditions.push(
+                    "NOT EXISTS (SELECT 1 FROM report_attachments WHERE report_id = r.id AND report_update_id IS NULL)"
+                        .to_string(),
+                );
+            }
+        }
+
+        if !self.severity.is_empty() {
+            args.push(array_arg(&self.severity));
+            conditions.push(format!(
+                "EXISTS (SELECT 1 FROM report_categories WHERE report_id = r.id AND severity = ANY(${}::report_severity[]))",
+                args.len()
+            ));
+        }
+
+        if !self.category_id.is_empty() {
+            args.push(array_arg(&self.category_id));
+            // Selecting a parent category also matches reports filed under its children
+            conditions.push(format!(
+                "EXISTS (SELECT 1 FROM report_categories WHERE report_id = r.id AND category_id IN (\
+                    WITH RECURSIVE tree AS (\
+                        SELECT id FROM categories WHERE id = ANY(${}::uuid[]) \
+                        UNION SELECT c.id FROM categories c JOIN tree t ON c.parent_id = t.id\
+                    ) SELECT id FROM tree))",
+                args.len()
+            ));
+        }
+
+        if !self.tag_type.is_empty() {
+            args.push(array_arg(&self.tag_type));
+            conditions.push(format!(
+                "EXISTS (SELECT 1 FROM report_tags WHERE report_id = r.id AND tag_type = ANY(${}::report_tag_type[]))",
+                args.len()
+            ));
+        }
+
+        for (column, region_id) in [
+            ("province_id", self.province_id),
+            ("regency_id", self.regency_id),
+            ("district_id", self.district_id),
+            ("village_id", self.village_id),
+        ] {
+            if let Some(region_id) = region_id {
+                args.push(region_id.to_string());
+                conditions.push(format!(
+                    "EXISTS (SELECT 1 FROM report_locations WHERE report_id = r.id AND {} = ${}::uuid)",
+                    column,
+                    args.len()
+                ));
+            }
+        }
+
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+
+        (where_clause, args)
+    }
+}
+
+/// Sort fields for reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportSortBy {
+    CreatedAt,
+    UpdatedAt,
+    VerifiedAt,
+    ResolvedAt,
+    Status,
+    ReferenceNumber,
+    Endorsements,
+    /// Full-text relevance (only with `q`, otherwise created_at)
+    Relevance,
+}
+
+impl ReportSortBy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportSortBy::CreatedAt => "created_at",
+            ReportSortBy::UpdatedAt => "updated_at",
+            ReportSortBy::VerifiedAt => "verified_at",
+            ReportSortBy::ResolvedAt => "resolved_at",
+            ReportSortBy::Status => "status",
+            ReportSortBy::ReferenceNumber => "reference_number",
+            ReportSortBy::Endorsements => "endorsements",
+            ReportSortBy::Relevance => "relevance",
+        }
+    }
+}
+
+/// Resolved sort of a report listing, ordered by a sort key and then by id
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReportSort {
+    pub by: ReportSortBy,
+    pub direction: SortDirection,
+}
+
+impl ReportSort {
+    /// Requested sort; relevance when searching without one, newest first otherwise
+    pub fn new(by: Option<ReportSortBy>, direction: SortDirection, searching: bool) -> Self {
+        let by = match by {
+            None | Some(ReportSortBy::Relevance) if searching => ReportSortBy::Relevance,
+            None | Some(ReportSortBy::Relevance) => ReportSortBy::CreatedAt,
+            Some(by) => by,
+        };
+        Self { by, direction }
+    }
+
+    /// Name the keyset cursors of this sort are issued for
+    pub fn name(&self) -> String {
+        format!(
+            "{}:{}",
+            self.by.as_str(),
+            self.direction.as_sql().to_lowercase()
+        )
+    }
+
+    /// Sort key expression over `reports r` and its SQL type
+    ///
+    /// Keys are never NULL so rows can be compared against a cursor; `rank` is the
+    /// relevance expression of the listing.
+    pub fn key(&self, rank: Option<&str>) -> (String, &'static str) {
+        match (self.by, rank) {
+            (ReportSortBy::UpdatedAt, _) => ("r.updated_at".to_string(), "timestamptz"),
+            (ReportSortBy::VerifiedAt, _) => (
+                "COALESCE(r.verified_at, '-infinity'::timestamptz)".to_string(),
+                "timestamptz",
+            ),
+            (ReportSortBy::ResolvedAt, _) => (
+                "COALESCE(r.resolved_at, '-infinity'::timestamptz)".to_string(),
+                "timestamptz",
+            ),
+            (ReportSortBy::Status, _) => ("r.status".to_string(), "report_status"),
+            (ReportSortBy::ReferenceNumber, _) => {
+                ("COALESCE(r.reference_number, '')".to_string(), "text")
+            }
+            (ReportSortBy::Endorsements, _) => ("r.endorsement_count".to_string(), "integer"),
+            (ReportSortBy::Relevance, Some(rank)) => (format!("({})::real", rank), "real"),
+            (ReportSortBy::CreatedAt, _) | (ReportSortBy::Relevance, None) => {
+                ("r.created_at".to_string(), "timestamptz")
+            }
+        }
+    }
+
+    /// ORDER BY list of the sort
+    pub fn order_by(&self, rank: Option<&str>) -> String {
+        let (key, _) = self.key(rank);
+        let dir = self.direction.as_sql();
+        format!("{} {}, r.id {}", key, dir, dir)
+    }
+
+    /// Extend a WHERE clause to the rows after the cursor, binding its key and id
+    pub fn after(
+        &self,
+        where_clause: String,
+        cursor: Option<&KeysetCursor>,
+        rank: Option<&str>,
+        args: &mut Vec<String>,
+    ) -> String {
+        let Some(cursor) = cursor else {
+            return where_clause;
+        };
+
+        let (key, key_type) = self.key(rank);
+        args.push(cursor.key.clone());
+        args.push(cursor.id.to_string());
+        let condition = format!(
+            "({}, r.id) {} (${}::{}, ${}::uuid)",
+            key,
+            self.direction.after_operator(),
+            args.len() - 1,
+            key_type,
+            args.len()
+        );
+
+        if where_clause.is_empty() {
+            format!("WHERE {}", condition)
+        } else {
+            format!("{} AND {}", where_clause, condition)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_where_clause_binds_lists_as_arrays() {
+        let filter: ReportFilter = serde_json::from_value(serde_json::json!({
+            "status": "verified",
+            "category_id": [Uuid::nil()],
+            "user_id": "user-1",
+            "district_id": Uuid::nil(),
+        }))
+        .unwrap();
+
+        let (where_clause, args) = filter.where_clause(ReportFilterScope::Admin);
+        assert!(where_clause.contains("r.status = ANY($1::report_status[])"));
+        assert!(where_clause.contains("WITH RECURSIVE tree"));
+        assert!(where_clause.contains("district_id = $4::uuid"));
+        assert_eq!(args[0], "{verified}");
+        assert_eq!(args[1], "user-1");
+        assert_eq!(args[2], format!("{{{}}}", Uuid::nil()));
+
+        // Admin-only filters never reach the public listing
+        let (where_clause, args) = filter.where_clause(ReportFilterScope::Public);
+        assert!(where_clause.starts_with("WHERE r.status NOT IN ('pending', 'rejected')"));
+        assert!(!where_clause.contains("user_id"));
+        assert_eq!(args.len(), 3);
+    }
+
+    #[test]
+    fn test_public_scope_requires_redacted_and_moderated_content() {
+        let published = "r.redacted_at IS NOT NULL AND r.moderated_at IS NOT NULL";
+
+        let (where_clause, args) = ReportFilter::default().where_clause(ReportFilterScope::Public);
+        assert!(where_clause.contains(published));
+        assert!(args.is_empty());
+
+        // A status filter for draft reports cannot surface unprocessed content either
+        let filter = ReportFilter {
+            status: vec![ReportStatus::Draft],
+            ..Default::default()
+        };
+        let (where_clause, _) = filter.where_clause(ReportFilterScope::Public);
+        assert!(where_clause.contains(&format!("{} AND r.status = ANY($1", published)));
+
+        let (where_clause, _) = filter.where_clause(ReportFilterScope::Admin);
+        assert!(!where_clause.contains("moderated_at"));
+    }
+
+    #[test]
+    fn test_sort_defaults_and_keyset_condition() {
+        let sort = ReportSort::new(None, SortDirection::Desc, true);
+        assert_eq!(sort.by, ReportSortBy::Relevance);
+        assert_eq!(
+            ReportSort::new(Some(ReportSortBy::Relevance), SortDirection::Asc, false).name(),
+            "created_at:asc"
+        );
+
+        let cursor = KeysetCursor::new(sort.name(), "0.5", Uuid::nil());
+        let mut args = vec!["{verified}".to_string()];
+        let where_clause = sort.after(
+            "WHERE r.status = ANY($1::report_status[])".to_string(),
+            Some(&cursor),
+            Some("rank_expr"),
+            &mut args,
+        );
+        assert!(where_clause.ends_with("AND ((rank_expr)::real, r.id) < ($2::real, $3::uuid)"));
+        assert_eq!(args.len(), 3);
+    }
+}
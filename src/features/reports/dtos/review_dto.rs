@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::features::reports::models::{ReportReview, ReportReviewStatus, ReportStatus};
+use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+/// Response DTO for a review queue entry
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportReviewDto {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub reference_number: Option<String>,
+    pub report_status: ReportStatus,
+    /// Citizen who submitted the report (None for anonymous reports)
+    pub user_id: Option<String>,
+    pub platform: Option<String>,
+    pub is_anonymous: bool,
+    /// Agent confidence score of the submission (0-1)
+    pub confidence_score: Option<f64>,
+    /// Threshold in effect when the report was queued
+    pub confidence_threshold: f64,
+    /// Citizen messages of the conversation (shortened)
+    pub conversation_excerpt: Option<String>,
+    pub status: ReportReviewStatus,
+    /// Latest message sent to the citizen asking for more information
+    pub info_request: Option<String>,
+    pub info_requested_at: Option<DateTime<Utc>>,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ReportReviewDto {
+    pub fn new(review: ReportReview, conversation_excerpt: Option<String>) -> Self {
+        Self {
+            id: review.id,
+            report_id: review.report_id,
+            reference_number: review.reference_number,
+            report_status: review.report_status,
+            user_id: review.user_id.filter(|_| !review.is_anonymous),
+            platform: review.platform,
+            is_anonymous: review.is_anonymous,
+            confidence_score: review.confidence_score,
+            confidence_threshold: review.confidence_threshold,
+            conversation_excerpt,
+            status: review.status,
+            info_request: review.info_request,
+            info_requested_at: review.info_requested_at,
+            reviewed_by: review.reviewed_by,
+            reviewed_at: review.reviewed_at,
+            review_note: review.review_note,
+            created_at: review.created_at,
+        }
+    }
+}
+
+/// Request DTO for approving a submission
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ApproveReportReviewDto {
+    #[validate(length(max = 2000))]
+    pub note: Option<String>,
+}
+
+/// Request DTO for asking the citizen for more information
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RequestReportInfoDto {
+    /// Message shown to the citizen
+    #[validate(length(min = 1, max = 2000))]
+    pub message: String,
+}
+
+/// Request DTO for rejecting a submission
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct RejectReportReviewDto {
+    /// Reason shown to the citizen
+    #[validate(length(min = 1, max = 2000))]
+    pub reason: String,
+}
+
+/// Query parameters for the review queue
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct ReportReviewQueryParams {
+    /// Filter by review status (default: open entries - pending and info_requested)
+    pub status: Option<ReportReviewStatus>,
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl ReportReviewQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
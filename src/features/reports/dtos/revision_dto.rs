@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::features::reports::models::{
+    ReportRevision, ReportRevisionSection, ReportRevisionSource, ReportSeverity, ReportTagType,
+};
+
+/// Response DTO for a report revision
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportRevisionDto {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    /// Sequence number per report, starting at 1
+    pub revision_number: i32,
+    pub source: ReportRevisionSource,
+    pub section: ReportRevisionSection,
+    /// Changed fields as {"field": {"old": ..., "new": ...}}
+    pub changes: Value,
+    pub reason: Option<String>,
+    /// Curator who made the change (None for extraction)
+    pub edited_by: Option<String>,
+    pub editor_role: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ReportRevision> for ReportRevisionDto {
+    fn from(r: ReportRevision) -> Self {
+        Self {
+            id: r.id,
+            report_id: r.report_id,
+            revision_number: r.revision_number,
+            source: r.source,
+            section: r.section,
+            changes: r.changes,
+            reason: r.reason,
+            edited_by: r.edited_by,
+            editor_role: r.editor_role,
+            created_at: r.created_at,
+        }
+    }
+}
+
+/// Request DTO for correcting report content
+///
+/// Omitted fields are left unchanged. The public redacted copy is regenerated.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateReportContentDto {
+    #[validate(length(min = 1, max = 200))]
+    pub title: Option<String>,
+    #[validate(length(min = 1, max = 10000))]
+    pub description: Option<String>,
+    #[validate(length(max = 1000))]
+    pub timeline: Option<String>,
+    #[validate(length(max = 1000))]
+    pub impact: Option<String>,
+    /// Bahasa Indonesia title for reports written in another language
+    #[validate(length(min = 1, max = 200))]
+    pub normalized_title: Option<String>,
+    /// Bahasa Indonesia description for reports written in another language
+    #[validate(length(min = 1, max = 10000))]
+    pub normalized_description: Option<String>,
+    /// Why the content was changed
+    #[validate(length(min = 1, max = 2000))]
+    pub reason: String,
+}
+
+/// Category with severity assigned by a curator
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportCategoryInputDto {
+    pub category_id: Uuid,
+    pub severity: ReportSeverity,
+}
+
+/// Request DTO for replacing the categories of a report
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateReportCategoriesDto {
+    #[validate(length(min = 1, max = 10))]
+    pub categories: Vec<ReportCategoryInputDto>,
+    /// Why the categories were changed
+    #[validate(length(min = 1, max = 2000))]
+    pub reason: String,
+}
+
+/// Request DTO for replacing the tags of a report
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateReportTagsDto {
+    #[validate(length(max = 5))]
+    pub tags: Vec<ReportTagType>,
+    /// Why the tags were changed
+    #[validate(length(min = 1, max = 2000))]
+    pub reason: String,
+}
+
+/// Request DTO for replacing the location of a report
+///
+/// The location is stored as a manual location; geocoder details of the previous
+/// location are cleared. Region IDs must belong to each other where given.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateReportLocationDto {
+    /// Location as it should be shown to officials
+    #[validate(length(min = 1, max = 500))]
+    pub raw_input: String,
+    #[validate(range(min = -90.0, max = 90.0))]
+    pub lat: Option<f64>,
+    #[validate(range(min = -180.0, max = 180.0))]
+    pub lon: Option<f64>,
+    pub province_id: Option<Uuid>,
+    pub regency_id: Option<Uuid>,
+    pub district_id: Option<Uuid>,
+    pub village_id: Option<Uuid>,
+    /// Why the location was changed
+    #[validate(length(min = 1, max = 2000))]
+    pub reason: String,
+}
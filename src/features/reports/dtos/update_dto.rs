@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::features::reports::models::{ReportUpdate, ReportUpdateAttachment};
+
+/// Request DTO for posting a progress update on a report
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateReportUpdateDto {
+    /// Public progress message shown to the citizen and on the dashboard
+    #[validate(length(min = 1, max = 5000))]
+    pub message: String,
+    /// IDs of public files uploaded by the author via the files API
+    #[serde(default)]
+    #[validate(length(max = 10))]
+    pub file_ids: Vec<Uuid>,
+}
+
+/// Response DTO for a file attached to an update
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportUpdateAttachmentDto {
+    pub file_id: Uuid,
+    pub original_filename: String,
+    pub content_type: String,
+    pub file_size: i64,
+    pub url: String,
+}
+
+impl From<ReportUpdateAttachment> for ReportUpdateAttachmentDto {
+    fn from(a: ReportUpdateAttachment) -> Self {
+        Self {
+            file_id: a.file_id,
+            original_filename: a.original_filename,
+            content_type: a.content_type,
+            file_size: a.file_size,
+            url: a.url,
+        }
+    }
+}
+
+/// Response DTO for a public progress update
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportUpdateDto {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    /// Role of the author (official, admin_curator, super_admin)
+    pub author_role: String,
+    pub message: String,
+    pub created_at: DateTime<Utc>,
+    pub attachments: Vec<ReportUpdateAttachmentDto>,
+}
+
+impl ReportUpdateDto {
+    pub fn new(update: ReportUpdate, attachments: Vec<ReportUpdateAttachment>) -> Self {
+        Self {
+            id: update.id,
+            report_id: update.report_id,
+            author_role: update.author_role,
+            message: update.message,
+            created_at: update.created_at,
+            attachments: attachments.into_iter().map(Into::into).collect(),
+        }
+    }
+}
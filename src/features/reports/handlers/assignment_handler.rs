@@ -0,0 +1,262 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::core::error::{AppError, Result};
+use crate::core::extractor::AppJson;
+use crate::features::auth::guards::{RequireAdminCurator, RequireOfficial};
+use crate::features::reports::dtos::{
+    AssignReportDto, AssignedReportDto, AssignedReportsQuery, ClaimReportDto, ReleaseReportDto,
+    ReportAssignmentDto,
+};
+use crate::features::reports::handlers::ReportState;
+use crate::features::reports::services::ReportAssignmentService;
+use crate::shared::types::{ApiResponse, Meta};
+
+// =============================================================================
+// OFFICIAL HANDLERS
+// =============================================================================
+
+/// List reports currently assigned to the authenticated official
+#[utoipa::path(
+    get,
+    path = "/api/reports/assigned",
+    params(AssignedReportsQuery),
+    responses(
+        (status = 200, description = "Reports assigned to the caller", body = ApiResponse<Vec<AssignedReportDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Official access required")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "reports"
+)]
+pub async fn list_assigned_reports(
+    RequireOfficial(user): RequireOfficial,
+    State(state): State<ReportState>,
+    Query(params): Query<AssignedReportsQuery>,
+) -> Result<Json<ApiResponse<Vec<AssignedReportDto>>>> {
+    let (items, total) = state
+        .assignment_service
+        .list_assigned_to(&user.sub, params.status, params.limit(), params.offset())
+        .await?;
+
+    let dtos: Vec<AssignedReportDto> = items
+        .into_iter()
+        .map(|(report, assignment)| AssignedReportDto {
+            report: report.into(),
+            assignment: assignment.into(),
+        })
+        .collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Claim an unassigned verified report
+#[utoipa::path(
+    post,
+    path = "/api/reports/{id}/claim",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    request_body = ClaimReportDto,
+    responses(
+        (status = 200, description = "Report claimed", body = ApiResponse<ReportAssignmentDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Official access required"),
+        (status = 404, description = "Report not found"),
+        (status = 409, description = "Report already assigned or not claimable")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "reports"
+)]
+pub async fn claim_report(
+    RequireOfficial(user): RequireOfficial,
+    State(state): State<ReportState>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<ClaimReportDto>,
+) -> Result<Json<ApiResponse<ReportAssignmentDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let assignment = state
+        .assignment_service
+        .claim(id, &user.sub, dto.note.as_deref())
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(assignment.into()),
+        Some("Report claimed".to_string()),
+        None,
+    )))
+}
+
+/// Release a claimed report so other officials can pick it up
+#[utoipa::path(
+    post,
+    path = "/api/reports/{id}/release",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    request_body = ReleaseReportDto,
+    responses(
+        (status = 200, description = "Assignment released", body = ApiResponse<ReportAssignmentDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not the current assignee"),
+        (status = 404, description = "Report has no active assignment")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "reports"
+)]
+pub async fn release_report(
+    RequireOfficial(user): RequireOfficial,
+    State(state): State<ReportState>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<ReleaseReportDto>,
+) -> Result<Json<ApiResponse<ReportAssignmentDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let assignment = state
+        .assignment_service
+        .release(
+            id,
+            &user.sub,
+            user.has_admin_access(),
+            dto.reason.as_deref(),
+        )
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(assignment.into()),
+        Some("Report released".to_string()),
+        None,
+    )))
+}
+
+/// Reassign a claimed report to another official
+#[utoipa::path(
+    post,
+    path = "/api/reports/{id}/reassign",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    request_body = AssignReportDto,
+    responses(
+        (status = 200, description = "Report reassigned", body = ApiResponse<ReportAssignmentDto>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Not the current assignee"),
+        (status = 404, description = "Report has no active assignment")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "reports"
+)]
+pub async fn reassign_report(
+    RequireOfficial(user): RequireOfficial,
+    State(state): State<ReportState>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<AssignReportDto>,
+) -> Result<Json<ApiResponse<ReportAssignmentDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let assignment = state
+        .assignment_service
+        .reassign(
+            id,
+            &user.sub,
+            user.has_admin_access(),
+            &dto.assignee_id,
+            dto.note.as_deref(),
+        )
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(assignment.into()),
+        Some("Report reassigned".to_string()),
+        None,
+    )))
+}
+
+// =============================================================================
+// ADMIN HANDLERS
+// =============================================================================
+
+/// Assign a report directly to an official (admin curator)
+#[utoipa::path(
+    post,
+    path = "/api/admin/reports/{id}/assign",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    request_body = AssignReportDto,
+    responses(
+        (status = 200, description = "Report assigned", body = ApiResponse<ReportAssignmentDto>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Report not found"),
+        (status = 409, description = "Report cannot be assigned in its current status")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn assign_report(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ReportAssignmentService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<AssignReportDto>,
+) -> Result<Json<ApiResponse<ReportAssignmentDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let assignment = service
+        .assign(id, &user.sub, &dto.assignee_id, dto.note.as_deref())
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(assignment.into()),
+        Some("Report assigned".to_string()),
+        None,
+    )))
+}
+
+/// List the assignment history of a report (admin curator)
+#[utoipa::path(
+    get,
+    path = "/api/admin/reports/{id}/assignments",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    responses(
+        (status = 200, description = "Assignment history (newest first)", body = ApiResponse<Vec<ReportAssignmentDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_report_assignments(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<ReportAssignmentService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ReportAssignmentDto>>>> {
+    let items = service.list_for_report(id).await?;
+    let total = items.len() as i64;
+    let dtos: Vec<ReportAssignmentDto> = items.into_iter().map(Into::into).collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
@@ -0,0 +1,190 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::core::error::{AppError, Result};
+use crate::core::extractor::AppJson;
+use crate::features::auth::guards::RequireAdminCurator;
+use crate::features::reports::dtos::{
+    DuplicateQueryParams, ReportDuplicateDto, ReviewDuplicateDto,
+};
+use crate::features::reports::services::ReportDuplicateService;
+use crate::shared::types::{ApiResponse, Meta};
+
+/// List duplicate candidates, highest score first (admin curator)
+#[utoipa::path(
+    get,
+    path = "/api/admin/report-duplicates",
+    params(DuplicateQueryParams),
+    responses(
+        (status = 200, description = "Duplicate candidates", body = ApiResponse<Vec<ReportDuplicateDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_report_duplicates(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<ReportDuplicateService>>,
+    Query(params): Query<DuplicateQueryParams>,
+) -> Result<Json<ApiResponse<Vec<ReportDuplicateDto>>>> {
+    let (items, total) = service
+        .list(params.status, params.limit(), params.offset())
+        .await?;
+    let dtos: Vec<ReportDuplicateDto> = items.into_iter().map(Into::into).collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// List duplicate candidates involving a report, on either side (admin curator)
+#[utoipa::path(
+    get,
+    path = "/api/admin/reports/{id}/duplicates",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    responses(
+        (status = 200, description = "Duplicate candidates of the report", body = ApiResponse<Vec<ReportDuplicateDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_duplicates_for_report(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<ReportDuplicateService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ReportDuplicateDto>>>> {
+    let items = service.list_for_report(id).await?;
+    let total = items.len() as i64;
+    let dtos: Vec<ReportDuplicateDto> = items.into_iter().map(Into::into).collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Confirm that two reports describe the same issue (admin curator)
+#[utoipa::path(
+    post,
+    path = "/api/admin/report-duplicates/{id}/confirm",
+    params(
+        ("id" = Uuid, Path, description = "Duplicate candidate ID")
+    ),
+    request_body = ReviewDuplicateDto,
+    responses(
+        (status = 200, description = "Duplicate confirmed", body = ApiResponse<ReportDuplicateDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Duplicate candidate not found"),
+        (status = 409, description = "Candidate already dismissed or merged")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn confirm_report_duplicate(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ReportDuplicateService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<ReviewDuplicateDto>,
+) -> Result<Json<ApiResponse<ReportDuplicateDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let duplicate = service.confirm(id, &user.sub, dto.note.as_deref()).await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(duplicate.into()),
+        Some("Duplicate confirmed".to_string()),
+        None,
+    )))
+}
+
+/// Dismiss a duplicate candidate as unrelated (admin curator)
+#[utoipa::path(
+    post,
+    path = "/api/admin/report-duplicates/{id}/dismiss",
+    params(
+        ("id" = Uuid, Path, description = "Duplicate candidate ID")
+    ),
+    request_body = ReviewDuplicateDto,
+    responses(
+        (status = 200, description = "Duplicate dismissed", body = ApiResponse<ReportDuplicateDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Duplicate candidate not found"),
+        (status = 409, description = "Candidate already dismissed or merged")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn dismiss_report_duplicate(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ReportDuplicateService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<ReviewDuplicateDto>,
+) -> Result<Json<ApiResponse<ReportDuplicateDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let duplicate = service.dismiss(id, &user.sub, dto.note.as_deref()).await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(duplicate.into()),
+        Some("Duplicate dismissed".to_string()),
+        None,
+    )))
+}
+
+/// Merge a duplicate into the earlier report (admin curator)
+///
+/// Attachments are linked to the earlier report and the duplicate is rejected.
+#[utoipa::path(
+    post,
+    path = "/api/admin/report-duplicates/{id}/merge",
+    params(
+        ("id" = Uuid, Path, description = "Duplicate candidate ID")
+    ),
+    request_body = ReviewDuplicateDto,
+    responses(
+        (status = 200, description = "Duplicate merged", body = ApiResponse<ReportDuplicateDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Duplicate candidate not found"),
+        (status = 409, description = "Candidate already closed or duplicate report cannot be rejected")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn merge_report_duplicate(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ReportDuplicateService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<ReviewDuplicateDto>,
+) -> Result<Json<ApiResponse<ReportDuplicateDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let duplicate = service
+        .merge(id, &user.sub, user.primary_role(), dto.note.as_deref())
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(duplicate.into()),
+        Some("Duplicate merged".to_string()),
+        None,
+    )))
+}
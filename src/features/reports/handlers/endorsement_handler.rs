@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::core::error::{AppError, Result};
+use crate::core::extractor::AppJson;
+use crate::features::auth::guards::RequireAdminCurator;
+use crate::features::auth::model::AuthenticatedUser;
+use crate::features::reports::dtos::{
+    EndorseReportDto, EndorsementQueryParams, ReportEndorsementDto,
+};
+use crate::features::reports::services::ReportEndorsementService;
+use crate::shared::types::{ApiResponse, Meta};
+
+// =============================================================================
+// CITIZEN HANDLERS
+// =============================================================================
+
+/// Endorse a verified report ("me too")
+///
+/// Each user can endorse a report once. Send `{}` to endorse without a comment.
+#[utoipa::path(
+    post,
+    path = "/api/reports/{id}/endorsements",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    request_body = EndorseReportDto,
+    responses(
+        (status = 200, description = "Report endorsed", body = ApiResponse<ReportEndorsementDto>),
+        (status = 400, description = "Validation error or own report"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Report not found"),
+        (status = 409, description = "Already endorsed or report not endorsable")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "reports"
+)]
+pub async fn endorse_report(
+    user: AuthenticatedUser,
+    State(service): State<Arc<ReportEndorsementService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<EndorseReportDto>,
+) -> Result<Json<ApiResponse<ReportEndorsementDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let comment = dto
+        .comment
+        .as_deref()
+        .map(str::trim)
+        .filter(|c| !c.is_empty());
+
+    let endorsement = service.endorse(id, &user.sub, comment).await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(endorsement.into()),
+        Some("Report endorsed".to_string()),
+        None,
+    )))
+}
+
+/// Withdraw the caller's endorsement of a report
+#[utoipa::path(
+    delete,
+    path = "/api/reports/{id}/endorsements",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    responses(
+        (status = 200, description = "Endorsement withdrawn"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Endorsement not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "reports"
+)]
+pub async fn withdraw_endorsement(
+    user: AuthenticatedUser,
+    State(service): State<Arc<ReportEndorsementService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>> {
+    service.withdraw(id, &user.sub).await?;
+
+    Ok(Json(ApiResponse::success(
+        None,
+        Some("Endorsement withdrawn".to_string()),
+        None,
+    )))
+}
+
+// =============================================================================
+// ADMIN HANDLERS
+// =============================================================================
+
+/// List citizens who endorsed a report (admin curator)
+#[utoipa::path(
+    get,
+    path = "/api/admin/reports/{id}/endorsements",
+    params(
+        ("id" = Uuid, Path, description = "Report ID"),
+        EndorsementQueryParams
+    ),
+    responses(
+        (status = 200, description = "Endorsements of the report (newest first)", body = ApiResponse<Vec<ReportEndorsementDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_report_endorsements(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<ReportEndorsementService>>,
+    Path(id): Path<Uuid>,
+    Query(params): Query<EndorsementQueryParams>,
+) -> Result<Json<ApiResponse<Vec<ReportEndorsementDto>>>> {
+    let (items, total) = service
+        .list_for_report(id, params.limit(), params.offset())
+        .await?;
+    let dtos: Vec<ReportEndorsementDto> = items.into_iter().map(Into::into).collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::core::error::{AppError, Result};
+use crate::core::extractor::AppJson;
+use crate::features::auth::guards::RequireSuperAdmin;
+use crate::features::citizen_report_agent::ConversationService;
+use crate::features::reports::dtos::{
+    IdentityRevealQueryParams, ReportIdentityDto, ReportIdentityRevealDto, RevealReportIdentityDto,
+};
+use crate::features::reports::services::ReportIdentityService;
+use crate::shared::types::{ApiResponse, Meta};
+
+/// State for the anonymous reporter break-glass handlers
+#[derive(Clone)]
+pub struct ReportIdentityState {
+    pub identity_service: Arc<ReportIdentityService>,
+    pub conversation_service: Arc<ConversationService>,
+}
+
+// =============================================================================
+// BREAK-GLASS HANDLERS
+// =============================================================================
+
+/// Reveal the reporter and conversation of an anonymous report (super admin)
+///
+/// Every call is recorded in the identity reveal audit log together with the reason.
+#[utoipa::path(
+    post,
+    path = "/api/admin/reports/{id}/identity",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    request_body = RevealReportIdentityDto,
+    responses(
+        (status = 200, description = "Reporter identity (reveal recorded)", body = ApiResponse<ReportIdentityDto>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required"),
+        (status = 404, description = "Report not found"),
+        (status = 409, description = "Report is not anonymous")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn reveal_report_identity(
+    RequireSuperAdmin(user): RequireSuperAdmin,
+    State(state): State<ReportIdentityState>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<RevealReportIdentityDto>,
+) -> Result<Json<ApiResponse<ReportIdentityDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let identity = state
+        .identity_service
+        .reveal(id, &user.sub, dto.reason.trim())
+        .await?;
+
+    // Best effort, like the job console: the reveal is already recorded
+    let messages = match identity.adk_thread_id {
+        Some(thread_id) => match state
+            .conversation_service
+            .list_thread_messages(thread_id)
+            .await
+        {
+            Ok(messages) => Some(messages),
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to load thread {} of anonymous report {}: {:?}",
+                    thread_id,
+                    id,
+                    e
+                );
+                None
+            }
+        },
+        None => None,
+    };
+
+    let dto = ReportIdentityDto {
+        report_id: id,
+        user_id: identity.user_id,
+        adk_thread_id: identity.adk_thread_id,
+        messages,
+        reveal: identity.reveal.into(),
+    };
+
+    Ok(Json(ApiResponse::success(
+        Some(dto),
+        Some("Identity revealed; this access has been recorded".to_string()),
+        None,
+    )))
+}
+
+/// List the identity reveal audit log, newest first (super admin)
+#[utoipa::path(
+    get,
+    path = "/api/admin/report-identity-reveals",
+    params(IdentityRevealQueryParams),
+    responses(
+        (status = 200, description = "Identity reveals", body = ApiResponse<Vec<ReportIdentityRevealDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_identity_reveals(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(state): State<ReportIdentityState>,
+    Query(params): Query<IdentityRevealQueryParams>,
+) -> Result<Json<ApiResponse<Vec<ReportIdentityRevealDto>>>> {
+    let (items, total) = state
+        .identity_service
+        .list_reveals(params.report_id, params.limit(), params.offset())
+        .await?;
+    let dtos: Vec<ReportIdentityRevealDto> = items.into_iter().map(Into::into).collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
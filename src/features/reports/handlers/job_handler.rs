@@ -0,0 +1,278 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::core::error::{AppError, Result};
+use crate::core::extractor::AppJson;
+use crate::features::auth::guards::RequireSuperAdmin;
+use crate::features::citizen_report_agent::ConversationService;
+use crate::features::reports::dtos::{
+    CancelReportJobDto, ReportJobDetailDto, ReportJobDto, ReportJobMetricsDto,
+    ReportJobQueryParams, RequeueReportJobsResultDto,
+};
+use crate::features::reports::services::{ReportJobService, ReportService};
+use crate::shared::types::{ApiResponse, Meta};
+
+/// State for the report job console handlers
+#[derive(Clone)]
+pub struct ReportJobState {
+    pub job_service: Arc<ReportJobService>,
+    pub report_service: Arc<ReportService>,
+    pub conversation_service: Arc<ConversationService>,
+}
+
+// =============================================================================
+// JOB CONSOLE HANDLERS
+// =============================================================================
+
+/// List report processing jobs, newest first (super admin)
+#[utoipa::path(
+    get,
+    path = "/api/admin/report-jobs",
+    params(ReportJobQueryParams),
+    responses(
+        (status = 200, description = "Report jobs with retry counts and latest errors", body = ApiResponse<Vec<ReportJobDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_report_jobs(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(state): State<ReportJobState>,
+    Query(params): Query<ReportJobQueryParams>,
+) -> Result<Json<ApiResponse<Vec<ReportJobDto>>>> {
+    let (items, total) = state
+        .job_service
+        .list(
+            params.status,
+            params.report_id,
+            params.limit(),
+            params.offset(),
+        )
+        .await?;
+    let dtos: Vec<ReportJobDto> = items.into_iter().map(Into::into).collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Queue depth and age metrics (super admin)
+#[utoipa::path(
+    get,
+    path = "/api/admin/report-jobs/metrics",
+    responses(
+        (status = 200, description = "Report job queue metrics", body = ApiResponse<ReportJobMetricsDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_report_job_metrics(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(state): State<ReportJobState>,
+) -> Result<Json<ApiResponse<ReportJobMetricsDto>>> {
+    let metrics = state.job_service.metrics().await?;
+    Ok(Json(ApiResponse::success(Some(metrics.into()), None, None)))
+}
+
+/// Get a job with its error log, report and conversation thread (super admin)
+#[utoipa::path(
+    get,
+    path = "/api/admin/report-jobs/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Report job ID")
+    ),
+    responses(
+        (status = 200, description = "Report job details", body = ApiResponse<ReportJobDetailDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required"),
+        (status = 404, description = "Report job not found")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_report_job(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(state): State<ReportJobState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ReportJobDetailDto>>> {
+    let job = state.job_service.get_by_id(id).await?;
+    let errors = state.job_service.list_errors(id).await?;
+    let report = state.report_service.get_by_id(job.report_id).await?;
+
+    // The conversation lives in the agent database; show the job even if it is unavailable.
+    // Conversations of anonymous reports are only available through the break-glass endpoint.
+    let thread_id = report.adk_thread_id.filter(|_| !report.is_anonymous);
+    let messages = match thread_id {
+        Some(thread_id) => match state
+            .conversation_service
+            .list_thread_messages(thread_id)
+            .await
+        {
+            Ok(messages) => Some(messages),
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to load thread {} of report job {}: {:?}",
+                    thread_id,
+                    id,
+                    e
+                );
+                None
+            }
+        },
+        None => None,
+    };
+
+    let dto = ReportJobDetailDto {
+        job: job.into(),
+        errors: errors.into_iter().map(Into::into).collect(),
+        report: report.into(),
+        messages,
+    };
+
+    Ok(Json(ApiResponse::success(Some(dto), None, None)))
+}
+
+/// Put a failed or cancelled job back in the queue (super admin)
+///
+/// The retry count is reset and the job resumes at its checkpointed stage.
+#[utoipa::path(
+    post,
+    path = "/api/admin/report-jobs/{id}/requeue",
+    params(
+        ("id" = Uuid, Path, description = "Report job ID")
+    ),
+    responses(
+        (status = 200, description = "Job requeued", body = ApiResponse<ReportJobDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required"),
+        (status = 404, description = "Report job not found"),
+        (status = 409, description = "Job is not failed or cancelled")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn requeue_report_job(
+    RequireSuperAdmin(user): RequireSuperAdmin,
+    State(state): State<ReportJobState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ReportJobDto>>> {
+    let job = state.job_service.requeue(id, &user.sub).await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(job.into()),
+        Some("Report job requeued".to_string()),
+        None,
+    )))
+}
+
+/// Put all failed jobs back in the queue (super admin)
+#[utoipa::path(
+    post,
+    path = "/api/admin/report-jobs/requeue-failed",
+    responses(
+        (status = 200, description = "Failed jobs requeued", body = ApiResponse<RequeueReportJobsResultDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn requeue_failed_report_jobs(
+    RequireSuperAdmin(user): RequireSuperAdmin,
+    State(state): State<ReportJobState>,
+) -> Result<Json<ApiResponse<RequeueReportJobsResultDto>>> {
+    let requeued = state.job_service.requeue_all_failed(&user.sub).await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(RequeueReportJobsResultDto { requeued }),
+        Some(format!("{} failed report jobs requeued", requeued)),
+        None,
+    )))
+}
+
+/// Cancel a submitted or failed job (super admin)
+#[utoipa::path(
+    post,
+    path = "/api/admin/report-jobs/{id}/cancel",
+    params(
+        ("id" = Uuid, Path, description = "Report job ID")
+    ),
+    request_body = CancelReportJobDto,
+    responses(
+        (status = 200, description = "Job cancelled", body = ApiResponse<ReportJobDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required"),
+        (status = 404, description = "Report job not found"),
+        (status = 409, description = "Job is processing or already finished")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn cancel_report_job(
+    RequireSuperAdmin(user): RequireSuperAdmin,
+    State(state): State<ReportJobState>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<CancelReportJobDto>,
+) -> Result<Json<ApiResponse<ReportJobDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let reason = dto
+        .reason
+        .as_deref()
+        .map(str::trim)
+        .filter(|r| !r.is_empty());
+    let job = state.job_service.cancel(id, &user.sub, reason).await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(job.into()),
+        Some("Report job cancelled".to_string()),
+        None,
+    )))
+}
+
+/// Run a completed job again from the extract stage (super admin)
+///
+/// Draft and pending reports are processed again in full. Verified, in-progress and resolved
+/// reports keep their content and status; only categories, tags and location are refreshed.
+#[utoipa::path(
+    post,
+    path = "/api/admin/report-jobs/{id}/re-extract",
+    params(
+        ("id" = Uuid, Path, description = "Report job ID")
+    ),
+    responses(
+        (status = 200, description = "Job queued for re-extraction", body = ApiResponse<ReportJobDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required"),
+        (status = 404, description = "Report job not found"),
+        (status = 409, description = "Job not completed, report rejected, or another job active")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn reextract_report_job(
+    RequireSuperAdmin(user): RequireSuperAdmin,
+    State(state): State<ReportJobState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ReportJobDto>>> {
+    let job = state.job_service.force_reextract(id, &user.sub).await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(job.into()),
+        Some("Report job queued for re-extraction".to_string()),
+        None,
+    )))
+}
@@ -1,3 +1,36 @@
+pub mod assignment_handler;
+pub mod duplicate_handler;
+pub mod endorsement_handler;
+pub mod identity_handler;
+pub mod job_handler;
 pub mod report_handler;
+pub mod review_handler;
+pub mod revision_handler;
+pub mod update_handler;
 
-pub use report_handler::{get_report, list_reports, update_report_status, ReportState};
+pub use assignment_handler::{
+    assign_report, claim_report, list_assigned_reports, list_report_assignments, reassign_report,
+    release_report,
+};
+pub use duplicate_handler::{
+    confirm_report_duplicate, dismiss_report_duplicate, list_duplicates_for_report,
+    list_report_duplicates, merge_report_duplicate,
+};
+pub use endorsement_handler::{endorse_report, list_report_endorsements, withdraw_endorsement};
+pub use identity_handler::{list_identity_reveals, reveal_report_identity, ReportIdentityState};
+pub use job_handler::{
+    cancel_report_job, get_report_job, get_report_job_metrics, list_report_jobs,
+    reextract_report_job, requeue_failed_report_jobs, requeue_report_job, ReportJobState,
+};
+pub use report_handler::{
+    get_report, get_report_status_history, list_reports, update_report_status, ReportState,
+};
+pub use review_handler::{
+    approve_report_review, get_report_review, list_report_reviews, reject_report_review,
+    request_report_info,
+};
+pub use revision_handler::{
+    list_report_revisions, update_report_categories, update_report_content, update_report_location,
+    update_report_tags,
+};
+pub use update_handler::{create_report_update, delete_report_update, list_report_updates};
@@ -5,19 +5,26 @@ use axum::{
     Json,
 };
 
-use crate::core::error::Result;
+use validator::Validate;
+
+use crate::core::error::{AppError, Result};
 use crate::core::extractor::AppJson;
 use crate::features::auth::model::AuthenticatedUser;
 use crate::features::reports::dtos::{
-    ReportDetailResponseDto, ReportLocationResponseDto, ReportResponseDto, UpdateReportStatusDto,
+    ReportDetailResponseDto, ReportLocationResponseDto, ReportResponseDto, ReportStatusHistoryDto,
+    UpdateReportStatusDto,
+};
+use crate::features::reports::services::{
+    ReportAssignmentService, ReportService, ReportUpdateService,
 };
-use crate::features::reports::services::ReportService;
-use crate::shared::types::ApiResponse;
+use crate::shared::types::{ApiResponse, Meta};
 
 /// State for report handlers
 #[derive(Clone)]
 pub struct ReportState {
     pub report_service: Arc<ReportService>,
+    pub assignment_service: Arc<ReportAssignmentService>,
+    pub update_service: Arc<ReportUpdateService>,
 }
 
 /// List reports for the authenticated user
@@ -40,7 +47,7 @@ pub async fn list_reports(
     Ok(Json(ApiResponse::success(Some(dtos), None, None)))
 }
 
-/// Get report by ID with location
+/// Get report by ID with location and progress updates
 #[utoipa::path(
     get,
     path = "/api/reports/{id}",
@@ -65,22 +72,25 @@ pub async fn get_report(
     // Verify ownership by checking the ticket belongs to the user
     let reports = state.report_service.list_by_user(&user.sub).await?;
     if !reports.iter().any(|r| r.id == id) {
-        return Err(crate::core::error::AppError::NotFound(format!(
-            "Report {} not found",
-            id
-        )));
+        return Err(AppError::NotFound(format!("Report {} not found", id)));
     }
 
     let location = state.report_service.get_location(id).await?;
+    let updates = state.update_service.list_for_report(id).await?;
     let dto = ReportDetailResponseDto {
         report: report.into(),
         location: location.map(ReportLocationResponseDto::from),
+        updates,
     };
 
     Ok(Json(ApiResponse::success(Some(dto), None, None)))
 }
 
-/// Update report status (admin only)
+/// Update report status (officials and admins only)
+///
+/// Transitions are validated against the report lifecycle; an illegal
+/// transition, or verifying a report that is still being processed, returns 409 Conflict.
+/// Reopening a rejected report queues it for processing again.
 #[utoipa::path(
     patch,
     path = "/api/reports/{id}/status",
@@ -90,8 +100,11 @@ pub async fn get_report(
     request_body = UpdateReportStatusDto,
     responses(
         (status = 200, description = "Status updated", body = ApiResponse<ReportResponseDto>),
+        (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized"),
-        (status = 404, description = "Report not found")
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Report not found"),
+        (status = 409, description = "Illegal status transition or report still being processed")
     ),
     security(("bearer_auth" = [])),
     tag = "reports"
@@ -102,10 +115,58 @@ pub async fn update_report_status(
     Path(id): Path<uuid::Uuid>,
     AppJson(dto): AppJson<UpdateReportStatusDto>,
 ) -> Result<Json<ApiResponse<ReportResponseDto>>> {
-    // TODO: Add admin role check
+    if !user.has_official_access() {
+        return Err(AppError::Forbidden(
+            "Official or admin access required".to_string(),
+        ));
+    }
+
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
     let report = state
         .report_service
-        .update_status(id, &dto, &user.sub)
+        .update_status(id, &dto, &user.sub, user.primary_role())
         .await?;
     Ok(Json(ApiResponse::success(Some(report.into()), None, None)))
 }
+
+/// Get the status timeline of a report
+///
+/// Available to the report owner and to officials/admins.
+#[utoipa::path(
+    get,
+    path = "/api/reports/{id}/status-history",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    responses(
+        (status = 200, description = "Status timeline (oldest first)", body = ApiResponse<Vec<ReportStatusHistoryDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Report not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "reports"
+)]
+pub async fn get_report_status_history(
+    user: AuthenticatedUser,
+    State(state): State<ReportState>,
+    Path(id): Path<uuid::Uuid>,
+) -> Result<Json<ApiResponse<Vec<ReportStatusHistoryDto>>>> {
+    let report = state.report_service.get_by_id(id).await?;
+
+    let is_owner = report.user_id.as_deref() == Some(user.sub.as_str());
+    if !is_owner && !user.has_official_access() {
+        return Err(AppError::NotFound(format!("Report {} not found", id)));
+    }
+
+    let history = state.report_service.get_status_history(id).await?;
+    let dtos: Vec<ReportStatusHistoryDto> = history.into_iter().map(Into::into).collect();
+    let total = dtos.len() as i64;
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
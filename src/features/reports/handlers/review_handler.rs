@@ -0,0 +1,205 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::core::error::{AppError, Result};
+use crate::core::extractor::AppJson;
+use crate::features::auth::guards::RequireAdminCurator;
+use crate::features::reports::dtos::{
+    ApproveReportReviewDto, RejectReportReviewDto, ReportReviewDto, ReportReviewQueryParams,
+    RequestReportInfoDto,
+};
+use crate::features::reports::models::ReportReview;
+use crate::features::reports::services::ReportReviewService;
+use crate::shared::types::{ApiResponse, Meta};
+
+/// Attach the conversation excerpt to a review queue entry
+async fn to_dto(service: &ReportReviewService, review: ReportReview) -> ReportReviewDto {
+    let excerpt = service.excerpt(&review).await;
+    ReportReviewDto::new(review, excerpt)
+}
+
+// =============================================================================
+// REVIEW QUEUE HANDLERS
+// =============================================================================
+
+/// List low-confidence submissions waiting for review, oldest first (admin curator)
+#[utoipa::path(
+    get,
+    path = "/api/admin/report-reviews",
+    params(ReportReviewQueryParams),
+    responses(
+        (status = 200, description = "Review queue entries with conversation excerpts", body = ApiResponse<Vec<ReportReviewDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_report_reviews(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<ReportReviewService>>,
+    Query(params): Query<ReportReviewQueryParams>,
+) -> Result<Json<ApiResponse<Vec<ReportReviewDto>>>> {
+    let (items, total) = service
+        .list(params.status, params.limit(), params.offset())
+        .await?;
+
+    let mut dtos = Vec::with_capacity(items.len());
+    for review in items {
+        dtos.push(to_dto(&service, review).await);
+    }
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Get a review queue entry (admin curator)
+#[utoipa::path(
+    get,
+    path = "/api/admin/report-reviews/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Report review ID")
+    ),
+    responses(
+        (status = 200, description = "Review queue entry", body = ApiResponse<ReportReviewDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Report review not found")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_report_review(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<ReportReviewService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ReportReviewDto>>> {
+    let review = service.get(id).await?;
+    let dto = to_dto(&service, review).await;
+    Ok(Json(ApiResponse::success(Some(dto), None, None)))
+}
+
+/// Approve a submission and queue it for extraction (admin curator)
+#[utoipa::path(
+    post,
+    path = "/api/admin/report-reviews/{id}/approve",
+    params(
+        ("id" = Uuid, Path, description = "Report review ID")
+    ),
+    request_body = ApproveReportReviewDto,
+    responses(
+        (status = 200, description = "Submission approved", body = ApiResponse<ReportReviewDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Report review not found"),
+        (status = 409, description = "Review already closed or report no longer pending")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn approve_report_review(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ReportReviewService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<ApproveReportReviewDto>,
+) -> Result<Json<ApiResponse<ReportReviewDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let review = service.approve(id, &user.sub, dto.note.as_deref()).await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(to_dto(&service, review).await),
+        Some("Submission approved for processing".to_string()),
+        None,
+    )))
+}
+
+/// Ask the citizen for more information (admin curator)
+///
+/// The citizen gets an in-app notification; the entry stays in the queue.
+#[utoipa::path(
+    post,
+    path = "/api/admin/report-reviews/{id}/request-info",
+    params(
+        ("id" = Uuid, Path, description = "Report review ID")
+    ),
+    request_body = RequestReportInfoDto,
+    responses(
+        (status = 200, description = "Information requested", body = ApiResponse<ReportReviewDto>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Report review not found"),
+        (status = 409, description = "Review already closed")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn request_report_info(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ReportReviewService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<RequestReportInfoDto>,
+) -> Result<Json<ApiResponse<ReportReviewDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let review = service
+        .request_info(id, &user.sub, dto.message.trim())
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(to_dto(&service, review).await),
+        Some("Information requested from citizen".to_string()),
+        None,
+    )))
+}
+
+/// Reject a submission with a reason (admin curator)
+#[utoipa::path(
+    post,
+    path = "/api/admin/report-reviews/{id}/reject",
+    params(
+        ("id" = Uuid, Path, description = "Report review ID")
+    ),
+    request_body = RejectReportReviewDto,
+    responses(
+        (status = 200, description = "Submission rejected", body = ApiResponse<ReportReviewDto>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Report review not found"),
+        (status = 409, description = "Review already closed or report cannot be rejected")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn reject_report_review(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ReportReviewService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<RejectReportReviewDto>,
+) -> Result<Json<ApiResponse<ReportReviewDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let review = service
+        .reject(id, &user.sub, user.primary_role(), dto.reason.trim())
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(to_dto(&service, review).await),
+        Some("Submission rejected".to_string()),
+        None,
+    )))
+}
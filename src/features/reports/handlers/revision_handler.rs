@@ -0,0 +1,208 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::core::error::{AppError, Result};
+use crate::core::extractor::AppJson;
+use crate::features::auth::guards::RequireAdminCurator;
+use crate::features::reports::dtos::{
+    ReportRevisionDto, UpdateReportCategoriesDto, UpdateReportContentDto, UpdateReportLocationDto,
+    UpdateReportTagsDto,
+};
+use crate::features::reports::services::ReportRevisionService;
+use crate::shared::types::{ApiResponse, Meta};
+
+/// List the revisions of a report, oldest first (admin curator)
+///
+/// Shows how the report evolved from the raw extraction to its curated form.
+#[utoipa::path(
+    get,
+    path = "/api/admin/reports/{id}/revisions",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    responses(
+        (status = 200, description = "Report revisions", body = ApiResponse<Vec<ReportRevisionDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Report not found")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_report_revisions(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<ReportRevisionService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ReportRevisionDto>>>> {
+    let revisions = service.list(id).await?;
+    let total = revisions.len() as i64;
+    let dtos = revisions.into_iter().map(ReportRevisionDto::from).collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Correct the content of a report (admin curator)
+///
+/// Omitted fields are left unchanged. The public redacted copy is regenerated.
+#[utoipa::path(
+    patch,
+    path = "/api/admin/reports/{id}/content",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    request_body = UpdateReportContentDto,
+    responses(
+        (status = 200, description = "Content updated", body = ApiResponse<ReportRevisionDto>),
+        (status = 400, description = "Validation error or nothing changed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Report not found")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_report_content(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ReportRevisionService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<UpdateReportContentDto>,
+) -> Result<Json<ApiResponse<ReportRevisionDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let revision = service
+        .update_content(id, &dto, &user.sub, user.primary_role())
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(revision.into()),
+        Some("Report content updated".to_string()),
+        None,
+    )))
+}
+
+/// Replace the categories of a report (admin curator)
+///
+/// SLA deadlines of the report are recomputed.
+#[utoipa::path(
+    put,
+    path = "/api/admin/reports/{id}/categories",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    request_body = UpdateReportCategoriesDto,
+    responses(
+        (status = 200, description = "Categories updated", body = ApiResponse<ReportRevisionDto>),
+        (status = 400, description = "Validation error, unknown category or nothing changed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Report not found")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_report_categories(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ReportRevisionService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<UpdateReportCategoriesDto>,
+) -> Result<Json<ApiResponse<ReportRevisionDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let revision = service
+        .update_categories(id, &dto, &user.sub, user.primary_role())
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(revision.into()),
+        Some("Report categories updated".to_string()),
+        None,
+    )))
+}
+
+/// Replace the tags of a report (admin curator)
+#[utoipa::path(
+    put,
+    path = "/api/admin/reports/{id}/tags",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    request_body = UpdateReportTagsDto,
+    responses(
+        (status = 200, description = "Tags updated", body = ApiResponse<ReportRevisionDto>),
+        (status = 400, description = "Validation error or nothing changed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Report not found")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_report_tags(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ReportRevisionService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<UpdateReportTagsDto>,
+) -> Result<Json<ApiResponse<ReportRevisionDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let revision = service
+        .update_tags(id, &dto, &user.sub, user.primary_role())
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(revision.into()),
+        Some("Report tags updated".to_string()),
+        None,
+    )))
+}
+
+/// Replace the location of a report with a manual location (admin curator)
+#[utoipa::path(
+    put,
+    path = "/api/admin/reports/{id}/location",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    request_body = UpdateReportLocationDto,
+    responses(
+        (status = 200, description = "Location updated", body = ApiResponse<ReportRevisionDto>),
+        (status = 400, description = "Validation error, inconsistent regions or nothing changed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Admin curator access required"),
+        (status = 404, description = "Report not found")
+    ),
+    tag = "admin",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_report_location(
+    RequireAdminCurator(user): RequireAdminCurator,
+    State(service): State<Arc<ReportRevisionService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<UpdateReportLocationDto>,
+) -> Result<Json<ApiResponse<ReportRevisionDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let revision = service
+        .update_location(id, &dto, &user.sub, user.primary_role())
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(revision.into()),
+        Some("Report location updated".to_string()),
+        None,
+    )))
+}
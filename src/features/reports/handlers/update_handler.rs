@@ -0,0 +1,135 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::core::error::{AppError, Result};
+use crate::core::extractor::AppJson;
+use crate::features::auth::guards::RequireOfficial;
+use crate::features::auth::model::AuthenticatedUser;
+use crate::features::reports::dtos::{CreateReportUpdateDto, ReportUpdateDto};
+use crate::features::reports::handlers::ReportState;
+use crate::features::reports::models::CreateReportUpdate;
+use crate::shared::types::{ApiResponse, Meta};
+
+/// Post a public progress update on a report (officials and admins only)
+///
+/// Attachments are referenced by file ID and must be public files uploaded
+/// by the caller through the files API.
+#[utoipa::path(
+    post,
+    path = "/api/reports/{id}/updates",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    request_body = CreateReportUpdateDto,
+    responses(
+        (status = 200, description = "Update posted", body = ApiResponse<ReportUpdateDto>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Official access required"),
+        (status = 404, description = "Report not found"),
+        (status = 409, description = "File already attached to this report")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "reports"
+)]
+pub async fn create_report_update(
+    RequireOfficial(user): RequireOfficial,
+    State(state): State<ReportState>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<CreateReportUpdateDto>,
+) -> Result<Json<ApiResponse<ReportUpdateDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let update = state
+        .update_service
+        .create(CreateReportUpdate {
+            report_id: id,
+            author_id: user.sub.clone(),
+            author_role: user.primary_role().to_string(),
+            message: dto.message,
+            file_ids: dto.file_ids,
+        })
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(update),
+        Some("Update posted".to_string()),
+        None,
+    )))
+}
+
+/// List public progress updates of a report (owner or officials)
+#[utoipa::path(
+    get,
+    path = "/api/reports/{id}/updates",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    responses(
+        (status = 200, description = "Report updates (oldest first)", body = ApiResponse<Vec<ReportUpdateDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Report not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "reports"
+)]
+pub async fn list_report_updates(
+    user: AuthenticatedUser,
+    State(state): State<ReportState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ReportUpdateDto>>>> {
+    let report = state.report_service.get_by_id(id).await?;
+
+    let is_owner = report.user_id.as_deref() == Some(user.sub.as_str());
+    if !is_owner && !user.has_official_access() {
+        return Err(AppError::NotFound(format!("Report {} not found", id)));
+    }
+
+    let updates = state.update_service.list_for_report(id).await?;
+    let total = updates.len() as i64;
+
+    Ok(Json(ApiResponse::success(
+        Some(updates),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Delete a progress update (author or admin only)
+#[utoipa::path(
+    delete,
+    path = "/api/reports/{id}/updates/{update_id}",
+    params(
+        ("id" = Uuid, Path, description = "Report ID"),
+        ("update_id" = Uuid, Path, description = "Update ID")
+    ),
+    responses(
+        (status = 200, description = "Update deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - not the author"),
+        (status = 404, description = "Update not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "reports"
+)]
+pub async fn delete_report_update(
+    RequireOfficial(user): RequireOfficial,
+    State(state): State<ReportState>,
+    Path((id, update_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<()>>> {
+    state
+        .update_service
+        .delete(id, update_id, &user.sub, user.has_admin_access())
+        .await?;
+
+    Ok(Json(ApiResponse::success(
+        None,
+        Some("Update deleted".to_string()),
+        None,
+    )))
+}
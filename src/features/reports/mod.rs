@@ -6,6 +6,8 @@ pub mod services;
 pub mod workers;
 
 pub use services::{
-    ExtractionService, GeocodingService, RegionLookupService, ReportJobService, ReportService,
+    ExtractionService, GeocodingService, RegionLookupService, ReportAssignmentService,
+    ReportDuplicateService, ReportEndorsementService, ReportIdentityService, ReportJobService,
+    ReportReviewService, ReportRevisionService, ReportService, ReportUpdateService,
 };
 pub use workers::ReportProcessor;
@@ -1,13 +1,37 @@
 mod report;
+mod report_assignment;
 mod report_attachment;
 mod report_category;
+mod report_duplicate;
+mod report_endorsement;
+mod report_identity_reveal;
 mod report_job;
 mod report_location;
+mod report_redaction;
+mod report_review;
+mod report_revision;
+mod report_status_history;
 mod report_tag;
+mod report_translation;
+mod report_update;
 
 pub use report::{CreateReportSubmission, Report, ReportSeverity, ReportStatus};
+pub use report_assignment::{CreateReportAssignment, ReportAssignment, ReportAssignmentType};
 pub use report_attachment::{CreateReportAttachment, ReportAttachment};
 pub use report_category::{CreateReportCategory, ReportCategory};
-pub use report_job::{CreateReportJob, ReportJob, ReportJobStatus};
+pub use report_duplicate::{ReportDuplicate, ReportDuplicateStatus};
+pub use report_endorsement::ReportEndorsement;
+pub use report_identity_reveal::ReportIdentityReveal;
+pub use report_job::{
+    CreateReportJob, ReportJob, ReportJobError, ReportJobMetrics, ReportJobStage, ReportJobStatus,
+};
 pub use report_location::{CreateReportLocation, GeocodingSource, ReportLocation};
-pub use report_tag::{CreateReportTag, ReportTag, ReportTagType};
+pub use report_redaction::PiiKind;
+pub use report_review::{ReportReview, ReportReviewStatus};
+pub use report_revision::{
+    CreateReportRevision, ReportRevision, ReportRevisionSection, ReportRevisionSource,
+};
+pub use report_status_history::ReportStatusHistory;
+pub use report_tag::{ReportTag, ReportTagType};
+pub use report_translation::ReportLanguage;
+pub use report_update::{CreateReportUpdate, ReportUpdate, ReportUpdateAttachment};
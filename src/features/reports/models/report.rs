@@ -31,6 +31,40 @@ impl std::fmt::Display for ReportStatus {
     }
 }
 
+impl ReportStatus {
+    /// Statuses a report may move to from this status.
+    ///
+    /// Lifecycle: pending -> draft (processed) -> verified -> in_progress -> resolved,
+    /// with reject paths from the open states and reopen paths from the terminal ones.
+    pub fn allowed_transitions(&self) -> &'static [ReportStatus] {
+        match self {
+            ReportStatus::Draft => &[
+                ReportStatus::Pending,
+                ReportStatus::Verified,
+                ReportStatus::Rejected,
+            ],
+            // Only processed (draft) reports can be verified
+            ReportStatus::Pending => &[ReportStatus::Draft, ReportStatus::Rejected],
+            ReportStatus::Verified => &[ReportStatus::InProgress, ReportStatus::Rejected],
+            ReportStatus::InProgress => &[ReportStatus::Resolved, ReportStatus::Rejected],
+            // Reopen paths
+            ReportStatus::Resolved => &[ReportStatus::InProgress],
+            ReportStatus::Rejected => &[ReportStatus::Pending],
+        }
+    }
+
+    /// Check whether moving from this status to `next` is a legal transition
+    pub fn can_transition_to(&self, next: ReportStatus) -> bool {
+        self.allowed_transitions().contains(&next)
+    }
+
+    /// Whether moving from this status to `next` sends the report through processing again
+    /// (reopening a rejected report)
+    pub fn reprocesses_on(&self, next: ReportStatus) -> bool {
+        *self == ReportStatus::Rejected && next == ReportStatus::Pending
+    }
+}
+
 /// Report severity enum matching database enum
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema, JsonSchema, Default,
@@ -78,6 +112,8 @@ pub struct Report {
     pub adk_thread_id: Option<Uuid>,
     pub user_id: Option<String>,
     pub platform: Option<String>,
+    /// Reporter identity and conversation are hidden outside the break-glass endpoint
+    pub is_anonymous: bool,
 }
 
 /// Data for creating a report submission (from agent - new workflow)
@@ -87,4 +123,48 @@ pub struct CreateReportSubmission {
     pub adk_thread_id: Uuid,
     pub user_id: String,
     pub platform: Option<String>,
+    pub is_anonymous: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forward_lifecycle_is_allowed() {
+        assert!(ReportStatus::Pending.can_transition_to(ReportStatus::Draft));
+        assert!(ReportStatus::Draft.can_transition_to(ReportStatus::Verified));
+        assert!(ReportStatus::Verified.can_transition_to(ReportStatus::InProgress));
+        assert!(ReportStatus::InProgress.can_transition_to(ReportStatus::Resolved));
+    }
+
+    #[test]
+    fn test_reject_and_reopen_paths() {
+        assert!(ReportStatus::Pending.can_transition_to(ReportStatus::Rejected));
+        assert!(ReportStatus::Verified.can_transition_to(ReportStatus::Rejected));
+        assert!(ReportStatus::Rejected.can_transition_to(ReportStatus::Pending));
+        assert!(ReportStatus::Resolved.can_transition_to(ReportStatus::InProgress));
+    }
+
+    #[test]
+    fn test_only_reopening_a_rejected_report_reprocesses_it() {
+        assert!(ReportStatus::Rejected.reprocesses_on(ReportStatus::Pending));
+        assert!(!ReportStatus::Draft.reprocesses_on(ReportStatus::Pending));
+        assert!(!ReportStatus::Resolved.reprocesses_on(ReportStatus::InProgress));
+        assert!(!ReportStatus::Pending.reprocesses_on(ReportStatus::Rejected));
+    }
+
+    #[test]
+    fn test_pending_report_cannot_be_verified_before_processing() {
+        assert!(!ReportStatus::Pending.can_transition_to(ReportStatus::Verified));
+        assert!(ReportStatus::Draft.can_transition_to(ReportStatus::Verified));
+    }
+
+    #[test]
+    fn test_illegal_transitions_are_rejected() {
+        assert!(!ReportStatus::Rejected.can_transition_to(ReportStatus::Resolved));
+        assert!(!ReportStatus::Draft.can_transition_to(ReportStatus::Resolved));
+        assert!(!ReportStatus::Pending.can_transition_to(ReportStatus::InProgress));
+        assert!(!ReportStatus::Resolved.can_transition_to(ReportStatus::Resolved));
+    }
 }
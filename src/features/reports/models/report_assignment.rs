@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// How an assignment was created, matching database enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "report_assignment_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportAssignmentType {
+    Claim,
+    Assign,
+    Reassign,
+}
+
+impl std::fmt::Display for ReportAssignmentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportAssignmentType::Claim => write!(f, "claim"),
+            ReportAssignmentType::Assign => write!(f, "assign"),
+            ReportAssignmentType::Reassign => write!(f, "reassign"),
+        }
+    }
+}
+
+/// Database model for a report assignment (official ownership)
+#[derive(Debug, Clone, FromRow)]
+#[allow(dead_code)]
+pub struct ReportAssignment {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub assignee_id: String,
+    pub assigned_by: String,
+    pub assignment_type: ReportAssignmentType,
+    pub note: Option<String>,
+    pub assigned_at: DateTime<Utc>,
+    pub released_at: Option<DateTime<Utc>>,
+    pub released_by: Option<String>,
+    pub release_reason: Option<String>,
+}
+
+/// Data for creating a new report assignment
+#[derive(Debug)]
+pub struct CreateReportAssignment {
+    pub report_id: Uuid,
+    pub assignee_id: String,
+    pub assigned_by: String,
+    pub assignment_type: ReportAssignmentType,
+    pub note: Option<String>,
+}
@@ -18,7 +18,6 @@ pub struct ReportCategory {
 /// Data for creating a new report category assignment
 #[derive(Debug, Clone)]
 pub struct CreateReportCategory {
-    pub report_id: Uuid,
     pub category_id: Uuid,
     pub severity: ReportSeverity,
 }
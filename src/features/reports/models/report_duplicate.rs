@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::ReportStatus;
+
+/// Review status of a duplicate candidate, matching database enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "report_duplicate_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportDuplicateStatus {
+    Pending,
+    Confirmed,
+    Dismissed,
+    Merged,
+}
+
+impl std::fmt::Display for ReportDuplicateStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportDuplicateStatus::Pending => write!(f, "pending"),
+            ReportDuplicateStatus::Confirmed => write!(f, "confirmed"),
+            ReportDuplicateStatus::Dismissed => write!(f, "dismissed"),
+            ReportDuplicateStatus::Merged => write!(f, "merged"),
+        }
+    }
+}
+
+impl ReportDuplicateStatus {
+    /// Whether an admin can still confirm, dismiss or merge the candidate
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self,
+            ReportDuplicateStatus::Pending | ReportDuplicateStatus::Confirmed
+        )
+    }
+}
+
+/// Database model for a duplicate candidate, with both reports' summaries
+#[derive(Debug, Clone, FromRow)]
+pub struct ReportDuplicate {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub report_reference: Option<String>,
+    pub report_title: Option<String>,
+    pub report_status: ReportStatus,
+    pub duplicate_of_id: Uuid,
+    pub duplicate_of_reference: Option<String>,
+    pub duplicate_of_title: Option<String>,
+    pub duplicate_of_status: ReportStatus,
+    pub score: f64,
+    pub text_similarity: f64,
+    pub distance_meters: Option<f64>,
+    pub status: ReportDuplicateStatus,
+    pub detected_at: DateTime<Utc>,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+}
@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for a citizen endorsement ("me too") of a report
+#[derive(Debug, Clone, FromRow)]
+pub struct ReportEndorsement {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub user_id: String,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
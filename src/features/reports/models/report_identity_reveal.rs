@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for an audited reveal of an anonymous reporter (break-glass)
+#[derive(Debug, Clone, FromRow)]
+pub struct ReportIdentityReveal {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub revealed_by: String,
+    pub reason: String,
+    pub created_at: DateTime<Utc>,
+}
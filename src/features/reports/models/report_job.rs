@@ -14,6 +14,8 @@ pub enum ReportJobStatus {
     Processing,
     Completed,
     Failed,
+    /// Cancelled by an admin; never claimed until requeued
+    Cancelled,
 }
 
 impl std::fmt::Display for ReportJobStatus {
@@ -23,6 +25,68 @@ impl std::fmt::Display for ReportJobStatus {
             ReportJobStatus::Processing => write!(f, "processing"),
             ReportJobStatus::Completed => write!(f, "completed"),
             ReportJobStatus::Failed => write!(f, "failed"),
+            ReportJobStatus::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+/// Report processing pipeline stage, matching database enum
+///
+/// A job stores the next stage to run; completed stages are not repeated on retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "report_job_stage", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportJobStage {
+    Extract,
+    Content,
+    Redact,
+    /// Held here while the report waits in the moderation queue
+    Moderate,
+    Classify,
+    Locate,
+    Attachments,
+    Finalize,
+}
+
+impl std::fmt::Display for ReportJobStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportJobStage::Extract => write!(f, "extract"),
+            ReportJobStage::Content => write!(f, "content"),
+            ReportJobStage::Redact => write!(f, "redact"),
+            ReportJobStage::Moderate => write!(f, "moderate"),
+            ReportJobStage::Classify => write!(f, "classify"),
+            ReportJobStage::Locate => write!(f, "locate"),
+            ReportJobStage::Attachments => write!(f, "attachments"),
+            ReportJobStage::Finalize => write!(f, "finalize"),
+        }
+    }
+}
+
+impl ReportJobStage {
+    /// Stage that runs after this one (None after the last stage)
+    pub fn next(&self) -> Option<ReportJobStage> {
+        match self {
+            ReportJobStage::Extract => Some(ReportJobStage::Content),
+            ReportJobStage::Content => Some(ReportJobStage::Redact),
+            ReportJobStage::Redact => Some(ReportJobStage::Moderate),
+            ReportJobStage::Moderate => Some(ReportJobStage::Classify),
+            ReportJobStage::Classify => Some(ReportJobStage::Locate),
+            ReportJobStage::Locate => Some(ReportJobStage::Attachments),
+            ReportJobStage::Attachments => Some(ReportJobStage::Finalize),
+            ReportJobStage::Finalize => None,
+        }
+    }
+
+    /// Stage that runs after this one when re-extracting a verified report
+    ///
+    /// Content, status and routing of a verified report are kept; only its categories,
+    /// tags and location are refreshed from the new extraction.
+    pub fn next_reextract(&self) -> Option<ReportJobStage> {
+        match self {
+            ReportJobStage::Extract => Some(ReportJobStage::Classify),
+            ReportJobStage::Classify => Some(ReportJobStage::Locate),
+            _ => None,
         }
     }
 }
@@ -41,6 +105,43 @@ pub struct ReportJob {
     pub processed_at: Option<DateTime<Utc>>,
     pub last_attempt_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
+    pub locked_by: Option<String>,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub heartbeat_at: Option<DateTime<Utc>>,
+    pub stage: ReportJobStage,
+    /// Checkpointed extraction result (ExtractedReportData)
+    pub extracted_data: Option<serde_json::Value>,
+    pub next_attempt_at: DateTime<Utc>,
+}
+
+/// Failed attempt of a report job
+#[derive(Debug, Clone, FromRow)]
+pub struct ReportJobError {
+    pub id: Uuid,
+    pub job_id: Uuid,
+    /// Stage that failed
+    pub stage: ReportJobStage,
+    /// Attempt number (1-based)
+    pub attempt: i32,
+    pub error: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Queue depth and age metrics of report jobs
+#[derive(Debug, Clone, FromRow)]
+pub struct ReportJobMetrics {
+    /// Submitted jobs that are due now
+    pub due: i64,
+    /// Submitted jobs waiting for their retry backoff
+    pub scheduled: i64,
+    pub processing: i64,
+    /// Processing jobs whose lease has expired (worker stopped responding)
+    pub expired_leases: i64,
+    pub completed: i64,
+    pub failed: i64,
+    pub cancelled: i64,
+    /// Submission time of the oldest job that is not finished yet
+    pub oldest_pending_at: Option<DateTime<Utc>>,
 }
 
 /// Data for creating a new report job
@@ -49,3 +150,47 @@ pub struct CreateReportJob {
     pub report_id: Uuid,
     pub confidence_score: Option<f64>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stages_run_in_order() {
+        let mut stages = vec![ReportJobStage::Extract];
+        while let Some(next) = stages.last().and_then(|s| s.next()) {
+            stages.push(next);
+        }
+
+        assert_eq!(
+            stages,
+            vec![
+                ReportJobStage::Extract,
+                ReportJobStage::Content,
+                ReportJobStage::Redact,
+                ReportJobStage::Moderate,
+                ReportJobStage::Classify,
+                ReportJobStage::Locate,
+                ReportJobStage::Attachments,
+                ReportJobStage::Finalize,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reextract_stages_skip_content_and_finalize() {
+        let mut stages = vec![ReportJobStage::Extract];
+        while let Some(next) = stages.last().and_then(|s| s.next_reextract()) {
+            stages.push(next);
+        }
+
+        assert_eq!(
+            stages,
+            vec![
+                ReportJobStage::Extract,
+                ReportJobStage::Classify,
+                ReportJobStage::Locate,
+            ]
+        );
+    }
+}
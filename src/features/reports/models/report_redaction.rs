@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// Kind of personal data detected in report content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiKind {
+    /// 16-digit national identity number (NIK)
+    Nik,
+    /// Mobile phone number (+62 / 08 prefix)
+    Phone,
+    Email,
+    /// Vehicle registration plate
+    Plate,
+    /// Person name following an honorific (optional)
+    Name,
+}
+
+impl PiiKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PiiKind::Nik => "nik",
+            PiiKind::Phone => "phone",
+            PiiKind::Email => "email",
+            PiiKind::Plate => "plate",
+            PiiKind::Name => "name",
+        }
+    }
+
+    /// Placeholder that replaces a detected value in public text
+    pub fn placeholder(&self) -> &'static str {
+        match self {
+            PiiKind::Nik => "[NIK]",
+            PiiKind::Phone => "[NOMOR TELEPON]",
+            PiiKind::Email => "[EMAIL]",
+            PiiKind::Plate => "[PLAT NOMOR]",
+            PiiKind::Name => "[NAMA]",
+        }
+    }
+}
+
+impl std::fmt::Display for PiiKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::ReportStatus;
+
+/// Review status of a low-confidence submission, matching database enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "report_review_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportReviewStatus {
+    Pending,
+    InfoRequested,
+    Approved,
+    Rejected,
+}
+
+impl std::fmt::Display for ReportReviewStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportReviewStatus::Pending => write!(f, "pending"),
+            ReportReviewStatus::InfoRequested => write!(f, "info_requested"),
+            ReportReviewStatus::Approved => write!(f, "approved"),
+            ReportReviewStatus::Rejected => write!(f, "rejected"),
+        }
+    }
+}
+
+impl ReportReviewStatus {
+    /// Whether a curator can still approve, reject or ask for more information
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self,
+            ReportReviewStatus::Pending | ReportReviewStatus::InfoRequested
+        )
+    }
+}
+
+/// Database model for a review queue entry, with a summary of its report
+#[derive(Debug, Clone, FromRow)]
+pub struct ReportReview {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub reference_number: Option<String>,
+    pub report_status: ReportStatus,
+    pub user_id: Option<String>,
+    pub adk_thread_id: Option<Uuid>,
+    pub platform: Option<String>,
+    pub is_anonymous: bool,
+    pub confidence_score: Option<f64>,
+    pub confidence_threshold: f64,
+    pub status: ReportReviewStatus,
+    pub info_request: Option<String>,
+    pub info_requested_at: Option<DateTime<Utc>>,
+    pub reviewed_by: Option<String>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub review_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
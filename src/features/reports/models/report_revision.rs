@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, Type};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Origin of a report revision, matching database enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "report_revision_source", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportRevisionSource {
+    /// Written by the report processor from the conversation
+    Extraction,
+    /// Edited by a curator
+    Admin,
+}
+
+impl std::fmt::Display for ReportRevisionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportRevisionSource::Extraction => write!(f, "extraction"),
+            ReportRevisionSource::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+/// Part of a report changed by a revision, matching database enum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, ToSchema)]
+#[sqlx(type_name = "report_revision_section", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportRevisionSection {
+    /// Title, description, timeline, impact and the normalized translation
+    Content,
+    Categories,
+    Tags,
+    Location,
+}
+
+impl std::fmt::Display for ReportRevisionSection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportRevisionSection::Content => write!(f, "content"),
+            ReportRevisionSection::Categories => write!(f, "categories"),
+            ReportRevisionSection::Tags => write!(f, "tags"),
+            ReportRevisionSection::Location => write!(f, "location"),
+        }
+    }
+}
+
+/// Database model for a report revision
+#[derive(Debug, Clone, FromRow)]
+pub struct ReportRevision {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub revision_number: i32,
+    pub source: ReportRevisionSource,
+    pub section: ReportRevisionSection,
+    /// Changed fields as {"field": {"old": ..., "new": ...}}
+    pub changes: serde_json::Value,
+    pub reason: Option<String>,
+    pub edited_by: Option<String>,
+    pub editor_role: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Data for recording a report revision
+#[derive(Debug, Clone)]
+pub struct CreateReportRevision<'a> {
+    pub report_id: Uuid,
+    pub source: ReportRevisionSource,
+    pub section: ReportRevisionSection,
+    pub edited_by: Option<&'a str>,
+    pub editor_role: &'a str,
+    pub reason: Option<&'a str>,
+}
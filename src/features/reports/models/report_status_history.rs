@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::ReportStatus;
+
+/// Database model for a report status transition
+#[derive(Debug, Clone, FromRow)]
+#[allow(dead_code)]
+pub struct ReportStatusHistory {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub from_status: Option<ReportStatus>,
+    pub to_status: ReportStatus,
+    pub changed_by: Option<String>,
+    pub changed_by_role: Option<String>,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
@@ -38,10 +38,3 @@ pub struct ReportTag {
     pub tag_type: ReportTagType,
     pub created_at: DateTime<Utc>,
 }
-
-/// Data for creating a new report tag
-#[derive(Debug, Clone)]
-pub struct CreateReportTag {
-    pub report_id: Uuid,
-    pub tag_type: ReportTagType,
-}
@@ -0,0 +1,40 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use utoipa::ToSchema;
+
+/// Language a report was written in, matching database enum
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type, ToSchema, JsonSchema,
+)]
+#[sqlx(type_name = "report_language", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ReportLanguage {
+    /// Bahasa Indonesia
+    Id,
+    /// Javanese
+    Jv,
+    /// Sundanese
+    Su,
+    /// Minangkabau
+    Min,
+    /// Balinese
+    Ban,
+    /// English
+    En,
+    Other,
+}
+
+impl std::fmt::Display for ReportLanguage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportLanguage::Id => write!(f, "id"),
+            ReportLanguage::Jv => write!(f, "jv"),
+            ReportLanguage::Su => write!(f, "su"),
+            ReportLanguage::Min => write!(f, "min"),
+            ReportLanguage::Ban => write!(f, "ban"),
+            ReportLanguage::En => write!(f, "en"),
+            ReportLanguage::Other => write!(f, "other"),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Database model for a public progress update on a report
+#[derive(Debug, Clone, FromRow)]
+#[allow(dead_code)]
+pub struct ReportUpdate {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub author_id: String,
+    pub author_role: String,
+    pub message: String,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<String>,
+}
+
+/// File attached to a report update
+#[derive(Debug, Clone, FromRow)]
+pub struct ReportUpdateAttachment {
+    pub report_update_id: Uuid,
+    pub file_id: Uuid,
+    pub original_filename: String,
+    pub content_type: String,
+    pub file_size: i64,
+    pub url: String,
+}
+
+/// Data for creating a new report update
+#[derive(Debug)]
+pub struct CreateReportUpdate {
+    pub report_id: Uuid,
+    pub author_id: String,
+    pub author_role: String,
+    pub message: String,
+    pub file_ids: Vec<Uuid>,
+}
@@ -1,23 +1,232 @@
 use std::sync::Arc;
 
-use axum::{routing::get, Router};
+use axum::{
+    routing::{delete, get, patch, post, put},
+    Router,
+};
 
-use crate::features::reports::handlers::{self, ReportState};
-use crate::features::reports::services::ReportService;
+use crate::features::citizen_report_agent::ConversationService;
+use crate::features::reports::handlers::{self, ReportIdentityState, ReportJobState, ReportState};
+use crate::features::reports::services::{
+    ReportAssignmentService, ReportDuplicateService, ReportEndorsementService,
+    ReportIdentityService, ReportJobService, ReportReviewService, ReportRevisionService,
+    ReportService, ReportUpdateService,
+};
 
 /// Create routes for the reports feature
 ///
 /// Protected routes require authentication
-pub fn routes(report_service: Arc<ReportService>) -> Router {
-    let state = ReportState { report_service };
+pub fn routes(
+    report_service: Arc<ReportService>,
+    assignment_service: Arc<ReportAssignmentService>,
+    update_service: Arc<ReportUpdateService>,
+    endorsement_service: Arc<ReportEndorsementService>,
+) -> Router {
+    let endorsement_routes = Router::new()
+        .route(
+            "/api/reports/{id}/endorsements",
+            post(handlers::endorse_report).delete(handlers::withdraw_endorsement),
+        )
+        .with_state(endorsement_service);
+
+    let state = ReportState {
+        report_service,
+        assignment_service,
+        update_service,
+    };
 
     Router::new()
         // Protected routes (require auth middleware to be applied by caller)
         .route("/api/reports", get(handlers::list_reports))
+        .route(
+            "/api/reports/assigned",
+            get(handlers::list_assigned_reports),
+        )
         .route("/api/reports/{id}", get(handlers::get_report))
         .route(
             "/api/reports/{id}/status",
             axum::routing::patch(handlers::update_report_status),
         )
+        .route(
+            "/api/reports/{id}/status-history",
+            get(handlers::get_report_status_history),
+        )
+        // Public progress updates (posting requires official access)
+        .route(
+            "/api/reports/{id}/updates",
+            get(handlers::list_report_updates).post(handlers::create_report_update),
+        )
+        .route(
+            "/api/reports/{id}/updates/{update_id}",
+            delete(handlers::delete_report_update),
+        )
+        // Official ownership (official access required)
+        .route("/api/reports/{id}/claim", post(handlers::claim_report))
+        .route("/api/reports/{id}/release", post(handlers::release_report))
+        .route(
+            "/api/reports/{id}/reassign",
+            post(handlers::reassign_report),
+        )
+        .with_state(state)
+        .merge(endorsement_routes)
+}
+
+/// Create admin routes for report assignment, duplicate review, endorsements,
+/// the low-confidence review queue (admin curator access) and the job console
+/// (super admin access)
+pub fn admin_routes(
+    assignment_service: Arc<ReportAssignmentService>,
+    duplicate_service: Arc<ReportDuplicateService>,
+    endorsement_service: Arc<ReportEndorsementService>,
+    review_service: Arc<ReportReviewService>,
+    job_service: Arc<ReportJobService>,
+    report_service: Arc<ReportService>,
+    conversation_service: Arc<ConversationService>,
+) -> Router {
+    let job_state = ReportJobState {
+        job_service,
+        report_service,
+        conversation_service,
+    };
+
+    let job_routes = Router::new()
+        .route("/api/admin/report-jobs", get(handlers::list_report_jobs))
+        .route(
+            "/api/admin/report-jobs/metrics",
+            get(handlers::get_report_job_metrics),
+        )
+        .route(
+            "/api/admin/report-jobs/requeue-failed",
+            post(handlers::requeue_failed_report_jobs),
+        )
+        .route("/api/admin/report-jobs/{id}", get(handlers::get_report_job))
+        .route(
+            "/api/admin/report-jobs/{id}/requeue",
+            post(handlers::requeue_report_job),
+        )
+        .route(
+            "/api/admin/report-jobs/{id}/cancel",
+            post(handlers::cancel_report_job),
+        )
+        .route(
+            "/api/admin/report-jobs/{id}/re-extract",
+            post(handlers::reextract_report_job),
+        )
+        .with_state(job_state);
+
+    let review_routes = Router::new()
+        .route(
+            "/api/admin/report-reviews",
+            get(handlers::list_report_reviews),
+        )
+        .route(
+            "/api/admin/report-reviews/{id}",
+            get(handlers::get_report_review),
+        )
+        .route(
+            "/api/admin/report-reviews/{id}/approve",
+            post(handlers::approve_report_review),
+        )
+        .route(
+            "/api/admin/report-reviews/{id}/request-info",
+            post(handlers::request_report_info),
+        )
+        .route(
+            "/api/admin/report-reviews/{id}/reject",
+            post(handlers::reject_report_review),
+        )
+        .with_state(review_service);
+
+    let endorsement_routes = Router::new()
+        .route(
+            "/api/admin/reports/{id}/endorsements",
+            get(handlers::list_report_endorsements),
+        )
+        .with_state(endorsement_service);
+
+    let duplicate_routes = Router::new()
+        .route(
+            "/api/admin/report-duplicates",
+            get(handlers::list_report_duplicates),
+        )
+        .route(
+            "/api/admin/report-duplicates/{id}/confirm",
+            post(handlers::confirm_report_duplicate),
+        )
+        .route(
+            "/api/admin/report-duplicates/{id}/dismiss",
+            post(handlers::dismiss_report_duplicate),
+        )
+        .route(
+            "/api/admin/report-duplicates/{id}/merge",
+            post(handlers::merge_report_duplicate),
+        )
+        .route(
+            "/api/admin/reports/{id}/duplicates",
+            get(handlers::list_duplicates_for_report),
+        )
+        .with_state(duplicate_service);
+
+    Router::new()
+        .route(
+            "/api/admin/reports/{id}/assign",
+            post(handlers::assign_report),
+        )
+        .route(
+            "/api/admin/reports/{id}/assignments",
+            get(handlers::list_report_assignments),
+        )
+        .with_state(assignment_service)
+        .merge(duplicate_routes)
+        .merge(endorsement_routes)
+        .merge(review_routes)
+        .merge(job_routes)
+}
+
+/// Create the curator edit routes with revision history (admin curator access)
+pub fn revision_routes(revision_service: Arc<ReportRevisionService>) -> Router {
+    Router::new()
+        .route(
+            "/api/admin/reports/{id}/revisions",
+            get(handlers::list_report_revisions),
+        )
+        .route(
+            "/api/admin/reports/{id}/content",
+            patch(handlers::update_report_content),
+        )
+        .route(
+            "/api/admin/reports/{id}/categories",
+            put(handlers::update_report_categories),
+        )
+        .route(
+            "/api/admin/reports/{id}/tags",
+            put(handlers::update_report_tags),
+        )
+        .route(
+            "/api/admin/reports/{id}/location",
+            put(handlers::update_report_location),
+        )
+        .with_state(revision_service)
+}
+
+/// Create the break-glass routes for anonymous reporters (super admin access, audited)
+pub fn identity_routes(
+    identity_service: Arc<ReportIdentityService>,
+    conversation_service: Arc<ConversationService>,
+) -> Router {
+    let state = ReportIdentityState {
+        identity_service,
+        conversation_service,
+    };
+
+    Router::new()
+        .route(
+            "/api/admin/reports/{id}/identity",
+            post(handlers::reveal_report_identity),
+        )
+        .route(
+            "/api/admin/report-identity-reveals",
+            get(handlers::list_identity_reveals),
+        )
         .with_state(state)
 }
@@ -7,7 +7,7 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::core::error::{AppError, Result};
-use crate::features::reports::models::{ReportSeverity, ReportTagType};
+use crate::features::reports::models::{ReportLanguage, ReportSeverity, ReportTagType};
 use crate::shared::llm::{parse_with_fallback, LlmResponse};
 use crate::shared::prompts::render_extraction_prompt;
 
@@ -39,12 +39,29 @@ pub struct ExtractedCategory {
 #[derive(Debug, Clone, Serialize, Deserialize, Default, JsonSchema)]
 #[schemars(title = "ExtractedReportData")]
 pub struct ExtractedReportData {
-    #[schemars(description = "Concise title for the report (max 200 characters)")]
+    #[schemars(
+        description = "Language the citizen wrote in: id (Bahasa Indonesia), jv (Javanese), su (Sundanese), min (Minangkabau), ban (Balinese), en (English) or other. Mixed slang counts as the dominant language."
+    )]
+    pub language: Option<ReportLanguage>,
+
+    #[schemars(
+        description = "Concise title for the report (max 200 characters), in the citizen's own language"
+    )]
     pub title: String,
 
-    #[schemars(description = "Detailed description of the issue")]
+    #[schemars(description = "Detailed description of the issue, in the citizen's own language")]
     pub description: String,
 
+    #[schemars(
+        description = "Title in standard Bahasa Indonesia for officials. Null if language is id."
+    )]
+    pub normalized_title: Option<String>,
+
+    #[schemars(
+        description = "Description in standard Bahasa Indonesia for officials. Null if language is id."
+    )]
+    pub normalized_description: Option<String>,
+
     #[schemars(
         description = "List of categories with their severities. A report can belong to multiple categories."
     )]
@@ -119,6 +136,33 @@ impl LlmResponse for ExtractedReportData {
     }
 }
 
+impl ExtractedReportData {
+    /// Normalized Bahasa Indonesia title and description, if the citizen wrote in another language
+    ///
+    /// Returns None for Indonesian reports, reports without a detected language and
+    /// extractions that did not produce a normalized title.
+    pub fn normalized(&self) -> Option<(&str, &str)> {
+        if matches!(self.language, None | Some(ReportLanguage::Id)) {
+            return None;
+        }
+        let title = self.normalized_title.as_deref().filter(|t| !t.is_empty())?;
+        let description = self
+            .normalized_description
+            .as_deref()
+            .filter(|d| !d.is_empty())
+            .unwrap_or(&self.description);
+        Some((title, description))
+    }
+
+    /// Content in Bahasa Indonesia, falling back to the original text
+    pub fn indonesian_content(&self) -> String {
+        let (title, description) = self
+            .normalized()
+            .unwrap_or((&self.title, &self.description));
+        format!("{}\n\n{}", title, description)
+    }
+}
+
 /// Service for extracting structured data from conversations using TensorZero
 pub struct ExtractionService {
     pool: PgPool,
@@ -573,4 +617,40 @@ mod tests {
         let data2: ExtractedReportData = serde_json::from_str(json2).unwrap();
         assert_eq!(data2.tag_type, Some(ReportTagType::Inquiry));
     }
+
+    #[test]
+    fn test_normalized_content_for_regional_languages() {
+        let json = r#"{
+            "language": "jv",
+            "title": "Dalan bolong neng ngarep pasar",
+            "description": "Dalane bolong gedhe, wis rong minggu ora didandani",
+            "normalized_title": "Jalan berlubang di depan pasar",
+            "normalized_description": "Jalan berlubang besar, sudah dua minggu tidak diperbaiki",
+            "categories": []
+        }"#;
+
+        let data: ExtractedReportData = serde_json::from_str(json).unwrap();
+        assert_eq!(data.language, Some(ReportLanguage::Jv));
+        assert_eq!(
+            data.normalized(),
+            Some((
+                "Jalan berlubang di depan pasar",
+                "Jalan berlubang besar, sudah dua minggu tidak diperbaiki"
+            ))
+        );
+        assert!(data.indonesian_content().starts_with("Jalan berlubang"));
+
+        let indonesian = ExtractedReportData {
+            language: Some(ReportLanguage::Id),
+            title: "Jalan berlubang".to_string(),
+            description: "Lubang besar".to_string(),
+            normalized_title: Some("Jalan berlubang".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(indonesian.normalized(), None);
+        assert_eq!(
+            indonesian.indonesian_content(),
+            "Jalan berlubang\n\nLubang besar"
+        );
+    }
 }
@@ -1,11 +1,27 @@
 mod extraction_service;
 mod geocoding_service;
+mod redaction_service;
 mod region_lookup_service;
+mod report_assignment_service;
+mod report_duplicate_service;
+mod report_endorsement_service;
+mod report_identity_service;
 mod report_job_service;
+mod report_review_service;
+mod report_revision_service;
 mod report_service;
+mod report_update_service;
 
-pub use extraction_service::ExtractionService;
+pub use extraction_service::{ExtractedReportData, ExtractionService};
 pub use geocoding_service::{GeocodingLevel, GeocodingService, LocationNames};
+pub use redaction_service::{redact_pii, RedactionService};
 pub use region_lookup_service::RegionLookupService;
-pub use report_job_service::ReportJobService;
+pub use report_assignment_service::ReportAssignmentService;
+pub use report_duplicate_service::ReportDuplicateService;
+pub use report_endorsement_service::ReportEndorsementService;
+pub use report_identity_service::ReportIdentityService;
+pub use report_job_service::{ReportJobService, REPORT_JOBS_CHANNEL};
+pub use report_review_service::ReportReviewService;
+pub use report_revision_service::{ReportRevisionService, RevisionAuthor};
 pub use report_service::ReportService;
+pub use report_update_service::ReportUpdateService;
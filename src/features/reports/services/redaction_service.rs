@@ -0,0 +1,345 @@
+use std::collections::BTreeSet;
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::reports::models::{PiiKind, ReportLanguage};
+
+static EMAIL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}\b").unwrap()
+});
+
+/// NIK: 16 digits, optionally grouped as 6-6-4
+static NIK_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b\d{6}[ .-]?\d{6}[ .-]?\d{4}\b").unwrap());
+
+/// Mobile numbers starting with +62, 62 or 08
+static PHONE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:\+62|\b62|\b0)[ .-]?8\d{1,3}(?:[ .-]?\d{2,4}){2,3}\b").unwrap()
+});
+
+/// Vehicle plates ("B 1234 XYZ"), restricted to registration area codes so that
+/// "RT 05 RW" or "KM 12 A" are not matched
+static PLATE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"\b(?:A|B|D|E|F|G|H|K|L|M|N|P|R|S|T|W|Z|AA|AB|AD|AE|AG|BA|BB|BD|BE|BG|BH|BK|BL|BM|BN|BP|DA|DB|DD|DE|DG|DH|DK|DL|DM|DN|DR|DT|EA|EB|ED|KB|KH|KT|KU|PA|PB) ?\d{1,4} ?[A-Z]{1,3}\b",
+    )
+    .unwrap()
+});
+
+/// Capitalized words following an honorific ("Pak Budi Santoso")
+static NAME_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"\b(Bapak|Pak|Ibu|Bu|Sdr|Sdri|Saudara|Saudari|Mas|Mbak|Bang|Kak)(\.?\s+)([A-Z][a-z]+(?:\s+[A-Z][a-z]+){0,2})",
+    )
+    .unwrap()
+});
+
+/// Words after an honorific that are titles or places rather than names
+const NON_NAME_WORDS: &[&str] = &[
+    "Kota", "Lurah", "Camat", "Bupati", "Walikota", "Wali", "Gubernur", "Kepala", "Presiden",
+    "Menteri", "Dokter", "Guru", "Polisi", "Ketua", "Dinas",
+];
+
+/// Text with personal data replaced by placeholders
+#[derive(Debug, Clone, PartialEq)]
+pub struct RedactedText {
+    pub text: String,
+    pub kinds: BTreeSet<PiiKind>,
+}
+
+/// Replace NIK, phone numbers, emails, vehicle plates and (optionally) names in `text`
+pub fn redact_pii(text: &str, redact_names: bool) -> RedactedText {
+    let mut kinds = BTreeSet::new();
+    let mut text = text.to_string();
+
+    // Emails first: their local part may contain digit runs
+    for (kind, regex) in [
+        (PiiKind::Email, &*EMAIL_REGEX),
+        (PiiKind::Nik, &*NIK_REGEX),
+        (PiiKind::Phone, &*PHONE_REGEX),
+        (PiiKind::Plate, &*PLATE_REGEX),
+    ] {
+        if regex.is_match(&text) {
+            kinds.insert(kind);
+            text = regex.replace_all(&text, kind.placeholder()).into_owned();
+        }
+    }
+
+    if redact_names {
+        let mut found = false;
+        text = NAME_REGEX
+            .replace_all(&text, |caps: &Captures| {
+                let first_word = caps[3].split_whitespace().next().unwrap_or_default();
+                if NON_NAME_WORDS.contains(&first_word) {
+                    return caps[0].to_string();
+                }
+                found = true;
+                format!("{}{}{}", &caps[1], &caps[2], PiiKind::Name.placeholder())
+            })
+            .into_owned();
+        if found {
+            kinds.insert(PiiKind::Name);
+        }
+    }
+
+    RedactedText { text, kinds }
+}
+
+/// Report text the redacted copy is produced from
+struct ReportContent<'a> {
+    title: Option<&'a str>,
+    description: Option<&'a str>,
+    timeline: Option<&'a str>,
+    impact: Option<&'a str>,
+}
+
+/// Service producing the public, redacted copy of report content
+pub struct RedactionService {
+    pool: PgPool,
+}
+
+impl RedactionService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Redact the current title, description, timeline and impact of a report and its
+    /// translations
+    pub async fn redact_report(&self, report_id: Uuid, redact_names: bool) -> Result<()> {
+        let row = sqlx::query!(
+            "SELECT title, description, timeline, impact FROM reports WHERE id = $1",
+            report_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report content: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report {} not found", report_id)))?;
+
+        self.store(
+            report_id,
+            ReportContent {
+                title: row.title.as_deref(),
+                description: row.description.as_deref(),
+                timeline: row.timeline.as_deref(),
+                impact: row.impact.as_deref(),
+            },
+            redact_names,
+        )
+        .await?;
+
+        self.redact_translations(report_id, redact_names).await
+    }
+
+    /// Redact processed reports without a (complete) redacted copy, e.g. processed before
+    /// redaction or timeline and impact redaction existed
+    /// Returns the number of reports redacted
+    pub async fn redact_pending(&self, limit: i64, redact_names: bool) -> Result<usize> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id, title, description, timeline, impact
+            FROM reports
+            WHERE title IS NOT NULL AND (
+                redacted_at IS NULL
+                OR (timeline IS NOT NULL AND redacted_timeline IS NULL)
+                OR (impact IS NOT NULL AND redacted_impact IS NULL)
+            )
+            ORDER BY created_at
+            LIMIT $1
+            "#,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list unredacted reports: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        for row in &rows {
+            self.store(
+                row.id,
+                ReportContent {
+                    title: row.title.as_deref(),
+                    description: row.description.as_deref(),
+                    timeline: row.timeline.as_deref(),
+                    impact: row.impact.as_deref(),
+                },
+                redact_names,
+            )
+            .await?;
+            self.redact_translations(row.id, redact_names).await?;
+        }
+
+        Ok(rows.len())
+    }
+
+    /// Redact the translated title and description of a report
+    async fn redact_translations(&self, report_id: Uuid, redact_names: bool) -> Result<()> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT language as "language: ReportLanguage", title, description
+            FROM report_translations
+            WHERE report_id = $1
+            "#,
+            report_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report translations: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        for row in rows {
+            let title = row.title.as_deref().map(|t| redact_pii(t, redact_names));
+            let description = row
+                .description
+                .as_deref()
+                .map(|d| redact_pii(d, redact_names));
+
+            sqlx::query!(
+                r#"
+                UPDATE report_translations
+                SET redacted_title = $5, redacted_description = $6,
+                    redacted_at = NOW(), updated_at = NOW()
+                WHERE report_id = $1 AND language = $2
+                  AND title IS NOT DISTINCT FROM $3
+                  AND description IS NOT DISTINCT FROM $4
+                "#,
+                report_id,
+                row.language as ReportLanguage,
+                row.title,
+                row.description,
+                title.as_ref().map(|r| r.text.as_str()),
+                description.as_ref().map(|r| r.text.as_str())
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to store redacted report translation: {:?}", e);
+                AppError::Database(e)
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Store the redacted copy unless the content changed since it was read
+    async fn store(
+        &self,
+        report_id: Uuid,
+        content: ReportContent<'_>,
+        redact_names: bool,
+    ) -> Result<()> {
+        let redact = |text: Option<&str>| text.map(|t| redact_pii(t, redact_names));
+        let title_redacted = redact(content.title);
+        let description_redacted = redact(content.description);
+        let timeline_redacted = redact(content.timeline);
+        let impact_redacted = redact(content.impact);
+
+        let pii_types: Vec<String> = [
+            &title_redacted,
+            &description_redacted,
+            &timeline_redacted,
+            &impact_redacted,
+        ]
+        .into_iter()
+        .flatten()
+        .flat_map(|r| r.kinds.iter().copied())
+        .collect::<BTreeSet<_>>()
+        .iter()
+        .map(|k| k.as_str().to_string())
+        .collect();
+
+        let updated = sqlx::query!(
+            r#"
+            UPDATE reports
+            SET redacted_title = $6, redacted_description = $7,
+                redacted_timeline = $8, redacted_impact = $9,
+                pii_types = $10, redacted_at = NOW()
+            WHERE id = $1
+              AND title IS NOT DISTINCT FROM $2
+              AND description IS NOT DISTINCT FROM $3
+              AND timeline IS NOT DISTINCT FROM $4
+              AND impact IS NOT DISTINCT FROM $5
+            "#,
+            report_id,
+            content.title,
+            content.description,
+            content.timeline,
+            content.impact,
+            title_redacted.as_ref().map(|r| r.text.as_str()),
+            description_redacted.as_ref().map(|r| r.text.as_str()),
+            timeline_redacted.as_ref().map(|r| r.text.as_str()),
+            impact_redacted.as_ref().map(|r| r.text.as_str()),
+            &pii_types
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to store redacted report content: {:?}", e);
+            AppError::Database(e)
+        })?
+        .rows_affected();
+
+        if updated == 0 {
+            tracing::info!(
+                "Content of report {} changed during redaction, skipping",
+                report_id
+            );
+        } else if !pii_types.is_empty() {
+            tracing::info!(
+                "Redacted personal data ({}) from report {}",
+                pii_types.join(", "),
+                report_id
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacts_identifiers() {
+        let redacted = redact_pii(
+            "NIK saya 3201234567890001, hubungi 0812-3456-7890 atau +62 813 1234 5678, \
+             email budi.s@mail.co.id. Mobil B 1234 XYZ parkir di RT 05 RW 02.",
+            false,
+        );
+
+        assert_eq!(
+            redacted.text,
+            "NIK saya [NIK], hubungi [NOMOR TELEPON] atau [NOMOR TELEPON], \
+             email [EMAIL]. Mobil [PLAT NOMOR] parkir di RT 05 RW 02."
+        );
+        assert_eq!(
+            redacted.kinds.into_iter().collect::<Vec<_>>(),
+            vec![PiiKind::Nik, PiiKind::Phone, PiiKind::Email, PiiKind::Plate]
+        );
+    }
+
+    #[test]
+    fn test_redacts_names_only_when_enabled() {
+        let text = "Laporan dari Pak Budi Santoso kepada Pak Lurah soal jalan di Ibu Kota.";
+
+        assert_eq!(redact_pii(text, false).text, text);
+
+        let redacted = redact_pii(text, true);
+        assert_eq!(
+            redacted.text,
+            "Laporan dari Pak [NAMA] kepada Pak Lurah soal jalan di Ibu Kota."
+        );
+        assert!(redacted.kinds.contains(&PiiKind::Name));
+    }
+}
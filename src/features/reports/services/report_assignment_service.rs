@@ -0,0 +1,572 @@
+use std::collections::HashMap;
+
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::notifications::NotificationService;
+use crate::features::reports::models::{
+    CreateReportAssignment, Report, ReportAssignment, ReportAssignmentType, ReportStatus,
+};
+
+/// Statuses in which an official can claim a report
+const CLAIMABLE_STATUSES: &[ReportStatus] = &[ReportStatus::Verified, ReportStatus::InProgress];
+
+/// Ensure an official can claim the report in its current state
+fn ensure_claimable(
+    report_id: Uuid,
+    status: ReportStatus,
+    active: Option<&ReportAssignment>,
+) -> Result<()> {
+    if !CLAIMABLE_STATUSES.contains(&status) {
+        return Err(AppError::Conflict(format!(
+            "Report {} cannot be claimed while '{}'",
+            report_id, status
+        )));
+    }
+
+    if let Some(active) = active {
+        return Err(AppError::Conflict(format!(
+            "Report {} is already assigned to {}",
+            report_id, active.assignee_id
+        )));
+    }
+
+    Ok(())
+}
+
+/// Ensure an admin can assign the report in its current status
+fn ensure_assignable(report_id: Uuid, status: ReportStatus) -> Result<()> {
+    if matches!(status, ReportStatus::Rejected | ReportStatus::Resolved) {
+        return Err(AppError::Conflict(format!(
+            "Report {} cannot be assigned while '{}'",
+            report_id, status
+        )));
+    }
+
+    Ok(())
+}
+
+/// Ensure the actor may hand over the active assignment (current assignee or admin)
+///
+/// `action` names the operation in the error, e.g. "release".
+fn ensure_can_hand_over(
+    active: &ReportAssignment,
+    actor_id: &str,
+    is_admin: bool,
+    action: &str,
+) -> Result<()> {
+    if active.assignee_id != actor_id && !is_admin {
+        return Err(AppError::Forbidden(format!(
+            "Only the current assignee can {} this report",
+            action
+        )));
+    }
+
+    Ok(())
+}
+
+/// Ensure the report is not already assigned to the new assignee
+fn ensure_new_assignee(
+    report_id: Uuid,
+    active: &ReportAssignment,
+    assignee_id: &str,
+) -> Result<()> {
+    if active.assignee_id == assignee_id {
+        return Err(AppError::BadRequest(format!(
+            "Report {} is already assigned to {}",
+            report_id, assignee_id
+        )));
+    }
+
+    Ok(())
+}
+
+/// Service for official ownership of reports (claim / release / reassign / assign)
+pub struct ReportAssignmentService {
+    pool: PgPool,
+}
+
+impl ReportAssignmentService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// List all assignments of a report, including released ones (newest first)
+    pub async fn list_for_report(&self, report_id: Uuid) -> Result<Vec<ReportAssignment>> {
+        sqlx::query_as!(
+            ReportAssignment,
+            r#"
+            SELECT
+                id, report_id, assignee_id, assigned_by,
+                assignment_type as "assignment_type: ReportAssignmentType",
+                note, assigned_at, released_at, released_by, release_reason
+            FROM report_assignments
+            WHERE report_id = $1
+            ORDER BY assigned_at DESC
+            "#,
+            report_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list report assignments: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Claim an unassigned verified report for the given official
+    pub async fn claim(
+        &self,
+        report_id: Uuid,
+        official_id: &str,
+        note: Option<&str>,
+    ) -> Result<ReportAssignment> {
+        let mut tx = self.begin().await?;
+
+        let status = Self::lock_report_status(&mut tx, report_id).await?;
+        let active = Self::get_active_for_update(&mut tx, report_id).await?;
+        ensure_claimable(report_id, status, active.as_ref())?;
+
+        let assignment = Self::insert(
+            &mut tx,
+            &CreateReportAssignment {
+                report_id,
+                assignee_id: official_id.to_string(),
+                assigned_by: official_id.to_string(),
+                assignment_type: ReportAssignmentType::Claim,
+                note: note.map(String::from),
+            },
+        )
+        .await?;
+
+        self.commit(tx).await?;
+
+        tracing::info!("Report {} claimed by {}", report_id, official_id);
+        Ok(assignment)
+    }
+
+    /// Release the active assignment of a report
+    ///
+    /// Only the current assignee or an admin may release.
+    pub async fn release(
+        &self,
+        report_id: Uuid,
+        actor_id: &str,
+        is_admin: bool,
+        reason: Option<&str>,
+    ) -> Result<ReportAssignment> {
+        let mut tx = self.begin().await?;
+
+        let active = Self::get_active_for_update(&mut tx, report_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("Report {} has no active assignment", report_id))
+            })?;
+
+        ensure_can_hand_over(&active, actor_id, is_admin, "release")?;
+
+        let released = Self::release_active(&mut tx, active.id, actor_id, reason).await?;
+
+        self.commit(tx).await?;
+
+        tracing::info!("Report {} released by {}", report_id, actor_id);
+        Ok(released)
+    }
+
+    /// Hand over a report from its current assignee to another official
+    ///
+    /// Only the current assignee or an admin may reassign.
+    pub async fn reassign(
+        &self,
+        report_id: Uuid,
+        actor_id: &str,
+        is_admin: bool,
+        new_assignee_id: &str,
+        note: Option<&str>,
+    ) -> Result<ReportAssignment> {
+        let mut tx = self.begin().await?;
+
+        let active = Self::get_active_for_update(&mut tx, report_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("Report {} has no active assignment", report_id))
+            })?;
+
+        ensure_can_hand_over(&active, actor_id, is_admin, "reassign")?;
+        ensure_new_assignee(report_id, &active, new_assignee_id)?;
+
+        let reason = format!("Reassigned to {}", new_assignee_id);
+        Self::release_active(&mut tx, active.id, actor_id, Some(&reason)).await?;
+
+        let assignment = Self::insert(
+            &mut tx,
+            &CreateReportAssignment {
+                report_id,
+                assignee_id: new_assignee_id.to_string(),
+                assigned_by: actor_id.to_string(),
+                assignment_type: ReportAssignmentType::Reassign,
+                note: note.map(String::from),
+            },
+        )
+        .await?;
+
+        self.commit(tx).await?;
+
+        tracing::info!(
+            "Report {} reassigned from {} to {} by {}",
+            report_id,
+            active.assignee_id,
+            new_assignee_id,
+            actor_id
+        );
+        Ok(assignment)
+    }
+
+    /// Assign a report directly to an official (admin curator)
+    ///
+    /// Replaces any existing active assignment.
+    pub async fn assign(
+        &self,
+        report_id: Uuid,
+        admin_id: &str,
+        assignee_id: &str,
+        note: Option<&str>,
+    ) -> Result<ReportAssignment> {
+        let mut tx = self.begin().await?;
+        let assignment =
+            Self::assign_in_tx(&mut tx, report_id, admin_id, assignee_id, note).await?;
+        self.commit(tx).await?;
+        Ok(assignment)
+    }
+
+    /// Assign a report inside the caller's transaction (see `assign`)
+    pub async fn assign_in_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        report_id: Uuid,
+        admin_id: &str,
+        assignee_id: &str,
+        note: Option<&str>,
+    ) -> Result<ReportAssignment> {
+        let status = Self::lock_report_status(tx, report_id).await?;
+        ensure_assignable(report_id, status)?;
+
+        if let Some(active) = Self::get_active_for_update(tx, report_id).await? {
+            ensure_new_assignee(report_id, &active, assignee_id)?;
+            let reason = format!("Assigned to {} by admin", assignee_id);
+            Self::release_active(tx, active.id, admin_id, Some(&reason)).await?;
+        }
+
+        let assignment = Self::insert(
+            tx,
+            &CreateReportAssignment {
+                report_id,
+                assignee_id: assignee_id.to_string(),
+                assigned_by: admin_id.to_string(),
+                assignment_type: ReportAssignmentType::Assign,
+                note: note.map(String::from),
+            },
+        )
+        .await?;
+
+        tracing::info!(
+            "Report {} assigned to {} by {}",
+            report_id,
+            assignee_id,
+            admin_id
+        );
+        Ok(assignment)
+    }
+
+    /// List reports actively assigned to an official, optionally filtered by status
+    pub async fn list_assigned_to(
+        &self,
+        assignee_id: &str,
+        status: Option<ReportStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<(Report, ReportAssignment)>, i64)> {
+        let assignments = sqlx::query_as!(
+            ReportAssignment,
+            r#"
+            SELECT
+                a.id, a.report_id, a.assignee_id, a.assigned_by,
+                a.assignment_type as "assignment_type: ReportAssignmentType",
+                a.note, a.assigned_at, a.released_at, a.released_by, a.release_reason
+            FROM report_assignments a
+            JOIN reports r ON r.id = a.report_id
+            WHERE a.assignee_id = $1
+              AND a.released_at IS NULL
+              AND ($2::report_status IS NULL OR r.status = $2)
+            ORDER BY a.assigned_at DESC
+            LIMIT $3 OFFSET $4
+            "#,
+            assignee_id,
+            status as Option<ReportStatus>,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list assigned reports: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let report_ids: Vec<Uuid> = assignments.iter().map(|a| a.report_id).collect();
+        let mut reports: HashMap<Uuid, Report> = sqlx::query_as!(
+            Report,
+            r#"
+            SELECT
+                id, title, description,
+                timeline, impact,
+                status as "status: ReportStatus",
+                verified_at, verified_by, resolved_at, resolved_by, resolution_notes,
+                created_at, updated_at,
+                reference_number, adk_thread_id, user_id, platform, is_anonymous
+            FROM reports
+            WHERE id = ANY($1)
+            "#,
+            &report_ids
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get assigned reports: {:?}", e);
+            AppError::Database(e)
+        })?
+        .into_iter()
+        .map(|report| (report.id, report))
+        .collect();
+
+        let total = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM report_assignments a
+            JOIN reports r ON r.id = a.report_id
+            WHERE a.assignee_id = $1
+              AND a.released_at IS NULL
+              AND ($2::report_status IS NULL OR r.status = $2)
+            "#,
+            assignee_id,
+            status as Option<ReportStatus>
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to count assigned reports: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        // Keep the assignment order; reports are fetched by id
+        let items = assignments
+            .into_iter()
+            .filter_map(|a| reports.remove(&a.report_id).map(|report| (report, a)))
+            .collect();
+
+        Ok((items, total))
+    }
+
+    // ===== Helpers =====
+
+    async fn begin(&self) -> Result<Transaction<'static, Postgres>> {
+        self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    async fn commit(&self, tx: Transaction<'static, Postgres>) -> Result<()> {
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit report assignment: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Lock the report row and return its current status
+    async fn lock_report_status(
+        tx: &mut Transaction<'_, Postgres>,
+        report_id: Uuid,
+    ) -> Result<ReportStatus> {
+        sqlx::query_scalar!(
+            r#"SELECT status as "status: ReportStatus" FROM reports WHERE id = $1 FOR UPDATE"#,
+            report_id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to lock report: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report {} not found", report_id)))
+    }
+
+    /// Get and lock the active assignment of a report
+    async fn get_active_for_update(
+        tx: &mut Transaction<'_, Postgres>,
+        report_id: Uuid,
+    ) -> Result<Option<ReportAssignment>> {
+        sqlx::query_as!(
+            ReportAssignment,
+            r#"
+            SELECT
+                id, report_id, assignee_id, assigned_by,
+                assignment_type as "assignment_type: ReportAssignmentType",
+                note, assigned_at, released_at, released_by, release_reason
+            FROM report_assignments
+            WHERE report_id = $1 AND released_at IS NULL
+            FOR UPDATE
+            "#,
+            report_id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to lock active report assignment: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    async fn insert(
+        tx: &mut Transaction<'_, Postgres>,
+        data: &CreateReportAssignment,
+    ) -> Result<ReportAssignment> {
+        let assignment = sqlx::query_as!(
+            ReportAssignment,
+            r#"
+            INSERT INTO report_assignments (report_id, assignee_id, assigned_by, assignment_type, note)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING
+                id, report_id, assignee_id, assigned_by,
+                assignment_type as "assignment_type: ReportAssignmentType",
+                note, assigned_at, released_at, released_by, release_reason
+            "#,
+            data.report_id,
+            data.assignee_id,
+            data.assigned_by,
+            data.assignment_type as ReportAssignmentType,
+            data.note
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to create report assignment: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        // Claims are self-assigned; everyone else gets told about their new report
+        if data.assignee_id != data.assigned_by {
+            NotificationService::on_report_assigned(
+                tx,
+                data.report_id,
+                &data.assignee_id,
+                &data.assigned_by,
+                data.note.as_deref(),
+            )
+            .await?;
+        }
+
+        Ok(assignment)
+    }
+
+    async fn release_active(
+        tx: &mut Transaction<'_, Postgres>,
+        assignment_id: Uuid,
+        released_by: &str,
+        reason: Option<&str>,
+    ) -> Result<ReportAssignment> {
+        sqlx::query_as!(
+            ReportAssignment,
+            r#"
+            UPDATE report_assignments
+            SET released_at = NOW(), released_by = $2, release_reason = $3
+            WHERE id = $1
+            RETURNING
+                id, report_id, assignee_id, assigned_by,
+                assignment_type as "assignment_type: ReportAssignmentType",
+                note, assigned_at, released_at, released_by, release_reason
+            "#,
+            assignment_id,
+            released_by,
+            reason
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to release report assignment: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn assignment(assignee_id: &str) -> ReportAssignment {
+        ReportAssignment {
+            id: Uuid::nil(),
+            report_id: Uuid::nil(),
+            assignee_id: assignee_id.to_string(),
+            assigned_by: "admin-1".to_string(),
+            assignment_type: ReportAssignmentType::Assign,
+            note: None,
+            assigned_at: Utc::now(),
+            released_at: None,
+            released_by: None,
+            release_reason: None,
+        }
+    }
+
+    #[test]
+    fn test_claim_requires_verified_unassigned_report() {
+        let id = Uuid::nil();
+        assert!(ensure_claimable(id, ReportStatus::Verified, None).is_ok());
+        assert!(ensure_claimable(id, ReportStatus::InProgress, None).is_ok());
+        assert!(matches!(
+            ensure_claimable(id, ReportStatus::Draft, None),
+            Err(AppError::Conflict(_))
+        ));
+        assert!(matches!(
+            ensure_claimable(id, ReportStatus::Verified, Some(&assignment("official-1"))),
+            Err(AppError::Conflict(_))
+        ));
+    }
+
+    #[test]
+    fn test_assign_rejects_closed_reports() {
+        let id = Uuid::nil();
+        assert!(ensure_assignable(id, ReportStatus::Draft).is_ok());
+        assert!(ensure_assignable(id, ReportStatus::InProgress).is_ok());
+        assert!(matches!(
+            ensure_assignable(id, ReportStatus::Resolved),
+            Err(AppError::Conflict(_))
+        ));
+        assert!(matches!(
+            ensure_assignable(id, ReportStatus::Rejected),
+            Err(AppError::Conflict(_))
+        ));
+    }
+
+    #[test]
+    fn test_only_assignee_or_admin_hands_over() {
+        let active = assignment("official-1");
+        assert!(ensure_can_hand_over(&active, "official-1", false, "release").is_ok());
+        assert!(ensure_can_hand_over(&active, "admin-1", true, "release").is_ok());
+        assert!(matches!(
+            ensure_can_hand_over(&active, "official-2", false, "reassign"),
+            Err(AppError::Forbidden(_))
+        ));
+    }
+
+    #[test]
+    fn test_new_assignee_must_differ() {
+        let active = assignment("official-1");
+        assert!(ensure_new_assignee(Uuid::nil(), &active, "official-2").is_ok());
+        assert!(matches!(
+            ensure_new_assignee(Uuid::nil(), &active, "official-1"),
+            Err(AppError::BadRequest(_))
+        ));
+    }
+}
@@ -0,0 +1,608 @@
+use std::collections::HashSet;
+
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::reports::models::{ReportDuplicate, ReportDuplicateStatus, ReportStatus};
+use crate::features::reports::services::ReportService;
+
+/// Only reports created within this window are compared
+const DUPLICATE_WINDOW_DAYS: i32 = 30;
+
+/// Maximum number of earlier reports compared per new report
+const MAX_CANDIDATES: i64 = 50;
+
+/// Locations further apart than this get no proximity credit
+const MAX_DISTANCE_METERS: f64 = 500.0;
+
+/// Minimum combined score for a pair to be stored as a duplicate candidate
+const DUPLICATE_SCORE_THRESHOLD: f64 = 0.5;
+
+/// Weight of text similarity when both reports have coordinates
+const TEXT_WEIGHT: f64 = 0.6;
+
+/// Text similarity is discounted when distance cannot be measured
+const TEXT_ONLY_WEIGHT: f64 = 0.8;
+
+/// Common Indonesian words ignored when comparing text
+const STOPWORDS: &[&str] = &[
+    "ada", "akan", "atau", "bahwa", "dan", "dari", "dengan", "di", "dalam", "ini", "itu", "juga",
+    "karena", "ke", "kami", "kita", "oleh", "pada", "saya", "sangat", "sudah", "telah", "tidak",
+    "untuk", "yang", "belum", "masih", "sekali", "banyak",
+];
+
+/// Lowercased significant words of a text
+fn tokenize(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(|w| w.to_lowercase())
+        .filter(|w| w.chars().count() >= 3 && !STOPWORDS.contains(&w.as_str()))
+        .collect()
+}
+
+/// Jaccard similarity of two word sets (0 when both are empty)
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// Text similarity of two reports (0-1)
+///
+/// Titles are short and precise, descriptions long and noisy, so the better
+/// of the title overlap and the full title+description overlap is used.
+pub fn text_similarity(
+    title_a: &str,
+    description_a: &str,
+    title_b: &str,
+    description_b: &str,
+) -> f64 {
+    let title_sim = jaccard(&tokenize(title_a), &tokenize(title_b));
+    let full_sim = jaccard(
+        &tokenize(&format!("{} {}", title_a, description_a)),
+        &tokenize(&format!("{} {}", title_b, description_b)),
+    );
+    title_sim.max(full_sim)
+}
+
+/// Great-circle distance between two coordinates in meters
+pub fn haversine_meters(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+
+    let d_lat = (lat2 - lat1).to_radians();
+    let d_lon = (lon2 - lon1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lon / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_METERS * a.sqrt().asin()
+}
+
+/// Combined duplicate score (0-1) from text similarity and optional distance
+pub fn duplicate_score(text_similarity: f64, distance_meters: Option<f64>) -> f64 {
+    match distance_meters {
+        Some(distance) => {
+            let proximity = (1.0 - distance / MAX_DISTANCE_METERS).max(0.0);
+            TEXT_WEIGHT * text_similarity + (1.0 - TEXT_WEIGHT) * proximity
+        }
+        None => TEXT_ONLY_WEIGHT * text_similarity,
+    }
+}
+
+/// Earlier report compared against a new one
+#[derive(Debug, sqlx::FromRow)]
+struct CandidateRow {
+    id: Uuid,
+    title: Option<String>,
+    description: Option<String>,
+    lat: Option<f64>,
+    lon: Option<f64>,
+}
+
+/// Service for detecting and reviewing duplicate reports
+pub struct ReportDuplicateService {
+    pool: PgPool,
+}
+
+impl ReportDuplicateService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Compare a report with earlier reports in the same area and category (called by ReportProcessor)
+    ///
+    /// Requires the report's location and categories to be set. Returns the number of
+    /// duplicate candidates stored.
+    pub async fn detect_for_report(&self, report_id: Uuid) -> Result<usize> {
+        let Some(report) = sqlx::query!(
+            r#"
+            SELECT
+                r.title, r.description, r.created_at,
+                rl.lat, rl.lon, rl.village_id, rl.district_id
+            FROM reports r
+            JOIN report_locations rl ON rl.report_id = r.id
+            WHERE r.id = $1
+            "#,
+            report_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report for duplicate detection: {:?}", e);
+            AppError::Database(e)
+        })?
+        else {
+            return Ok(0);
+        };
+
+        // Regency-level locations are too coarse to compare
+        if report.village_id.is_none() && report.district_id.is_none() {
+            return Ok(0);
+        }
+
+        let candidates = sqlx::query_as!(
+            CandidateRow,
+            r#"
+            SELECT r.id, r.title, r.description, rl.lat, rl.lon
+            FROM reports r
+            JOIN report_locations rl ON rl.report_id = r.id
+            WHERE r.id <> $1
+              AND r.status <> 'rejected'
+              AND r.created_at < $6
+              AND r.created_at >= NOW() - make_interval(days => $2)
+              AND (rl.village_id = $3 OR rl.district_id = $4)
+              AND EXISTS(
+                  SELECT 1 FROM report_categories rc
+                  JOIN report_categories own ON own.category_id = rc.category_id
+                  WHERE rc.report_id = r.id AND own.report_id = $1
+              )
+            ORDER BY r.created_at DESC
+            LIMIT $5
+            "#,
+            report_id,
+            DUPLICATE_WINDOW_DAYS,
+            report.village_id,
+            report.district_id,
+            MAX_CANDIDATES,
+            report.created_at
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get duplicate candidates: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let title = report.title.unwrap_or_default();
+        let description = report.description.unwrap_or_default();
+        let mut stored = 0;
+
+        for candidate in candidates {
+            let text_sim = text_similarity(
+                &title,
+                &description,
+                candidate.title.as_deref().unwrap_or_default(),
+                candidate.description.as_deref().unwrap_or_default(),
+            );
+            let distance = match (report.lat, report.lon, candidate.lat, candidate.lon) {
+                (Some(lat1), Some(lon1), Some(lat2), Some(lon2)) => {
+                    Some(haversine_meters(lat1, lon1, lat2, lon2))
+                }
+                _ => None,
+            };
+            let score = duplicate_score(text_sim, distance);
+
+            if score < DUPLICATE_SCORE_THRESHOLD {
+                continue;
+            }
+
+            // Re-detection (job retry) refreshes scores of unreviewed candidates only
+            sqlx::query!(
+                r#"
+                INSERT INTO report_duplicates
+                    (report_id, duplicate_of_id, score, text_similarity, distance_meters)
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (report_id, duplicate_of_id) DO UPDATE SET
+                    score = EXCLUDED.score,
+                    text_similarity = EXCLUDED.text_similarity,
+                    distance_meters = EXCLUDED.distance_meters,
+                    detected_at = NOW()
+                WHERE report_duplicates.status = 'pending'
+                "#,
+                report_id,
+                candidate.id,
+                score,
+                text_sim,
+                distance
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to save duplicate candidate: {:?}", e);
+                AppError::Database(e)
+            })?;
+
+            tracing::info!(
+                "Report {} looks like a duplicate of {} (score={:.2}, text={:.2}, distance={:?})",
+                report_id,
+                candidate.id,
+                score,
+                text_sim,
+                distance
+            );
+            stored += 1;
+        }
+
+        Ok(stored)
+    }
+
+    /// Get a duplicate candidate
+    pub async fn get(&self, id: Uuid) -> Result<ReportDuplicate> {
+        sqlx::query_as!(
+            ReportDuplicate,
+            r#"
+            SELECT
+                d.id,
+                d.report_id, r.reference_number as report_reference, r.title as report_title,
+                r.status as "report_status: ReportStatus",
+                d.duplicate_of_id, o.reference_number as duplicate_of_reference,
+                o.title as duplicate_of_title,
+                o.status as "duplicate_of_status: ReportStatus",
+                d.score, d.text_similarity, d.distance_meters,
+                d.status as "status: ReportDuplicateStatus",
+                d.detected_at, d.reviewed_by, d.reviewed_at, d.review_note
+            FROM report_duplicates d
+            JOIN reports r ON r.id = d.report_id
+            JOIN reports o ON o.id = d.duplicate_of_id
+            WHERE d.id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get duplicate candidate: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Duplicate candidate {} not found", id)))
+    }
+
+    /// List duplicate candidates (highest score first)
+    pub async fn list(
+        &self,
+        status: Option<ReportDuplicateStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<ReportDuplicate>, i64)> {
+        let items = sqlx::query_as!(
+            ReportDuplicate,
+            r#"
+            SELECT
+                d.id,
+                d.report_id, r.reference_number as report_reference, r.title as report_title,
+                r.status as "report_status: ReportStatus",
+                d.duplicate_of_id, o.reference_number as duplicate_of_reference,
+                o.title as duplicate_of_title,
+                o.status as "duplicate_of_status: ReportStatus",
+                d.score, d.text_similarity, d.distance_meters,
+                d.status as "status: ReportDuplicateStatus",
+                d.detected_at, d.reviewed_by, d.reviewed_at, d.review_note
+            FROM report_duplicates d
+            JOIN reports r ON r.id = d.report_id
+            JOIN reports o ON o.id = d.duplicate_of_id
+            WHERE ($1::report_duplicate_status IS NULL OR d.status = $1)
+            ORDER BY d.score DESC, d.detected_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+            status as Option<ReportDuplicateStatus>,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list duplicate candidates: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let total = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!" FROM report_duplicates
+            WHERE ($1::report_duplicate_status IS NULL OR status = $1)
+            "#,
+            status as Option<ReportDuplicateStatus>
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to count duplicate candidates: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok((items, total))
+    }
+
+    /// List duplicate candidates involving a report (on either side)
+    pub async fn list_for_report(&self, report_id: Uuid) -> Result<Vec<ReportDuplicate>> {
+        sqlx::query_as!(
+            ReportDuplicate,
+            r#"
+            SELECT
+                d.id,
+                d.report_id, r.reference_number as report_reference, r.title as report_title,
+                r.status as "report_status: ReportStatus",
+                d.duplicate_of_id, o.reference_number as duplicate_of_reference,
+                o.title as duplicate_of_title,
+                o.status as "duplicate_of_status: ReportStatus",
+                d.score, d.text_similarity, d.distance_meters,
+                d.status as "status: ReportDuplicateStatus",
+                d.detected_at, d.reviewed_by, d.reviewed_at, d.review_note
+            FROM report_duplicates d
+            JOIN reports r ON r.id = d.report_id
+            JOIN reports o ON o.id = d.duplicate_of_id
+            WHERE d.report_id = $1 OR d.duplicate_of_id = $1
+            ORDER BY d.score DESC
+            "#,
+            report_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list report duplicates: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Confirm that both reports describe the same issue (both stay open)
+    pub async fn confirm(
+        &self,
+        id: Uuid,
+        reviewer_id: &str,
+        note: Option<&str>,
+    ) -> Result<ReportDuplicate> {
+        self.review(id, ReportDuplicateStatus::Confirmed, reviewer_id, note)
+            .await
+    }
+
+    /// Dismiss a candidate as unrelated
+    pub async fn dismiss(
+        &self,
+        id: Uuid,
+        reviewer_id: &str,
+        note: Option<&str>,
+    ) -> Result<ReportDuplicate> {
+        self.review(id, ReportDuplicateStatus::Dismissed, reviewer_id, note)
+            .await
+    }
+
+    /// Merge the duplicate into the earlier report
+    ///
+    /// Attachments of the duplicate are linked to the earlier report and the
+    /// duplicate is rejected with a note pointing at it, in one transaction.
+    pub async fn merge(
+        &self,
+        id: Uuid,
+        reviewer_id: &str,
+        role: &str,
+        note: Option<&str>,
+    ) -> Result<ReportDuplicate> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let (report_id, duplicate_of_id) = Self::lock_open(&mut tx, id).await?;
+
+        let current = ReportService::lock_status(&mut tx, report_id).await?;
+        ReportService::ensure_transition(report_id, current, ReportStatus::Rejected)?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO report_attachments (report_id, file_id)
+            SELECT $2, file_id FROM report_attachments
+            WHERE report_id = $1 AND report_update_id IS NULL
+            ON CONFLICT (report_id, file_id) DO NOTHING
+            "#,
+            report_id,
+            duplicate_of_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to copy attachments to merged report: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let original_ref = sqlx::query_scalar!(
+            r#"SELECT reference_number FROM reports WHERE id = $1"#,
+            duplicate_of_id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get original report: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let merge_note = format!(
+            "Merged into report {}",
+            original_ref.unwrap_or_else(|| duplicate_of_id.to_string())
+        );
+
+        sqlx::query!(
+            r#"
+            UPDATE reports
+            SET status = 'rejected', resolution_notes = $2, updated_at = NOW()
+            WHERE id = $1
+            "#,
+            report_id,
+            merge_note
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to reject merged report: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        ReportService::record_status_change(
+            &mut tx,
+            report_id,
+            Some(current),
+            ReportStatus::Rejected,
+            Some(reviewer_id),
+            role,
+            Some(&merge_note),
+        )
+        .await?;
+
+        Self::set_status(
+            &mut tx,
+            id,
+            ReportDuplicateStatus::Merged,
+            reviewer_id,
+            note,
+        )
+        .await?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit duplicate merge: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!(
+            "Report {} merged into {} by {}",
+            report_id,
+            duplicate_of_id,
+            reviewer_id
+        );
+
+        self.get(id).await
+    }
+
+    // ===== Helpers =====
+
+    /// Move an open candidate to a review status
+    async fn review(
+        &self,
+        id: Uuid,
+        status: ReportDuplicateStatus,
+        reviewer_id: &str,
+        note: Option<&str>,
+    ) -> Result<ReportDuplicate> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Self::lock_open(&mut tx, id).await?;
+        Self::set_status(&mut tx, id, status, reviewer_id, note).await?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit duplicate review: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!("Duplicate candidate {} {} by {}", id, status, reviewer_id);
+        self.get(id).await
+    }
+
+    /// Lock a candidate and ensure it can still be reviewed
+    ///
+    /// Returns (report_id, duplicate_of_id).
+    async fn lock_open(tx: &mut Transaction<'_, Postgres>, id: Uuid) -> Result<(Uuid, Uuid)> {
+        let row = sqlx::query!(
+            r#"
+            SELECT report_id, duplicate_of_id, status as "status: ReportDuplicateStatus"
+            FROM report_duplicates
+            WHERE id = $1
+            FOR UPDATE
+            "#,
+            id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to lock duplicate candidate: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Duplicate candidate {} not found", id)))?;
+
+        if !row.status.is_open() {
+            return Err(AppError::Conflict(format!(
+                "Duplicate candidate {} is already {}",
+                id, row.status
+            )));
+        }
+
+        Ok((row.report_id, row.duplicate_of_id))
+    }
+
+    async fn set_status(
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+        status: ReportDuplicateStatus,
+        reviewer_id: &str,
+        note: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE report_duplicates
+            SET status = $2, reviewed_by = $3, reviewed_at = NOW(), review_note = $4
+            WHERE id = $1
+            "#,
+            id,
+            status as ReportDuplicateStatus,
+            reviewer_id,
+            note
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to update duplicate candidate: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_similarity_ignores_case_and_stopwords() {
+        let sim = text_similarity(
+            "Jalan berlubang di depan pasar",
+            "",
+            "JALAN BERLUBANG dekat pasar",
+            "",
+        );
+        assert!(sim >= 0.6, "similarity was {}", sim);
+
+        let unrelated = text_similarity("Banjir di perumahan", "", "Lampu jalan mati", "");
+        assert_eq!(unrelated, 0.0);
+    }
+
+    #[test]
+    fn test_haversine_distance() {
+        // Roughly 111 km per degree of latitude
+        let d = haversine_meters(-6.9, 107.6, -5.9, 107.6);
+        assert!((d - 111_195.0).abs() < 100.0, "distance was {}", d);
+        assert_eq!(haversine_meters(-6.9, 107.6, -6.9, 107.6), 0.0);
+    }
+
+    #[test]
+    fn test_duplicate_score_rewards_proximity() {
+        let near = duplicate_score(0.5, Some(50.0));
+        let far = duplicate_score(0.5, Some(5_000.0));
+        let unknown = duplicate_score(0.5, None);
+
+        assert!(near > unknown);
+        assert!(unknown > far);
+        assert!(near >= DUPLICATE_SCORE_THRESHOLD);
+        assert!(far < DUPLICATE_SCORE_THRESHOLD);
+    }
+}
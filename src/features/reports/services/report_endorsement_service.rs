@@ -0,0 +1,202 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::reports::models::{ReportEndorsement, ReportStatus};
+
+/// Statuses in which a report can be endorsed by citizens
+const ENDORSABLE_STATUSES: &[ReportStatus] = &[ReportStatus::Verified, ReportStatus::InProgress];
+
+/// Service for citizen endorsements ("me too") of reports
+pub struct ReportEndorsementService {
+    pool: PgPool,
+}
+
+impl ReportEndorsementService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Endorse a verified report on behalf of a citizen
+    ///
+    /// Each user can endorse a report once; reporters cannot endorse their own report.
+    pub async fn endorse(
+        &self,
+        report_id: Uuid,
+        user_id: &str,
+        comment: Option<&str>,
+    ) -> Result<ReportEndorsement> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let report = sqlx::query!(
+            r#"
+            SELECT status as "status: ReportStatus", user_id
+            FROM reports
+            WHERE id = $1
+            FOR UPDATE
+            "#,
+            report_id
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to lock report: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report {} not found", report_id)))?;
+
+        if !ENDORSABLE_STATUSES.contains(&report.status) {
+            return Err(AppError::Conflict(format!(
+                "Report {} cannot be endorsed while '{}'",
+                report_id, report.status
+            )));
+        }
+
+        if report.user_id.as_deref() == Some(user_id) {
+            return Err(AppError::BadRequest(
+                "You cannot endorse your own report".to_string(),
+            ));
+        }
+
+        let endorsement = sqlx::query_as!(
+            ReportEndorsement,
+            r#"
+            INSERT INTO report_endorsements (report_id, user_id, comment)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (report_id, user_id) DO NOTHING
+            RETURNING id, report_id, user_id, comment, created_at
+            "#,
+            report_id,
+            user_id,
+            comment
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to create endorsement: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| {
+            AppError::Conflict(format!("You have already endorsed report {}", report_id))
+        })?;
+
+        sqlx::query!(
+            "UPDATE reports SET endorsement_count = endorsement_count + 1 WHERE id = $1",
+            report_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to update endorsement count: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit endorsement: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!("Report {} endorsed by {}", report_id, user_id);
+        Ok(endorsement)
+    }
+
+    /// Withdraw the caller's endorsement of a report
+    pub async fn withdraw(&self, report_id: Uuid, user_id: &str) -> Result<()> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let deleted = sqlx::query!(
+            "DELETE FROM report_endorsements WHERE report_id = $1 AND user_id = $2",
+            report_id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to delete endorsement: {:?}", e);
+            AppError::Database(e)
+        })?
+        .rows_affected();
+
+        if deleted == 0 {
+            return Err(AppError::NotFound(format!(
+                "You have not endorsed report {}",
+                report_id
+            )));
+        }
+
+        sqlx::query!(
+            r#"
+            UPDATE reports
+            SET endorsement_count = GREATEST(endorsement_count - 1, 0)
+            WHERE id = $1
+            "#,
+            report_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to update endorsement count: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit endorsement withdrawal: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!(
+            "Endorsement of report {} withdrawn by {}",
+            report_id,
+            user_id
+        );
+        Ok(())
+    }
+
+    /// List endorsements of a report (newest first)
+    /// Returns (endorsements, total_count)
+    pub async fn list_for_report(
+        &self,
+        report_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<ReportEndorsement>, i64)> {
+        let total = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!" FROM report_endorsements WHERE report_id = $1"#,
+            report_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to count endorsements: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let endorsements = sqlx::query_as!(
+            ReportEndorsement,
+            r#"
+            SELECT id, report_id, user_id, comment, created_at
+            FROM report_endorsements
+            WHERE report_id = $1
+            ORDER BY created_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+            report_id,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list endorsements: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok((endorsements, total))
+    }
+}
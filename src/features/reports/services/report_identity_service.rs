@@ -0,0 +1,145 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::reports::models::ReportIdentityReveal;
+
+/// Reporter identity of an anonymous report, returned by a reveal
+#[derive(Debug, Clone)]
+pub struct RevealedIdentity {
+    pub user_id: Option<String>,
+    pub adk_thread_id: Option<Uuid>,
+    pub reveal: ReportIdentityReveal,
+}
+
+/// Service for the audited break-glass access to anonymous reporters
+pub struct ReportIdentityService {
+    pool: PgPool,
+}
+
+impl ReportIdentityService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Reveal the reporter and conversation of an anonymous report
+    ///
+    /// The reveal is recorded in the audit log in the same transaction, so an identity is
+    /// never returned without a matching audit entry.
+    pub async fn reveal(
+        &self,
+        report_id: Uuid,
+        revealed_by: &str,
+        reason: &str,
+    ) -> Result<RevealedIdentity> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let report = sqlx::query!(
+            r#"
+            SELECT user_id, adk_thread_id, is_anonymous
+            FROM reports
+            WHERE id = $1
+            "#,
+            report_id
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report identity: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report {} not found", report_id)))?;
+
+        if !report.is_anonymous {
+            return Err(AppError::Conflict(format!(
+                "Report {} is not anonymous",
+                report_id
+            )));
+        }
+
+        let reveal = sqlx::query_as!(
+            ReportIdentityReveal,
+            r#"
+            INSERT INTO report_identity_reveals (report_id, revealed_by, reason)
+            VALUES ($1, $2, $3)
+            RETURNING id, report_id, revealed_by, reason, created_at
+            "#,
+            report_id,
+            revealed_by,
+            reason
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to record identity reveal: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit identity reveal: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::warn!(
+            "Identity of anonymous report {} revealed by {} (reveal {})",
+            report_id,
+            revealed_by,
+            reveal.id
+        );
+
+        Ok(RevealedIdentity {
+            user_id: report.user_id,
+            adk_thread_id: report.adk_thread_id,
+            reveal,
+        })
+    }
+
+    /// List identity reveals, newest first
+    /// Returns (reveals, total_count)
+    pub async fn list_reveals(
+        &self,
+        report_id: Option<Uuid>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<ReportIdentityReveal>, i64)> {
+        let total = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM report_identity_reveals
+            WHERE ($1::uuid IS NULL OR report_id = $1)
+            "#,
+            report_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to count identity reveals: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let reveals = sqlx::query_as!(
+            ReportIdentityReveal,
+            r#"
+            SELECT id, report_id, revealed_by, reason, created_at
+            FROM report_identity_reveals
+            WHERE ($1::uuid IS NULL OR report_id = $1)
+            ORDER BY created_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+            report_id,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list identity reveals: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok((reveals, total))
+    }
+}
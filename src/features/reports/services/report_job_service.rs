@@ -1,9 +1,37 @@
 use rust_decimal::Decimal;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 
 use crate::core::error::{AppError, Result};
-use crate::features::reports::models::{CreateReportJob, ReportJob, ReportJobStatus};
+use crate::features::reports::models::{
+    CreateReportJob, ReportJob, ReportJobError, ReportJobMetrics, ReportJobStage, ReportJobStatus,
+    ReportStatus,
+};
+
+/// Postgres NOTIFY channel fired when a report job is queued (see migration trigger)
+pub const REPORT_JOBS_CHANNEL: &str = "report_jobs";
+
+/// Delay before the first retry of a failed job
+const RETRY_BASE_DELAY_SECS: i64 = 60;
+
+/// Upper bound for the retry delay
+const RETRY_MAX_DELAY_SECS: i64 = 60 * 60;
+
+/// Delay before the next attempt after `retry_count` failures (exponential, capped)
+pub fn retry_delay_secs(retry_count: i32) -> i64 {
+    let exponent = retry_count.saturating_sub(1).clamp(0, 20) as u32;
+    RETRY_BASE_DELAY_SECS
+        .saturating_mul(2_i64.pow(exponent))
+        .min(RETRY_MAX_DELAY_SECS)
+}
+
+/// Error for a job whose lease expired and was recovered or claimed by another worker
+fn lost_lease(job_id: Uuid, worker_id: &str) -> AppError {
+    AppError::Conflict(format!(
+        "Report job {} is no longer held by {}",
+        job_id, worker_id
+    ))
+}
 
 /// Service for report job operations (background processing queue)
 pub struct ReportJobService {
@@ -17,6 +45,15 @@ impl ReportJobService {
 
     /// Create a new report job
     pub async fn create(&self, data: &CreateReportJob) -> Result<ReportJob> {
+        let mut conn = self.pool.acquire().await.map_err(|e| {
+            tracing::error!("Failed to acquire connection: {:?}", e);
+            AppError::Database(e)
+        })?;
+        Self::insert(&mut conn, data).await
+    }
+
+    /// Insert a report job using the caller's connection or transaction
+    pub async fn insert(conn: &mut PgConnection, data: &CreateReportJob) -> Result<ReportJob> {
         let confidence_decimal = data
             .confidence_score
             .map(|c| Decimal::try_from(c.clamp(0.0, 1.0)).unwrap_or_else(|_| Decimal::new(50, 2)));
@@ -29,13 +66,15 @@ impl ReportJobService {
             RETURNING
                 id, report_id, status as "status: ReportJobStatus",
                 confidence_score, retry_count, error_message,
-                submitted_at, processed_at, last_attempt_at, created_at
+                submitted_at, processed_at, last_attempt_at, created_at,
+                locked_by, locked_until, heartbeat_at,
+                stage as "stage: ReportJobStage", extracted_data, next_attempt_at
             "#,
             data.report_id,
             confidence_decimal,
             ReportJobStatus::Submitted as ReportJobStatus
         )
-        .fetch_one(&self.pool)
+        .fetch_one(conn)
         .await
         .map_err(|e| {
             tracing::error!("Failed to create report job: {:?}", e);
@@ -59,7 +98,9 @@ impl ReportJobService {
             SELECT
                 id, report_id, status as "status: ReportJobStatus",
                 confidence_score, retry_count, error_message,
-                submitted_at, processed_at, last_attempt_at, created_at
+                submitted_at, processed_at, last_attempt_at, created_at,
+                locked_by, locked_until, heartbeat_at,
+                stage as "stage: ReportJobStage", extracted_data, next_attempt_at
             FROM report_jobs
             WHERE report_id = $1
             "#,
@@ -73,62 +114,168 @@ impl ReportJobService {
         })
     }
 
-    /// Fetch pending jobs for processing
-    pub async fn fetch_pending(&self, max_retries: i32, batch_size: i64) -> Result<Vec<ReportJob>> {
+    /// Claim submitted jobs for processing by one worker
+    ///
+    /// Rows are picked with `FOR UPDATE SKIP LOCKED`, so concurrent workers never claim
+    /// the same job. Claimed jobs move to `processing` with a lease of `lease_secs`.
+    pub async fn claim_pending(
+        &self,
+        worker_id: &str,
+        max_retries: i32,
+        batch_size: i64,
+        lease_secs: i64,
+    ) -> Result<Vec<ReportJob>> {
         sqlx::query_as!(
             ReportJob,
             r#"
-            SELECT
-                id, report_id, status as "status: ReportJobStatus",
-                confidence_score, retry_count, error_message,
-                submitted_at, processed_at, last_attempt_at, created_at
-            FROM report_jobs
-            WHERE status = 'submitted'
-            AND retry_count < $1
-            ORDER BY submitted_at ASC
-            LIMIT $2
+            UPDATE report_jobs j
+            SET status = 'processing',
+                locked_by = $1,
+                locked_until = NOW() + make_interval(secs => $4),
+                heartbeat_at = NOW(),
+                last_attempt_at = NOW()
+            FROM (
+                SELECT id FROM report_jobs
+                WHERE status = 'submitted'
+                AND retry_count < $2
+                AND next_attempt_at <= NOW()
+                ORDER BY next_attempt_at ASC, submitted_at ASC
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            ) claimable
+            WHERE j.id = claimable.id
+            RETURNING
+                j.id, j.report_id, j.status as "status: ReportJobStatus",
+                j.confidence_score, j.retry_count, j.error_message,
+                j.submitted_at, j.processed_at, j.last_attempt_at, j.created_at,
+                j.locked_by, j.locked_until, j.heartbeat_at,
+                j.stage as "stage: ReportJobStage", j.extracted_data, j.next_attempt_at
             "#,
+            worker_id,
             max_retries,
-            batch_size
+            batch_size,
+            lease_secs as f64
         )
         .fetch_all(&self.pool)
         .await
         .map_err(|e| {
-            tracing::error!("Failed to fetch pending report jobs: {:?}", e);
+            tracing::error!("Failed to claim pending report jobs: {:?}", e);
             AppError::Database(e)
         })
     }
 
-    /// Update job status
-    pub async fn update_status(&self, job_id: Uuid, status: ReportJobStatus) -> Result<()> {
-        sqlx::query!(
+    /// Extend the leases of all jobs a worker is processing
+    ///
+    /// Returns the number of leases extended; 0 while a job is being processed means the
+    /// worker lost its lease.
+    pub async fn heartbeat(&self, worker_id: &str, lease_secs: i64) -> Result<u64> {
+        let result = sqlx::query!(
             r#"
             UPDATE report_jobs
-            SET status = $2, last_attempt_at = NOW()
-            WHERE id = $1
+            SET locked_until = NOW() + make_interval(secs => $2), heartbeat_at = NOW()
+            WHERE locked_by = $1 AND status = 'processing'
             "#,
-            job_id,
-            status as ReportJobStatus
+            worker_id,
+            lease_secs as f64
         )
         .execute(&self.pool)
         .await
         .map_err(|e| {
-            tracing::error!("Failed to update report job status: {:?}", e);
+            tracing::error!("Failed to extend report job leases: {:?}", e);
             AppError::Database(e)
         })?;
 
+        Ok(result.rows_affected())
+    }
+
+    /// Recover jobs stuck in `processing` after their worker crashed
+    ///
+    /// Jobs whose lease expired go back to `submitted` (counting as a failed attempt),
+    /// or to `failed` once retries are exhausted. Returns the number of jobs recovered.
+    pub async fn recover_expired(&self, max_retries: i32) -> Result<u64> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE report_jobs
+            SET status = CASE
+                    WHEN retry_count + 1 >= $1 THEN 'failed'::report_job_status
+                    ELSE 'submitted'::report_job_status
+                END,
+                retry_count = retry_count + 1,
+                error_message = 'Processing lease expired (worker '
+                    || COALESCE(locked_by, 'unknown') || ' stopped responding)',
+                locked_by = NULL,
+                locked_until = NULL
+            WHERE status = 'processing'
+            -- Jobs claimed before leases existed get a grace period
+            AND COALESCE(locked_until, last_attempt_at + INTERVAL '15 minutes') < NOW()
+            "#,
+            max_retries
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to recover expired report jobs: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let recovered = result.rows_affected();
+        if recovered > 0 {
+            tracing::warn!("Recovered {} report jobs with expired leases", recovered);
+        }
+        Ok(recovered)
+    }
+
+    /// Persist pipeline progress so a retry resumes at `next_stage`
+    ///
+    /// `extracted_data` is only written when given; earlier checkpoints are kept.
+    /// Fails with a conflict when `worker_id` no longer holds the job's lease.
+    pub async fn save_checkpoint(
+        &self,
+        job_id: Uuid,
+        worker_id: &str,
+        next_stage: ReportJobStage,
+        extracted_data: Option<&serde_json::Value>,
+    ) -> Result<()> {
+        let updated = sqlx::query!(
+            r#"
+            UPDATE report_jobs
+            SET stage = $3, extracted_data = COALESCE($4, extracted_data)
+            WHERE id = $1 AND locked_by = $2 AND status = 'processing'
+            "#,
+            job_id,
+            worker_id,
+            next_stage as ReportJobStage,
+            extracted_data
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to save report job checkpoint: {:?}", e);
+            AppError::Database(e)
+        })?
+        .rows_affected();
+
+        if updated == 0 {
+            return Err(lost_lease(job_id, worker_id));
+        }
+
+        tracing::debug!("Report job {} checkpointed at stage {}", job_id, next_stage);
         Ok(())
     }
 
     /// Mark job as completed
-    pub async fn mark_completed(&self, job_id: Uuid) -> Result<()> {
-        sqlx::query!(
+    ///
+    /// Fails with a conflict when `worker_id` no longer holds the job's lease.
+    pub async fn mark_completed(&self, job_id: Uuid, worker_id: &str) -> Result<()> {
+        let updated = sqlx::query!(
             r#"
             UPDATE report_jobs
-            SET status = $2, processed_at = NOW(), last_attempt_at = NOW()
-            WHERE id = $1
+            SET status = $3, processed_at = NOW(), last_attempt_at = NOW(),
+                locked_by = NULL, locked_until = NULL
+            WHERE id = $1 AND locked_by = $2 AND status = 'processing'
             "#,
             job_id,
+            worker_id,
             ReportJobStatus::Completed as ReportJobStatus
         )
         .execute(&self.pool)
@@ -136,16 +283,26 @@ impl ReportJobService {
         .map_err(|e| {
             tracing::error!("Failed to mark report job as completed: {:?}", e);
             AppError::Database(e)
-        })?;
+        })?
+        .rows_affected();
+
+        if updated == 0 {
+            return Err(lost_lease(job_id, worker_id));
+        }
 
         tracing::info!("Report job {} marked as completed", job_id);
         Ok(())
     }
 
     /// Mark job as failed with error message
+    ///
+    /// The failure is logged against the job's current stage. The job is retried after an
+    /// exponential backoff until `max_retries` is reached, then marked as failed.
+    /// Fails with a conflict when `worker_id` no longer holds the job's lease.
     pub async fn mark_failed(
         &self,
         job_id: Uuid,
+        worker_id: &str,
         current_retry_count: i32,
         max_retries: i32,
         error_message: &str,
@@ -156,43 +313,597 @@ impl ReportJobService {
         } else {
             ReportJobStatus::Submitted // Keep as submitted for retry
         };
+        let delay_secs = retry_delay_secs(new_retry_count);
 
-        sqlx::query!(
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let stage = sqlx::query_scalar!(
             r#"
             UPDATE report_jobs
-            SET status = $2, error_message = $3, retry_count = $4, last_attempt_at = NOW()
-            WHERE id = $1
+            SET status = $2, error_message = $3, retry_count = $4, last_attempt_at = NOW(),
+                next_attempt_at = NOW() + make_interval(secs => $5),
+                locked_by = NULL, locked_until = NULL
+            WHERE id = $1 AND locked_by = $6 AND status = 'processing'
+            RETURNING stage as "stage: ReportJobStage"
             "#,
             job_id,
             new_status as ReportJobStatus,
             error_message,
-            new_retry_count
+            new_retry_count,
+            delay_secs as f64,
+            worker_id
         )
-        .execute(&self.pool)
+        .fetch_optional(&mut *tx)
         .await
         .map_err(|e| {
             tracing::error!("Failed to mark report job as failed: {:?}", e);
             AppError::Database(e)
+        })?
+        .ok_or_else(|| lost_lease(job_id, worker_id))?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO report_job_errors (job_id, stage, attempt, error)
+            VALUES ($1, $2, $3, $4)
+            "#,
+            job_id,
+            stage as ReportJobStage,
+            new_retry_count,
+            error_message
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to record report job error: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit report job failure: {:?}", e);
+            AppError::Database(e)
         })?;
 
         if new_retry_count >= max_retries {
             tracing::warn!(
-                "Report job {} permanently failed after {} retries",
+                "Report job {} permanently failed at stage {} after {} retries",
                 job_id,
+                stage,
                 max_retries
             );
         } else {
             tracing::info!(
-                "Report job {} marked for retry ({}/{})",
+                "Report job {} failed at stage {}, retry {}/{} in {}s",
                 job_id,
+                stage,
                 new_retry_count,
-                max_retries
+                max_retries,
+                delay_secs
             );
         }
 
         Ok(())
     }
 
+    // ===== Admin console =====
+
+    /// Get job by ID
+    pub async fn get_by_id(&self, id: Uuid) -> Result<ReportJob> {
+        sqlx::query_as!(
+            ReportJob,
+            r#"
+            SELECT
+                id, report_id, status as "status: ReportJobStatus",
+                confidence_score, retry_count, error_message,
+                submitted_at, processed_at, last_attempt_at, created_at,
+                locked_by, locked_until, heartbeat_at,
+                stage as "stage: ReportJobStage", extracted_data, next_attempt_at
+            FROM report_jobs
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report job: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report job {} not found", id)))
+    }
+
+    /// List jobs, optionally filtered by status and report (newest first)
+    /// Returns (jobs, total_count)
+    pub async fn list(
+        &self,
+        status: Option<ReportJobStatus>,
+        report_id: Option<Uuid>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<ReportJob>, i64)> {
+        let total = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM report_jobs
+            WHERE ($1::report_job_status IS NULL OR status = $1)
+            AND ($2::uuid IS NULL OR report_id = $2)
+            "#,
+            status as Option<ReportJobStatus>,
+            report_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to count report jobs: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let jobs = sqlx::query_as!(
+            ReportJob,
+            r#"
+            SELECT
+                id, report_id, status as "status: ReportJobStatus",
+                confidence_score, retry_count, error_message,
+                submitted_at, processed_at, last_attempt_at, created_at,
+                locked_by, locked_until, heartbeat_at,
+                stage as "stage: ReportJobStage", extracted_data, next_attempt_at
+            FROM report_jobs
+            WHERE ($1::report_job_status IS NULL OR status = $1)
+            AND ($2::uuid IS NULL OR report_id = $2)
+            ORDER BY created_at DESC
+            LIMIT $3 OFFSET $4
+            "#,
+            status as Option<ReportJobStatus>,
+            report_id,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list report jobs: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok((jobs, total))
+    }
+
+    /// Failed attempts of a job (oldest first)
+    pub async fn list_errors(&self, job_id: Uuid) -> Result<Vec<ReportJobError>> {
+        sqlx::query_as!(
+            ReportJobError,
+            r#"
+            SELECT id, job_id, stage as "stage: ReportJobStage", attempt, error, created_at
+            FROM report_job_errors
+            WHERE job_id = $1
+            ORDER BY created_at ASC
+            "#,
+            job_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list report job errors: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Queue depth and age metrics
+    pub async fn metrics(&self) -> Result<ReportJobMetrics> {
+        sqlx::query_as!(
+            ReportJobMetrics,
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE status = 'submitted' AND next_attempt_at <= NOW()) as "due!",
+                COUNT(*) FILTER (WHERE status = 'submitted' AND next_attempt_at > NOW()) as "scheduled!",
+                COUNT(*) FILTER (WHERE status = 'processing') as "processing!",
+                COUNT(*) FILTER (WHERE status = 'processing' AND locked_until < NOW()) as "expired_leases!",
+                COUNT(*) FILTER (WHERE status = 'completed') as "completed!",
+                COUNT(*) FILTER (WHERE status = 'failed') as "failed!",
+                COUNT(*) FILTER (WHERE status = 'cancelled') as "cancelled!",
+                MIN(submitted_at) FILTER (WHERE status IN ('submitted', 'processing')) as oldest_pending_at
+            FROM report_jobs
+            "#
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report job metrics: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Put a failed or cancelled job back in the queue
+    ///
+    /// The retry budget is reset; the job resumes at its checkpointed stage.
+    pub async fn requeue(&self, id: Uuid, requested_by: &str) -> Result<ReportJob> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let status = Self::lock_status(&mut tx, id).await?;
+        if !matches!(status, ReportJobStatus::Failed | ReportJobStatus::Cancelled) {
+            return Err(AppError::Conflict(format!(
+                "Report job {} is '{}'; only failed or cancelled jobs can be requeued",
+                id, status
+            )));
+        }
+
+        let job = sqlx::query_as!(
+            ReportJob,
+            r#"
+            UPDATE report_jobs
+            SET status = 'submitted', retry_count = 0, next_attempt_at = NOW()
+            WHERE id = $1
+            RETURNING
+                id, report_id, status as "status: ReportJobStatus",
+                confidence_score, retry_count, error_message,
+                submitted_at, processed_at, last_attempt_at, created_at,
+                locked_by, locked_until, heartbeat_at,
+                stage as "stage: ReportJobStage", extracted_data, next_attempt_at
+            "#,
+            id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to requeue report job: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Self::wake_workers(&mut tx).await?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit report job requeue: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!(
+            "Report job {} requeued at stage {} by {}",
+            id,
+            job.stage,
+            requested_by
+        );
+        Ok(job)
+    }
+
+    /// Resume the job of a report held at the moderate stage, using the caller's transaction
+    /// Returns whether a held job was found
+    pub async fn resume_held(conn: &mut PgConnection, report_id: Uuid) -> Result<bool> {
+        let resumed = sqlx::query!(
+            r#"
+            UPDATE report_jobs
+            SET status = 'submitted', retry_count = 0, next_attempt_at = NOW()
+            WHERE report_id = $1 AND status = 'completed' AND stage = 'moderate'
+            "#,
+            report_id
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to resume held report job: {:?}", e);
+            AppError::Database(e)
+        })?
+        .rows_affected();
+
+        if resumed > 0 {
+            Self::wake_workers(conn).await?;
+        }
+
+        Ok(resumed > 0)
+    }
+
+    /// Put all failed jobs back in the queue
+    /// Returns the number of requeued jobs
+    pub async fn requeue_all_failed(&self, requested_by: &str) -> Result<u64> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let requeued = sqlx::query!(
+            r#"
+            UPDATE report_jobs
+            SET status = 'submitted', retry_count = 0, next_attempt_at = NOW()
+            WHERE status = 'failed'
+            "#
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to requeue failed report jobs: {:?}", e);
+            AppError::Database(e)
+        })?
+        .rows_affected();
+
+        if requeued > 0 {
+            Self::wake_workers(&mut tx).await?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit report job requeue: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!(
+            "{} failed report jobs requeued by {}",
+            requeued,
+            requested_by
+        );
+        Ok(requeued)
+    }
+
+    /// Cancel a queued or failed job so it is never processed
+    ///
+    /// Jobs that are being processed cannot be cancelled; the worker holds their lease.
+    pub async fn cancel(
+        &self,
+        id: Uuid,
+        requested_by: &str,
+        reason: Option<&str>,
+    ) -> Result<ReportJob> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let status = Self::lock_status(&mut tx, id).await?;
+        if !matches!(status, ReportJobStatus::Submitted | ReportJobStatus::Failed) {
+            return Err(AppError::Conflict(format!(
+                "Report job {} is '{}'; only submitted or failed jobs can be cancelled",
+                id, status
+            )));
+        }
+
+        let message = match reason {
+            Some(reason) => format!("Cancelled by {}: {}", requested_by, reason),
+            None => format!("Cancelled by {}", requested_by),
+        };
+
+        let job = sqlx::query_as!(
+            ReportJob,
+            r#"
+            UPDATE report_jobs
+            SET status = 'cancelled', error_message = $2
+            WHERE id = $1
+            RETURNING
+                id, report_id, status as "status: ReportJobStatus",
+                confidence_score, retry_count, error_message,
+                submitted_at, processed_at, last_attempt_at, created_at,
+                locked_by, locked_until, heartbeat_at,
+                stage as "stage: ReportJobStage", extracted_data, next_attempt_at
+            "#,
+            id,
+            message
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to cancel report job: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit report job cancellation: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!("Report job {} cancelled by {}", id, requested_by);
+        Ok(job)
+    }
+
+    /// Run a completed job again from the extract stage
+    ///
+    /// The checkpointed extraction is discarded, so the conversation is sent to the LLM again.
+    /// Draft and pending reports go through the whole pipeline; for verified, in-progress and
+    /// resolved reports only the categories, tags and location are refreshed and the status
+    /// is kept (see `ReportJobStage::next_reextract`). Rejected reports must be reopened
+    /// first, and no other job of the report may be active.
+    pub async fn force_reextract(&self, id: Uuid, requested_by: &str) -> Result<ReportJob> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let status = Self::lock_status(&mut tx, id).await?;
+        if status != ReportJobStatus::Completed {
+            return Err(AppError::Conflict(format!(
+                "Report job {} is '{}'; only completed jobs can be re-extracted",
+                id, status
+            )));
+        }
+
+        let report = sqlx::query!(
+            r#"
+            SELECT r.id, r.status as "status: ReportStatus",
+                EXISTS (
+                    SELECT 1 FROM report_jobs o
+                    WHERE o.report_id = r.id AND o.id <> $1
+                    AND o.status IN ('submitted', 'processing')
+                ) as "has_active_job!"
+            FROM report_jobs j
+            JOIN reports r ON r.id = j.report_id
+            WHERE j.id = $1
+            FOR UPDATE OF r
+            "#,
+            id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report of job: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        if report.status == ReportStatus::Rejected {
+            return Err(AppError::Conflict(format!(
+                "Report {} is rejected; reopen it before re-extracting",
+                report.id
+            )));
+        }
+        if report.has_active_job {
+            return Err(AppError::Conflict(format!(
+                "Report {} already has a job in the queue",
+                report.id
+            )));
+        }
+
+        let job = sqlx::query_as!(
+            ReportJob,
+            r#"
+            UPDATE report_jobs
+            SET status = 'submitted', stage = 'extract', extracted_data = NULL,
+                retry_count = 0, error_message = NULL, processed_at = NULL,
+                next_attempt_at = NOW()
+            WHERE id = $1
+            RETURNING
+                id, report_id, status as "status: ReportJobStatus",
+                confidence_score, retry_count, error_message,
+                submitted_at, processed_at, last_attempt_at, created_at,
+                locked_by, locked_until, heartbeat_at,
+                stage as "stage: ReportJobStage", extracted_data, next_attempt_at
+            "#,
+            id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to reset report job: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Self::wake_workers(&mut tx).await?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit report job re-extraction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!(
+            "Report job {} queued for re-extraction of report {} by {}",
+            id,
+            report.id,
+            requested_by
+        );
+        Ok(job)
+    }
+
+    /// Whether a job of the report is queued or being processed
+    pub async fn has_active_job(conn: &mut PgConnection, report_id: Uuid) -> Result<bool> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM report_jobs
+                WHERE report_id = $1 AND status IN ('submitted', 'processing')
+            ) as "exists!"
+            "#,
+            report_id
+        )
+        .fetch_one(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check active report jobs: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Process a report again from the extract stage, using the caller's transaction
+    ///
+    /// The latest job of the report is reset (or a job is created if it never had one);
+    /// nothing changes while a job is still active.
+    pub async fn reprocess_report(conn: &mut PgConnection, report_id: Uuid) -> Result<()> {
+        let latest = sqlx::query!(
+            r#"
+            SELECT id, status as "status: ReportJobStatus"
+            FROM report_jobs
+            WHERE report_id = $1
+            ORDER BY created_at DESC
+            LIMIT 1
+            FOR UPDATE
+            "#,
+            report_id
+        )
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to lock latest report job: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let Some(latest) = latest else {
+            let data = CreateReportJob {
+                report_id,
+                confidence_score: None,
+            };
+            Self::insert(conn, &data).await?;
+            return Ok(());
+        };
+
+        if matches!(
+            latest.status,
+            ReportJobStatus::Submitted | ReportJobStatus::Processing
+        ) {
+            return Ok(());
+        }
+
+        sqlx::query!(
+            r#"
+            UPDATE report_jobs
+            SET status = 'submitted', stage = 'extract', extracted_data = NULL,
+                retry_count = 0, error_message = NULL, processed_at = NULL,
+                next_attempt_at = NOW()
+            WHERE id = $1
+            "#,
+            latest.id
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to reset report job: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Self::wake_workers(conn).await?;
+
+        tracing::info!(
+            "Report job {} queued to process report {} again",
+            latest.id,
+            report_id
+        );
+        Ok(())
+    }
+
+    /// Lock a job row and return its status
+    async fn lock_status(conn: &mut PgConnection, id: Uuid) -> Result<ReportJobStatus> {
+        sqlx::query_scalar!(
+            r#"SELECT status as "status: ReportJobStatus" FROM report_jobs WHERE id = $1 FOR UPDATE"#,
+            id
+        )
+        .fetch_optional(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to lock report job: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report job {} not found", id)))
+    }
+
+    /// Wake up processors once the transaction commits (the insert trigger only covers new jobs)
+    async fn wake_workers(conn: &mut PgConnection) -> Result<()> {
+        sqlx::query!("SELECT pg_notify($1, '')", REPORT_JOBS_CHANNEL)
+            .execute(conn)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to notify report processors: {:?}", e);
+                AppError::Database(e)
+            })?;
+
+        Ok(())
+    }
+
     /// Count reports created today by user (for rate limiting)
     #[allow(dead_code)]
     pub async fn count_user_reports_today(&self, user_id: &str) -> Result<i64> {
@@ -217,3 +928,18 @@ impl ReportJobService {
         Ok(count)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay_is_exponential_and_capped() {
+        assert_eq!(retry_delay_secs(0), 60);
+        assert_eq!(retry_delay_secs(1), 60);
+        assert_eq!(retry_delay_secs(2), 120);
+        assert_eq!(retry_delay_secs(3), 240);
+        assert_eq!(retry_delay_secs(10), RETRY_MAX_DELAY_SECS);
+        assert_eq!(retry_delay_secs(i32::MAX), RETRY_MAX_DELAY_SECS);
+    }
+}
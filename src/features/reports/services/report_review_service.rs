@@ -0,0 +1,484 @@
+use std::sync::Arc;
+
+use balungpisah_adk::{MessageStorage, PostgresStorage, Role};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::notifications::models::NotificationPayload;
+use crate::features::notifications::NotificationService;
+use crate::features::reports::models::{
+    CreateReportJob, ReportReview, ReportReviewStatus, ReportStatus,
+};
+use crate::features::reports::services::{redact_pii, ReportJobService, ReportService};
+
+/// Maximum length of the conversation excerpt shown in the review queue
+const EXCERPT_MAX_CHARS: usize = 600;
+
+/// Citizen messages of a conversation, joined and cut to `max_chars`
+pub fn conversation_excerpt<'a>(
+    citizen_messages: impl IntoIterator<Item = &'a str>,
+    max_chars: usize,
+) -> String {
+    let joined = citizen_messages
+        .into_iter()
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if joined.chars().count() <= max_chars {
+        return joined;
+    }
+
+    let mut excerpt: String = joined.chars().take(max_chars).collect();
+    excerpt.push('…');
+    excerpt
+}
+
+/// Service for the curator review queue of low-confidence submissions
+pub struct ReportReviewService {
+    pool: PgPool,
+    adk_storage: Arc<PostgresStorage>,
+}
+
+impl ReportReviewService {
+    pub fn new(pool: PgPool, adk_storage: Arc<PostgresStorage>) -> Self {
+        Self { pool, adk_storage }
+    }
+
+    // ===== Producers =====
+
+    /// Whether a curator approved the report for processing
+    pub async fn is_approved(pool: &PgPool, report_id: Uuid) -> Result<bool> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM report_reviews WHERE report_id = $1 AND status = 'approved'
+            ) as "exists!"
+            "#,
+            report_id
+        )
+        .fetch_one(pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check report review: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Put a submission in the review queue
+    ///
+    /// No-op if it is already queued; a rejected entry (report reopened since) is queued again.
+    pub async fn enqueue(
+        pool: &PgPool,
+        report_id: Uuid,
+        confidence_score: Option<f64>,
+        confidence_threshold: f64,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO report_reviews (report_id, confidence_score, confidence_threshold)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (report_id) DO UPDATE
+            SET status = 'pending', confidence_score = EXCLUDED.confidence_score,
+                confidence_threshold = EXCLUDED.confidence_threshold,
+                reviewed_by = NULL, reviewed_at = NULL, review_note = NULL, updated_at = NOW()
+            WHERE report_reviews.status = 'rejected'
+            "#,
+            report_id,
+            confidence_score,
+            confidence_threshold
+        )
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to queue report for review: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!(
+            "Report {} queued for review (confidence={:?}, threshold={:.2})",
+            report_id,
+            confidence_score,
+            confidence_threshold
+        );
+        Ok(())
+    }
+
+    // ===== Queue =====
+
+    /// Get a review queue entry
+    pub async fn get(&self, id: Uuid) -> Result<ReportReview> {
+        sqlx::query_as!(
+            ReportReview,
+            r#"
+            SELECT
+                rv.id, rv.report_id, r.reference_number,
+                r.status as "report_status: ReportStatus",
+                r.user_id, r.adk_thread_id, r.platform, r.is_anonymous,
+                rv.confidence_score, rv.confidence_threshold,
+                rv.status as "status: ReportReviewStatus",
+                rv.info_request, rv.info_requested_at,
+                rv.reviewed_by, rv.reviewed_at, rv.review_note, rv.created_at
+            FROM report_reviews rv
+            JOIN reports r ON r.id = rv.report_id
+            WHERE rv.id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report review: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report review {} not found", id)))
+    }
+
+    /// List review queue entries (oldest first)
+    ///
+    /// Without a status filter only open entries (pending and info requested) are returned.
+    pub async fn list(
+        &self,
+        status: Option<ReportReviewStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<ReportReview>, i64)> {
+        let items = sqlx::query_as!(
+            ReportReview,
+            r#"
+            SELECT
+                rv.id, rv.report_id, r.reference_number,
+                r.status as "report_status: ReportStatus",
+                r.user_id, r.adk_thread_id, r.platform, r.is_anonymous,
+                rv.confidence_score, rv.confidence_threshold,
+                rv.status as "status: ReportReviewStatus",
+                rv.info_request, rv.info_requested_at,
+                rv.reviewed_by, rv.reviewed_at, rv.review_note, rv.created_at
+            FROM report_reviews rv
+            JOIN reports r ON r.id = rv.report_id
+            WHERE CASE
+                WHEN $1::report_review_status IS NULL
+                    THEN rv.status IN ('pending', 'info_requested')
+                ELSE rv.status = $1
+            END
+            ORDER BY rv.created_at ASC
+            LIMIT $2 OFFSET $3
+            "#,
+            status as Option<ReportReviewStatus>,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list report reviews: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let total = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!" FROM report_reviews
+            WHERE CASE
+                WHEN $1::report_review_status IS NULL
+                    THEN status IN ('pending', 'info_requested')
+                ELSE status = $1
+            END
+            "#,
+            status as Option<ReportReviewStatus>
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to count report reviews: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok((items, total))
+    }
+
+    /// Citizen side of the report's conversation, shortened for the queue
+    ///
+    /// Best effort: returns None if the thread cannot be read. Personal data is redacted
+    /// from excerpts of anonymous reports.
+    pub async fn excerpt(&self, review: &ReportReview) -> Option<String> {
+        let thread_id = review.adk_thread_id?;
+
+        let messages = match self.adk_storage.get_thread_messages(thread_id).await {
+            Ok(messages) => messages,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to fetch conversation {} for review {}: {:?}",
+                    thread_id,
+                    review.id,
+                    e
+                );
+                return None;
+            }
+        };
+
+        let texts: Vec<String> = messages
+            .iter()
+            .filter(|m| matches!(m.role, Role::User))
+            .map(|m| m.text())
+            .collect();
+
+        let excerpt = conversation_excerpt(texts.iter().map(String::as_str), EXCERPT_MAX_CHARS);
+
+        // Curators still need the content of anonymous reports, but not who sent it
+        if review.is_anonymous {
+            return Some(redact_pii(&excerpt, true).text);
+        }
+        Some(excerpt)
+    }
+
+    // ===== Decisions =====
+
+    /// Approve a submission and queue it for extraction
+    pub async fn approve(
+        &self,
+        id: Uuid,
+        reviewer_id: &str,
+        note: Option<&str>,
+    ) -> Result<ReportReview> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let (report_id, confidence_score) = Self::lock_open(&mut tx, id).await?;
+
+        let current = ReportService::lock_status(&mut tx, report_id).await?;
+        if current != ReportStatus::Pending {
+            return Err(AppError::Conflict(format!(
+                "Report {} is '{}' and can no longer be approved for processing",
+                report_id, current
+            )));
+        }
+
+        Self::set_status(&mut tx, id, ReportReviewStatus::Approved, reviewer_id, note).await?;
+
+        ReportJobService::insert(
+            &mut tx,
+            &CreateReportJob {
+                report_id,
+                confidence_score,
+            },
+        )
+        .await?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit review approval: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!(
+            "Report {} approved for processing by {}",
+            report_id,
+            reviewer_id
+        );
+        self.get(id).await
+    }
+
+    /// Ask the citizen for more information (the entry stays in the queue)
+    pub async fn request_info(
+        &self,
+        id: Uuid,
+        reviewer_id: &str,
+        message: &str,
+    ) -> Result<ReportReview> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let (report_id, _) = Self::lock_open(&mut tx, id).await?;
+
+        let report = sqlx::query!(
+            r#"
+            UPDATE report_reviews rv
+            SET status = 'info_requested', info_request = $2, info_requested_at = NOW(),
+                reviewed_by = $3, updated_at = NOW()
+            FROM reports r
+            WHERE rv.id = $1 AND r.id = rv.report_id
+            RETURNING r.user_id, r.reference_number
+            "#,
+            id,
+            message,
+            reviewer_id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to request report information: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        if let Some(user_id) = report.user_id.as_deref() {
+            let payload = NotificationPayload::ReportInfoRequested {
+                report_id,
+                reference_number: report.reference_number,
+                message: message.to_string(),
+            };
+            NotificationService::notify(&mut tx, user_id, &payload).await?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit information request: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!(
+            "More information requested for report {} by {}",
+            report_id,
+            reviewer_id
+        );
+        self.get(id).await
+    }
+
+    /// Reject a submission with a reason shown to the citizen
+    pub async fn reject(
+        &self,
+        id: Uuid,
+        reviewer_id: &str,
+        role: &str,
+        reason: &str,
+    ) -> Result<ReportReview> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let (report_id, _) = Self::lock_open(&mut tx, id).await?;
+
+        let current = ReportService::lock_status(&mut tx, report_id).await?;
+        ReportService::ensure_transition(report_id, current, ReportStatus::Rejected)?;
+
+        sqlx::query!(
+            r#"
+            UPDATE reports
+            SET status = 'rejected', resolution_notes = $2, updated_at = NOW()
+            WHERE id = $1
+            "#,
+            report_id,
+            reason
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to reject reviewed report: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        ReportService::record_status_change(
+            &mut tx,
+            report_id,
+            Some(current),
+            ReportStatus::Rejected,
+            Some(reviewer_id),
+            role,
+            Some(reason),
+        )
+        .await?;
+
+        Self::set_status(
+            &mut tx,
+            id,
+            ReportReviewStatus::Rejected,
+            reviewer_id,
+            Some(reason),
+        )
+        .await?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit review rejection: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!("Report {} rejected in review by {}", report_id, reviewer_id);
+        self.get(id).await
+    }
+
+    // ===== Helpers =====
+
+    /// Lock an entry and ensure it can still be reviewed
+    ///
+    /// Returns (report_id, confidence_score).
+    async fn lock_open(
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+    ) -> Result<(Uuid, Option<f64>)> {
+        let row = sqlx::query!(
+            r#"
+            SELECT report_id, confidence_score, status as "status: ReportReviewStatus"
+            FROM report_reviews
+            WHERE id = $1
+            FOR UPDATE
+            "#,
+            id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to lock report review: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report review {} not found", id)))?;
+
+        if !row.status.is_open() {
+            return Err(AppError::Conflict(format!(
+                "Report review {} is already {}",
+                id, row.status
+            )));
+        }
+
+        Ok((row.report_id, row.confidence_score))
+    }
+
+    /// Close an entry with a decision
+    async fn set_status(
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+        status: ReportReviewStatus,
+        reviewer_id: &str,
+        note: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE report_reviews
+            SET status = $2, reviewed_by = $3, reviewed_at = NOW(), review_note = $4,
+                updated_at = NOW()
+            WHERE id = $1
+            "#,
+            id,
+            status as ReportReviewStatus,
+            reviewer_id,
+            note
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to update report review: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversation_excerpt_joins_and_truncates() {
+        let messages = ["  Jalan rusak  ", "", "di depan pasar"];
+        assert_eq!(
+            conversation_excerpt(messages, 100),
+            "Jalan rusak\ndi depan pasar"
+        );
+        assert_eq!(conversation_excerpt(messages, 5), "Jalan…");
+        assert_eq!(conversation_excerpt([], 10), "");
+    }
+}
@@ -0,0 +1,762 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use serde_json::{json, Map, Value};
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::reports::dtos::{
+    ReportCategoryInputDto, UpdateReportCategoriesDto, UpdateReportContentDto,
+    UpdateReportLocationDto, UpdateReportTagsDto,
+};
+use crate::features::reports::models::{
+    CreateReportRevision, ReportLanguage, ReportRevision, ReportRevisionSection,
+    ReportRevisionSource, ReportSeverity, ReportTagType,
+};
+use crate::features::reports::services::{RedactionService, ReportService};
+use crate::features::settings::{SettingKey, SettingsService};
+use crate::features::sla::SlaService;
+
+/// Fields that differ between two snapshots, as {"field": {"old": ..., "new": ...}}
+///
+/// Fields missing from one side count as null.
+pub fn diff_fields(old: &Value, new: &Value) -> Map<String, Value> {
+    let empty = Map::new();
+    let old = old.as_object().unwrap_or(&empty);
+    let new = new.as_object().unwrap_or(&empty);
+
+    old.keys()
+        .chain(new.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter_map(|field| {
+            let before = old.get(field).unwrap_or(&Value::Null);
+            let after = new.get(field).unwrap_or(&Value::Null);
+            (before != after).then(|| (field.clone(), json!({ "old": before, "new": after })))
+        })
+        .collect()
+}
+
+/// Curator making an edit and the reason given for it
+#[derive(Debug, Clone, Copy)]
+pub struct RevisionAuthor<'a> {
+    pub edited_by: &'a str,
+    pub role: &'a str,
+    pub reason: &'a str,
+}
+
+/// Service for curator edits of report content, categories, tags and location
+///
+/// Every change is stored as a revision with its editor, reason and field diff.
+pub struct ReportRevisionService {
+    pool: PgPool,
+    redaction_service: RedactionService,
+    settings_service: Arc<SettingsService>,
+}
+
+impl ReportRevisionService {
+    pub fn new(pool: PgPool, settings_service: Arc<SettingsService>) -> Self {
+        Self {
+            redaction_service: RedactionService::new(pool.clone()),
+            pool,
+            settings_service,
+        }
+    }
+
+    // ===== Recording =====
+
+    /// Record the difference between two snapshots as a revision
+    ///
+    /// Returns None without recording anything if the snapshots are equal. The caller must
+    /// hold the report row lock so revision numbers stay sequential.
+    pub async fn record(
+        conn: &mut PgConnection,
+        revision: &CreateReportRevision<'_>,
+        before: &Value,
+        after: &Value,
+    ) -> Result<Option<ReportRevision>> {
+        let changes = diff_fields(before, after);
+        if changes.is_empty() {
+            return Ok(None);
+        }
+
+        let revision = sqlx::query_as!(
+            ReportRevision,
+            r#"
+            INSERT INTO report_revisions
+                (report_id, revision_number, source, section, changes, reason, edited_by, editor_role)
+            SELECT $1, COALESCE(MAX(revision_number), 0) + 1, $2, $3, $4, $5, $6, $7
+            FROM report_revisions
+            WHERE report_id = $1
+            RETURNING
+                id, report_id, revision_number,
+                source as "source: ReportRevisionSource",
+                section as "section: ReportRevisionSection",
+                changes, reason, edited_by, editor_role, created_at
+            "#,
+            revision.report_id,
+            revision.source as ReportRevisionSource,
+            revision.section as ReportRevisionSection,
+            Value::Object(changes),
+            revision.reason,
+            revision.edited_by,
+            revision.editor_role
+        )
+        .fetch_one(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to record report revision: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(Some(revision))
+    }
+
+    /// Current title, description, timeline, impact and normalized translation of a report
+    pub async fn content_snapshot(conn: &mut PgConnection, report_id: Uuid) -> Result<Value> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                r.title, r.description, r.timeline, r.impact,
+                r.language as "language: ReportLanguage",
+                t.title as "normalized_title?",
+                t.description as "normalized_description?"
+            FROM reports r
+            LEFT JOIN report_translations t ON t.report_id = r.id AND t.language = 'id'
+            WHERE r.id = $1
+            "#,
+            report_id
+        )
+        .fetch_optional(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to read report content: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report {} not found", report_id)))?;
+
+        Ok(json!({
+            "title": row.title,
+            "description": row.description,
+            "timeline": row.timeline,
+            "impact": row.impact,
+            "language": row.language,
+            "normalized_title": row.normalized_title,
+            "normalized_description": row.normalized_description,
+        }))
+    }
+
+    async fn categories_snapshot(conn: &mut PgConnection, report_id: Uuid) -> Result<Value> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT c.slug, rc.severity as "severity: ReportSeverity"
+            FROM report_categories rc
+            JOIN categories c ON c.id = rc.category_id
+            WHERE rc.report_id = $1
+            ORDER BY c.slug
+            "#,
+            report_id
+        )
+        .fetch_all(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to read report categories: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let categories: Vec<Value> = rows
+            .into_iter()
+            .map(|r| json!({ "slug": r.slug, "severity": r.severity }))
+            .collect();
+        Ok(json!({ "categories": categories }))
+    }
+
+    /// Current tags of a report
+    pub async fn current_tags(
+        conn: &mut PgConnection,
+        report_id: Uuid,
+    ) -> Result<Vec<ReportTagType>> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT tag_type as "tag_type: ReportTagType"
+            FROM report_tags
+            WHERE report_id = $1
+            ORDER BY tag_type::TEXT
+            "#,
+            report_id
+        )
+        .fetch_all(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to read report tags: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    async fn tags_snapshot(conn: &mut PgConnection, report_id: Uuid) -> Result<Value> {
+        let tags = Self::current_tags(conn, report_id).await?;
+        Ok(json!({ "tags": tags }))
+    }
+
+    async fn location_snapshot(conn: &mut PgConnection, report_id: Uuid) -> Result<Value> {
+        let row = sqlx::query!(
+            r#"
+            SELECT raw_input, lat, lon, geocoding_source::TEXT as source,
+                   province_id, regency_id, district_id, village_id
+            FROM report_locations
+            WHERE report_id = $1
+            "#,
+            report_id
+        )
+        .fetch_optional(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to read report location: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(match row {
+            Some(r) => json!({
+                "raw_input": r.raw_input,
+                "lat": r.lat,
+                "lon": r.lon,
+                "geocoding_source": r.source,
+                "province_id": r.province_id,
+                "regency_id": r.regency_id,
+                "district_id": r.district_id,
+                "village_id": r.village_id,
+            }),
+            None => json!({}),
+        })
+    }
+
+    // ===== History =====
+
+    /// List the revisions of a report (oldest first)
+    pub async fn list(&self, report_id: Uuid) -> Result<Vec<ReportRevision>> {
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM reports WHERE id = $1) as "exists!""#,
+            report_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check report: {:?}", e);
+            AppError::Database(e)
+        })?;
+        if !exists {
+            return Err(AppError::NotFound(format!(
+                "Report {} not found",
+                report_id
+            )));
+        }
+
+        sqlx::query_as!(
+            ReportRevision,
+            r#"
+            SELECT
+                id, report_id, revision_number,
+                source as "source: ReportRevisionSource",
+                section as "section: ReportRevisionSection",
+                changes, reason, edited_by, editor_role, created_at
+            FROM report_revisions
+            WHERE report_id = $1
+            ORDER BY revision_number ASC
+            "#,
+            report_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list report revisions: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    // ===== Edits =====
+
+    /// Correct the content of a report
+    ///
+    /// The public redacted copy is regenerated after the change.
+    pub async fn update_content(
+        &self,
+        report_id: Uuid,
+        dto: &UpdateReportContentDto,
+        edited_by: &str,
+        role: &str,
+    ) -> Result<ReportRevision> {
+        let mut tx = self.begin().await?;
+        ReportService::lock_status(&mut tx, report_id).await?;
+        let before = Self::content_snapshot(&mut tx, report_id).await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE reports
+            SET title = COALESCE($2, title),
+                description = COALESCE($3, description),
+                timeline = COALESCE($4, timeline),
+                impact = COALESCE($5, impact),
+                redacted_title = NULL, redacted_description = NULL,
+                redacted_timeline = NULL, redacted_impact = NULL,
+                pii_types = '{}', redacted_at = NULL,
+                updated_at = NOW()
+            WHERE id = $1
+            "#,
+            report_id,
+            dto.title.as_deref(),
+            dto.description.as_deref(),
+            dto.timeline.as_deref(),
+            dto.impact.as_deref()
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to update report content: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        if dto.normalized_title.is_some() || dto.normalized_description.is_some() {
+            sqlx::query!(
+                r#"
+                INSERT INTO report_translations (report_id, language, title, description)
+                SELECT $1, 'id', COALESCE($2, r.title), COALESCE($3, r.description)
+                FROM reports r
+                WHERE r.id = $1
+                ON CONFLICT (report_id, language) DO UPDATE SET
+                    title = COALESCE($2, report_translations.title),
+                    description = COALESCE($3, report_translations.description),
+                    redacted_title = NULL, redacted_description = NULL,
+                    redacted_at = NULL, updated_at = NOW()
+                "#,
+                report_id,
+                dto.normalized_title.as_deref(),
+                dto.normalized_description.as_deref()
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to update report translation: {:?}", e);
+                AppError::Database(e)
+            })?;
+        }
+
+        let after = Self::content_snapshot(&mut tx, report_id).await?;
+        let author = RevisionAuthor {
+            edited_by,
+            role,
+            reason: &dto.reason,
+        };
+        let revision = Self::record_edit(
+            &mut tx,
+            report_id,
+            ReportRevisionSection::Content,
+            &author,
+            &before,
+            &after,
+        )
+        .await?;
+        let revision = Self::changed(revision)?;
+        self.commit(tx).await?;
+
+        let redact_names = self
+            .settings_service
+            .get_bool(SettingKey::RedactionRedactNames)
+            .await;
+        self.redaction_service
+            .redact_report(report_id, redact_names)
+            .await?;
+
+        tracing::info!(
+            "Report {} content edited by {} (revision {})",
+            report_id,
+            edited_by,
+            revision.revision_number
+        );
+        Ok(revision)
+    }
+
+    /// Replace the categories of a report
+    pub async fn update_categories(
+        &self,
+        report_id: Uuid,
+        dto: &UpdateReportCategoriesDto,
+        edited_by: &str,
+        role: &str,
+    ) -> Result<ReportRevision> {
+        let author = RevisionAuthor {
+            edited_by,
+            role,
+            reason: &dto.reason,
+        };
+
+        let mut tx = self.begin().await?;
+        let revision =
+            Self::replace_categories_in_tx(&mut tx, report_id, &dto.categories, &author).await?;
+        let revision = Self::changed(revision)?;
+        self.commit(tx).await?;
+        Ok(revision)
+    }
+
+    /// Replace the categories of a report inside the caller's transaction
+    ///
+    /// SLA deadlines are recomputed since category and severity select the policy.
+    /// Returns None if the categories were already as requested.
+    pub async fn replace_categories_in_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        report_id: Uuid,
+        categories: &[ReportCategoryInputDto],
+        author: &RevisionAuthor<'_>,
+    ) -> Result<Option<ReportRevision>> {
+        let category_ids: Vec<Uuid> = categories.iter().map(|c| c.category_id).collect();
+        let severities: Vec<ReportSeverity> = categories.iter().map(|c| c.severity).collect();
+
+        let unique: BTreeSet<Uuid> = category_ids.iter().copied().collect();
+        if unique.len() != category_ids.len() {
+            return Err(AppError::BadRequest(
+                "Each category can only be assigned once".to_string(),
+            ));
+        }
+
+        ReportService::lock_status(tx, report_id).await?;
+
+        let known = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!" FROM categories WHERE id = ANY($1)"#,
+            &category_ids
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check categories: {:?}", e);
+            AppError::Database(e)
+        })?;
+        if known as usize != category_ids.len() {
+            return Err(AppError::BadRequest("Unknown category".to_string()));
+        }
+
+        let before = Self::categories_snapshot(tx, report_id).await?;
+
+        sqlx::query!(
+            "DELETE FROM report_categories WHERE report_id = $1",
+            report_id
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to clear report categories: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO report_categories (report_id, category_id, severity)
+            SELECT $1, category_id, severity
+            FROM UNNEST($2::uuid[], $3::report_severity[]) AS c(category_id, severity)
+            "#,
+            report_id,
+            &category_ids,
+            &severities as &[ReportSeverity]
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to assign report categories: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        SlaService::recompute_open_deadlines(tx, report_id).await?;
+
+        let after = Self::categories_snapshot(tx, report_id).await?;
+        let revision = Self::record_edit(
+            tx,
+            report_id,
+            ReportRevisionSection::Categories,
+            author,
+            &before,
+            &after,
+        )
+        .await?;
+
+        if let Some(ref r) = revision {
+            tracing::info!(
+                "Report {} categories edited by {} (revision {})",
+                report_id,
+                author.edited_by,
+                r.revision_number
+            );
+        }
+        Ok(revision)
+    }
+
+    /// Replace the tags of a report
+    pub async fn update_tags(
+        &self,
+        report_id: Uuid,
+        dto: &UpdateReportTagsDto,
+        edited_by: &str,
+        role: &str,
+    ) -> Result<ReportRevision> {
+        let author = RevisionAuthor {
+            edited_by,
+            role,
+            reason: &dto.reason,
+        };
+
+        let mut tx = self.begin().await?;
+        let revision = Self::replace_tags_in_tx(&mut tx, report_id, &dto.tags, &author).await?;
+        let revision = Self::changed(revision)?;
+        self.commit(tx).await?;
+        Ok(revision)
+    }
+
+    /// Replace the tags of a report inside the caller's transaction
+    ///
+    /// Returns None if the tags were already as requested.
+    pub async fn replace_tags_in_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        report_id: Uuid,
+        tags: &[ReportTagType],
+        author: &RevisionAuthor<'_>,
+    ) -> Result<Option<ReportRevision>> {
+        ReportService::lock_status(tx, report_id).await?;
+        let before = Self::tags_snapshot(tx, report_id).await?;
+
+        sqlx::query!("DELETE FROM report_tags WHERE report_id = $1", report_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to clear report tags: {:?}", e);
+                AppError::Database(e)
+            })?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO report_tags (report_id, tag_type)
+            SELECT DISTINCT $1::uuid, tag_type
+            FROM UNNEST($2::report_tag_type[]) AS t(tag_type)
+            "#,
+            report_id,
+            tags as &[ReportTagType]
+        )
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to add report tags: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let after = Self::tags_snapshot(tx, report_id).await?;
+        let revision = Self::record_edit(
+            tx,
+            report_id,
+            ReportRevisionSection::Tags,
+            author,
+            &before,
+            &after,
+        )
+        .await?;
+
+        if let Some(ref r) = revision {
+            tracing::info!(
+                "Report {} tags edited by {} (revision {})",
+                report_id,
+                author.edited_by,
+                r.revision_number
+            );
+        }
+        Ok(revision)
+    }
+
+    /// Replace the location of a report with a manual location
+    pub async fn update_location(
+        &self,
+        report_id: Uuid,
+        dto: &UpdateReportLocationDto,
+        edited_by: &str,
+        role: &str,
+    ) -> Result<ReportRevision> {
+        let mut tx = self.begin().await?;
+        ReportService::lock_status(&mut tx, report_id).await?;
+
+        let regions = sqlx::query!(
+            r#"
+            SELECT
+                ($1::uuid IS NULL OR EXISTS(
+                    SELECT 1 FROM provinces WHERE id = $1
+                )) as "province!",
+                ($2::uuid IS NULL OR EXISTS(
+                    SELECT 1 FROM regencies WHERE id = $2 AND ($1::uuid IS NULL OR province_id = $1)
+                )) as "regency!",
+                ($3::uuid IS NULL OR EXISTS(
+                    SELECT 1 FROM districts WHERE id = $3 AND ($2::uuid IS NULL OR regency_id = $2)
+                )) as "district!",
+                ($4::uuid IS NULL OR EXISTS(
+                    SELECT 1 FROM villages WHERE id = $4 AND ($3::uuid IS NULL OR district_id = $3)
+                )) as "village!"
+            "#,
+            dto.province_id,
+            dto.regency_id,
+            dto.district_id,
+            dto.village_id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check location regions: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        for (ok, level) in [
+            (regions.province, "province"),
+            (regions.regency, "regency"),
+            (regions.district, "district"),
+            (regions.village, "village"),
+        ] {
+            if !ok {
+                return Err(AppError::BadRequest(format!(
+                    "Unknown {} or {} outside the given parent region",
+                    level, level
+                )));
+            }
+        }
+
+        let before = Self::location_snapshot(&mut tx, report_id).await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO report_locations (
+                report_id, raw_input, lat, lon, geocoding_source, geocoded_at,
+                province_id, regency_id, district_id, village_id
+            )
+            VALUES ($1, $2, $3, $4, 'manual', NOW(), $5, $6, $7, $8)
+            ON CONFLICT (report_id) DO UPDATE SET
+                raw_input = EXCLUDED.raw_input, lat = EXCLUDED.lat, lon = EXCLUDED.lon,
+                display_name = NULL, osm_id = NULL, osm_type = NULL,
+                road = NULL, neighbourhood = NULL, suburb = NULL, city = NULL, state = NULL,
+                postcode = NULL, country_code = NULL, bounding_box = NULL,
+                geocoding_source = EXCLUDED.geocoding_source, geocoding_score = NULL,
+                geocoded_at = EXCLUDED.geocoded_at,
+                province_id = EXCLUDED.province_id, regency_id = EXCLUDED.regency_id,
+                district_id = EXCLUDED.district_id, village_id = EXCLUDED.village_id
+            "#,
+            report_id,
+            dto.raw_input.trim(),
+            dto.lat,
+            dto.lon,
+            dto.province_id,
+            dto.regency_id,
+            dto.district_id,
+            dto.village_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to update report location: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let after = Self::location_snapshot(&mut tx, report_id).await?;
+        let author = RevisionAuthor {
+            edited_by,
+            role,
+            reason: &dto.reason,
+        };
+        let revision = Self::record_edit(
+            &mut tx,
+            report_id,
+            ReportRevisionSection::Location,
+            &author,
+            &before,
+            &after,
+        )
+        .await?;
+        let revision = Self::changed(revision)?;
+        self.commit(tx).await?;
+
+        tracing::info!(
+            "Report {} location edited by {} (revision {})",
+            report_id,
+            edited_by,
+            revision.revision_number
+        );
+        Ok(revision)
+    }
+
+    async fn begin(&self) -> Result<Transaction<'static, Postgres>> {
+        self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    async fn commit(&self, tx: Transaction<'static, Postgres>) -> Result<()> {
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit report edit: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Record a curator edit (None if it changed nothing)
+    async fn record_edit(
+        conn: &mut PgConnection,
+        report_id: Uuid,
+        section: ReportRevisionSection,
+        author: &RevisionAuthor<'_>,
+        before: &Value,
+        after: &Value,
+    ) -> Result<Option<ReportRevision>> {
+        let revision = CreateReportRevision {
+            report_id,
+            source: ReportRevisionSource::Admin,
+            section,
+            edited_by: Some(author.edited_by),
+            editor_role: author.role,
+            reason: Some(author.reason.trim()),
+        };
+
+        Self::record(conn, &revision, before, after).await
+    }
+
+    /// Reject single edits that changed nothing
+    fn changed(revision: Option<ReportRevision>) -> Result<ReportRevision> {
+        revision.ok_or_else(|| AppError::BadRequest("No changes to apply".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_fields_reports_changed_fields_only() {
+        let before = json!({ "title": "Jalan rusak", "timeline": null, "impact": "Warga" });
+        let after =
+            json!({ "title": "Jalan berlubang", "timeline": "Sejak Senin", "impact": "Warga" });
+
+        let diff = diff_fields(&before, &after);
+        assert_eq!(diff.len(), 2);
+        assert_eq!(
+            diff["title"],
+            json!({ "old": "Jalan rusak", "new": "Jalan berlubang" })
+        );
+        assert_eq!(
+            diff["timeline"],
+            json!({ "old": null, "new": "Sejak Senin" })
+        );
+
+        assert!(diff_fields(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn test_diff_fields_treats_missing_fields_as_null() {
+        let diff = diff_fields(
+            &json!({}),
+            &json!({ "raw_input": "Pasar Baru", "lat": null }),
+        );
+        assert_eq!(diff.len(), 1);
+        assert_eq!(
+            diff["raw_input"],
+            json!({ "old": null, "new": "Pasar Baru" })
+        );
+    }
+}
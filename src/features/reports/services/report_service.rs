@@ -1,14 +1,24 @@
+use std::collections::BTreeMap;
+
 use chrono::Utc;
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, Transaction};
 use uuid::Uuid;
 
 use crate::core::error::{AppError, Result};
+use crate::features::notifications::NotificationService;
 use crate::features::reports::dtos::UpdateReportStatusDto;
 use crate::features::reports::models::{
-    CreateReportAttachment, CreateReportCategory, CreateReportLocation, CreateReportSubmission,
-    CreateReportTag, GeocodingSource, Report, ReportAttachment, ReportCategory, ReportLocation,
-    ReportSeverity, ReportStatus, ReportTag, ReportTagType,
+    CreateReportAttachment, CreateReportCategory, CreateReportLocation, CreateReportRevision,
+    CreateReportSubmission, GeocodingSource, Report, ReportAttachment, ReportCategory,
+    ReportLanguage, ReportLocation, ReportRevisionSection, ReportRevisionSource, ReportSeverity,
+    ReportStatus, ReportStatusHistory, ReportTag, ReportTagType,
+};
+use crate::features::reports::services::{
+    ExtractedReportData, ReportJobService, ReportRevisionService,
 };
+use crate::features::sla::SlaService;
+use crate::features::webhooks::WebhookService;
+use crate::shared::constants::{ROLE_CITIZEN, ROLE_SYSTEM};
 
 /// Service for report operations
 pub struct ReportService {
@@ -41,36 +51,61 @@ impl ReportService {
     /// Returns the report with reference number for immediate citizen feedback
     /// Status starts as 'pending' - waiting for background processing
     pub async fn create_submission(&self, data: &CreateReportSubmission) -> Result<Report> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
         let report = sqlx::query_as!(
             Report,
             r#"
-            INSERT INTO reports (reference_number, adk_thread_id, user_id, platform, status)
-            VALUES ($1, $2, $3, $4, 'pending')
+            INSERT INTO reports (reference_number, adk_thread_id, user_id, platform, is_anonymous, status)
+            VALUES ($1, $2, $3, $4, $5, 'pending')
             RETURNING
                 id, title, description,
                 timeline, impact,
                 status as "status: ReportStatus",
                 verified_at, verified_by, resolved_at, resolved_by, resolution_notes,
                 created_at, updated_at,
-                reference_number, adk_thread_id, user_id, platform
+                reference_number, adk_thread_id, user_id, platform, is_anonymous
             "#,
             data.reference_number,
             data.adk_thread_id,
             data.user_id,
-            data.platform.as_deref().unwrap_or("web")
+            data.platform.as_deref().unwrap_or("web"),
+            data.is_anonymous
         )
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| {
             tracing::error!("Failed to create report submission: {:?}", e);
             AppError::Database(e)
         })?;
 
+        // The history is visible to officials, so anonymous reporters are not recorded as actor
+        let changed_by = (!data.is_anonymous).then_some(data.user_id.as_str());
+        Self::record_status_change(
+            &mut tx,
+            report.id,
+            None,
+            report.status,
+            changed_by,
+            ROLE_CITIZEN,
+            None,
+        )
+        .await?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit report submission: {:?}", e);
+            AppError::Database(e)
+        })?;
+
         tracing::info!(
-            "Created report submission: {} (ref: {}) for user: {}",
+            "Created report submission: {} (ref: {}) for user: {} (anonymous: {})",
             report.id,
             data.reference_number,
-            data.user_id
+            data.user_id,
+            data.is_anonymous
         );
 
         Ok(report)
@@ -82,6 +117,7 @@ impl ReportService {
         adk_thread_id: Uuid,
         user_id: &str,
         platform: Option<&str>,
+        is_anonymous: bool,
     ) -> Result<Report> {
         let reference_number = self.generate_reference_number().await?;
         let data = CreateReportSubmission {
@@ -89,25 +125,42 @@ impl ReportService {
             adk_thread_id,
             user_id: user_id.to_string(),
             platform: platform.map(String::from),
+            is_anonymous,
         };
         self.create_submission(&data).await
     }
 
     /// Update report with extracted content (called by ReportProcessor)
-    /// Status changes to 'draft' - processed successfully, waiting for verification
+    /// Status changes to 'draft' - processed successfully, waiting for verification.
+    /// The redacted copy and moderation mark are cleared until the redact and moderate
+    /// stages run again.
+    /// The original text is stored on the report; a normalized Bahasa Indonesia version
+    /// replaces any earlier translations.
     pub async fn update_content(
         &self,
         report_id: Uuid,
-        title: &str,
-        description: &str,
-        timeline: Option<&str>,
-        impact: Option<&str>,
+        data: &ExtractedReportData,
     ) -> Result<Report> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let current = Self::lock_status(&mut tx, report_id).await?;
+        if current != ReportStatus::Draft {
+            Self::ensure_transition(report_id, current, ReportStatus::Draft)?;
+        }
+
+        let before = ReportRevisionService::content_snapshot(&mut tx, report_id).await?;
+
         let report = sqlx::query_as!(
             Report,
             r#"
             UPDATE reports
-            SET title = $2, description = $3, timeline = $4, impact = $5,
+            SET title = $2, description = $3, timeline = $4, impact = $5, language = $6,
+                redacted_title = NULL, redacted_description = NULL,
+                redacted_timeline = NULL, redacted_impact = NULL,
+                pii_types = '{}', redacted_at = NULL, moderated_at = NULL,
                 status = 'draft', updated_at = NOW()
             WHERE id = $1
             RETURNING
@@ -116,27 +169,86 @@ impl ReportService {
                 status as "status: ReportStatus",
                 verified_at, verified_by, resolved_at, resolved_by, resolution_notes,
                 created_at, updated_at,
-                reference_number, adk_thread_id, user_id, platform
+                reference_number, adk_thread_id, user_id, platform, is_anonymous
             "#,
             report_id,
-            title,
-            description,
-            timeline,
-            impact
+            data.title,
+            data.description,
+            data.timeline,
+            data.impact,
+            data.language as Option<ReportLanguage>
         )
-        .fetch_optional(&self.pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| {
             tracing::error!("Failed to update report content: {:?}", e);
             AppError::Database(e)
-        })?
-        .ok_or_else(|| AppError::NotFound(format!("Report {} not found", report_id)))?;
+        })?;
+
+        sqlx::query!(
+            "DELETE FROM report_translations WHERE report_id = $1",
+            report_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to clear report translations: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        if let Some((title, description)) = data.normalized() {
+            sqlx::query!(
+                r#"
+                INSERT INTO report_translations (report_id, language, title, description)
+                VALUES ($1, 'id', $2, $3)
+                "#,
+                report_id,
+                title,
+                description
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to store report translation: {:?}", e);
+                AppError::Database(e)
+            })?;
+        }
+
+        let after = ReportRevisionService::content_snapshot(&mut tx, report_id).await?;
+        let revision = CreateReportRevision {
+            report_id,
+            source: ReportRevisionSource::Extraction,
+            section: ReportRevisionSection::Content,
+            edited_by: None,
+            editor_role: ROLE_SYSTEM,
+            reason: Some("Report content extracted"),
+        };
+        ReportRevisionService::record(&mut tx, &revision, &before, &after).await?;
+
+        if current != ReportStatus::Draft {
+            Self::record_status_change(
+                &mut tx,
+                report_id,
+                Some(current),
+                ReportStatus::Draft,
+                None,
+                ROLE_SYSTEM,
+                Some("Report content extracted"),
+            )
+            .await?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit report content update: {:?}", e);
+            AppError::Database(e)
+        })?;
 
         tracing::info!("Updated report content: {}", report_id);
         Ok(report)
     }
 
     /// Create a report location
+    /// Replaces the existing location, so re-running the locate stage is safe
     pub async fn create_location(&self, data: &CreateReportLocation) -> Result<ReportLocation> {
         let location = sqlx::query_as!(
             ReportLocation,
@@ -148,6 +260,18 @@ impl ReportService {
                 province_id, regency_id, district_id, village_id
             )
             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, NOW(), $18, $19, $20, $21)
+            ON CONFLICT (report_id) DO UPDATE SET
+                raw_input = EXCLUDED.raw_input, display_name = EXCLUDED.display_name,
+                lat = EXCLUDED.lat, lon = EXCLUDED.lon,
+                osm_id = EXCLUDED.osm_id, osm_type = EXCLUDED.osm_type,
+                road = EXCLUDED.road, neighbourhood = EXCLUDED.neighbourhood,
+                suburb = EXCLUDED.suburb, city = EXCLUDED.city, state = EXCLUDED.state,
+                postcode = EXCLUDED.postcode, country_code = EXCLUDED.country_code,
+                bounding_box = EXCLUDED.bounding_box,
+                geocoding_source = EXCLUDED.geocoding_source,
+                geocoding_score = EXCLUDED.geocoding_score, geocoded_at = EXCLUDED.geocoded_at,
+                province_id = EXCLUDED.province_id, regency_id = EXCLUDED.regency_id,
+                district_id = EXCLUDED.district_id, village_id = EXCLUDED.village_id
             RETURNING
                 id, report_id, raw_input, display_name, lat, lon,
                 osm_id, osm_type, road, neighbourhood, suburb, city, state, postcode, country_code,
@@ -198,7 +322,7 @@ impl ReportService {
                 status as "status: ReportStatus",
                 verified_at, verified_by, resolved_at, resolved_by, resolution_notes,
                 created_at, updated_at,
-                reference_number, adk_thread_id, user_id, platform
+                reference_number, adk_thread_id, user_id, platform, is_anonymous
             FROM reports
             WHERE id = $1
             "#,
@@ -225,7 +349,7 @@ impl ReportService {
                 status as "status: ReportStatus",
                 verified_at, verified_by, resolved_at, resolved_by, resolution_notes,
                 created_at, updated_at,
-                reference_number, adk_thread_id, user_id, platform
+                reference_number, adk_thread_id, user_id, platform, is_anonymous
             FROM reports
             WHERE reference_number = $1
             "#,
@@ -275,7 +399,7 @@ impl ReportService {
                 status as "status: ReportStatus",
                 verified_at, verified_by, resolved_at, resolved_by, resolution_notes,
                 created_at, updated_at,
-                reference_number, adk_thread_id, user_id, platform
+                reference_number, adk_thread_id, user_id, platform, is_anonymous
             FROM reports
             WHERE user_id = $1
               AND status NOT IN ('pending', 'rejected')
@@ -291,8 +415,17 @@ impl ReportService {
         })
     }
 
-    /// Mark report as rejected (low confidence or invalid)
+    /// Mark report as rejected by the system (e.g. invalid submission)
+    #[allow(dead_code)]
     pub async fn reject(&self, report_id: Uuid, reason: Option<&str>) -> Result<Report> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let current = Self::lock_status(&mut tx, report_id).await?;
+        Self::ensure_transition(report_id, current, ReportStatus::Rejected)?;
+
         let report = sqlx::query_as!(
             Report,
             r#"
@@ -305,40 +438,106 @@ impl ReportService {
                 status as "status: ReportStatus",
                 verified_at, verified_by, resolved_at, resolved_by, resolution_notes,
                 created_at, updated_at,
-                reference_number, adk_thread_id, user_id, platform
+                reference_number, adk_thread_id, user_id, platform, is_anonymous
             "#,
             report_id,
             reason
         )
-        .fetch_optional(&self.pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| {
             tracing::error!("Failed to reject report: {:?}", e);
             AppError::Database(e)
-        })?
-        .ok_or_else(|| AppError::NotFound(format!("Report {} not found", report_id)))?;
+        })?;
+
+        Self::record_status_change(
+            &mut tx,
+            report_id,
+            Some(current),
+            ReportStatus::Rejected,
+            None,
+            ROLE_SYSTEM,
+            reason,
+        )
+        .await?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit report rejection: {:?}", e);
+            AppError::Database(e)
+        })?;
 
         tracing::info!("Report {} rejected: {:?}", report_id, reason);
         Ok(report)
     }
 
     /// Update report status
+    ///
+    /// Enforces the report lifecycle (see `ReportStatus::allowed_transitions`) and
+    /// records the transition in `report_status_history`.
+    /// Returns `AppError::Conflict` if the transition is not allowed, or when verifying a
+    /// report whose job is still running or that has not cleared redaction and moderation. Reopening a rejected report queues it for
+    /// processing again.
     pub async fn update_status(
         &self,
         id: Uuid,
         dto: &UpdateReportStatusDto,
         user_id: &str,
+        role: &str,
+    ) -> Result<Report> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let report = Self::update_status_in_tx(&mut tx, id, dto, user_id, role).await?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit report status update: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(report)
+    }
+
+    /// Update report status inside the caller's transaction (see `update_status`)
+    pub async fn update_status_in_tx(
+        tx: &mut Transaction<'_, Postgres>,
+        id: Uuid,
+        dto: &UpdateReportStatusDto,
+        user_id: &str,
+        role: &str,
     ) -> Result<Report> {
         let now = Utc::now();
 
-        // Set verified_at/resolved_at based on status transition
+        let current = Self::lock_status(tx, id).await?;
+        Self::ensure_transition(id, current, dto.status)?;
+
+        if dto.status == ReportStatus::Verified {
+            // The content stage would fail on a report verified under its feet
+            if ReportJobService::has_active_job(tx, id).await? {
+                return Err(AppError::Conflict(format!(
+                    "Report {} is still being processed; verify it once its job has finished",
+                    id
+                )));
+            }
+
+            // Drafts held for moderation or not redacted yet must not be published
+            if !Self::is_published(tx, id).await? {
+                return Err(AppError::Conflict(format!(
+                    "Report {} has not cleared redaction and moderation yet",
+                    id
+                )));
+            }
+        }
+
+        // Stamp verified_at/resolved_at when entering those states
         let (verified_at, verified_by, resolved_at, resolved_by) = match dto.status {
             ReportStatus::Verified => (Some(now), Some(user_id.to_string()), None, None),
             ReportStatus::Resolved => (None, None, Some(now), Some(user_id.to_string())),
             _ => (None, None, None, None),
         };
 
-        sqlx::query_as!(
+        let report = sqlx::query_as!(
             Report,
             r#"
             UPDATE reports
@@ -357,7 +556,7 @@ impl ReportService {
                 status as "status: ReportStatus",
                 verified_at, verified_by, resolved_at, resolved_by, resolution_notes,
                 created_at, updated_at,
-                reference_number, adk_thread_id, user_id, platform
+                reference_number, adk_thread_id, user_id, platform, is_anonymous
             "#,
             id,
             dto.status as ReportStatus,
@@ -367,72 +566,215 @@ impl ReportService {
             resolved_at,
             resolved_by
         )
-        .fetch_optional(&self.pool)
+        .fetch_one(&mut **tx)
         .await
         .map_err(|e| {
             tracing::error!("Failed to update report status: {:?}", e);
             AppError::Database(e)
+        })?;
+
+        Self::record_status_change(
+            tx,
+            id,
+            Some(current),
+            dto.status,
+            Some(user_id),
+            role,
+            dto.note.as_deref().or(dto.resolution_notes.as_deref()),
+        )
+        .await?;
+
+        if current.reprocesses_on(dto.status) {
+            ReportJobService::reprocess_report(tx, id).await?;
+        }
+
+        tracing::info!(
+            "Report {} status changed: {} -> {} by {}",
+            id,
+            current,
+            dto.status,
+            user_id
+        );
+        Ok(report)
+    }
+
+    /// Get the full status timeline of a report (oldest first)
+    pub async fn get_status_history(&self, report_id: Uuid) -> Result<Vec<ReportStatusHistory>> {
+        sqlx::query_as!(
+            ReportStatusHistory,
+            r#"
+            SELECT
+                id, report_id,
+                from_status as "from_status: ReportStatus",
+                to_status as "to_status: ReportStatus",
+                changed_by, changed_by_role, note, created_at
+            FROM report_status_history
+            WHERE report_id = $1
+            ORDER BY created_at ASC
+            "#,
+            report_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report status history: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    // ===== Status Lifecycle Helpers =====
+    // Public so other services can change status inside their own transaction
+
+    /// Lock the report row and return its current status
+    pub async fn lock_status(
+        tx: &mut Transaction<'_, Postgres>,
+        report_id: Uuid,
+    ) -> Result<ReportStatus> {
+        sqlx::query_scalar!(
+            r#"SELECT status as "status: ReportStatus" FROM reports WHERE id = $1 FOR UPDATE"#,
+            report_id
+        )
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to lock report status: {:?}", e);
+            AppError::Database(e)
         })?
-        .ok_or_else(|| AppError::NotFound(format!("Report {} not found", id)))
+        .ok_or_else(|| AppError::NotFound(format!("Report {} not found", report_id)))
     }
 
-    // ===== Category Management =====
+    /// Whether the report has a redacted copy and cleared moderation
+    async fn is_published(tx: &mut Transaction<'_, Postgres>, report_id: Uuid) -> Result<bool> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT redacted_at IS NOT NULL AND moderated_at IS NOT NULL as "published!"
+            FROM reports
+            WHERE id = $1
+            "#,
+            report_id
+        )
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check report publication: {:?}", e);
+            AppError::Database(e)
+        })
+    }
 
-    /// Assign a category to a report with severity
-    pub async fn assign_category(&self, data: &CreateReportCategory) -> Result<ReportCategory> {
-        let category = sqlx::query_as!(
-            ReportCategory,
+    /// Return a conflict error if the transition is not allowed
+    pub fn ensure_transition(report_id: Uuid, from: ReportStatus, to: ReportStatus) -> Result<()> {
+        if from.can_transition_to(to) {
+            Ok(())
+        } else {
+            Err(AppError::Conflict(format!(
+                "Report {} cannot transition from '{}' to '{}'",
+                report_id, from, to
+            )))
+        }
+    }
+
+    /// Append an entry to the report status history
+    pub async fn record_status_change(
+        tx: &mut Transaction<'_, Postgres>,
+        report_id: Uuid,
+        from: Option<ReportStatus>,
+        to: ReportStatus,
+        changed_by: Option<&str>,
+        role: &str,
+        note: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query!(
             r#"
-            INSERT INTO report_categories (report_id, category_id, severity)
-            VALUES ($1, $2, $3)
-            ON CONFLICT (report_id, category_id)
-            DO UPDATE SET severity = $3
-            RETURNING
-                id, report_id, category_id,
-                severity as "severity: ReportSeverity",
-                created_at
+            INSERT INTO report_status_history
+                (report_id, from_status, to_status, changed_by, changed_by_role, note)
+            VALUES ($1, $2, $3, $4, $5, $6)
             "#,
-            data.report_id,
-            data.category_id,
-            data.severity as ReportSeverity
+            report_id,
+            from as Option<ReportStatus>,
+            to as ReportStatus,
+            changed_by,
+            role,
+            note
         )
-        .fetch_one(&self.pool)
+        .execute(&mut **tx)
         .await
         .map_err(|e| {
-            tracing::error!("Failed to assign category: {:?}", e);
+            tracing::error!("Failed to record report status change: {:?}", e);
             AppError::Database(e)
         })?;
 
-        tracing::debug!(
-            "Assigned category {} to report {} with severity {:?}",
-            data.category_id,
-            data.report_id,
-            data.severity
-        );
+        SlaService::on_status_entered(tx, report_id, to).await?;
+        NotificationService::on_status_changed(tx, report_id, from, to, note).await?;
+        WebhookService::on_status_changed(tx, report_id, from, to, note).await?;
 
-        Ok(category)
+        Ok(())
     }
 
-    /// Assign multiple categories to a report
-    pub async fn assign_categories(
+    // ===== Category Management =====
+
+    /// Replace the categories of a report
+    ///
+    /// Earlier assignments (e.g. from a previous extraction) are removed in the same
+    /// transaction; a category listed twice keeps its last severity.
+    pub async fn replace_categories(
         &self,
         report_id: Uuid,
         categories: &[CreateReportCategory],
     ) -> Result<Vec<ReportCategory>> {
-        let mut results = Vec::with_capacity(categories.len());
+        let severities: BTreeMap<Uuid, ReportSeverity> = categories
+            .iter()
+            .map(|c| (c.category_id, c.severity))
+            .collect();
+        let (category_ids, severities): (Vec<Uuid>, Vec<ReportSeverity>) =
+            severities.into_iter().unzip();
 
-        for cat in categories {
-            // Ensure report_id matches
-            let data = CreateReportCategory {
-                report_id,
-                category_id: cat.category_id,
-                severity: cat.severity,
-            };
-            let result = self.assign_category(&data).await?;
-            results.push(result);
-        }
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
 
-        Ok(results)
+        sqlx::query!(
+            "DELETE FROM report_categories WHERE report_id = $1",
+            report_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to clear report categories: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let assigned = sqlx::query_as!(
+            ReportCategory,
+            r#"
+            INSERT INTO report_categories (report_id, category_id, severity)
+            SELECT $1, category_id, severity
+            FROM UNNEST($2::uuid[], $3::report_severity[]) AS c(category_id, severity)
+            RETURNING
+                id, report_id, category_id,
+                severity as "severity: ReportSeverity",
+                created_at
+            "#,
+            report_id,
+            &category_ids,
+            &severities as &[ReportSeverity]
+        )
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to assign report categories: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        // Category/severity decide which SLA policy applies
+        SlaService::recompute_open_deadlines(&mut tx, report_id).await?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit report categories: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(assigned)
     }
 
     /// Get all categories for a report
@@ -461,72 +803,54 @@ impl ReportService {
 
     // ===== Tag Management =====
 
-    /// Add a tag to a report
-    pub async fn add_tag(&self, data: &CreateReportTag) -> Result<ReportTag> {
-        let tag = sqlx::query_as!(
+    /// Replace the tags of a report
+    ///
+    /// Earlier tags (e.g. from a previous extraction) are removed in the same transaction.
+    pub async fn replace_tags(
+        &self,
+        report_id: Uuid,
+        tag_types: &[ReportTagType],
+    ) -> Result<Vec<ReportTag>> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        sqlx::query!("DELETE FROM report_tags WHERE report_id = $1", report_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to clear report tags: {:?}", e);
+                AppError::Database(e)
+            })?;
+
+        let tags = sqlx::query_as!(
             ReportTag,
             r#"
             INSERT INTO report_tags (report_id, tag_type)
-            VALUES ($1, $2)
-            ON CONFLICT (report_id, tag_type) DO NOTHING
+            SELECT DISTINCT $1::uuid, tag_type
+            FROM UNNEST($2::report_tag_type[]) AS t(tag_type)
             RETURNING
                 id, report_id,
                 tag_type as "tag_type: ReportTagType",
                 created_at
             "#,
-            data.report_id,
-            data.tag_type as ReportTagType
+            report_id,
+            tag_types as &[ReportTagType]
         )
-        .fetch_optional(&self.pool)
+        .fetch_all(&mut *tx)
         .await
         .map_err(|e| {
-            tracing::error!("Failed to add tag: {:?}", e);
+            tracing::error!("Failed to add report tags: {:?}", e);
             AppError::Database(e)
         })?;
 
-        // If conflict occurred, fetch the existing tag
-        match tag {
-            Some(t) => Ok(t),
-            None => sqlx::query_as!(
-                ReportTag,
-                r#"
-                    SELECT
-                        id, report_id,
-                        tag_type as "tag_type: ReportTagType",
-                        created_at
-                    FROM report_tags
-                    WHERE report_id = $1 AND tag_type = $2
-                    "#,
-                data.report_id,
-                data.tag_type as ReportTagType
-            )
-            .fetch_one(&self.pool)
-            .await
-            .map_err(|e| {
-                tracing::error!("Failed to fetch existing tag: {:?}", e);
-                AppError::Database(e)
-            }),
-        }
-    }
-
-    /// Add multiple tags to a report
-    pub async fn add_tags(
-        &self,
-        report_id: Uuid,
-        tag_types: &[ReportTagType],
-    ) -> Result<Vec<ReportTag>> {
-        let mut results = Vec::with_capacity(tag_types.len());
-
-        for tag_type in tag_types {
-            let data = CreateReportTag {
-                report_id,
-                tag_type: *tag_type,
-            };
-            let result = self.add_tag(&data).await?;
-            results.push(result);
-        }
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit report tags: {:?}", e);
+            AppError::Database(e)
+        })?;
 
-        Ok(results)
+        Ok(tags)
     }
 
     /// Get all tags for a report
@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::reports::dtos::ReportUpdateDto;
+use crate::features::reports::models::{CreateReportUpdate, ReportUpdate, ReportUpdateAttachment};
+
+/// Service for public progress updates posted by officials on a report
+pub struct ReportUpdateService {
+    pool: PgPool,
+}
+
+impl ReportUpdateService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Post a progress update with optional attachments
+    ///
+    /// Attached files must be active public files uploaded by the author.
+    pub async fn create(&self, data: CreateReportUpdate) -> Result<ReportUpdateDto> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM reports WHERE id = $1) as "exists!""#,
+            data.report_id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check report: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        if !exists {
+            return Err(AppError::NotFound(format!(
+                "Report {} not found",
+                data.report_id
+            )));
+        }
+
+        let mut file_ids = data.file_ids.clone();
+        file_ids.sort();
+        file_ids.dedup();
+
+        if !file_ids.is_empty() {
+            let usable = sqlx::query_scalar!(
+                r#"
+                SELECT COUNT(*) as "count!"
+                FROM files
+                WHERE id = ANY($1)
+                  AND is_active = true
+                  AND visibility = 'public'
+                  AND uploaded_by = $2
+                "#,
+                &file_ids,
+                data.author_id
+            )
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to check update attachments: {:?}", e);
+                AppError::Database(e)
+            })?;
+
+            if usable != file_ids.len() as i64 {
+                return Err(AppError::Validation(
+                    "Attachments must be active public files uploaded by you".to_string(),
+                ));
+            }
+        }
+
+        let update = sqlx::query_as!(
+            ReportUpdate,
+            r#"
+            INSERT INTO report_updates (report_id, author_id, author_role, message)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, report_id, author_id, author_role, message,
+                      created_at, deleted_at, deleted_by
+            "#,
+            data.report_id,
+            data.author_id,
+            data.author_role,
+            data.message
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to create report update: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        if !file_ids.is_empty() {
+            let inserted = sqlx::query!(
+                r#"
+                INSERT INTO report_attachments (report_id, file_id, report_update_id)
+                SELECT $1, file_id, $2 FROM UNNEST($3::uuid[]) AS file_id
+                ON CONFLICT (report_id, file_id) DO NOTHING
+                "#,
+                data.report_id,
+                update.id,
+                &file_ids
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to attach files to report update: {:?}", e);
+                AppError::Database(e)
+            })?
+            .rows_affected();
+
+            if inserted != file_ids.len() as u64 {
+                return Err(AppError::Conflict(
+                    "One or more files are already attached to this report".to_string(),
+                ));
+            }
+        }
+
+        let attachments = Self::fetch_attachments(&mut tx, &[update.id]).await?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit report update: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!(
+            "Update {} posted on report {} by {}",
+            update.id,
+            update.report_id,
+            update.author_id
+        );
+
+        Ok(ReportUpdateDto::new(update, attachments))
+    }
+
+    /// List visible updates of a report with their attachments (oldest first)
+    pub async fn list_for_report(&self, report_id: Uuid) -> Result<Vec<ReportUpdateDto>> {
+        let updates = sqlx::query_as!(
+            ReportUpdate,
+            r#"
+            SELECT id, report_id, author_id, author_role, message,
+                   created_at, deleted_at, deleted_by
+            FROM report_updates
+            WHERE report_id = $1 AND deleted_at IS NULL
+            ORDER BY created_at ASC
+            "#,
+            report_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list report updates: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let ids: Vec<Uuid> = updates.iter().map(|u| u.id).collect();
+        let mut conn = self.pool.acquire().await.map_err(|e| {
+            tracing::error!("Failed to acquire connection: {:?}", e);
+            AppError::Database(e)
+        })?;
+        let attachments = Self::fetch_attachments(&mut conn, &ids).await?;
+
+        let mut by_update: HashMap<Uuid, Vec<ReportUpdateAttachment>> = HashMap::new();
+        for attachment in attachments {
+            by_update
+                .entry(attachment.report_update_id)
+                .or_default()
+                .push(attachment);
+        }
+
+        Ok(updates
+            .into_iter()
+            .map(|u| {
+                let files = by_update.remove(&u.id).unwrap_or_default();
+                ReportUpdateDto::new(u, files)
+            })
+            .collect())
+    }
+
+    /// Soft delete an update
+    ///
+    /// Only the author or an admin may delete.
+    pub async fn delete(
+        &self,
+        report_id: Uuid,
+        update_id: Uuid,
+        actor_id: &str,
+        is_admin: bool,
+    ) -> Result<()> {
+        let author_id = sqlx::query_scalar!(
+            r#"
+            SELECT author_id FROM report_updates
+            WHERE id = $1 AND report_id = $2 AND deleted_at IS NULL
+            "#,
+            update_id,
+            report_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report update: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report update {} not found", update_id)))?;
+
+        if author_id != actor_id && !is_admin {
+            return Err(AppError::Forbidden(
+                "Only the author can delete this update".to_string(),
+            ));
+        }
+
+        sqlx::query!(
+            r#"
+            UPDATE report_updates
+            SET deleted_at = NOW(), deleted_by = $2
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+            update_id,
+            actor_id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to delete report update: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!(
+            "Update {} on report {} deleted by {}",
+            update_id,
+            report_id,
+            actor_id
+        );
+        Ok(())
+    }
+
+    /// Fetch the active files attached to the given updates
+    async fn fetch_attachments(
+        conn: &mut sqlx::PgConnection,
+        update_ids: &[Uuid],
+    ) -> Result<Vec<ReportUpdateAttachment>> {
+        if update_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        sqlx::query_as!(
+            ReportUpdateAttachment,
+            r#"
+            SELECT
+                ra.report_update_id as "report_update_id!",
+                f.id as file_id,
+                f.original_filename,
+                f.content_type,
+                f.file_size,
+                f.url
+            FROM report_attachments ra
+            JOIN files f ON f.id = ra.file_id
+            WHERE ra.report_update_id = ANY($1) AND f.is_active = true
+            ORDER BY ra.created_at ASC
+            "#,
+            update_ids
+        )
+        .fetch_all(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report update attachments: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+}
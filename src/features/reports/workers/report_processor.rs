@@ -1,28 +1,33 @@
+use sqlx::postgres::PgListener;
 use sqlx::PgPool;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::time::interval;
+use tokio::task::JoinHandle;
+use tokio::time::{interval, sleep};
 use uuid::Uuid;
 
 use crate::core::error::{AppError, Result};
-use crate::features::reports::models::{CreateReportCategory, ReportJob, ReportJobStatus};
-use crate::features::reports::services::ExtractionService;
+use crate::features::agencies::AgencyRoutingService;
+use crate::features::moderation::ModerationService;
+use crate::features::notifications::models::NotificationPayload;
+use crate::features::notifications::NotificationService;
+use crate::features::reports::models::{
+    CreateReportCategory, Report, ReportJob, ReportJobStage, ReportStatus,
+};
+use crate::features::reports::services::{ExtractedReportData, ExtractionService};
 use crate::features::reports::services::{
-    GeocodingLevel, GeocodingService, RegionLookupService, ReportJobService, ReportService,
+    GeocodingLevel, GeocodingService, RedactionService, RegionLookupService,
+    ReportDuplicateService, ReportJobService, ReportReviewService, ReportService,
+    REPORT_JOBS_CHANNEL,
 };
+use crate::features::settings::{SettingKey, SettingsService};
+use crate::features::webhooks::WebhookService;
 
-/// Maximum retry attempts for failed jobs
-const MAX_RETRIES: i32 = 3;
-
-/// Delay between processing batches
-const BATCH_INTERVAL_SECS: u64 = 30;
-
-/// Batch size for processing
-const BATCH_SIZE: i64 = 10;
+/// Lease on claimed jobs; other workers recover them once it expires
+const JOB_LEASE_SECS: i64 = 300;
 
-/// Minimum confidence score required for processing
-/// Reports below this threshold will be rejected
-const MIN_CONFIDENCE_THRESHOLD: f64 = 0.7;
+/// Interval between lease extensions while a batch is being processed
+const HEARTBEAT_INTERVAL_SECS: u64 = 60;
 
 /// Report processor worker that runs in the background
 /// Processes report submissions by extracting data from conversations
@@ -33,9 +38,20 @@ pub struct ReportProcessor {
     report_service: Arc<ReportService>,
     report_job_service: Arc<ReportJobService>,
     region_lookup_service: Arc<RegionLookupService>,
+    agency_routing_service: Arc<AgencyRoutingService>,
+    moderation_service: Arc<ModerationService>,
+    duplicate_service: ReportDuplicateService,
+    redaction_service: RedactionService,
+    notification_service: NotificationService,
+    webhook_service: WebhookService,
+    /// Retry budget, batch size and poll interval (hot reloaded)
+    settings_service: Arc<SettingsService>,
+    /// Identifies this instance in job leases
+    worker_id: String,
 }
 
 impl ReportProcessor {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         pool: PgPool,
         extraction_service: Arc<ExtractionService>,
@@ -43,59 +59,194 @@ impl ReportProcessor {
         report_service: Arc<ReportService>,
         report_job_service: Arc<ReportJobService>,
         region_lookup_service: Arc<RegionLookupService>,
+        agency_routing_service: Arc<AgencyRoutingService>,
+        moderation_service: Arc<ModerationService>,
+        settings_service: Arc<SettingsService>,
     ) -> Self {
         Self {
+            duplicate_service: ReportDuplicateService::new(pool.clone()),
+            redaction_service: RedactionService::new(pool.clone()),
+            notification_service: NotificationService::new(pool.clone()),
+            webhook_service: WebhookService::new(pool.clone()),
+            worker_id: format!("report-processor-{}", Uuid::now_v7()),
             pool,
             extraction_service,
             geocoding_service,
             report_service,
             report_job_service,
             region_lookup_service,
+            agency_routing_service,
+            moderation_service,
+            settings_service,
         }
     }
 
     /// Run the processor in a background loop
+    ///
+    /// Wakes up on job notifications (LISTEN/NOTIFY) and falls back to polling every
+    /// `report_processor.batch_interval_secs` if the listener is unavailable. Settings are
+    /// re-read on every iteration, so changes apply without a restart.
     pub async fn run(&self) {
-        tracing::info!("Starting report processor worker");
+        tracing::info!("Starting report processor worker {}", self.worker_id);
 
-        let mut interval = interval(Duration::from_secs(BATCH_INTERVAL_SECS));
+        let mut listener = self.listen().await;
 
         loop {
-            interval.tick().await;
+            let max_retries = self
+                .settings_service
+                .get_i64(SettingKey::ReportProcessorMaxRetries)
+                .await as i32;
+            let batch_size = self
+                .settings_service
+                .get_i64(SettingKey::ReportProcessorBatchSize)
+                .await;
+            let batch_interval = Duration::from_secs(
+                self.settings_service
+                    .get_i64(SettingKey::ReportProcessorBatchIntervalSecs)
+                    .await as u64,
+            );
+
+            if let Err(e) = self.report_job_service.recover_expired(max_retries).await {
+                tracing::error!("Error recovering expired report jobs: {:?}", e);
+            }
+
+            // Reports that skipped the redact stage (processed before it existed)
+            match self
+                .redaction_service
+                .redact_pending(batch_size, self.redact_names().await)
+                .await
+            {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Redacted {} previously processed reports", count),
+                Err(e) => tracing::error!("Error redacting processed reports: {:?}", e),
+            }
+
+            match self.process_batch(max_retries, batch_size).await {
+                // A full batch means more jobs are probably waiting
+                Ok(claimed) if claimed as i64 >= batch_size => continue,
+                Ok(_) => {}
+                Err(e) => tracing::error!("Error processing report batch: {:?}", e),
+            }
+
+            let woken = match listener.as_mut() {
+                Some(listener) => tokio::select! {
+                    _ = sleep(batch_interval) => Ok(()),
+                    notification = listener.recv() => notification.map(|_| ()),
+                },
+                None => {
+                    sleep(batch_interval).await;
+                    Ok(())
+                }
+            };
+
+            if let Err(e) = woken {
+                tracing::warn!(
+                    "Report job listener failed, falling back to polling: {:?}",
+                    e
+                );
+                listener = None;
+            }
+            if listener.is_none() {
+                listener = self.listen().await;
+            }
+        }
+    }
 
-            if let Err(e) = self.process_batch().await {
-                tracing::error!("Error processing report batch: {:?}", e);
+    /// Subscribe to report job notifications (None if the listener cannot connect)
+    async fn listen(&self) -> Option<PgListener> {
+        let mut listener = match PgListener::connect_with(&self.pool).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                tracing::warn!("Failed to connect report job listener: {:?}", e);
+                return None;
             }
+        };
+
+        if let Err(e) = listener.listen(REPORT_JOBS_CHANNEL).await {
+            tracing::warn!("Failed to listen on {}: {:?}", REPORT_JOBS_CHANNEL, e);
+            return None;
         }
+
+        Some(listener)
     }
 
-    /// Process a batch of pending report jobs
-    async fn process_batch(&self) -> Result<()> {
-        // Fetch submitted jobs that haven't been processed
+    /// Claim and process a batch of pending report jobs
+    /// Returns the number of jobs claimed
+    async fn process_batch(&self, max_retries: i32, batch_size: i64) -> Result<usize> {
         let jobs = self
             .report_job_service
-            .fetch_pending(MAX_RETRIES, BATCH_SIZE)
+            .claim_pending(&self.worker_id, max_retries, batch_size, JOB_LEASE_SECS)
             .await?;
 
         if jobs.is_empty() {
-            return Ok(());
+            return Ok(0);
         }
 
         tracing::info!("Processing {} pending report jobs", jobs.len());
 
+        let heartbeat = self.spawn_heartbeat();
+        self.process_jobs(&jobs, max_retries).await;
+        heartbeat.abort();
+
+        Ok(jobs.len())
+    }
+
+    /// Process claimed jobs one by one, recording failures for retry
+    ///
+    /// A failure that cannot be recorded (e.g. the lease was lost) is logged and the
+    /// remaining jobs are still processed.
+    async fn process_jobs(&self, jobs: &[ReportJob], max_retries: i32) {
         for job in jobs {
-            if let Err(e) = self.process_job(&job).await {
+            if let Err(e) = self.process_job(job).await {
                 tracing::error!("Failed to process report job {}: {:?}", job.id, e);
-                self.report_job_service
-                    .mark_failed(job.id, job.retry_count, MAX_RETRIES, &e.to_string())
-                    .await?;
+                if let Err(e) = self
+                    .report_job_service
+                    .mark_failed(
+                        job.id,
+                        &self.worker_id,
+                        job.retry_count,
+                        max_retries,
+                        &e.to_string(),
+                    )
+                    .await
+                {
+                    tracing::error!("Failed to record failure of report job {}: {:?}", job.id, e);
+                }
             }
         }
+    }
 
-        Ok(())
+    /// Keep extending the leases of this worker's jobs until aborted
+    fn spawn_heartbeat(&self) -> JoinHandle<()> {
+        let report_job_service = Arc::clone(&self.report_job_service);
+        let worker_id = self.worker_id.clone();
+
+        tokio::spawn(async move {
+            let mut ticker = interval(Duration::from_secs(HEARTBEAT_INTERVAL_SECS));
+            // The first tick completes immediately; the claim already set the lease
+            ticker.tick().await;
+
+            loop {
+                ticker.tick().await;
+                match report_job_service
+                    .heartbeat(&worker_id, JOB_LEASE_SECS)
+                    .await
+                {
+                    // The lease of the current job was lost; finishing it will be rejected
+                    Ok(0) => tracing::warn!("{} no longer holds any job leases", worker_id),
+                    Ok(_) => {}
+                    Err(e) => {
+                        tracing::warn!("Failed to extend leases of {}: {:?}", worker_id, e)
+                    }
+                }
+            }
+        })
     }
 
     /// Process a single report job
+    ///
+    /// Runs the pipeline stages in order, starting at the job's checkpoint. Each finished
+    /// stage is persisted, so a retry resumes at the stage that failed.
     async fn process_job(&self, job: &ReportJob) -> Result<()> {
         // Get the report to find adk_thread_id
         let report = self.report_service.get_by_id(job.report_id).await?;
@@ -104,74 +255,222 @@ impl ReportProcessor {
             AppError::Internal(format!("Report {} has no adk_thread_id", report.id))
         })?;
 
-        // Check confidence score - reject low confidence reports
-        let confidence = job
-            .confidence_score
-            .map(|d| d.to_string().parse::<f64>().unwrap_or(0.0))
-            .unwrap_or(0.0);
+        // Only new submissions go through the confidence gate; re-extraction of a draft does not
+        if job.stage == ReportJobStage::Extract && report.status == ReportStatus::Pending {
+            // Check confidence score - low confidence reports wait for a curator unless approved
+            let confidence = job
+                .confidence_score
+                .map(|d| d.to_string().parse::<f64>().unwrap_or(0.0))
+                .unwrap_or(0.0);
+            let threshold = self
+                .settings_service
+                .get_f64(SettingKey::ReportReviewConfidenceThreshold)
+                .await;
+
+            if confidence < threshold
+                && !ReportReviewService::is_approved(&self.pool, report.id).await?
+            {
+                tracing::info!(
+                    "Sending report {} (ref: {:?}) to review due to low confidence: {:.2}",
+                    report.id,
+                    report.reference_number,
+                    confidence
+                );
 
-        if confidence < MIN_CONFIDENCE_THRESHOLD {
-            tracing::info!(
-                "Rejecting report {} (ref: {:?}) due to low confidence: {:.2}",
-                report.id,
-                report.reference_number,
-                confidence
-            );
+                ReportReviewService::enqueue(&self.pool, report.id, Some(confidence), threshold)
+                    .await?;
 
-            self.report_service
-                .reject(
+                self.report_job_service
+                    .mark_completed(job.id, &self.worker_id)
+                    .await?;
+
+                tracing::info!(
+                    "Report job {} completed (queued for review) for report {} (ref: {:?})",
+                    job.id,
                     report.id,
-                    Some(&format!(
-                        "Low confidence score: {:.2} (threshold: {:.2})",
-                        confidence, MIN_CONFIDENCE_THRESHOLD
-                    )),
-                )
-                .await?;
+                    report.reference_number
+                );
 
-            self.report_job_service.mark_completed(job.id).await?;
+                return Ok(());
+            }
+        }
 
+        tracing::info!(
+            "Processing report job: {} for report: {} (ref: {:?}) from stage {}",
+            job.id,
+            report.id,
+            report.reference_number,
+            job.stage
+        );
+
+        let mut extracted = job
+            .extracted_data
+            .clone()
+            .map(serde_json::from_value::<ExtractedReportData>)
+            .transpose()
+            .map_err(|e| {
+                AppError::Internal(format!(
+                    "Invalid extracted data checkpoint for job {}: {}",
+                    job.id, e
+                ))
+            })?;
+
+        // Verified reports keep their content and status; extraction only refreshes metadata
+        let refresh_only = matches!(
+            report.status,
+            ReportStatus::Verified | ReportStatus::InProgress | ReportStatus::Resolved
+        );
+
+        let mut stage = Some(job.stage);
+        while let Some(current) = stage {
+            let mut checkpoint_data = None;
+
+            match current {
+                ReportJobStage::Extract => {
+                    // Extract data from conversation using LLM
+                    let data = self
+                        .extraction_service
+                        .extract_from_thread(adk_thread_id)
+                        .await?;
+                    checkpoint_data = Some(serde_json::to_value(&data).map_err(|e| {
+                        AppError::Internal(format!("Failed to serialize extracted data: {}", e))
+                    })?);
+                    extracted = Some(data);
+                }
+                ReportJobStage::Content => {
+                    let data = Self::checkpointed(job, extracted.as_ref())?;
+                    self.report_service.update_content(report.id, data).await?;
+                    tracing::info!(
+                        "Updated report {} with extracted content (language: {:?})",
+                        report.id,
+                        data.language
+                    );
+                }
+                ReportJobStage::Redact => {
+                    self.redaction_service
+                        .redact_report(report.id, self.redact_names().await)
+                        .await?;
+                }
+                ReportJobStage::Moderate => {
+                    let data = Self::checkpointed(job, extracted.as_ref())?;
+                    if !self.moderate(job, &report, data).await? {
+                        return Ok(());
+                    }
+                }
+                ReportJobStage::Classify => {
+                    let data = Self::checkpointed(job, extracted.as_ref())?;
+                    self.classify(report.id, data).await?;
+                }
+                ReportJobStage::Locate => {
+                    let data = Self::checkpointed(job, extracted.as_ref())?;
+                    self.locate(report.id, data).await?;
+                }
+                ReportJobStage::Attachments => {
+                    self.link_attachments(report.id, adk_thread_id).await?;
+                }
+                ReportJobStage::Finalize => {
+                    self.finalize(job, report.id).await?;
+                }
+            }
+
+            stage = if refresh_only {
+                current.next_reextract()
+            } else {
+                current.next()
+            };
+            if let Some(next) = stage {
+                self.report_job_service
+                    .save_checkpoint(job.id, &self.worker_id, next, checkpoint_data.as_ref())
+                    .await?;
+            }
+        }
+
+        if refresh_only {
+            self.report_job_service
+                .mark_completed(job.id, &self.worker_id)
+                .await?;
             tracing::info!(
-                "Report job {} completed (rejected) for report {} (ref: {:?})",
+                "Report job {} refreshed categories and location of {} report {} (ref: {:?})",
                 job.id,
+                report.status,
                 report.id,
                 report.reference_number
             );
+        }
+
+        Ok(())
+    }
+
+    /// Whether person names are redacted in addition to identifiers
+    async fn redact_names(&self) -> bool {
+        self.settings_service
+            .get_bool(SettingKey::RedactionRedactNames)
+            .await
+    }
+
+    /// Extracted data of a job past the extract stage
+    fn checkpointed<'a>(
+        job: &ReportJob,
+        extracted: Option<&'a ExtractedReportData>,
+    ) -> Result<&'a ExtractedReportData> {
+        extracted.ok_or_else(|| {
+            AppError::Internal(format!(
+                "Report job {} is at stage {} without extracted data",
+                job.id, job.stage
+            ))
+        })
+    }
 
-            return Ok(());
+    /// Moderate stage: hold reports flagged as spam, abuse, off-topic or test
+    ///
+    /// Returns false if the report was queued for moderation; the job is then completed at
+    /// this stage and resumes here once a moderator clears the report.
+    async fn moderate(
+        &self,
+        job: &ReportJob,
+        report: &Report,
+        extracted: &ExtractedReportData,
+    ) -> Result<bool> {
+        if ModerationService::clear_if_approved(&self.pool, report.id).await? {
+            return Ok(true);
         }
 
-        tracing::info!(
-            "Processing report job: {} for report: {} (ref: {:?}, confidence: {:.2})",
-            job.id,
-            report.id,
-            report.reference_number,
-            confidence
-        );
+        let use_llm = self
+            .settings_service
+            .get_bool(SettingKey::ModerationLlmEnabled)
+            .await;
+        // Keyword lists and the classifier prompt are Indonesian
+        let content = extracted.indonesian_content();
 
-        // Mark as processing
-        self.report_job_service
-            .update_status(job.id, ReportJobStatus::Processing)
+        let verdict = self
+            .moderation_service
+            .moderate_report(report.id, report.user_id.as_deref(), &content, use_llm)
             .await?;
 
-        // Extract data from conversation using LLM
-        let extracted = self
-            .extraction_service
-            .extract_from_thread(adk_thread_id)
-            .await?;
+        if !verdict.category.is_flagged() {
+            return Ok(true);
+        }
 
-        // Update report with extracted content
-        self.report_service
-            .update_content(
-                report.id,
-                &extracted.title,
-                &extracted.description,
-                extracted.timeline.as_deref(),
-                extracted.impact.as_deref(),
-            )
+        self.report_job_service
+            .mark_completed(job.id, &self.worker_id)
             .await?;
 
-        tracing::info!("Updated report {} with extracted content", report.id);
+        tracing::info!(
+            "Report job {} held for moderation ({}) for report {} (ref: {:?})",
+            job.id,
+            verdict.category,
+            report.id,
+            report.reference_number
+        );
+
+        Ok(false)
+    }
 
+    /// Classify stage: assign categories and tag type
+    ///
+    /// Replaces the categories and tag of an earlier extraction; they are kept when the
+    /// new extraction yields none.
+    async fn classify(&self, report_id: Uuid, extracted: &ExtractedReportData) -> Result<()> {
         // Assign multiple categories with their severities
         if !extracted.categories.is_empty() {
             let mut category_assignments = Vec::new();
@@ -179,7 +478,6 @@ impl ReportProcessor {
             for cat in &extracted.categories {
                 if let Some(category_id) = self.lookup_category_id(&cat.slug).await? {
                     category_assignments.push(CreateReportCategory {
-                        report_id: report.id,
                         category_id,
                         severity: cat.severity,
                     });
@@ -187,33 +485,40 @@ impl ReportProcessor {
                     tracing::warn!(
                         "Category slug '{}' not found, skipping for report {}",
                         cat.slug,
-                        report.id
+                        report_id
                     );
                 }
             }
 
             if !category_assignments.is_empty() {
                 self.report_service
-                    .assign_categories(report.id, &category_assignments)
+                    .replace_categories(report_id, &category_assignments)
                     .await?;
                 tracing::info!(
                     "Assigned {} categories to report {}",
                     category_assignments.len(),
-                    report.id
+                    report_id
                 );
             }
         }
 
         // Add tag if extracted
         if let Some(tag_type) = extracted.tag_type {
-            self.report_service.add_tags(report.id, &[tag_type]).await?;
-            tracing::info!("Added tag {:?} to report {}", tag_type, report.id);
+            self.report_service
+                .replace_tags(report_id, &[tag_type])
+                .await?;
+            tracing::info!("Added tag {:?} to report {}", tag_type, report_id);
         }
 
+        Ok(())
+    }
+
+    /// Locate stage: geocode, resolve regions, store the location and link duplicates
+    async fn locate(&self, report_id: Uuid, extracted: &ExtractedReportData) -> Result<()> {
         // Log extracted location fields
         tracing::info!(
             "Extracted location for report {}: village={:?}, district={:?}, regency={:?}, province={:?}, street={:?}",
-            report.id,
+            report_id,
             extracted.location_village,
             extracted.location_district,
             extracted.location_regency,
@@ -336,7 +641,7 @@ impl ReportProcessor {
                 .await?;
 
             let mut create_location = self.geocoding_service.to_create_location(
-                report.id,
+                report_id,
                 raw_input,
                 nominatim_response,
                 crate::features::reports::services::LocationNames {
@@ -380,27 +685,77 @@ impl ReportProcessor {
 
             // NOTE: Geographic clustering disabled - use regional hierarchy instead
             // (province_id, regency_id, district_id, village_id in report_locations)
-        }
 
-        // Copy attachments from thread to report
-        if let Some(thread_id) = report.adk_thread_id {
-            let attachment_count = self
-                .report_service
-                .copy_attachments_from_thread(report.id, thread_id)
-                .await?;
-
-            if attachment_count > 0 {
-                tracing::info!(
-                    "Linked {} attachments from thread {} to report {}",
-                    attachment_count,
-                    thread_id,
-                    report.id
+            // Link likely duplicates now that location and categories are set (best effort)
+            if let Err(e) = self.duplicate_service.detect_for_report(report_id).await {
+                tracing::warn!(
+                    "Failed to detect duplicates for report {}: {:?}",
+                    report_id,
+                    e
                 );
             }
         }
 
+        Ok(())
+    }
+
+    /// Attachments stage: copy attachments from the conversation thread to the report
+    async fn link_attachments(&self, report_id: Uuid, thread_id: Uuid) -> Result<()> {
+        let attachment_count = self
+            .report_service
+            .copy_attachments_from_thread(report_id, thread_id)
+            .await?;
+
+        if attachment_count > 0 {
+            tracing::info!(
+                "Linked {} attachments from thread {} to report {}",
+                attachment_count,
+                thread_id,
+                report_id
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Finalize stage: route the report, complete the job and tell subscribers and the citizen
+    async fn finalize(&self, job: &ReportJob, report_id: Uuid) -> Result<()> {
+        // Route report to the responsible agency (best effort - can be re-run later)
+        if let Err(e) = self.agency_routing_service.route_report(report_id).await {
+            tracing::warn!("Failed to route report {} to an agency: {:?}", report_id, e);
+        }
+
         // Mark job as completed
-        self.report_job_service.mark_completed(job.id).await?;
+        self.report_job_service
+            .mark_completed(job.id, &self.worker_id)
+            .await?;
+
+        let report = self.report_service.get_by_id(report_id).await?;
+
+        // Publish the redacted report to webhook subscribers (best effort)
+        if let Err(e) = self.webhook_service.on_report_processed(report.id).await {
+            tracing::warn!(
+                "Failed to publish webhook for processed report {}: {:?}",
+                report.id,
+                e
+            );
+        }
+
+        // Tell the citizen their report was received (best effort)
+        if let Some(user_id) = report.user_id.as_deref() {
+            let payload = NotificationPayload::ReportProcessed {
+                report_id: report.id,
+                reference_number: report.reference_number.clone(),
+                title: report.title.clone(),
+            };
+            if let Err(e) = self.notification_service.send(user_id, payload).await {
+                tracing::warn!(
+                    "Failed to notify user about processed report {}: {:?}",
+                    report.id,
+                    e
+                );
+            }
+        }
 
         tracing::info!(
             "Report job {} completed for report {} (ref: {:?})",
@@ -0,0 +1,5 @@
+mod settings_dto;
+
+pub use settings_dto::{
+    SettingAuditQueryParams, SystemSettingAuditDto, SystemSettingDto, UpdateSystemSettingDto,
+};
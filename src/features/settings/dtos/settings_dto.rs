@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::features::settings::models::{
+    SettingKey, SettingKind, SystemSetting, SystemSettingAudit,
+};
+use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+/// Response DTO for a runtime setting
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SystemSettingDto {
+    pub key: String,
+    pub description: String,
+    pub kind: SettingKind,
+    /// Value in effect
+    pub value: Value,
+    pub default_value: Value,
+    /// Smallest accepted value (0 for boolean settings)
+    pub min: f64,
+    /// Largest accepted value (1 for boolean settings)
+    pub max: f64,
+    /// Whether the built-in default is in effect
+    pub is_default: bool,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub updated_by: Option<String>,
+}
+
+impl SystemSettingDto {
+    pub fn new(key: SettingKey, stored: Option<SystemSetting>) -> Self {
+        let def = key.definition();
+        let default_value = key.default_value();
+
+        let (value, updated_at, updated_by) = match stored {
+            Some(s) => (s.value, Some(s.updated_at), s.updated_by),
+            None => (default_value.clone(), None, None),
+        };
+
+        Self {
+            key: key.as_str().to_string(),
+            description: def.description.to_string(),
+            kind: def.kind,
+            is_default: updated_at.is_none(),
+            value,
+            default_value,
+            min: def.min,
+            max: def.max,
+            updated_at,
+            updated_by,
+        }
+    }
+}
+
+/// Request DTO for changing a runtime setting
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct UpdateSystemSettingDto {
+    /// New value (a number within the setting's range, or true/false for boolean settings)
+    pub value: Value,
+}
+
+/// Response DTO for an audit entry of a setting change
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SystemSettingAuditDto {
+    pub id: Uuid,
+    pub key: String,
+    pub old_value: Option<Value>,
+    /// Null when the setting was reset to its default
+    pub new_value: Option<Value>,
+    pub changed_by: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+impl From<SystemSettingAudit> for SystemSettingAuditDto {
+    fn from(a: SystemSettingAudit) -> Self {
+        Self {
+            id: a.id,
+            key: a.key,
+            old_value: a.old_value,
+            new_value: a.new_value,
+            changed_by: a.changed_by,
+            changed_at: a.changed_at,
+        }
+    }
+}
+
+/// Query parameters for the settings audit log
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct SettingAuditQueryParams {
+    /// Filter by setting key
+    pub key: Option<String>,
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl SettingAuditQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
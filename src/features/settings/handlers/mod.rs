@@ -0,0 +1,3 @@
+pub mod settings_handler;
+
+pub use settings_handler::*;
@@ -0,0 +1,184 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+
+use crate::core::error::{AppError, Result};
+use crate::core::extractor::AppJson;
+use crate::features::auth::guards::RequireSuperAdmin;
+use crate::features::settings::dtos::{
+    SettingAuditQueryParams, SystemSettingAuditDto, SystemSettingDto, UpdateSystemSettingDto,
+};
+use crate::features::settings::models::SettingKey;
+use crate::features::settings::services::SettingsService;
+use crate::shared::types::{ApiResponse, Meta};
+
+fn parse_key(key: &str) -> Result<SettingKey> {
+    SettingKey::parse(key).ok_or_else(|| AppError::NotFound(format!("Setting '{}' not found", key)))
+}
+
+/// List all runtime settings with their effective values
+#[utoipa::path(
+    get,
+    path = "/api/admin/settings",
+    responses(
+        (status = 200, description = "Runtime settings", body = ApiResponse<Vec<SystemSettingDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required")
+    ),
+    tag = "settings",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_settings(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<SettingsService>>,
+) -> Result<Json<ApiResponse<Vec<SystemSettingDto>>>> {
+    let mut stored = service.list_overrides().await?;
+
+    let dtos: Vec<SystemSettingDto> = SettingKey::ALL
+        .iter()
+        .map(|key| {
+            let current = stored
+                .iter()
+                .position(|s| s.key == key.as_str())
+                .map(|i| stored.swap_remove(i));
+            SystemSettingDto::new(*key, current)
+        })
+        .collect();
+
+    Ok(Json(ApiResponse::success(Some(dtos), None, None)))
+}
+
+/// Get a runtime setting
+#[utoipa::path(
+    get,
+    path = "/api/admin/settings/{key}",
+    params(
+        ("key" = String, Path, description = "Setting key, e.g. report_processor.batch_size")
+    ),
+    responses(
+        (status = 200, description = "Runtime setting", body = ApiResponse<SystemSettingDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required"),
+        (status = 404, description = "Setting not found")
+    ),
+    tag = "settings",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_setting(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<SettingsService>>,
+    Path(key): Path<String>,
+) -> Result<Json<ApiResponse<SystemSettingDto>>> {
+    let key = parse_key(&key)?;
+    let stored = service.get_override(key).await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(SystemSettingDto::new(key, stored)),
+        None,
+        None,
+    )))
+}
+
+/// Change a runtime setting
+///
+/// The value is checked against the setting's type and range. Running workers pick up
+/// the change within 30 seconds.
+#[utoipa::path(
+    put,
+    path = "/api/admin/settings/{key}",
+    params(
+        ("key" = String, Path, description = "Setting key")
+    ),
+    request_body = UpdateSystemSettingDto,
+    responses(
+        (status = 200, description = "Setting updated", body = ApiResponse<SystemSettingDto>),
+        (status = 400, description = "Value has the wrong type or is out of range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required"),
+        (status = 404, description = "Setting not found")
+    ),
+    tag = "settings",
+    security(("bearer_auth" = []))
+)]
+pub async fn update_setting(
+    RequireSuperAdmin(user): RequireSuperAdmin,
+    State(service): State<Arc<SettingsService>>,
+    Path(key): Path<String>,
+    AppJson(dto): AppJson<UpdateSystemSettingDto>,
+) -> Result<Json<ApiResponse<SystemSettingDto>>> {
+    let key = parse_key(&key)?;
+    let setting = service.update(key, &dto.value, &user.sub).await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(SystemSettingDto::new(key, Some(setting))),
+        Some("Setting updated".to_string()),
+        None,
+    )))
+}
+
+/// Reset a runtime setting to its built-in default
+#[utoipa::path(
+    delete,
+    path = "/api/admin/settings/{key}",
+    params(
+        ("key" = String, Path, description = "Setting key")
+    ),
+    responses(
+        (status = 200, description = "Setting reset to default", body = ApiResponse<SystemSettingDto>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required"),
+        (status = 404, description = "Setting not found")
+    ),
+    tag = "settings",
+    security(("bearer_auth" = []))
+)]
+pub async fn reset_setting(
+    RequireSuperAdmin(user): RequireSuperAdmin,
+    State(service): State<Arc<SettingsService>>,
+    Path(key): Path<String>,
+) -> Result<Json<ApiResponse<SystemSettingDto>>> {
+    let key = parse_key(&key)?;
+    service.reset(key, &user.sub).await?;
+
+    Ok(Json(ApiResponse::success(
+        Some(SystemSettingDto::new(key, None)),
+        Some("Setting reset to default".to_string()),
+        None,
+    )))
+}
+
+/// List changes to runtime settings (newest first)
+#[utoipa::path(
+    get,
+    path = "/api/admin/settings/audit",
+    params(SettingAuditQueryParams),
+    responses(
+        (status = 200, description = "Setting changes", body = ApiResponse<Vec<SystemSettingAuditDto>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Super admin access required"),
+        (status = 404, description = "Setting not found")
+    ),
+    tag = "settings",
+    security(("bearer_auth" = []))
+)]
+pub async fn list_setting_audit(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<SettingsService>>,
+    Query(params): Query<SettingAuditQueryParams>,
+) -> Result<Json<ApiResponse<Vec<SystemSettingAuditDto>>>> {
+    let key = params.key.as_deref().map(parse_key).transpose()?;
+
+    let (items, total) = service
+        .list_audit(key, params.limit(), params.offset())
+        .await?;
+    let dtos: Vec<SystemSettingAuditDto> = items.into_iter().map(Into::into).collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
@@ -0,0 +1,8 @@
+pub mod dtos;
+pub mod handlers;
+pub mod models;
+pub mod routes;
+pub mod services;
+
+pub use models::SettingKey;
+pub use services::SettingsService;
@@ -0,0 +1,5 @@
+mod system_setting;
+
+pub use system_setting::{
+    SettingKey, SettingKind, SystemSetting, SystemSettingAudit, ATTACHMENT_MAX_SIZE_UPPER_BOUND,
+};
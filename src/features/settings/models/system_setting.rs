@@ -0,0 +1,302 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Value type of a runtime setting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SettingKind {
+    Integer,
+    Float,
+    /// On/off flag stored as a JSON bool; `default` is 1.0 for on and the range is 0..1
+    Boolean,
+}
+
+/// Type, range and default of a runtime setting
+#[derive(Debug, Clone, Copy)]
+pub struct SettingDefinition {
+    pub kind: SettingKind,
+    pub default: f64,
+    pub min: f64,
+    pub max: f64,
+    pub description: &'static str,
+}
+
+/// Keys of the runtime settings registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SettingKey {
+    ReportProcessorMaxRetries,
+    ReportProcessorBatchSize,
+    ReportProcessorBatchIntervalSecs,
+    ReportReviewConfidenceThreshold,
+    AttachmentsMaxPerThread,
+    AttachmentsMaxSizeBytes,
+    AgentMaxIterations,
+    TrackingRequestsPerMinute,
+    RedactionRedactNames,
+    ModerationLlmEnabled,
+    ModerationAbuseThreshold,
+    ModerationAbuseCooldownHours,
+}
+
+/// Largest value accepted for `attachments.max_size_bytes` (upload body limits depend on it)
+pub const ATTACHMENT_MAX_SIZE_UPPER_BOUND: usize = 50 * 1024 * 1024;
+
+impl SettingKey {
+    /// All registered settings
+    pub const ALL: &'static [SettingKey] = &[
+        SettingKey::ReportProcessorMaxRetries,
+        SettingKey::ReportProcessorBatchSize,
+        SettingKey::ReportProcessorBatchIntervalSecs,
+        SettingKey::ReportReviewConfidenceThreshold,
+        SettingKey::AttachmentsMaxPerThread,
+        SettingKey::AttachmentsMaxSizeBytes,
+        SettingKey::AgentMaxIterations,
+        SettingKey::TrackingRequestsPerMinute,
+        SettingKey::RedactionRedactNames,
+        SettingKey::ModerationLlmEnabled,
+        SettingKey::ModerationAbuseThreshold,
+        SettingKey::ModerationAbuseCooldownHours,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SettingKey::ReportProcessorMaxRetries => "report_processor.max_retries",
+            SettingKey::ReportProcessorBatchSize => "report_processor.batch_size",
+            SettingKey::ReportProcessorBatchIntervalSecs => "report_processor.batch_interval_secs",
+            SettingKey::ReportReviewConfidenceThreshold => "report_review.confidence_threshold",
+            SettingKey::AttachmentsMaxPerThread => "attachments.max_per_thread",
+            SettingKey::AttachmentsMaxSizeBytes => "attachments.max_size_bytes",
+            SettingKey::AgentMaxIterations => "agent.max_iterations",
+            SettingKey::TrackingRequestsPerMinute => "tracking.requests_per_minute",
+            SettingKey::RedactionRedactNames => "redaction.redact_names",
+            SettingKey::ModerationLlmEnabled => "moderation.llm_enabled",
+            SettingKey::ModerationAbuseThreshold => "moderation.abuse_threshold",
+            SettingKey::ModerationAbuseCooldownHours => "moderation.abuse_cooldown_hours",
+        }
+    }
+
+    /// Look up a key by its name
+    pub fn parse(key: &str) -> Option<SettingKey> {
+        Self::ALL.iter().copied().find(|k| k.as_str() == key)
+    }
+
+    pub fn definition(&self) -> SettingDefinition {
+        use SettingKind::{Boolean, Float, Integer};
+
+        let (kind, default, min, max, description) = match self {
+            SettingKey::ReportProcessorMaxRetries => (
+                Integer,
+                3.0,
+                1.0,
+                20.0,
+                "Attempts before a report job is marked as failed",
+            ),
+            SettingKey::ReportProcessorBatchSize => (
+                Integer,
+                10.0,
+                1.0,
+                100.0,
+                "Report jobs claimed per processor batch",
+            ),
+            SettingKey::ReportProcessorBatchIntervalSecs => (
+                Integer,
+                30.0,
+                5.0,
+                3600.0,
+                "Seconds between processor polls when no job notification arrives",
+            ),
+            SettingKey::ReportReviewConfidenceThreshold => (
+                Float,
+                0.7,
+                0.0,
+                1.0,
+                "Submissions below this agent confidence go to the review queue",
+            ),
+            SettingKey::AttachmentsMaxPerThread => (
+                Integer,
+                5.0,
+                1.0,
+                50.0,
+                "Maximum attachments per conversation thread",
+            ),
+            SettingKey::AttachmentsMaxSizeBytes => (
+                Integer,
+                (20 * 1024 * 1024) as f64,
+                1024.0,
+                ATTACHMENT_MAX_SIZE_UPPER_BOUND as f64,
+                "Maximum size of a single attachment in bytes",
+            ),
+            SettingKey::AgentMaxIterations => (
+                Integer,
+                10.0,
+                1.0,
+                50.0,
+                "Maximum tool-use iterations of the citizen report agent per message",
+            ),
+            SettingKey::TrackingRequestsPerMinute => (
+                Integer,
+                30.0,
+                1.0,
+                600.0,
+                "Public report tracking lookups allowed per client per minute",
+            ),
+            SettingKey::RedactionRedactNames => (
+                Boolean,
+                0.0,
+                0.0,
+                1.0,
+                "Also redact person names following honorifics (Pak, Bu, Sdr, ...)",
+            ),
+            SettingKey::ModerationLlmEnabled => (
+                Boolean,
+                1.0,
+                0.0,
+                1.0,
+                "Classify reports with the LLM when the keyword lists find nothing",
+            ),
+            SettingKey::ModerationAbuseThreshold => (
+                Integer,
+                5.0,
+                1.0,
+                1000.0,
+                "Spam and abuse flags after which a user is blocked from chatting",
+            ),
+            SettingKey::ModerationAbuseCooldownHours => (
+                Integer,
+                24.0,
+                1.0,
+                720.0,
+                "Hours a user over the abuse threshold stays blocked after their latest flag",
+            ),
+        };
+
+        SettingDefinition {
+            kind,
+            default,
+            min,
+            max,
+            description,
+        }
+    }
+
+    /// Built-in default as a JSON value
+    pub fn default_value(&self) -> Value {
+        let def = self.definition();
+        match def.kind {
+            SettingKind::Integer => Value::from(def.default as i64),
+            SettingKind::Float => Value::from(def.default),
+            SettingKind::Boolean => Value::from(def.default != 0.0),
+        }
+    }
+
+    /// Check a new value against the type and range of the setting
+    /// Returns the normalized value to store
+    pub fn validate(&self, value: &Value) -> Result<Value, String> {
+        let def = self.definition();
+
+        if def.kind == SettingKind::Boolean {
+            return value
+                .as_bool()
+                .map(Value::from)
+                .ok_or_else(|| format!("'{}' must be true or false", self.as_str()));
+        }
+
+        let number = value
+            .as_f64()
+            .ok_or_else(|| format!("'{}' must be a number", self.as_str()))?;
+
+        if number < def.min || number > def.max {
+            return Err(format!(
+                "'{}' must be between {} and {}",
+                self.as_str(),
+                def.min,
+                def.max
+            ));
+        }
+
+        if def.kind == SettingKind::Integer {
+            if number.fract() != 0.0 {
+                return Err(format!("'{}' must be an integer", self.as_str()));
+            }
+            return Ok(Value::from(number as i64));
+        }
+
+        Ok(Value::from(number))
+    }
+}
+
+impl std::fmt::Display for SettingKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Database model for a setting override
+#[derive(Debug, Clone, FromRow)]
+pub struct SystemSetting {
+    pub key: String,
+    pub value: Value,
+    pub updated_at: DateTime<Utc>,
+    pub updated_by: Option<String>,
+}
+
+/// Database model for an audit entry of a setting change
+#[derive(Debug, Clone, FromRow)]
+pub struct SystemSettingAudit {
+    pub id: Uuid,
+    pub key: String,
+    pub old_value: Option<Value>,
+    /// None when the setting was reset to its default
+    pub new_value: Option<Value>,
+    pub changed_by: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_keys_round_trip() {
+        for key in SettingKey::ALL {
+            assert_eq!(SettingKey::parse(key.as_str()), Some(*key));
+            assert!(key.validate(&key.default_value()).is_ok());
+        }
+        assert_eq!(SettingKey::parse("unknown.key"), None);
+    }
+
+    #[test]
+    fn test_validate_checks_type_and_range() {
+        let retries = SettingKey::ReportProcessorMaxRetries;
+        assert_eq!(retries.validate(&json!(5)), Ok(json!(5)));
+        assert_eq!(retries.validate(&json!(5.0)), Ok(json!(5)));
+        assert!(retries.validate(&json!(2.5)).is_err());
+        assert!(retries.validate(&json!(0)).is_err());
+        assert!(retries.validate(&json!("5")).is_err());
+
+        let threshold = SettingKey::ReportReviewConfidenceThreshold;
+        assert_eq!(threshold.validate(&json!(1)), Ok(json!(1.0)));
+        assert!(threshold.validate(&json!(1.5)).is_err());
+    }
+
+    #[test]
+    fn test_flags_are_booleans() {
+        let flag = SettingKey::ModerationLlmEnabled;
+        assert_eq!(flag.definition().kind, SettingKind::Boolean);
+        assert_eq!(flag.default_value(), json!(true));
+        assert_eq!(
+            SettingKey::RedactionRedactNames.default_value(),
+            json!(false)
+        );
+
+        assert_eq!(flag.validate(&json!(false)), Ok(json!(false)));
+        assert!(flag.validate(&json!(0)).is_err());
+        assert!(flag.validate(&json!(1)).is_err());
+        assert!(flag.validate(&json!("true")).is_err());
+    }
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use axum::{routing::get, Router};
+
+use crate::features::settings::handlers;
+use crate::features::settings::services::SettingsService;
+
+/// Create admin routes for runtime settings (super admin only)
+pub fn admin_routes(settings_service: Arc<SettingsService>) -> Router {
+    Router::new()
+        .route("/api/admin/settings", get(handlers::list_settings))
+        .route(
+            "/api/admin/settings/audit",
+            get(handlers::list_setting_audit),
+        )
+        .route(
+            "/api/admin/settings/{key}",
+            get(handlers::get_setting)
+                .put(handlers::update_setting)
+                .delete(handlers::reset_setting),
+        )
+        .with_state(settings_service)
+}
@@ -0,0 +1,3 @@
+mod settings_service;
+
+pub use settings_service::SettingsService;
@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+use sqlx::PgPool;
+use tokio::sync::RwLock;
+
+use crate::core::error::{AppError, Result};
+use crate::features::settings::models::{SettingKey, SystemSetting, SystemSettingAudit};
+
+/// How long cached overrides are used before they are read again
+///
+/// Changes made through another instance take effect after at most this long.
+const CACHE_TTL: Duration = Duration::from_secs(30);
+
+struct SettingsCache {
+    values: HashMap<String, Value>,
+    loaded_at: Instant,
+}
+
+/// Service for the runtime settings registry
+pub struct SettingsService {
+    pool: PgPool,
+    cache: RwLock<Option<SettingsCache>>,
+}
+
+impl SettingsService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            cache: RwLock::new(None),
+        }
+    }
+
+    // ===== Typed reads (cached) =====
+
+    /// Effective value of an integer setting
+    pub async fn get_i64(&self, key: SettingKey) -> i64 {
+        let value = self.effective(key).await;
+        value
+            .as_i64()
+            .or_else(|| value.as_f64().map(|v| v as i64))
+            .unwrap_or(key.definition().default as i64)
+    }
+
+    /// Effective value of a boolean setting
+    pub async fn get_bool(&self, key: SettingKey) -> bool {
+        self.effective(key)
+            .await
+            .as_bool()
+            .unwrap_or(key.definition().default != 0.0)
+    }
+
+    /// Effective value of a float setting
+    pub async fn get_f64(&self, key: SettingKey) -> f64 {
+        self.effective(key)
+            .await
+            .as_f64()
+            .unwrap_or(key.definition().default)
+    }
+
+    /// Override or default of a setting
+    ///
+    /// Falls back to the default when the settings cannot be loaded, so workers keep running.
+    async fn effective(&self, key: SettingKey) -> Value {
+        {
+            let cache = self.cache.read().await;
+            if let Some(ref cached) = *cache {
+                if cached.loaded_at.elapsed() < CACHE_TTL {
+                    return Self::value_or_default(&cached.values, key);
+                }
+            }
+        }
+
+        match self.reload().await {
+            Ok(values) => Self::value_or_default(&values, key),
+            Err(e) => {
+                tracing::warn!("Failed to load settings, using default of {}: {:?}", key, e);
+                key.default_value()
+            }
+        }
+    }
+
+    fn value_or_default(values: &HashMap<String, Value>, key: SettingKey) -> Value {
+        values
+            .get(key.as_str())
+            .cloned()
+            .unwrap_or_else(|| key.default_value())
+    }
+
+    /// Read all overrides and refresh the cache
+    async fn reload(&self) -> Result<HashMap<String, Value>> {
+        let values: HashMap<String, Value> = self
+            .list_overrides()
+            .await?
+            .into_iter()
+            .filter_map(|s| {
+                // Skip rows that no longer validate (e.g. a range was narrowed)
+                let key = SettingKey::parse(&s.key)?;
+                match key.validate(&s.value) {
+                    Ok(value) => Some((s.key, value)),
+                    Err(e) => {
+                        tracing::warn!("Ignoring stored setting: {}", e);
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        let mut cache = self.cache.write().await;
+        *cache = Some(SettingsCache {
+            values: values.clone(),
+            loaded_at: Instant::now(),
+        });
+
+        Ok(values)
+    }
+
+    /// Drop cached values so the next read sees the latest change
+    async fn invalidate(&self) {
+        *self.cache.write().await = None;
+    }
+
+    // ===== Admin =====
+
+    /// List all stored overrides
+    pub async fn list_overrides(&self) -> Result<Vec<SystemSetting>> {
+        sqlx::query_as!(
+            SystemSetting,
+            "SELECT key, value, updated_at, updated_by FROM system_settings ORDER BY key"
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list system settings: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Get the stored override of a setting (None if it uses the default)
+    pub async fn get_override(&self, key: SettingKey) -> Result<Option<SystemSetting>> {
+        sqlx::query_as!(
+            SystemSetting,
+            "SELECT key, value, updated_at, updated_by FROM system_settings WHERE key = $1",
+            key.as_str()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get system setting: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Validate and store a new value, recording the change in the audit log
+    pub async fn update(
+        &self,
+        key: SettingKey,
+        value: &Value,
+        changed_by: &str,
+    ) -> Result<SystemSetting> {
+        let value = key.validate(value).map_err(AppError::Validation)?;
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let old_value = sqlx::query_scalar!(
+            "SELECT value FROM system_settings WHERE key = $1 FOR UPDATE",
+            key.as_str()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to lock system setting: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let setting = sqlx::query_as!(
+            SystemSetting,
+            r#"
+            INSERT INTO system_settings (key, value, updated_at, updated_by)
+            VALUES ($1, $2, NOW(), $3)
+            ON CONFLICT (key) DO UPDATE
+            SET value = EXCLUDED.value, updated_at = NOW(), updated_by = EXCLUDED.updated_by
+            RETURNING key, value, updated_at, updated_by
+            "#,
+            key.as_str(),
+            value,
+            changed_by
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to update system setting: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO system_setting_audits (key, old_value, new_value, changed_by)
+            VALUES ($1, $2, $3, $4)
+            "#,
+            key.as_str(),
+            old_value,
+            Some(&setting.value),
+            changed_by
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to record system setting audit: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit system setting update: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        self.invalidate().await;
+
+        tracing::info!(
+            "Setting {} changed to {} by {}",
+            key,
+            setting.value,
+            changed_by
+        );
+        Ok(setting)
+    }
+
+    /// Remove the override of a setting so the default applies again
+    pub async fn reset(&self, key: SettingKey, changed_by: &str) -> Result<()> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let old_value = sqlx::query_scalar!(
+            "DELETE FROM system_settings WHERE key = $1 RETURNING value",
+            key.as_str()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to reset system setting: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        // Nothing to audit if the default was already in effect
+        let Some(old_value) = old_value else {
+            return Ok(());
+        };
+
+        sqlx::query!(
+            r#"
+            INSERT INTO system_setting_audits (key, old_value, new_value, changed_by)
+            VALUES ($1, $2, NULL, $3)
+            "#,
+            key.as_str(),
+            old_value,
+            changed_by
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to record system setting audit: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit system setting reset: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        self.invalidate().await;
+
+        tracing::info!("Setting {} reset to default by {}", key, changed_by);
+        Ok(())
+    }
+
+    /// List setting changes, optionally for one key (newest first)
+    /// Returns (entries, total_count)
+    pub async fn list_audit(
+        &self,
+        key: Option<SettingKey>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<SystemSettingAudit>, i64)> {
+        let key = key.map(|k| k.as_str());
+
+        let total = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM system_setting_audits
+            WHERE ($1::text IS NULL OR key = $1)
+            "#,
+            key
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to count system setting audits: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let entries = sqlx::query_as!(
+            SystemSettingAudit,
+            r#"
+            SELECT id, key, old_value, new_value, changed_by, changed_at
+            FROM system_setting_audits
+            WHERE ($1::text IS NULL OR key = $1)
+            ORDER BY changed_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+            key,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list system setting audits: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok((entries, total))
+    }
+}
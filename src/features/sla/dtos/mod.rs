@@ -0,0 +1,3 @@
+mod sla_dto;
+
+pub use sla_dto::*;
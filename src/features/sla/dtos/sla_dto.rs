@@ -0,0 +1,181 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::features::reports::models::{ReportSeverity, ReportStatus};
+use crate::features::sla::models::{ReportSlaDeadline, ReportSlaEscalation, SlaPolicy};
+
+// =============================================================================
+// SLA POLICIES
+// =============================================================================
+
+/// Query params for listing SLA policies
+#[derive(Debug, Clone, Deserialize, IntoParams, ToSchema)]
+pub struct SlaPolicyQueryParams {
+    /// Filter by active status (true = active, false = inactive, none = all)
+    pub is_active: Option<bool>,
+}
+
+/// Create SLA policy request
+///
+/// Leave `category_id` and/or `severity` empty to match any category/severity.
+/// The most specific active policy matching a report wins.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateSlaPolicyDto {
+    #[validate(length(min = 1, max = 200))]
+    pub name: String,
+
+    /// Category the policy applies to (child categories included)
+    pub category_id: Option<Uuid>,
+
+    pub severity: Option<ReportSeverity>,
+
+    /// Hours allowed from submission until verification (none = no deadline)
+    #[validate(range(min = 1, max = 8760))]
+    pub verify_within_hours: Option<i32>,
+
+    /// Hours allowed from verification until resolution (none = no deadline)
+    #[validate(range(min = 1, max = 8760))]
+    pub resolve_within_hours: Option<i32>,
+}
+
+/// Update SLA policy request (the category/severity scope cannot be changed)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateSlaPolicyDto {
+    #[validate(length(min = 1, max = 200))]
+    pub name: Option<String>,
+
+    #[validate(range(min = 1, max = 8760))]
+    pub verify_within_hours: Option<i32>,
+
+    #[validate(range(min = 1, max = 8760))]
+    pub resolve_within_hours: Option<i32>,
+
+    pub is_active: Option<bool>,
+}
+
+/// SLA policy response
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SlaPolicyResponseDto {
+    pub id: Uuid,
+    pub name: String,
+    pub category_id: Option<Uuid>,
+    pub severity: Option<ReportSeverity>,
+    pub verify_within_hours: Option<i32>,
+    pub resolve_within_hours: Option<i32>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<SlaPolicy> for SlaPolicyResponseDto {
+    fn from(p: SlaPolicy) -> Self {
+        Self {
+            id: p.id,
+            name: p.name,
+            category_id: p.category_id,
+            severity: p.severity,
+            verify_within_hours: p.verify_within_hours,
+            resolve_within_hours: p.resolve_within_hours,
+            is_active: p.is_active,
+            created_at: p.created_at,
+            updated_at: p.updated_at,
+        }
+    }
+}
+
+// =============================================================================
+// REPORT SLA
+// =============================================================================
+
+/// SLA deadline of a report
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReportSlaDeadlineDto {
+    pub id: Uuid,
+    pub policy_id: Option<Uuid>,
+    /// Status the report must reach before `due_at`
+    pub target_status: ReportStatus,
+    pub started_at: DateTime<Utc>,
+    pub due_at: DateTime<Utc>,
+    pub met_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub breached_at: Option<DateTime<Utc>>,
+    pub escalation_level: i32,
+    pub last_escalated_at: Option<DateTime<Utc>>,
+    /// TRUE if the deadline is still open and past due
+    pub is_overdue: bool,
+}
+
+impl From<ReportSlaDeadline> for ReportSlaDeadlineDto {
+    fn from(d: ReportSlaDeadline) -> Self {
+        let is_overdue = d.met_at.is_none() && d.cancelled_at.is_none() && d.due_at < Utc::now();
+        Self {
+            id: d.id,
+            policy_id: d.policy_id,
+            target_status: d.target_status,
+            started_at: d.started_at,
+            due_at: d.due_at,
+            met_at: d.met_at,
+            cancelled_at: d.cancelled_at,
+            breached_at: d.breached_at,
+            escalation_level: d.escalation_level,
+            last_escalated_at: d.last_escalated_at,
+            is_overdue,
+        }
+    }
+}
+
+/// Escalation of a breached SLA deadline
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReportSlaEscalationDto {
+    pub id: Uuid,
+    pub deadline_id: Uuid,
+    pub escalation_level: i32,
+    /// "agency" or "admin_curators"
+    pub target_type: String,
+    pub agency_id: Option<Uuid>,
+    pub agency_name: Option<String>,
+    /// Region level of the agency jurisdiction the report was escalated to
+    pub region_level: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ReportSlaEscalation> for ReportSlaEscalationDto {
+    fn from(e: ReportSlaEscalation) -> Self {
+        Self {
+            id: e.id,
+            deadline_id: e.deadline_id,
+            escalation_level: e.escalation_level,
+            target_type: e.target_type,
+            agency_id: e.agency_id,
+            agency_name: e.agency_name,
+            region_level: e.region_level,
+            created_at: e.created_at,
+        }
+    }
+}
+
+/// SLA state of a report: deadlines and escalations
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReportSlaDto {
+    pub report_id: Uuid,
+    pub deadlines: Vec<ReportSlaDeadlineDto>,
+    pub escalations: Vec<ReportSlaEscalationDto>,
+}
+
+/// Overdue counts across all reports
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SlaOverdueSummaryDto {
+    /// Reports with at least one open deadline past due
+    pub overdue_reports: i64,
+    /// Reports past their verification deadline
+    pub overdue_verification: i64,
+    /// Reports past their resolution deadline
+    pub overdue_resolution: i64,
+    /// Reports with at least one escalated open deadline
+    pub escalated_reports: i64,
+    /// Reports with an open deadline due in the next 24 hours
+    pub due_within_24h: i64,
+}
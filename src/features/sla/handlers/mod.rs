@@ -0,0 +1,3 @@
+pub mod sla_handler;
+
+pub use sla_handler::*;
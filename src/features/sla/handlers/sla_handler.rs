@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::core::error::{AppError, Result};
+use crate::core::extractor::AppJson;
+use crate::features::auth::guards::{RequireAdminCurator, RequireSuperAdmin};
+use crate::features::sla::dtos::{
+    CreateSlaPolicyDto, ReportSlaDto, SlaOverdueSummaryDto, SlaPolicyQueryParams,
+    SlaPolicyResponseDto, UpdateSlaPolicyDto,
+};
+use crate::features::sla::services::SlaService;
+use crate::shared::types::{ApiResponse, Meta};
+
+// =============================================================================
+// SLA POLICY HANDLERS
+// =============================================================================
+
+/// List SLA policies (super admin only)
+#[utoipa::path(
+    get,
+    path = "/api/admin/sla-policies",
+    params(SlaPolicyQueryParams),
+    responses(
+        (status = 200, description = "SLA policies retrieved", body = ApiResponse<Vec<SlaPolicyResponseDto>>),
+        (status = 403, description = "Forbidden - super admin only")
+    ),
+    tag = "sla",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_sla_policies(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<SlaService>>,
+    Query(params): Query<SlaPolicyQueryParams>,
+) -> Result<Json<ApiResponse<Vec<SlaPolicyResponseDto>>>> {
+    let policies = service.list_policies(&params).await?;
+    let total = policies.len() as i64;
+    Ok(Json(ApiResponse::success(
+        Some(policies),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Create an SLA policy (super admin only)
+#[utoipa::path(
+    post,
+    path = "/api/admin/sla-policies",
+    request_body = CreateSlaPolicyDto,
+    responses(
+        (status = 200, description = "SLA policy created", body = ApiResponse<SlaPolicyResponseDto>),
+        (status = 400, description = "Validation error"),
+        (status = 403, description = "Forbidden - super admin only"),
+        (status = 409, description = "Active policy already exists for this category and severity")
+    ),
+    tag = "sla",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_sla_policy(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<SlaService>>,
+    AppJson(dto): AppJson<CreateSlaPolicyDto>,
+) -> Result<Json<ApiResponse<SlaPolicyResponseDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let policy = service.create_policy(dto).await?;
+    Ok(Json(ApiResponse::success(Some(policy), None, None)))
+}
+
+/// Update an SLA policy (super admin only)
+#[utoipa::path(
+    put,
+    path = "/api/admin/sla-policies/{id}",
+    params(
+        ("id" = Uuid, Path, description = "SLA policy ID")
+    ),
+    request_body = UpdateSlaPolicyDto,
+    responses(
+        (status = 200, description = "SLA policy updated", body = ApiResponse<SlaPolicyResponseDto>),
+        (status = 400, description = "Validation error"),
+        (status = 404, description = "SLA policy not found"),
+        (status = 403, description = "Forbidden - super admin only"),
+        (status = 409, description = "Active policy already exists for this category and severity")
+    ),
+    tag = "sla",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_sla_policy(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<SlaService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<UpdateSlaPolicyDto>,
+) -> Result<Json<ApiResponse<SlaPolicyResponseDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let policy = service.update_policy(id, dto).await?;
+    Ok(Json(ApiResponse::success(Some(policy), None, None)))
+}
+
+/// Deactivate an SLA policy (soft delete, super admin only)
+#[utoipa::path(
+    delete,
+    path = "/api/admin/sla-policies/{id}",
+    params(
+        ("id" = Uuid, Path, description = "SLA policy ID")
+    ),
+    responses(
+        (status = 200, description = "SLA policy deactivated"),
+        (status = 404, description = "SLA policy not found"),
+        (status = 403, description = "Forbidden - super admin only")
+    ),
+    tag = "sla",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_sla_policy(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<SlaService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>> {
+    service.delete_policy(id).await?;
+    Ok(Json(ApiResponse::success(None, None, None)))
+}
+
+// =============================================================================
+// REPORT SLA HANDLERS
+// =============================================================================
+
+/// Get overdue and escalated report counts (admin curator)
+#[utoipa::path(
+    get,
+    path = "/api/admin/sla/overdue-summary",
+    responses(
+        (status = 200, description = "Overdue counts", body = ApiResponse<SlaOverdueSummaryDto>),
+        (status = 403, description = "Forbidden - Admin curator access required")
+    ),
+    tag = "sla",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_sla_overdue_summary(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<SlaService>>,
+) -> Result<Json<ApiResponse<SlaOverdueSummaryDto>>> {
+    let summary = service.overdue_summary().await?;
+    Ok(Json(ApiResponse::success(Some(summary), None, None)))
+}
+
+/// Get SLA deadlines and escalations of a report (admin curator)
+#[utoipa::path(
+    get,
+    path = "/api/admin/reports/{id}/sla",
+    params(
+        ("id" = Uuid, Path, description = "Report ID")
+    ),
+    responses(
+        (status = 200, description = "Report SLA state", body = ApiResponse<ReportSlaDto>),
+        (status = 404, description = "Report not found"),
+        (status = 403, description = "Forbidden - Admin curator access required")
+    ),
+    tag = "sla",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_report_sla(
+    RequireAdminCurator(_user): RequireAdminCurator,
+    State(service): State<Arc<SlaService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ReportSlaDto>>> {
+    let sla = service.get_report_sla(id).await?;
+    Ok(Json(ApiResponse::success(Some(sla), None, None)))
+}
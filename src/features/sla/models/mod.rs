@@ -0,0 +1,7 @@
+mod report_sla_deadline;
+mod report_sla_escalation;
+mod sla_policy;
+
+pub use report_sla_deadline::{ReportSlaDeadline, SlaTransition};
+pub use report_sla_escalation::{EscalationTargetType, ReportSlaEscalation};
+pub use sla_policy::SlaPolicy;
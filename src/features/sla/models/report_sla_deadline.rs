@@ -0,0 +1,100 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::features::reports::models::ReportStatus;
+
+/// Database model for an SLA deadline of a report
+#[derive(Debug, Clone, FromRow)]
+#[allow(dead_code)]
+pub struct ReportSlaDeadline {
+    pub id: Uuid,
+    pub report_id: Uuid,
+    pub policy_id: Option<Uuid>,
+    pub target_status: ReportStatus,
+    pub started_at: DateTime<Utc>,
+    pub due_at: DateTime<Utc>,
+    pub met_at: Option<DateTime<Utc>>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub breached_at: Option<DateTime<Utc>>,
+    pub escalation_level: i32,
+    pub last_escalated_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Effect of a report entering a status on its SLA deadlines
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SlaTransition {
+    /// Open deadline for this target status is met
+    pub close: Option<ReportStatus>,
+    /// Open a deadline for this target status (if none is open)
+    pub open: Option<ReportStatus>,
+    /// Cancel all open deadlines
+    pub cancel_all: bool,
+}
+
+impl SlaTransition {
+    /// SLA effect of entering `status`
+    ///
+    /// - pending/draft: verification clock starts
+    /// - verified: verification met, resolution clock starts
+    /// - in_progress: resolution clock starts if not running (reopened reports)
+    /// - resolved: resolution met
+    /// - rejected: all clocks stop
+    pub fn for_status(status: ReportStatus) -> Self {
+        match status {
+            ReportStatus::Pending | ReportStatus::Draft => Self {
+                open: Some(ReportStatus::Verified),
+                ..Default::default()
+            },
+            ReportStatus::Verified => Self {
+                close: Some(ReportStatus::Verified),
+                open: Some(ReportStatus::Resolved),
+                ..Default::default()
+            },
+            ReportStatus::InProgress => Self {
+                open: Some(ReportStatus::Resolved),
+                ..Default::default()
+            },
+            ReportStatus::Resolved => Self {
+                close: Some(ReportStatus::Resolved),
+                ..Default::default()
+            },
+            ReportStatus::Rejected => Self {
+                cancel_all: true,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_submission_starts_verification_clock() {
+        let t = SlaTransition::for_status(ReportStatus::Pending);
+        assert_eq!(t.open, Some(ReportStatus::Verified));
+        assert_eq!(t.close, None);
+        assert!(!t.cancel_all);
+    }
+
+    #[test]
+    fn test_verification_closes_and_starts_resolution_clock() {
+        let t = SlaTransition::for_status(ReportStatus::Verified);
+        assert_eq!(t.close, Some(ReportStatus::Verified));
+        assert_eq!(t.open, Some(ReportStatus::Resolved));
+    }
+
+    #[test]
+    fn test_resolution_and_rejection_stop_clocks() {
+        let resolved = SlaTransition::for_status(ReportStatus::Resolved);
+        assert_eq!(resolved.close, Some(ReportStatus::Resolved));
+        assert_eq!(resolved.open, None);
+
+        let rejected = SlaTransition::for_status(ReportStatus::Rejected);
+        assert!(rejected.cancel_all);
+        assert_eq!(rejected.open, None);
+    }
+}
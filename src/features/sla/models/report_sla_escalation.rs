@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Who a breached deadline was escalated to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscalationTargetType {
+    /// Agency covering the parent region of the previous escalation level
+    Agency,
+    /// Admin curators (no agency left higher in the region hierarchy)
+    AdminCurators,
+}
+
+impl EscalationTargetType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EscalationTargetType::Agency => "agency",
+            EscalationTargetType::AdminCurators => "admin_curators",
+        }
+    }
+}
+
+impl std::fmt::Display for EscalationTargetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Database model for an escalation of a breached SLA deadline
+#[derive(Debug, Clone, FromRow)]
+#[allow(dead_code)]
+pub struct ReportSlaEscalation {
+    pub id: Uuid,
+    pub deadline_id: Uuid,
+    pub report_id: Uuid,
+    pub escalation_level: i32,
+    pub target_type: String,
+    pub agency_id: Option<Uuid>,
+    pub agency_name: Option<String>,
+    pub region_level: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::features::reports::models::ReportSeverity;
+
+/// Database model for an SLA policy
+#[derive(Debug, Clone, FromRow)]
+#[allow(dead_code)]
+pub struct SlaPolicy {
+    pub id: Uuid,
+    pub name: String,
+    pub category_id: Option<Uuid>,
+    pub severity: Option<ReportSeverity>,
+    pub verify_within_hours: Option<i32>,
+    pub resolve_within_hours: Option<i32>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use axum::{
+    routing::{get, put},
+    Router,
+};
+
+use crate::features::sla::handlers;
+use crate::features::sla::services::SlaService;
+
+/// Create admin routes for SLA policies and report SLA state
+pub fn admin_routes(sla_service: Arc<SlaService>) -> Router {
+    Router::new()
+        .route(
+            "/api/admin/sla-policies",
+            get(handlers::list_sla_policies).post(handlers::create_sla_policy),
+        )
+        .route(
+            "/api/admin/sla-policies/{id}",
+            put(handlers::update_sla_policy).delete(handlers::delete_sla_policy),
+        )
+        .route(
+            "/api/admin/sla/overdue-summary",
+            get(handlers::get_sla_overdue_summary),
+        )
+        .route("/api/admin/reports/{id}/sla", get(handlers::get_report_sla))
+        .with_state(sla_service)
+}
@@ -0,0 +1,3 @@
+mod sla_service;
+
+pub use sla_service::SlaService;
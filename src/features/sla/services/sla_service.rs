@@ -0,0 +1,569 @@
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::reports::models::{ReportSeverity, ReportStatus};
+use crate::features::sla::dtos::{
+    CreateSlaPolicyDto, ReportSlaDto, SlaOverdueSummaryDto, SlaPolicyQueryParams,
+    SlaPolicyResponseDto, UpdateSlaPolicyDto,
+};
+use crate::features::sla::models::{
+    EscalationTargetType, ReportSlaDeadline, ReportSlaEscalation, SlaPolicy, SlaTransition,
+};
+
+/// Convert database error to more specific AppError with user-friendly messages
+fn handle_db_error(e: sqlx::Error) -> AppError {
+    if let sqlx::Error::Database(db_err) = &e {
+        // Unique constraint violation
+        if db_err.code() == Some(std::borrow::Cow::Borrowed("23505")) {
+            return AppError::Conflict(
+                "An active SLA policy already exists for this category and severity.".to_string(),
+            );
+        }
+        // Foreign key violation
+        if db_err.code() == Some(std::borrow::Cow::Borrowed("23503")) {
+            return AppError::BadRequest("Referenced category does not exist.".to_string());
+        }
+    }
+    AppError::Database(e)
+}
+
+/// Service for SLA policies, report deadlines and escalations
+pub struct SlaService {
+    pool: PgPool,
+}
+
+impl SlaService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // ===== Policies =====
+
+    /// List SLA policies, most specific first
+    pub async fn list_policies(
+        &self,
+        params: &SlaPolicyQueryParams,
+    ) -> Result<Vec<SlaPolicyResponseDto>> {
+        let policies = sqlx::query_as!(
+            SlaPolicy,
+            r#"
+            SELECT
+                id, name, category_id,
+                severity as "severity: ReportSeverity",
+                verify_within_hours, resolve_within_hours,
+                is_active, created_at, updated_at
+            FROM sla_policies
+            WHERE ($1::bool IS NULL OR is_active = $1)
+            ORDER BY (category_id IS NOT NULL) DESC, (severity IS NOT NULL) DESC, name ASC
+            "#,
+            params.is_active
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list SLA policies: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(policies.into_iter().map(Into::into).collect())
+    }
+
+    /// Create an SLA policy
+    pub async fn create_policy(&self, dto: CreateSlaPolicyDto) -> Result<SlaPolicyResponseDto> {
+        if dto.verify_within_hours.is_none() && dto.resolve_within_hours.is_none() {
+            return Err(AppError::Validation(
+                "At least one of verify_within_hours or resolve_within_hours is required"
+                    .to_string(),
+            ));
+        }
+
+        let policy = sqlx::query_as!(
+            SlaPolicy,
+            r#"
+            INSERT INTO sla_policies
+                (name, category_id, severity, verify_within_hours, resolve_within_hours)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING
+                id, name, category_id,
+                severity as "severity: ReportSeverity",
+                verify_within_hours, resolve_within_hours,
+                is_active, created_at, updated_at
+            "#,
+            dto.name,
+            dto.category_id,
+            dto.severity as Option<ReportSeverity>,
+            dto.verify_within_hours,
+            dto.resolve_within_hours
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(handle_db_error)?;
+
+        tracing::info!("Created SLA policy {} ({})", policy.name, policy.id);
+        Ok(policy.into())
+    }
+
+    /// Update an SLA policy
+    ///
+    /// Deadlines already opened keep their due date; new deadlines use the updated hours.
+    pub async fn update_policy(
+        &self,
+        id: Uuid,
+        dto: UpdateSlaPolicyDto,
+    ) -> Result<SlaPolicyResponseDto> {
+        let policy = sqlx::query_as!(
+            SlaPolicy,
+            r#"
+            UPDATE sla_policies
+            SET
+                name = COALESCE($2, name),
+                verify_within_hours = COALESCE($3, verify_within_hours),
+                resolve_within_hours = COALESCE($4, resolve_within_hours),
+                is_active = COALESCE($5, is_active),
+                updated_at = NOW()
+            WHERE id = $1
+            RETURNING
+                id, name, category_id,
+                severity as "severity: ReportSeverity",
+                verify_within_hours, resolve_within_hours,
+                is_active, created_at, updated_at
+            "#,
+            id,
+            dto.name,
+            dto.verify_within_hours,
+            dto.resolve_within_hours,
+            dto.is_active
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(handle_db_error)?
+        .ok_or_else(|| AppError::NotFound(format!("SLA policy {} not found", id)))?;
+
+        tracing::info!("Updated SLA policy {} ({})", policy.name, policy.id);
+        Ok(policy.into())
+    }
+
+    /// Deactivate an SLA policy (soft delete)
+    pub async fn delete_policy(&self, id: Uuid) -> Result<()> {
+        let result = sqlx::query!(
+            r#"UPDATE sla_policies SET is_active = FALSE, updated_at = NOW() WHERE id = $1"#,
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to deactivate SLA policy: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!("SLA policy {} not found", id)));
+        }
+
+        tracing::info!("Deactivated SLA policy {}", id);
+        Ok(())
+    }
+
+    // ===== Report SLA =====
+
+    /// Get deadlines and escalations of a report
+    pub async fn get_report_sla(&self, report_id: Uuid) -> Result<ReportSlaDto> {
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM reports WHERE id = $1) as "exists!""#,
+            report_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check report: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        if !exists {
+            return Err(AppError::NotFound(format!(
+                "Report {} not found",
+                report_id
+            )));
+        }
+
+        let deadlines = sqlx::query_as!(
+            ReportSlaDeadline,
+            r#"
+            SELECT
+                id, report_id, policy_id,
+                target_status as "target_status: ReportStatus",
+                started_at, due_at, met_at, cancelled_at, breached_at,
+                escalation_level, last_escalated_at, created_at
+            FROM report_sla_deadlines
+            WHERE report_id = $1
+            ORDER BY started_at ASC
+            "#,
+            report_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report SLA deadlines: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let escalations = sqlx::query_as!(
+            ReportSlaEscalation,
+            r#"
+            SELECT
+                e.id, e.deadline_id, e.report_id, e.escalation_level, e.target_type,
+                e.agency_id, a.name as "agency_name?", e.region_level, e.created_at
+            FROM report_sla_escalations e
+            LEFT JOIN agencies a ON a.id = e.agency_id
+            WHERE e.report_id = $1
+            ORDER BY e.created_at ASC
+            "#,
+            report_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report SLA escalations: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(ReportSlaDto {
+            report_id,
+            deadlines: deadlines.into_iter().map(Into::into).collect(),
+            escalations: escalations.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    /// Count overdue and escalated reports across all open deadlines
+    pub async fn overdue_summary(&self) -> Result<SlaOverdueSummaryDto> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                COUNT(DISTINCT report_id) FILTER (WHERE due_at < NOW()) as "overdue_reports!",
+                COUNT(DISTINCT report_id) FILTER (
+                    WHERE due_at < NOW() AND target_status = 'verified'
+                ) as "overdue_verification!",
+                COUNT(DISTINCT report_id) FILTER (
+                    WHERE due_at < NOW() AND target_status = 'resolved'
+                ) as "overdue_resolution!",
+                COUNT(DISTINCT report_id) FILTER (WHERE escalation_level > 0) as "escalated_reports!",
+                COUNT(DISTINCT report_id) FILTER (
+                    WHERE due_at >= NOW() AND due_at < NOW() + INTERVAL '24 hours'
+                ) as "due_within_24h!"
+            FROM report_sla_deadlines
+            WHERE met_at IS NULL AND cancelled_at IS NULL
+            "#
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get SLA overdue summary: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(SlaOverdueSummaryDto {
+            overdue_reports: row.overdue_reports,
+            overdue_verification: row.overdue_verification,
+            overdue_resolution: row.overdue_resolution,
+            escalated_reports: row.escalated_reports,
+            due_within_24h: row.due_within_24h,
+        })
+    }
+
+    // ===== Escalation (used by SlaEscalationWorker) =====
+
+    /// Fetch open deadlines past due that are ready for (another) escalation
+    ///
+    /// Deadlines already escalated to admin curators are skipped; others are
+    /// escalated again once `repeat_hours` have passed since the last escalation.
+    pub async fn fetch_due_for_escalation(
+        &self,
+        repeat_hours: i32,
+        limit: i64,
+    ) -> Result<Vec<ReportSlaDeadline>> {
+        sqlx::query_as!(
+            ReportSlaDeadline,
+            r#"
+            SELECT
+                d.id, d.report_id, d.policy_id,
+                d.target_status as "target_status: ReportStatus",
+                d.started_at, d.due_at, d.met_at, d.cancelled_at, d.breached_at,
+                d.escalation_level, d.last_escalated_at, d.created_at
+            FROM report_sla_deadlines d
+            WHERE d.met_at IS NULL AND d.cancelled_at IS NULL
+              AND d.due_at < NOW()
+              AND (d.last_escalated_at IS NULL
+                   OR d.last_escalated_at < NOW() - make_interval(hours => $1))
+              AND NOT EXISTS(
+                  SELECT 1 FROM report_sla_escalations e
+                  WHERE e.deadline_id = d.id AND e.target_type = 'admin_curators'
+              )
+            ORDER BY d.due_at ASC
+            LIMIT $2
+            "#,
+            repeat_hours,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch due SLA deadlines: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Most recent escalation of a deadline
+    pub async fn get_last_escalation(
+        &self,
+        deadline_id: Uuid,
+    ) -> Result<Option<ReportSlaEscalation>> {
+        sqlx::query_as!(
+            ReportSlaEscalation,
+            r#"
+            SELECT
+                e.id, e.deadline_id, e.report_id, e.escalation_level, e.target_type,
+                e.agency_id, a.name as "agency_name?", e.region_level, e.created_at
+            FROM report_sla_escalations e
+            LEFT JOIN agencies a ON a.id = e.agency_id
+            WHERE e.deadline_id = $1
+            ORDER BY e.escalation_level DESC
+            LIMIT 1
+            "#,
+            deadline_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get last SLA escalation: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Flag a deadline as breached and record an escalation
+    pub async fn escalate(
+        &self,
+        deadline: &ReportSlaDeadline,
+        target_type: EscalationTargetType,
+        agency_id: Option<Uuid>,
+        region_level: Option<&str>,
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let level = sqlx::query_scalar!(
+            r#"
+            UPDATE report_sla_deadlines
+            SET
+                breached_at = COALESCE(breached_at, NOW()),
+                escalation_level = escalation_level + 1,
+                last_escalated_at = NOW()
+            WHERE id = $1
+            RETURNING escalation_level
+            "#,
+            deadline.id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to mark SLA deadline breached: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO report_sla_escalations
+                (deadline_id, report_id, escalation_level, target_type, agency_id, region_level)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            deadline.id,
+            deadline.report_id,
+            level,
+            target_type.as_str(),
+            agency_id,
+            region_level
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to record SLA escalation: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit SLA escalation: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(())
+    }
+
+    // ===== Deadline bookkeeping (called from ReportService inside its transactions) =====
+
+    /// Open, close or cancel deadlines when a report enters a status
+    pub async fn on_status_entered(
+        conn: &mut PgConnection,
+        report_id: Uuid,
+        status: ReportStatus,
+    ) -> Result<()> {
+        let transition = SlaTransition::for_status(status);
+
+        if transition.cancel_all {
+            sqlx::query!(
+                r#"
+                UPDATE report_sla_deadlines SET cancelled_at = NOW()
+                WHERE report_id = $1 AND met_at IS NULL AND cancelled_at IS NULL
+                "#,
+                report_id
+            )
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to cancel SLA deadlines: {:?}", e);
+                AppError::Database(e)
+            })?;
+        }
+
+        if let Some(target) = transition.close {
+            sqlx::query!(
+                r#"
+                UPDATE report_sla_deadlines SET met_at = NOW()
+                WHERE report_id = $1 AND target_status = $2
+                  AND met_at IS NULL AND cancelled_at IS NULL
+                "#,
+                report_id,
+                target as ReportStatus
+            )
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to close SLA deadline: {:?}", e);
+                AppError::Database(e)
+            })?;
+        }
+
+        if let Some(target) = transition.open {
+            if let Some((policy_id, hours)) = Self::select_policy(conn, report_id, target).await? {
+                sqlx::query!(
+                    r#"
+                    INSERT INTO report_sla_deadlines (report_id, policy_id, target_status, due_at)
+                    VALUES ($1, $2, $3, NOW() + make_interval(hours => $4))
+                    ON CONFLICT (report_id, target_status)
+                        WHERE met_at IS NULL AND cancelled_at IS NULL
+                    DO NOTHING
+                    "#,
+                    report_id,
+                    policy_id,
+                    target as ReportStatus,
+                    hours
+                )
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| {
+                    tracing::error!("Failed to open SLA deadline: {:?}", e);
+                    AppError::Database(e)
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Recompute due dates of open deadlines after the report's categories changed
+    ///
+    /// Reports are submitted before extraction assigns categories, so the initial
+    /// deadline uses the generic policy until this runs.
+    pub async fn recompute_open_deadlines(conn: &mut PgConnection, report_id: Uuid) -> Result<()> {
+        let open = sqlx::query!(
+            r#"
+            SELECT id, target_status as "target_status: ReportStatus"
+            FROM report_sla_deadlines
+            WHERE report_id = $1 AND met_at IS NULL AND cancelled_at IS NULL
+            "#,
+            report_id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get open SLA deadlines: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        for deadline in open {
+            let Some((policy_id, hours)) =
+                Self::select_policy(conn, report_id, deadline.target_status).await?
+            else {
+                continue;
+            };
+
+            sqlx::query!(
+                r#"
+                UPDATE report_sla_deadlines
+                SET policy_id = $2, due_at = started_at + make_interval(hours => $3)
+                WHERE id = $1
+                "#,
+                deadline.id,
+                policy_id,
+                hours
+            )
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to update SLA deadline: {:?}", e);
+                AppError::Database(e)
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Pick the most specific active policy for a report and target status
+    ///
+    /// A policy matches when its category is one of the report's categories (or their
+    /// parent) and its severity equals that category's severity; NULL matches anything.
+    /// Ties are broken by the shortest deadline. Returns (policy_id, hours).
+    async fn select_policy(
+        conn: &mut PgConnection,
+        report_id: Uuid,
+        target: ReportStatus,
+    ) -> Result<Option<(Uuid, i32)>> {
+        let for_verification = target == ReportStatus::Verified;
+
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                p.id,
+                (CASE WHEN $2 THEN p.verify_within_hours ELSE p.resolve_within_hours END) as "hours!"
+            FROM sla_policies p
+            WHERE p.is_active = TRUE
+              AND (CASE WHEN $2 THEN p.verify_within_hours ELSE p.resolve_within_hours END) IS NOT NULL
+              AND (
+                  (p.category_id IS NULL AND p.severity IS NULL)
+                  OR EXISTS(
+                      SELECT 1 FROM report_categories rc
+                      JOIN categories c ON c.id = rc.category_id
+                      WHERE rc.report_id = $1
+                        AND (p.category_id IS NULL OR p.category_id IN (c.id, c.parent_id))
+                        AND (p.severity IS NULL OR p.severity = rc.severity)
+                  )
+              )
+            ORDER BY
+                ((p.category_id IS NOT NULL)::int * 2 + (p.severity IS NOT NULL)::int) DESC,
+                2 ASC
+            LIMIT 1
+            "#,
+            report_id,
+            for_verification
+        )
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to select SLA policy: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(row.map(|r| (r.id, r.hours)))
+    }
+}
@@ -0,0 +1,3 @@
+mod sla_escalation_worker;
+
+pub use sla_escalation_worker::SlaEscalationWorker;
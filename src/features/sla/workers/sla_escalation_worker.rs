@@ -0,0 +1,185 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::interval;
+
+use crate::core::error::Result;
+use crate::features::agencies::models::RegionLevel;
+use crate::features::agencies::services::{AgencyRoutingService, RoutingCandidate};
+use crate::features::sla::models::{EscalationTargetType, ReportSlaDeadline};
+use crate::features::sla::SlaService;
+
+/// Delay between SLA checks
+const SLA_CHECK_INTERVAL_SECS: u64 = 300;
+
+/// Hours to wait before escalating a still-breached deadline one level further
+const ESCALATION_REPEAT_HOURS: i32 = 24;
+
+/// Maximum deadlines escalated per check
+const BATCH_SIZE: i64 = 100;
+
+/// Pick the agency one level up the region hierarchy from `current_level`
+///
+/// `candidates` must be ranked best match first (most specific region first), so the
+/// first candidate less specific than the current level is the closest parent agency.
+/// Returns None when there is no current level (report was never routed) or no
+/// agency above it, in which case the escalation goes to admin curators.
+pub fn next_escalation_agency(
+    candidates: &[RoutingCandidate],
+    current_level: Option<RegionLevel>,
+) -> Option<&RoutingCandidate> {
+    let current_level = current_level?;
+    candidates.iter().find(|c| c.region_level < current_level)
+}
+
+/// SLA escalation worker that runs in the background
+/// Flags breached deadlines and escalates them up the region hierarchy
+pub struct SlaEscalationWorker {
+    sla_service: Arc<SlaService>,
+    agency_routing_service: Arc<AgencyRoutingService>,
+}
+
+impl SlaEscalationWorker {
+    pub fn new(
+        sla_service: Arc<SlaService>,
+        agency_routing_service: Arc<AgencyRoutingService>,
+    ) -> Self {
+        Self {
+            sla_service,
+            agency_routing_service,
+        }
+    }
+
+    /// Run the worker in a background loop
+    pub async fn run(&self) {
+        tracing::info!("Starting SLA escalation worker");
+
+        let mut interval = interval(Duration::from_secs(SLA_CHECK_INTERVAL_SECS));
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = self.process_batch().await {
+                tracing::error!("Error processing SLA escalations: {:?}", e);
+            }
+        }
+    }
+
+    /// Escalate a batch of breached deadlines
+    async fn process_batch(&self) -> Result<()> {
+        let deadlines = self
+            .sla_service
+            .fetch_due_for_escalation(ESCALATION_REPEAT_HOURS, BATCH_SIZE)
+            .await?;
+
+        if deadlines.is_empty() {
+            return Ok(());
+        }
+
+        tracing::info!("Escalating {} breached SLA deadlines", deadlines.len());
+
+        for deadline in deadlines {
+            if let Err(e) = self.escalate(&deadline).await {
+                tracing::error!("Failed to escalate SLA deadline {}: {:?}", deadline.id, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Escalate one deadline to the next agency up, or to admin curators
+    async fn escalate(&self, deadline: &ReportSlaDeadline) -> Result<()> {
+        // Level the report currently sits at: last escalation target, else its route
+        let current_level = match self.sla_service.get_last_escalation(deadline.id).await? {
+            Some(last) => last
+                .region_level
+                .as_deref()
+                .and_then(RegionLevel::from_name),
+            None => self
+                .agency_routing_service
+                .get_route(deadline.report_id)
+                .await?
+                .and_then(|route| RegionLevel::from_name(&route.matched_region_level)),
+        };
+
+        let candidates = self
+            .agency_routing_service
+            .candidates_for_report(deadline.report_id)
+            .await?;
+
+        match next_escalation_agency(&candidates, current_level) {
+            Some(agency) => {
+                self.sla_service
+                    .escalate(
+                        deadline,
+                        EscalationTargetType::Agency,
+                        Some(agency.agency_id),
+                        Some(agency.region_level.as_str()),
+                    )
+                    .await?;
+
+                tracing::warn!(
+                    "SLA breached for report {} (target: {}, due: {}), escalated to agency {} ({})",
+                    deadline.report_id,
+                    deadline.target_status,
+                    deadline.due_at,
+                    agency.agency_code,
+                    agency.region_level
+                );
+            }
+            None => {
+                self.sla_service
+                    .escalate(deadline, EscalationTargetType::AdminCurators, None, None)
+                    .await?;
+
+                tracing::warn!(
+                    "SLA breached for report {} (target: {}, due: {}), escalated to admin curators",
+                    deadline.report_id,
+                    deadline.target_status,
+                    deadline.due_at
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn candidate(code: &str, level: RegionLevel) -> RoutingCandidate {
+        RoutingCandidate {
+            agency_id: Uuid::new_v4(),
+            agency_code: code.to_string(),
+            agency_name: code.to_uppercase(),
+            region_level: level,
+            region_id: Uuid::nil(),
+            category_matched: false,
+        }
+    }
+
+    #[test]
+    fn test_escalates_to_closest_parent_level() {
+        let candidates = vec![
+            candidate("kecamatan", RegionLevel::District),
+            candidate("kota", RegionLevel::Regency),
+            candidate("provinsi", RegionLevel::Province),
+        ];
+
+        let next = next_escalation_agency(&candidates, Some(RegionLevel::District)).unwrap();
+        assert_eq!(next.agency_code, "kota");
+
+        let next = next_escalation_agency(&candidates, Some(RegionLevel::Regency)).unwrap();
+        assert_eq!(next.agency_code, "provinsi");
+    }
+
+    #[test]
+    fn test_falls_back_to_admin_curators() {
+        let candidates = vec![candidate("provinsi", RegionLevel::Province)];
+
+        assert!(next_escalation_agency(&candidates, Some(RegionLevel::Province)).is_none());
+        assert!(next_escalation_agency(&candidates, None).is_none());
+    }
+}
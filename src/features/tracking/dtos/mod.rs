@@ -0,0 +1,3 @@
+mod tracking_dto;
+
+pub use tracking_dto::*;
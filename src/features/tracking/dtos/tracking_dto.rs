@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::features::reports::models::{ReportSeverity, ReportStatus, ReportTagType};
+
+/// Public view of a report looked up by its reference number
+///
+/// Only contains data that is safe to show to anyone holding the reference
+/// number: no reporter identity, conversation thread or exact coordinates.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportTrackingDto {
+    pub reference_number: String,
+    /// Report title with personal data redacted (None until processed)
+    pub title: Option<String>,
+    pub status: ReportStatus,
+    pub tag_type: Option<ReportTagType>,
+    pub categories: Vec<TrackingCategoryDto>,
+    pub region: Option<TrackingRegionDto>,
+    pub status_history: Vec<TrackingStatusChangeDto>,
+    pub updates: Vec<TrackingUpdateDto>,
+    pub verified_at: Option<DateTime<Utc>>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Category assigned to a tracked report
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TrackingCategoryDto {
+    pub name: String,
+    pub slug: String,
+    pub severity: ReportSeverity,
+}
+
+/// Administrative region of a tracked report (down to district level)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TrackingRegionDto {
+    pub province_name: Option<String>,
+    pub regency_name: Option<String>,
+    pub district_name: Option<String>,
+}
+
+/// Status transition of a tracked report
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TrackingStatusChangeDto {
+    /// Previous status (None for the initial entry)
+    pub from_status: Option<ReportStatus>,
+    pub to_status: ReportStatus,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Public progress update on a tracked report
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TrackingUpdateDto {
+    pub id: Uuid,
+    /// Role of the author (official, admin_curator, super_admin)
+    pub author_role: String,
+    pub message: String,
+    pub created_at: DateTime<Utc>,
+}
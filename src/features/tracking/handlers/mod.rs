@@ -0,0 +1,3 @@
+pub mod tracking_handler;
+
+pub use tracking_handler::*;
@@ -0,0 +1,47 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::{
+    extract::{ConnectInfo, Path, State},
+    http::HeaderMap,
+    Json,
+};
+
+use crate::core::error::AppError;
+use crate::features::tracking::dtos::ReportTrackingDto;
+use crate::features::tracking::services::TrackingService;
+use crate::shared::types::ApiResponse;
+
+/// Track a report by its reference number
+///
+/// Public and rate limited per client IP. Returns only the report status,
+/// status history, categories, region (down to district) and public updates.
+#[utoipa::path(
+    get,
+    path = "/api/track/{reference_number}",
+    params(
+        ("reference_number" = String, Path, description = "Report reference number (e.g. RPT-2026-0000001)")
+    ),
+    tag = "tracking",
+    responses(
+        (status = 200, description = "Public report status", body = ApiResponse<ReportTrackingDto>),
+        (status = 404, description = "Report not found"),
+        (status = 429, description = "Too many tracking requests")
+    )
+)]
+pub async fn track_report(
+    State(service): State<Arc<TrackingService>>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Path(reference_number): Path<String>,
+) -> Result<Json<ApiResponse<ReportTrackingDto>>, AppError> {
+    service
+        .check_rate_limit(&service.client_ip(&headers, peer))
+        .await?;
+
+    let report = service
+        .get_by_reference(reference_number.trim().to_uppercase().as_str())
+        .await?;
+
+    Ok(Json(ApiResponse::success(Some(report), None, None)))
+}
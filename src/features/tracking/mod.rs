@@ -0,0 +1,7 @@
+pub mod dtos;
+pub mod handlers;
+pub mod routes;
+pub mod services;
+
+pub use routes::routes;
+pub use services::TrackingService;
@@ -0,0 +1,12 @@
+use axum::{routing::get, Router};
+use std::sync::Arc;
+
+use crate::features::tracking::handlers;
+use crate::features::tracking::services::TrackingService;
+
+/// Create public report tracking routes
+pub fn routes(tracking_service: Arc<TrackingService>) -> Router {
+    Router::new()
+        .route("/api/track/{reference_number}", get(handlers::track_report))
+        .with_state(tracking_service)
+}
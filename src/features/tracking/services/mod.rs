@@ -0,0 +1,5 @@
+mod tracking_rate_limiter;
+mod tracking_service;
+
+pub use tracking_rate_limiter::{client_ip, TrackingRateLimiter};
+pub use tracking_service::TrackingService;
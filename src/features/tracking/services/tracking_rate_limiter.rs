@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use axum::http::HeaderMap;
+
+/// Length of a rate limit window
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// Maximum number of tracked clients; expired windows are pruned first, then the oldest
+const MAX_CLIENTS: usize = 10_000;
+
+/// Request counter of a single client within the current window
+struct Window {
+    started_at: Instant,
+    count: u32,
+}
+
+/// In-memory fixed-window limiter for public tracking lookups, keyed by client IP
+///
+/// Counters are per instance; with several replicas the effective limit is
+/// multiplied by the replica count.
+#[derive(Default)]
+pub struct TrackingRateLimiter {
+    windows: Mutex<HashMap<String, Window>>,
+}
+
+impl TrackingRateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a request of `client` and return whether it is within `limit`
+    pub fn check(&self, client: &str, limit: u32, now: Instant) -> bool {
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+
+        if windows.len() >= MAX_CLIENTS && !windows.contains_key(client) {
+            windows.retain(|_, w| now.duration_since(w.started_at) < WINDOW);
+
+            if windows.len() >= MAX_CLIENTS {
+                let oldest = windows
+                    .iter()
+                    .min_by_key(|(_, w)| w.started_at)
+                    .map(|(k, _)| k.clone());
+                if let Some(oldest) = oldest {
+                    windows.remove(&oldest);
+                }
+            }
+        }
+
+        let window = windows.entry(client.to_string()).or_insert(Window {
+            started_at: now,
+            count: 0,
+        });
+
+        if now.duration_since(window.started_at) >= WINDOW {
+            window.started_at = now;
+            window.count = 0;
+        }
+
+        if window.count >= limit {
+            return false;
+        }
+
+        window.count += 1;
+        true
+    }
+}
+
+/// Resolve the client IP of a request
+///
+/// Forwarding headers are client-controlled unless a reverse proxy overwrites
+/// them, so they are only read when `trust_proxy_headers` is set. Then
+/// `X-Real-IP` wins, followed by the last hop of `X-Forwarded-For` (the
+/// address seen by our proxy). Otherwise the peer address is used.
+pub fn client_ip(headers: &HeaderMap, peer: SocketAddr, trust_proxy_headers: bool) -> String {
+    if !trust_proxy_headers {
+        return peer.ip().to_string();
+    }
+
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+
+    if let Some(ip) = header("x-real-ip")
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+    {
+        return ip;
+    }
+
+    if let Some(ip) = header("x-forwarded-for").and_then(|v| {
+        v.rsplit(',')
+            .map(str::trim)
+            .find(|hop| !hop.is_empty())
+            .map(str::to_string)
+    }) {
+        return ip;
+    }
+
+    peer.ip().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_limits_per_client_and_resets_after_window() {
+        let limiter = TrackingRateLimiter::new();
+        let start = Instant::now();
+
+        assert!(limiter.check("1.1.1.1", 2, start));
+        assert!(limiter.check("1.1.1.1", 2, start));
+        assert!(!limiter.check("1.1.1.1", 2, start));
+        assert!(limiter.check("2.2.2.2", 2, start));
+
+        assert!(limiter.check("1.1.1.1", 2, start + WINDOW));
+    }
+
+    #[test]
+    fn test_check_bounds_tracked_clients() {
+        let limiter = TrackingRateLimiter::new();
+        let start = Instant::now();
+
+        for i in 0..MAX_CLIENTS {
+            assert!(limiter.check(&format!("client-{i}"), 1, start));
+        }
+        assert_eq!(limiter.windows.lock().unwrap().len(), MAX_CLIENTS);
+
+        // Still full of active windows: the oldest one makes room
+        assert!(limiter.check("newcomer", 1, start + Duration::from_secs(1)));
+        assert_eq!(limiter.windows.lock().unwrap().len(), MAX_CLIENTS);
+
+        // Once the windows expire they are pruned in one go
+        assert!(limiter.check("latecomer", 1, start + WINDOW));
+        assert_eq!(limiter.windows.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_client_ip_ignores_proxy_headers_unless_trusted() {
+        let peer: SocketAddr = "10.0.0.1:5000".parse().unwrap();
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(client_ip(&headers, peer, true), "10.0.0.1");
+
+        headers.insert("x-forwarded-for", "6.6.6.6, 3.3.3.3".parse().unwrap());
+        assert_eq!(client_ip(&headers, peer, true), "3.3.3.3");
+
+        headers.insert("x-real-ip", "4.4.4.4".parse().unwrap());
+        assert_eq!(client_ip(&headers, peer, true), "4.4.4.4");
+
+        // Without a trusted proxy in front, the headers are whatever the client sent
+        assert_eq!(client_ip(&headers, peer, false), "10.0.0.1");
+    }
+}
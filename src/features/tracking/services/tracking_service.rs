@@ -0,0 +1,197 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Instant;
+
+use axum::http::HeaderMap;
+use sqlx::PgPool;
+
+use crate::core::error::{AppError, Result};
+use crate::features::reports::models::{ReportSeverity, ReportStatus, ReportTagType};
+use crate::features::settings::{SettingKey, SettingsService};
+use crate::features::tracking::dtos::*;
+use crate::features::tracking::services::{client_ip, TrackingRateLimiter};
+
+/// Service for public report tracking by reference number
+///
+/// Queries select only public fields so that reporter identity, thread IDs
+/// and exact coordinates never leave the database for this endpoint.
+pub struct TrackingService {
+    pool: PgPool,
+    settings_service: Arc<SettingsService>,
+    limiter: TrackingRateLimiter,
+    trust_proxy_headers: bool,
+}
+
+impl TrackingService {
+    pub fn new(
+        pool: PgPool,
+        settings_service: Arc<SettingsService>,
+        trust_proxy_headers: bool,
+    ) -> Self {
+        Self {
+            pool,
+            settings_service,
+            limiter: TrackingRateLimiter::new(),
+            trust_proxy_headers,
+        }
+    }
+
+    /// Resolve the rate limit key of a request
+    pub fn client_ip(&self, headers: &HeaderMap, peer: SocketAddr) -> String {
+        client_ip(headers, peer, self.trust_proxy_headers)
+    }
+
+    /// Count a lookup of `client` against the per-minute limit
+    pub async fn check_rate_limit(&self, client: &str) -> Result<()> {
+        let limit = self
+            .settings_service
+            .get_i64(SettingKey::TrackingRequestsPerMinute)
+            .await;
+
+        if !self.limiter.check(client, limit as u32, Instant::now()) {
+            tracing::warn!("Tracking rate limit exceeded for {}", client);
+            return Err(AppError::RateLimitExceeded(
+                "Too many tracking requests, please try again in a minute".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Get the public projection of a report by its reference number
+    pub async fn get_by_reference(&self, reference_number: &str) -> Result<ReportTrackingDto> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                r.id,
+                r.reference_number as "reference_number!",
+                r.redacted_title as title,
+                r.status as "status: ReportStatus",
+                r.verified_at,
+                r.resolved_at,
+                r.created_at,
+                r.updated_at
+            FROM reports r
+            WHERE r.reference_number = $1
+            "#,
+            reference_number
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch report by reference: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Report {} not found", reference_number)))?;
+
+        let tag_type = sqlx::query_scalar!(
+            r#"
+            SELECT tag_type as "tag_type: ReportTagType"
+            FROM report_tags
+            WHERE report_id = $1
+            ORDER BY created_at
+            LIMIT 1
+            "#,
+            row.id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch report tag: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let categories = sqlx::query_as!(
+            TrackingCategoryDto,
+            r#"
+            SELECT c.name, c.slug, rc.severity as "severity: ReportSeverity"
+            FROM report_categories rc
+            JOIN categories c ON c.id = rc.category_id
+            WHERE rc.report_id = $1
+            ORDER BY c.name
+            "#,
+            row.id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch report categories: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let region = sqlx::query_as!(
+            TrackingRegionDto,
+            r#"
+            SELECT
+                p.name as "province_name?",
+                rg.name as "regency_name?",
+                d.name as "district_name?"
+            FROM report_locations rl
+            LEFT JOIN provinces p ON p.id = rl.province_id
+            LEFT JOIN regencies rg ON rg.id = rl.regency_id
+            LEFT JOIN districts d ON d.id = rl.district_id
+            WHERE rl.report_id = $1
+            LIMIT 1
+            "#,
+            row.id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch report region: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let status_history = sqlx::query_as!(
+            TrackingStatusChangeDto,
+            r#"
+            SELECT
+                from_status as "from_status: ReportStatus",
+                to_status as "to_status: ReportStatus",
+                created_at
+            FROM report_status_history
+            WHERE report_id = $1
+            ORDER BY created_at, id
+            "#,
+            row.id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch report status history: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let updates = sqlx::query_as!(
+            TrackingUpdateDto,
+            r#"
+            SELECT id, author_role, message, created_at
+            FROM report_updates
+            WHERE report_id = $1 AND deleted_at IS NULL
+            ORDER BY created_at, id
+            "#,
+            row.id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to fetch report updates: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(ReportTrackingDto {
+            reference_number: row.reference_number,
+            title: row.title,
+            status: row.status,
+            tag_type,
+            categories,
+            region,
+            status_history,
+            updates,
+            verified_at: row.verified_at,
+            resolved_at: row.resolved_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
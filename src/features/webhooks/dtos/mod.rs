@@ -0,0 +1,3 @@
+mod webhook_dto;
+
+pub use webhook_dto::*;
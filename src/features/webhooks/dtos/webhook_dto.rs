@@ -0,0 +1,244 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::features::webhooks::models::{
+    WebhookDelivery, WebhookDeliveryAttempt, WebhookDeliveryStatus, WebhookEventType,
+    WebhookSubscription,
+};
+use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+
+// =============================================================================
+// SUBSCRIPTIONS
+// =============================================================================
+
+/// Query params for listing webhook subscriptions
+#[derive(Debug, Clone, Deserialize, IntoParams, ToSchema)]
+pub struct WebhookSubscriptionQueryParams {
+    /// Filter by active status (true = active, false = inactive, none = all)
+    pub is_active: Option<bool>,
+}
+
+/// Create webhook subscription request
+///
+/// Leave `secret` empty to have one generated. The secret is only returned once.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateWebhookSubscriptionDto {
+    #[validate(length(min = 1, max = 200))]
+    pub name: String,
+
+    /// HTTP(S) endpoint receiving POSTed events
+    #[validate(url(message = "Target URL must be a valid URL"))]
+    pub target_url: String,
+
+    /// Shared secret for HMAC-SHA256 signatures
+    #[validate(length(min = 16, max = 255))]
+    pub secret: Option<String>,
+
+    #[validate(length(min = 1))]
+    pub event_types: Vec<WebhookEventType>,
+}
+
+/// Update webhook subscription request
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateWebhookSubscriptionDto {
+    #[validate(length(min = 1, max = 200))]
+    pub name: Option<String>,
+
+    #[validate(url(message = "Target URL must be a valid URL"))]
+    pub target_url: Option<String>,
+
+    #[validate(length(min = 1))]
+    pub event_types: Option<Vec<WebhookEventType>>,
+
+    pub is_active: Option<bool>,
+}
+
+/// Webhook subscription response (the secret is masked)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookSubscriptionDto {
+    pub id: Uuid,
+    pub name: String,
+    pub target_url: String,
+    pub event_types: Vec<WebhookEventType>,
+    /// Last four characters of the secret
+    pub secret_hint: String,
+    pub is_active: bool,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Mask a secret down to its last four characters
+fn secret_hint(secret: &str) -> String {
+    let tail: Vec<char> = secret.chars().rev().take(4).collect();
+    format!("...{}", tail.into_iter().rev().collect::<String>())
+}
+
+impl From<WebhookSubscription> for WebhookSubscriptionDto {
+    fn from(s: WebhookSubscription) -> Self {
+        Self {
+            id: s.id,
+            name: s.name,
+            target_url: s.target_url,
+            event_types: s
+                .event_types
+                .iter()
+                .filter_map(|t| WebhookEventType::from_name(t))
+                .collect(),
+            secret_hint: secret_hint(&s.secret),
+            is_active: s.is_active,
+            created_by: s.created_by,
+            created_at: s.created_at,
+            updated_at: s.updated_at,
+        }
+    }
+}
+
+/// Webhook subscription response including the plain secret
+/// (returned on create and secret rotation only)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookSubscriptionSecretDto {
+    #[serde(flatten)]
+    pub subscription: WebhookSubscriptionDto,
+    pub secret: String,
+}
+
+impl From<WebhookSubscription> for WebhookSubscriptionSecretDto {
+    fn from(s: WebhookSubscription) -> Self {
+        let secret = s.secret.clone();
+        Self {
+            subscription: s.into(),
+            secret,
+        }
+    }
+}
+
+// =============================================================================
+// DELIVERIES
+// =============================================================================
+
+/// Query params for the delivery log of a subscription
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct WebhookDeliveryQueryParams {
+    /// Filter by delivery status
+    pub status: Option<WebhookDeliveryStatus>,
+    /// Page number (1-indexed, default: 1)
+    #[serde(default = "default_page")]
+    #[param(minimum = 1)]
+    pub page: i64,
+    /// Number of items per page (default: 10, max: 100)
+    #[serde(default = "default_page_size")]
+    #[param(minimum = 1, maximum = 100)]
+    pub page_size: i64,
+}
+
+impl WebhookDeliveryQueryParams {
+    pub fn offset(&self) -> i64 {
+        (self.page.max(1) - 1) * self.limit()
+    }
+    pub fn limit(&self) -> i64 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+}
+
+fn default_page() -> i64 {
+    1
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_PAGE_SIZE
+}
+
+/// Webhook delivery response
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookDeliveryDto {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    /// Shared by all deliveries of the same event
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub status: WebhookDeliveryStatus,
+    pub attempt_count: i32,
+    pub max_attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    /// Original delivery when this is a manual redelivery
+    pub redelivery_of: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryDto {
+    fn from(d: WebhookDelivery) -> Self {
+        Self {
+            id: d.id,
+            subscription_id: d.subscription_id,
+            event_id: d.event_id,
+            event_type: d.event_type,
+            status: d.status,
+            attempt_count: d.attempt_count,
+            max_attempts: d.max_attempts,
+            next_attempt_at: d.next_attempt_at,
+            last_attempt_at: d.last_attempt_at,
+            last_status_code: d.last_status_code,
+            last_error: d.last_error,
+            delivered_at: d.delivered_at,
+            redelivery_of: d.redelivery_of,
+            created_at: d.created_at,
+        }
+    }
+}
+
+/// Single HTTP attempt of a delivery
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookDeliveryAttemptDto {
+    pub id: Uuid,
+    pub attempt_number: i32,
+    /// HTTP status code (none = network error or timeout)
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub duration_ms: i32,
+    pub attempted_at: DateTime<Utc>,
+}
+
+impl From<WebhookDeliveryAttempt> for WebhookDeliveryAttemptDto {
+    fn from(a: WebhookDeliveryAttempt) -> Self {
+        Self {
+            id: a.id,
+            attempt_number: a.attempt_number,
+            status_code: a.status_code,
+            error: a.error,
+            duration_ms: a.duration_ms,
+            attempted_at: a.attempted_at,
+        }
+    }
+}
+
+/// Webhook delivery with payload and attempt log
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WebhookDeliveryDetailDto {
+    #[serde(flatten)]
+    pub delivery: WebhookDeliveryDto,
+    /// Event body sent to the receiver
+    pub payload: serde_json::Value,
+    pub attempts: Vec<WebhookDeliveryAttemptDto>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_hint_keeps_last_four_characters() {
+        assert_eq!(secret_hint("whsec_0123456789abcdef"), "...cdef");
+        assert_eq!(secret_hint("abc"), "...abc");
+        // Slicing by bytes would split the multibyte characters here
+        assert_eq!(secret_hint("rahasia-sangat-panjang-ékçü"), "...ékçü");
+        assert_eq!(secret_hint("kunci-rahasia-🔑🔑"), "...a-🔑🔑");
+    }
+}
@@ -0,0 +1,3 @@
+pub mod webhook_handler;
+
+pub use webhook_handler::*;
@@ -0,0 +1,323 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::core::error::{AppError, Result};
+use crate::core::extractor::AppJson;
+use crate::features::auth::guards::RequireSuperAdmin;
+use crate::features::webhooks::dtos::{
+    CreateWebhookSubscriptionDto, UpdateWebhookSubscriptionDto, WebhookDeliveryAttemptDto,
+    WebhookDeliveryDetailDto, WebhookDeliveryDto, WebhookDeliveryQueryParams,
+    WebhookSubscriptionDto, WebhookSubscriptionQueryParams, WebhookSubscriptionSecretDto,
+};
+use crate::features::webhooks::services::WebhookService;
+use crate::shared::types::{ApiResponse, Meta};
+
+// =============================================================================
+// SUBSCRIPTION HANDLERS
+// =============================================================================
+
+/// List webhook subscriptions (super admin only)
+#[utoipa::path(
+    get,
+    path = "/api/admin/webhooks",
+    params(WebhookSubscriptionQueryParams),
+    responses(
+        (status = 200, description = "Webhook subscriptions retrieved", body = ApiResponse<Vec<WebhookSubscriptionDto>>),
+        (status = 403, description = "Forbidden - super admin only")
+    ),
+    tag = "webhooks",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_webhooks(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<WebhookService>>,
+    Query(params): Query<WebhookSubscriptionQueryParams>,
+) -> Result<Json<ApiResponse<Vec<WebhookSubscriptionDto>>>> {
+    let subscriptions = service.list_subscriptions(params.is_active).await?;
+    let total = subscriptions.len() as i64;
+    let dtos: Vec<WebhookSubscriptionDto> = subscriptions.into_iter().map(Into::into).collect();
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Create a webhook subscription (super admin only)
+///
+/// The response contains the signing secret; it is not shown again.
+#[utoipa::path(
+    post,
+    path = "/api/admin/webhooks",
+    request_body = CreateWebhookSubscriptionDto,
+    responses(
+        (status = 200, description = "Webhook subscription created", body = ApiResponse<WebhookSubscriptionSecretDto>),
+        (status = 400, description = "Validation error"),
+        (status = 403, description = "Forbidden - super admin only")
+    ),
+    tag = "webhooks",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_webhook(
+    RequireSuperAdmin(user): RequireSuperAdmin,
+    State(service): State<Arc<WebhookService>>,
+    AppJson(dto): AppJson<CreateWebhookSubscriptionDto>,
+) -> Result<Json<ApiResponse<WebhookSubscriptionSecretDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let subscription = service.create_subscription(&dto, &user.sub).await?;
+    Ok(Json(ApiResponse::success(
+        Some(subscription.into()),
+        Some("Webhook subscription created".to_string()),
+        None,
+    )))
+}
+
+/// Get a webhook subscription (super admin only)
+#[utoipa::path(
+    get,
+    path = "/api/admin/webhooks/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Webhook subscription ID")
+    ),
+    responses(
+        (status = 200, description = "Webhook subscription retrieved", body = ApiResponse<WebhookSubscriptionDto>),
+        (status = 403, description = "Forbidden - super admin only"),
+        (status = 404, description = "Webhook subscription not found")
+    ),
+    tag = "webhooks",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_webhook(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<WebhookService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<WebhookSubscriptionDto>>> {
+    let subscription = service.get_subscription(id).await?;
+    Ok(Json(ApiResponse::success(
+        Some(subscription.into()),
+        None,
+        None,
+    )))
+}
+
+/// Update a webhook subscription (super admin only)
+#[utoipa::path(
+    put,
+    path = "/api/admin/webhooks/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Webhook subscription ID")
+    ),
+    request_body = UpdateWebhookSubscriptionDto,
+    responses(
+        (status = 200, description = "Webhook subscription updated", body = ApiResponse<WebhookSubscriptionDto>),
+        (status = 400, description = "Validation error"),
+        (status = 403, description = "Forbidden - super admin only"),
+        (status = 404, description = "Webhook subscription not found")
+    ),
+    tag = "webhooks",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_webhook(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<WebhookService>>,
+    Path(id): Path<Uuid>,
+    AppJson(dto): AppJson<UpdateWebhookSubscriptionDto>,
+) -> Result<Json<ApiResponse<WebhookSubscriptionDto>>> {
+    dto.validate()
+        .map_err(|e| AppError::Validation(e.to_string()))?;
+
+    let subscription = service.update_subscription(id, &dto).await?;
+    Ok(Json(ApiResponse::success(
+        Some(subscription.into()),
+        Some("Webhook subscription updated".to_string()),
+        None,
+    )))
+}
+
+/// Deactivate a webhook subscription (super admin only)
+///
+/// Pending deliveries are left in the log but no longer sent.
+#[utoipa::path(
+    delete,
+    path = "/api/admin/webhooks/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Webhook subscription ID")
+    ),
+    responses(
+        (status = 200, description = "Webhook subscription deactivated"),
+        (status = 403, description = "Forbidden - super admin only"),
+        (status = 404, description = "Webhook subscription not found")
+    ),
+    tag = "webhooks",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn delete_webhook(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<WebhookService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>> {
+    service.delete_subscription(id).await?;
+    Ok(Json(ApiResponse::success(
+        None,
+        Some("Webhook subscription deactivated".to_string()),
+        None,
+    )))
+}
+
+/// Rotate the signing secret of a webhook subscription (super admin only)
+#[utoipa::path(
+    post,
+    path = "/api/admin/webhooks/{id}/rotate-secret",
+    params(
+        ("id" = Uuid, Path, description = "Webhook subscription ID")
+    ),
+    responses(
+        (status = 200, description = "Secret rotated", body = ApiResponse<WebhookSubscriptionSecretDto>),
+        (status = 403, description = "Forbidden - super admin only"),
+        (status = 404, description = "Webhook subscription not found")
+    ),
+    tag = "webhooks",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn rotate_webhook_secret(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<WebhookService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<WebhookSubscriptionSecretDto>>> {
+    let subscription = service.rotate_secret(id).await?;
+    Ok(Json(ApiResponse::success(
+        Some(subscription.into()),
+        Some("Webhook secret rotated".to_string()),
+        None,
+    )))
+}
+
+// =============================================================================
+// DELIVERY LOG HANDLERS
+// =============================================================================
+
+/// List deliveries of a webhook subscription (super admin only)
+#[utoipa::path(
+    get,
+    path = "/api/admin/webhooks/{id}/deliveries",
+    params(
+        ("id" = Uuid, Path, description = "Webhook subscription ID"),
+        WebhookDeliveryQueryParams
+    ),
+    responses(
+        (status = 200, description = "Deliveries retrieved (newest first)", body = ApiResponse<Vec<WebhookDeliveryDto>>),
+        (status = 403, description = "Forbidden - super admin only"),
+        (status = 404, description = "Webhook subscription not found")
+    ),
+    tag = "webhooks",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_webhook_deliveries(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<WebhookService>>,
+    Path(id): Path<Uuid>,
+    Query(params): Query<WebhookDeliveryQueryParams>,
+) -> Result<Json<ApiResponse<Vec<WebhookDeliveryDto>>>> {
+    service.get_subscription(id).await?;
+
+    let (deliveries, total) = service
+        .list_deliveries(id, params.status, params.limit(), params.offset())
+        .await?;
+    let dtos: Vec<WebhookDeliveryDto> = deliveries.into_iter().map(Into::into).collect();
+
+    Ok(Json(ApiResponse::success(
+        Some(dtos),
+        None,
+        Some(Meta::new(total)),
+    )))
+}
+
+/// Get a webhook delivery with payload and attempt log (super admin only)
+#[utoipa::path(
+    get,
+    path = "/api/admin/webhook-deliveries/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Webhook delivery ID")
+    ),
+    responses(
+        (status = 200, description = "Delivery retrieved", body = ApiResponse<WebhookDeliveryDetailDto>),
+        (status = 403, description = "Forbidden - super admin only"),
+        (status = 404, description = "Webhook delivery not found")
+    ),
+    tag = "webhooks",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_webhook_delivery(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<WebhookService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<WebhookDeliveryDetailDto>>> {
+    let (delivery, attempts) = service.get_delivery(id).await?;
+    let payload = delivery.payload.clone();
+    let detail = WebhookDeliveryDetailDto {
+        delivery: delivery.into(),
+        payload,
+        attempts: attempts
+            .into_iter()
+            .map(WebhookDeliveryAttemptDto::from)
+            .collect(),
+    };
+
+    Ok(Json(ApiResponse::success(Some(detail), None, None)))
+}
+
+/// Redeliver a webhook event (super admin only)
+///
+/// Queues a new delivery of the same event with a fresh retry budget.
+#[utoipa::path(
+    post,
+    path = "/api/admin/webhook-deliveries/{id}/redeliver",
+    params(
+        ("id" = Uuid, Path, description = "Webhook delivery ID")
+    ),
+    responses(
+        (status = 200, description = "Redelivery queued", body = ApiResponse<WebhookDeliveryDto>),
+        (status = 403, description = "Forbidden - super admin only"),
+        (status = 404, description = "Webhook delivery not found or subscription inactive")
+    ),
+    tag = "webhooks",
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn redeliver_webhook_delivery(
+    RequireSuperAdmin(_user): RequireSuperAdmin,
+    State(service): State<Arc<WebhookService>>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<WebhookDeliveryDto>>> {
+    let delivery = service.redeliver(id).await?;
+    Ok(Json(ApiResponse::success(
+        Some(delivery.into()),
+        Some("Redelivery queued".to_string()),
+        None,
+    )))
+}
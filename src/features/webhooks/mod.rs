@@ -0,0 +1,9 @@
+pub mod dtos;
+pub mod handlers;
+pub mod models;
+pub mod routes;
+pub mod services;
+pub mod workers;
+
+pub use services::WebhookService;
+pub use workers::WebhookDispatcher;
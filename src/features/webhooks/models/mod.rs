@@ -0,0 +1,7 @@
+mod webhook_delivery;
+mod webhook_subscription;
+
+pub use webhook_delivery::{
+    DueWebhookDelivery, WebhookDelivery, WebhookDeliveryAttempt, WebhookDeliveryStatus,
+};
+pub use webhook_subscription::{WebhookEventType, WebhookSubscription};
@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Report lifecycle event types a webhook can subscribe to
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+pub enum WebhookEventType {
+    /// A citizen submitted a report (status `pending`)
+    #[serde(rename = "report.submitted")]
+    ReportSubmitted,
+    /// The report processor finished extracting a report
+    #[serde(rename = "report.processed")]
+    ReportProcessed,
+    /// A report moved from one status to another
+    #[serde(rename = "report.status_changed")]
+    ReportStatusChanged,
+    /// A report was rejected
+    #[serde(rename = "report.rejected")]
+    ReportRejected,
+}
+
+impl WebhookEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEventType::ReportSubmitted => "report.submitted",
+            WebhookEventType::ReportProcessed => "report.processed",
+            WebhookEventType::ReportStatusChanged => "report.status_changed",
+            WebhookEventType::ReportRejected => "report.rejected",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "report.submitted" => Some(WebhookEventType::ReportSubmitted),
+            "report.processed" => Some(WebhookEventType::ReportProcessed),
+            "report.status_changed" => Some(WebhookEventType::ReportStatusChanged),
+            "report.rejected" => Some(WebhookEventType::ReportRejected),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for WebhookEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Database model for a webhook subscription
+#[derive(Debug, Clone, FromRow)]
+pub struct WebhookSubscription {
+    pub id: Uuid,
+    pub name: String,
+    pub target_url: String,
+    pub secret: String,
+    pub event_types: Vec<String>,
+    pub is_active: bool,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_type_names_round_trip() {
+        for event_type in [
+            WebhookEventType::ReportSubmitted,
+            WebhookEventType::ReportProcessed,
+            WebhookEventType::ReportStatusChanged,
+            WebhookEventType::ReportRejected,
+        ] {
+            let json = serde_json::to_value(event_type).unwrap();
+            assert_eq!(json, event_type.as_str());
+            assert_eq!(
+                WebhookEventType::from_name(event_type.as_str()),
+                Some(event_type)
+            );
+        }
+        assert_eq!(WebhookEventType::from_name("report.deleted"), None);
+    }
+}
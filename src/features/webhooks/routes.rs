@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::features::webhooks::handlers;
+use crate::features::webhooks::services::WebhookService;
+
+/// Create admin routes for webhook subscriptions and the delivery log
+pub fn admin_routes(webhook_service: Arc<WebhookService>) -> Router {
+    Router::new()
+        .route(
+            "/api/admin/webhooks",
+            get(handlers::list_webhooks).post(handlers::create_webhook),
+        )
+        .route(
+            "/api/admin/webhooks/{id}",
+            get(handlers::get_webhook)
+                .put(handlers::update_webhook)
+                .delete(handlers::delete_webhook),
+        )
+        .route(
+            "/api/admin/webhooks/{id}/rotate-secret",
+            post(handlers::rotate_webhook_secret),
+        )
+        .route(
+            "/api/admin/webhooks/{id}/deliveries",
+            get(handlers::list_webhook_deliveries),
+        )
+        .route(
+            "/api/admin/webhook-deliveries/{id}",
+            get(handlers::get_webhook_delivery),
+        )
+        .route(
+            "/api/admin/webhook-deliveries/{id}/redeliver",
+            post(handlers::redeliver_webhook_delivery),
+        )
+        .with_state(webhook_service)
+}
@@ -0,0 +1,3 @@
+mod webhook_service;
+
+pub use webhook_service::{sign_payload, WebhookService};
@@ -0,0 +1,706 @@
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use serde_json::json;
+use sha2::Sha256;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::core::error::{AppError, Result};
+use crate::features::reports::models::ReportStatus;
+use crate::features::webhooks::dtos::{CreateWebhookSubscriptionDto, UpdateWebhookSubscriptionDto};
+use crate::features::webhooks::models::{
+    DueWebhookDelivery, WebhookDelivery, WebhookDeliveryAttempt, WebhookDeliveryStatus,
+    WebhookEventType, WebhookSubscription,
+};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Delay before the first retry; doubles on every further attempt
+const RETRY_BASE_DELAY_SECS: i64 = 30;
+
+/// Upper bound for the retry delay
+const RETRY_MAX_DELAY_SECS: i64 = 6 * 60 * 60;
+
+/// Compute the HMAC-SHA256 signature of a delivery
+///
+/// The signed message is `"{timestamp}.{body}"` so receivers can reject replays,
+/// and the result is sent as `X-Webhook-Signature: sha256=<hex>`.
+pub fn sign_payload(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Delay before retrying after `attempt_count` failed attempts (exponential backoff)
+pub fn retry_delay_secs(attempt_count: i32) -> i64 {
+    let exponent = (attempt_count.max(1) - 1).min(20) as u32;
+    RETRY_BASE_DELAY_SECS
+        .saturating_mul(2_i64.pow(exponent))
+        .min(RETRY_MAX_DELAY_SECS)
+}
+
+/// Generate a random subscription secret
+fn generate_secret() -> String {
+    format!(
+        "whsec_{}{}",
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    )
+}
+
+/// Ensure the target is an HTTP(S) endpoint
+fn validate_target_url(url: &str) -> Result<()> {
+    if url.starts_with("https://") || url.starts_with("http://") {
+        Ok(())
+    } else {
+        Err(AppError::Validation(
+            "Target URL must use http or https".to_string(),
+        ))
+    }
+}
+
+/// Data of a `report.processed` event
+///
+/// Subscribers are external, so the event carries the redacted title only.
+fn report_processed_data(
+    report_id: Uuid,
+    reference_number: Option<&str>,
+    redacted_title: Option<&str>,
+    status: ReportStatus,
+) -> serde_json::Value {
+    json!({
+        "report_id": report_id,
+        "reference_number": reference_number,
+        "title": redacted_title,
+        "status": status,
+    })
+}
+
+fn event_type_names(event_types: &[WebhookEventType]) -> Vec<String> {
+    let mut names: Vec<String> = event_types.iter().map(|t| t.as_str().to_string()).collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Service for webhook subscriptions and the delivery outbox
+pub struct WebhookService {
+    pool: PgPool,
+}
+
+impl WebhookService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // ===== Producers =====
+
+    /// Publish an event outside of any transaction
+    pub async fn publish(
+        &self,
+        event_type: WebhookEventType,
+        data: serde_json::Value,
+    ) -> Result<()> {
+        let mut conn = self.pool.acquire().await.map_err(|e| {
+            tracing::error!("Failed to acquire connection: {:?}", e);
+            AppError::Database(e)
+        })?;
+        Self::enqueue(&mut conn, event_type, data).await
+    }
+
+    /// Queue an event for every active subscription using the caller's connection
+    ///
+    /// Runs inside the caller's transaction, so an event is only delivered if the
+    /// change that produced it was committed.
+    pub async fn enqueue(
+        conn: &mut PgConnection,
+        event_type: WebhookEventType,
+        data: serde_json::Value,
+    ) -> Result<()> {
+        let event_id = Uuid::now_v7();
+        let payload = json!({
+            "id": event_id,
+            "type": event_type.as_str(),
+            "created_at": Utc::now(),
+            "data": data,
+        });
+
+        sqlx::query!(
+            r#"
+            INSERT INTO webhook_deliveries (subscription_id, event_id, event_type, payload)
+            SELECT id, $1, $2::text, $3
+            FROM webhook_subscriptions
+            WHERE is_active = true AND $2::text = ANY(event_types)
+            "#,
+            event_id,
+            event_type.as_str(),
+            payload
+        )
+        .execute(conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to enqueue webhook event: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(())
+    }
+
+    /// Queue lifecycle events for a recorded status transition
+    ///
+    /// Creation emits `report.submitted`; every later transition emits
+    /// `report.status_changed`, plus `report.rejected` when entering `rejected`.
+    pub async fn on_status_changed(
+        conn: &mut PgConnection,
+        report_id: Uuid,
+        from: Option<ReportStatus>,
+        to: ReportStatus,
+        note: Option<&str>,
+    ) -> Result<()> {
+        let reference_number = sqlx::query_scalar!(
+            "SELECT reference_number FROM reports WHERE id = $1",
+            report_id
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get report reference: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let Some(from) = from else {
+            let data = json!({
+                "report_id": report_id,
+                "reference_number": reference_number,
+                "status": to,
+            });
+            return Self::enqueue(conn, WebhookEventType::ReportSubmitted, data).await;
+        };
+
+        let data = json!({
+            "report_id": report_id,
+            "reference_number": reference_number,
+            "old_status": from,
+            "new_status": to,
+            "note": note,
+        });
+        Self::enqueue(&mut *conn, WebhookEventType::ReportStatusChanged, data).await?;
+
+        if to == ReportStatus::Rejected {
+            let data = json!({
+                "report_id": report_id,
+                "reference_number": reference_number,
+                "reason": note,
+            });
+            Self::enqueue(conn, WebhookEventType::ReportRejected, data).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Publish `report.processed` once the processor has finished a report
+    pub async fn on_report_processed(&self, report_id: Uuid) -> Result<()> {
+        let report = sqlx::query!(
+            r#"
+            SELECT reference_number, redacted_title, status as "status: ReportStatus"
+            FROM reports
+            WHERE id = $1
+            "#,
+            report_id
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get processed report: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let data = report_processed_data(
+            report_id,
+            report.reference_number.as_deref(),
+            report.redacted_title.as_deref(),
+            report.status,
+        );
+        self.publish(WebhookEventType::ReportProcessed, data).await
+    }
+
+    // ===== Subscriptions =====
+
+    /// List subscriptions, optionally filtered by active status
+    pub async fn list_subscriptions(
+        &self,
+        is_active: Option<bool>,
+    ) -> Result<Vec<WebhookSubscription>> {
+        sqlx::query_as!(
+            WebhookSubscription,
+            r#"
+            SELECT id, name, target_url, secret, event_types, is_active,
+                   created_by, created_at, updated_at
+            FROM webhook_subscriptions
+            WHERE ($1::boolean IS NULL OR is_active = $1)
+            ORDER BY created_at DESC
+            "#,
+            is_active
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list webhook subscriptions: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Get a subscription by ID
+    pub async fn get_subscription(&self, id: Uuid) -> Result<WebhookSubscription> {
+        sqlx::query_as!(
+            WebhookSubscription,
+            r#"
+            SELECT id, name, target_url, secret, event_types, is_active,
+                   created_by, created_at, updated_at
+            FROM webhook_subscriptions
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get webhook subscription: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Webhook subscription {} not found", id)))
+    }
+
+    /// Create a subscription (generates a secret when none is given)
+    pub async fn create_subscription(
+        &self,
+        dto: &CreateWebhookSubscriptionDto,
+        created_by: &str,
+    ) -> Result<WebhookSubscription> {
+        validate_target_url(&dto.target_url)?;
+        let secret = dto.secret.clone().unwrap_or_else(generate_secret);
+
+        let subscription = sqlx::query_as!(
+            WebhookSubscription,
+            r#"
+            INSERT INTO webhook_subscriptions (name, target_url, secret, event_types, created_by)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, name, target_url, secret, event_types, is_active,
+                      created_by, created_at, updated_at
+            "#,
+            dto.name,
+            dto.target_url,
+            secret,
+            &event_type_names(&dto.event_types),
+            created_by
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to create webhook subscription: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tracing::info!(
+            "Webhook subscription {} created by {} ({})",
+            subscription.id,
+            created_by,
+            subscription.target_url
+        );
+        Ok(subscription)
+    }
+
+    /// Update a subscription
+    pub async fn update_subscription(
+        &self,
+        id: Uuid,
+        dto: &UpdateWebhookSubscriptionDto,
+    ) -> Result<WebhookSubscription> {
+        if let Some(url) = &dto.target_url {
+            validate_target_url(url)?;
+        }
+        let event_types = dto.event_types.as_deref().map(event_type_names);
+
+        sqlx::query_as!(
+            WebhookSubscription,
+            r#"
+            UPDATE webhook_subscriptions
+            SET
+                name = COALESCE($2, name),
+                target_url = COALESCE($3, target_url),
+                event_types = COALESCE($4, event_types),
+                is_active = COALESCE($5, is_active),
+                updated_at = NOW()
+            WHERE id = $1
+            RETURNING id, name, target_url, secret, event_types, is_active,
+                      created_by, created_at, updated_at
+            "#,
+            id,
+            dto.name,
+            dto.target_url,
+            event_types.as_deref(),
+            dto.is_active
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to update webhook subscription: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Webhook subscription {} not found", id)))
+    }
+
+    /// Deactivate a subscription (soft delete, keeps the delivery log)
+    pub async fn delete_subscription(&self, id: Uuid) -> Result<()> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE webhook_subscriptions
+            SET is_active = false, updated_at = NOW()
+            WHERE id = $1
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to deactivate webhook subscription: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::NotFound(format!(
+                "Webhook subscription {} not found",
+                id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Replace the secret of a subscription with a newly generated one
+    pub async fn rotate_secret(&self, id: Uuid) -> Result<WebhookSubscription> {
+        sqlx::query_as!(
+            WebhookSubscription,
+            r#"
+            UPDATE webhook_subscriptions
+            SET secret = $2, updated_at = NOW()
+            WHERE id = $1
+            RETURNING id, name, target_url, secret, event_types, is_active,
+                      created_by, created_at, updated_at
+            "#,
+            id,
+            generate_secret()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to rotate webhook secret: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Webhook subscription {} not found", id)))
+    }
+
+    // ===== Delivery log =====
+
+    /// List deliveries of a subscription (newest first)
+    /// Returns (deliveries, total_count)
+    pub async fn list_deliveries(
+        &self,
+        subscription_id: Uuid,
+        status: Option<WebhookDeliveryStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<WebhookDelivery>, i64)> {
+        let total = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!"
+            FROM webhook_deliveries
+            WHERE subscription_id = $1
+              AND ($2::webhook_delivery_status IS NULL OR status = $2)
+            "#,
+            subscription_id,
+            status as Option<WebhookDeliveryStatus>
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to count webhook deliveries: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        let deliveries = sqlx::query_as!(
+            WebhookDelivery,
+            r#"
+            SELECT
+                id, subscription_id, event_id, event_type, payload,
+                status as "status: WebhookDeliveryStatus",
+                attempt_count, max_attempts, next_attempt_at, last_attempt_at,
+                last_status_code, last_error, delivered_at, redelivery_of, created_at
+            FROM webhook_deliveries
+            WHERE subscription_id = $1
+              AND ($2::webhook_delivery_status IS NULL OR status = $2)
+            ORDER BY created_at DESC
+            LIMIT $3 OFFSET $4
+            "#,
+            subscription_id,
+            status as Option<WebhookDeliveryStatus>,
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to list webhook deliveries: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok((deliveries, total))
+    }
+
+    /// Get a delivery with its attempt log
+    pub async fn get_delivery(
+        &self,
+        id: Uuid,
+    ) -> Result<(WebhookDelivery, Vec<WebhookDeliveryAttempt>)> {
+        let delivery = sqlx::query_as!(
+            WebhookDelivery,
+            r#"
+            SELECT
+                id, subscription_id, event_id, event_type, payload,
+                status as "status: WebhookDeliveryStatus",
+                attempt_count, max_attempts, next_attempt_at, last_attempt_at,
+                last_status_code, last_error, delivered_at, redelivery_of, created_at
+            FROM webhook_deliveries
+            WHERE id = $1
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get webhook delivery: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| AppError::NotFound(format!("Webhook delivery {} not found", id)))?;
+
+        let attempts = sqlx::query_as!(
+            WebhookDeliveryAttempt,
+            r#"
+            SELECT id, attempt_number, status_code, error, duration_ms, attempted_at
+            FROM webhook_delivery_attempts
+            WHERE delivery_id = $1
+            ORDER BY attempt_number ASC
+            "#,
+            id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get webhook delivery attempts: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok((delivery, attempts))
+    }
+
+    /// Manually redeliver an event as a new delivery with a fresh attempt budget
+    ///
+    /// The original delivery and its log are kept; the subscription must be active.
+    pub async fn redeliver(&self, id: Uuid) -> Result<WebhookDelivery> {
+        let delivery = sqlx::query_as!(
+            WebhookDelivery,
+            r#"
+            INSERT INTO webhook_deliveries
+                (subscription_id, event_id, event_type, payload, redelivery_of)
+            SELECT d.subscription_id, d.event_id, d.event_type, d.payload, d.id
+            FROM webhook_deliveries d
+            JOIN webhook_subscriptions s ON s.id = d.subscription_id
+            WHERE d.id = $1 AND s.is_active = true
+            RETURNING
+                id, subscription_id, event_id, event_type, payload,
+                status as "status: WebhookDeliveryStatus",
+                attempt_count, max_attempts, next_attempt_at, last_attempt_at,
+                last_status_code, last_error, delivered_at, redelivery_of, created_at
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to redeliver webhook: {:?}", e);
+            AppError::Database(e)
+        })?
+        .ok_or_else(|| {
+            AppError::NotFound(format!(
+                "Webhook delivery {} not found or subscription inactive",
+                id
+            ))
+        })?;
+
+        tracing::info!("Webhook delivery {} queued again as {}", id, delivery.id);
+        Ok(delivery)
+    }
+
+    // ===== Dispatcher =====
+
+    /// Claim due deliveries for sending
+    ///
+    /// Claimed rows have `next_attempt_at` pushed forward by `lease_secs`, so another
+    /// dispatcher (or this one after a crash) only picks them up again once the lease expires.
+    pub async fn claim_due(&self, limit: i64, lease_secs: i64) -> Result<Vec<DueWebhookDelivery>> {
+        sqlx::query_as!(
+            DueWebhookDelivery,
+            r#"
+            UPDATE webhook_deliveries d
+            SET next_attempt_at = NOW() + make_interval(secs => $2)
+            FROM webhook_subscriptions s
+            WHERE s.id = d.subscription_id
+              AND d.id IN (
+                  SELECT id FROM webhook_deliveries
+                  WHERE status = 'pending' AND next_attempt_at <= NOW()
+                  ORDER BY next_attempt_at
+                  LIMIT $1
+                  FOR UPDATE SKIP LOCKED
+              )
+            RETURNING
+                d.id, d.event_id, d.event_type, d.payload, d.attempt_count, d.max_attempts,
+                s.target_url, s.secret
+            "#,
+            limit,
+            lease_secs as f64
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to claim webhook deliveries: {:?}", e);
+            AppError::Database(e)
+        })
+    }
+
+    /// Record the outcome of an attempt and schedule a retry or finish the delivery
+    pub async fn record_attempt(
+        &self,
+        delivery: &DueWebhookDelivery,
+        status_code: Option<i32>,
+        error: Option<&str>,
+        duration_ms: i32,
+    ) -> Result<WebhookDeliveryStatus> {
+        let attempt_number = delivery.attempt_count + 1;
+        let succeeded = error.is_none() && status_code.is_some_and(|c| (200..300).contains(&c));
+        let status = if succeeded {
+            WebhookDeliveryStatus::Succeeded
+        } else if attempt_number >= delivery.max_attempts {
+            WebhookDeliveryStatus::Failed
+        } else {
+            WebhookDeliveryStatus::Pending
+        };
+        let retry_in = retry_delay_secs(attempt_number) as f64;
+
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            tracing::error!("Failed to begin transaction: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO webhook_delivery_attempts
+                (delivery_id, attempt_number, status_code, error, duration_ms)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+            delivery.id,
+            attempt_number,
+            status_code,
+            error,
+            duration_ms
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to record webhook attempt: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        sqlx::query!(
+            r#"
+            UPDATE webhook_deliveries
+            SET
+                status = $2,
+                attempt_count = $3,
+                last_attempt_at = NOW(),
+                last_status_code = $4,
+                last_error = $5,
+                delivered_at = CASE WHEN $2 = 'succeeded'::webhook_delivery_status
+                                    THEN NOW() ELSE delivered_at END,
+                next_attempt_at = NOW() + make_interval(secs => $6)
+            WHERE id = $1
+            "#,
+            delivery.id,
+            status as WebhookDeliveryStatus,
+            attempt_number,
+            status_code,
+            error,
+            retry_in
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to update webhook delivery: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Failed to commit webhook attempt: {:?}", e);
+            AppError::Database(e)
+        })?;
+
+        Ok(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::reports::services::redact_pii;
+
+    #[test]
+    fn test_sign_payload() {
+        let signature = sign_payload("secret", 1_700_000_000, r#"{"id":1}"#);
+        assert_eq!(
+            signature,
+            "3dd1b9aef568d75f6790a84bd2e5dfa1f44409eef3cbdbd3f10b837376100c11"
+        );
+        assert_ne!(
+            signature,
+            sign_payload("secret", 1_700_000_001, r#"{"id":1}"#)
+        );
+    }
+
+    #[test]
+    fn test_report_processed_data_carries_redacted_title() {
+        let title = "Jalan rusak, hubungi Pak RT di 0812-3456-7890";
+        let redacted = redact_pii(title, false);
+
+        let data = report_processed_data(
+            Uuid::nil(),
+            Some("RPT-2026-0000001"),
+            Some(&redacted.text),
+            ReportStatus::Draft,
+        );
+
+        assert_eq!(
+            data["title"],
+            "Jalan rusak, hubungi Pak RT di [NOMOR TELEPON]"
+        );
+        assert_eq!(data["status"], "draft");
+        assert!(!data.to_string().contains("0812"));
+    }
+
+    #[test]
+    fn test_retry_delay_is_exponential_and_capped() {
+        assert_eq!(retry_delay_secs(1), 30);
+        assert_eq!(retry_delay_secs(2), 60);
+        assert_eq!(retry_delay_secs(3), 120);
+        assert_eq!(retry_delay_secs(30), RETRY_MAX_DELAY_SECS);
+    }
+}
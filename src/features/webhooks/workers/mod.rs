@@ -0,0 +1,3 @@
+mod webhook_dispatcher;
+
+pub use webhook_dispatcher::WebhookDispatcher;
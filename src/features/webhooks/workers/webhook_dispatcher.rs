@@ -0,0 +1,149 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::time::interval;
+
+use crate::core::error::Result;
+use crate::features::webhooks::models::{DueWebhookDelivery, WebhookDeliveryStatus};
+use crate::features::webhooks::services::{sign_payload, WebhookService};
+
+/// Delay between outbox polls
+const DISPATCH_INTERVAL_SECS: u64 = 10;
+
+/// Maximum deliveries sent per poll
+const BATCH_SIZE: i64 = 50;
+
+/// HTTP timeout for a single delivery attempt
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+
+/// How long a claimed delivery stays invisible to other dispatchers
+const CLAIM_LEASE_SECS: i64 = 120;
+
+/// Maximum length of a receiver error body kept in the delivery log
+const MAX_ERROR_LENGTH: usize = 500;
+
+/// Webhook dispatcher that runs in the background
+/// Sends due deliveries from the outbox and schedules retries
+pub struct WebhookDispatcher {
+    webhook_service: Arc<WebhookService>,
+    http_client: reqwest::Client,
+}
+
+impl WebhookDispatcher {
+    pub fn new(webhook_service: Arc<WebhookService>) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            webhook_service,
+            http_client,
+        }
+    }
+
+    /// Run the dispatcher in a background loop
+    pub async fn run(&self) {
+        tracing::info!("Starting webhook dispatcher");
+
+        let mut interval = interval(Duration::from_secs(DISPATCH_INTERVAL_SECS));
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = self.process_batch().await {
+                tracing::error!("Error dispatching webhooks: {:?}", e);
+            }
+        }
+    }
+
+    /// Send a batch of due deliveries
+    async fn process_batch(&self) -> Result<()> {
+        let deliveries = self
+            .webhook_service
+            .claim_due(BATCH_SIZE, CLAIM_LEASE_SECS)
+            .await?;
+
+        if deliveries.is_empty() {
+            return Ok(());
+        }
+
+        tracing::debug!("Dispatching {} webhook deliveries", deliveries.len());
+
+        for delivery in deliveries {
+            if let Err(e) = self.deliver(&delivery).await {
+                tracing::error!("Failed to record webhook delivery {}: {:?}", delivery.id, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// POST one delivery and record the outcome
+    async fn deliver(&self, delivery: &DueWebhookDelivery) -> Result<()> {
+        let body = delivery.payload.to_string();
+        let timestamp = chrono::Utc::now().timestamp();
+        let signature = sign_payload(&delivery.secret, timestamp, &body);
+
+        let started = Instant::now();
+        let response = self
+            .http_client
+            .post(&delivery.target_url)
+            .header("Content-Type", "application/json")
+            .header("X-Webhook-Id", delivery.id.to_string())
+            .header("X-Webhook-Event", &delivery.event_type)
+            .header("X-Webhook-Event-Id", delivery.event_id.to_string())
+            .header("X-Webhook-Timestamp", timestamp.to_string())
+            .header("X-Webhook-Signature", format!("sha256={}", signature))
+            .body(body)
+            .send()
+            .await;
+        let duration_ms = started.elapsed().as_millis().min(i32::MAX as u128) as i32;
+
+        let (status_code, error) = match response {
+            Ok(response) => {
+                let status = response.status();
+                if status.is_success() {
+                    (Some(status.as_u16() as i32), None)
+                } else {
+                    let text = response.text().await.unwrap_or_default();
+                    let snippet: String = text.chars().take(MAX_ERROR_LENGTH).collect();
+                    (
+                        Some(status.as_u16() as i32),
+                        Some(format!("HTTP {}: {}", status.as_u16(), snippet)),
+                    )
+                }
+            }
+            Err(e) => (None, Some(e.to_string())),
+        };
+
+        let status = self
+            .webhook_service
+            .record_attempt(delivery, status_code, error.as_deref(), duration_ms)
+            .await?;
+
+        match status {
+            WebhookDeliveryStatus::Succeeded => tracing::debug!(
+                "Webhook delivery {} ({}) succeeded",
+                delivery.id,
+                delivery.event_type
+            ),
+            WebhookDeliveryStatus::Pending => tracing::warn!(
+                "Webhook delivery {} to {} failed (attempt {}/{}): {:?}, will retry",
+                delivery.id,
+                delivery.target_url,
+                delivery.attempt_count + 1,
+                delivery.max_attempts,
+                error
+            ),
+            WebhookDeliveryStatus::Failed => tracing::error!(
+                "Webhook delivery {} to {} failed permanently after {} attempts: {:?}",
+                delivery.id,
+                delivery.target_url,
+                delivery.attempt_count + 1,
+                error
+            ),
+        }
+
+        Ok(())
+    }
+}
@@ -6,7 +6,9 @@ mod shared;
 use crate::core::config::Config;
 use crate::core::openapi::{ApiDoc, SwaggerInfoModifier};
 use crate::core::{database, middleware};
-use crate::features::admin::{routes as admin_routes, AdminService};
+use crate::features::admin::services::{ReportBulkService, ReportExportService};
+use crate::features::admin::{routes as admin_routes, AdminService, ReportExportWorker};
+use crate::features::agencies::{routes as agencies_routes, AgencyRoutingService, AgencyService};
 use crate::features::auth;
 use crate::features::auth::clients::LogtoAuthClient;
 use crate::features::auth::routes as auth_routes;
@@ -21,6 +23,13 @@ use crate::features::dashboard::{routes as dashboard_routes, DashboardService};
 use crate::features::expectations::{routes as expectations_routes, ExpectationService};
 use crate::features::files::{routes as files_routes, FileService};
 use crate::features::logto::token_manager::LogtoTokenManager;
+use crate::features::moderation::{
+    routes as moderation_routes, ModerationClassifier, ModerationService,
+};
+use crate::features::notifications::{routes as notifications_routes, NotificationService};
+use crate::features::open_data::{
+    routes as open_data_routes, OpenDataService, OpenDataSnapshotWorker,
+};
 use crate::features::prompts::{routes as prompts_routes, PromptService};
 use crate::features::rate_limits::{
     routes as rate_limits_routes, RateLimitConfigService, RateLimitService,
@@ -28,11 +37,17 @@ use crate::features::rate_limits::{
 use crate::features::regions::{routes as regions_routes, RegionService};
 use crate::features::reports::{
     routes as reports_routes, ExtractionService, GeocodingService, RegionLookupService,
-    ReportJobService, ReportProcessor, ReportService,
+    ReportAssignmentService, ReportDuplicateService, ReportEndorsementService,
+    ReportIdentityService, ReportJobService, ReportProcessor, ReportReviewService,
+    ReportRevisionService, ReportService, ReportUpdateService,
 };
+use crate::features::settings::{routes as settings_routes, SettingsService};
+use crate::features::sla::{routes as sla_routes, SlaEscalationWorker, SlaService};
+use crate::features::tracking::{routes as tracking_routes, TrackingService};
 use crate::features::users::{
     clients::logto::LogtoUserProfileClient, routes as users_routes, services::UserProfileService,
 };
+use crate::features::webhooks::{routes as webhooks_routes, WebhookDispatcher, WebhookService};
 use axum::{middleware::from_fn, Router};
 use balungpisah_adk::Storage;
 use std::sync::Arc;
@@ -188,23 +203,96 @@ async fn async_main(worker_threads: usize) -> anyhow::Result<()> {
     let report_job_service = Arc::new(ReportJobService::new(pool.clone()));
     let geocoding_service = Arc::new(GeocodingService::new());
     let region_lookup_service = Arc::new(RegionLookupService::new(pool.clone()));
+    let report_assignment_service = Arc::new(ReportAssignmentService::new(pool.clone()));
+    let report_duplicate_service = Arc::new(ReportDuplicateService::new(pool.clone()));
+    let report_update_service = Arc::new(ReportUpdateService::new(pool.clone()));
+    let report_endorsement_service = Arc::new(ReportEndorsementService::new(pool.clone()));
+    let report_identity_service = Arc::new(ReportIdentityService::new(pool.clone()));
     tracing::info!("Report services initialized");
 
+    // Initialize Agency Services
+    let agency_service = Arc::new(AgencyService::new(pool.clone()));
+    let agency_routing_service = Arc::new(AgencyRoutingService::new(pool.clone()));
+    tracing::info!("Agency services initialized");
+
+    // Initialize SLA Service
+    let sla_service = Arc::new(SlaService::new(pool.clone()));
+    tracing::info!("SLA services initialized");
+
+    // Initialize Notification Service
+    let notification_service = Arc::new(NotificationService::new(pool.clone()));
+    tracing::info!("Notification service initialized");
+
+    // Initialize Webhook Service
+    let webhook_service = Arc::new(WebhookService::new(pool.clone()));
+    tracing::info!("Webhook service initialized");
+
     // Initialize Dashboard Service
     let dashboard_service = Arc::new(DashboardService::new(pool.clone()));
     tracing::info!("Dashboard service initialized");
 
+    // Initialize Open Data Service (public feed and daily snapshots)
+    let open_data_service = Arc::new(OpenDataService::new(
+        pool.clone(),
+        Arc::clone(&minio_client),
+    ));
+    tracing::info!("Open data service initialized");
+
+    // Initialize Runtime Settings
+    let settings_service = Arc::new(SettingsService::new(pool.clone()));
+    tracing::info!("Settings service initialized");
+
+    // Initialize Report Revision Service (curator edits, reads the redaction settings)
+    let report_revision_service = Arc::new(ReportRevisionService::new(
+        pool.clone(),
+        Arc::clone(&settings_service),
+    ));
+    tracing::info!("Report revision service initialized");
+
+    // Initialize Tracking Service
+    let tracking_service = Arc::new(TrackingService::new(
+        pool.clone(),
+        Arc::clone(&settings_service),
+        config.app.trust_proxy_headers,
+    ));
+    tracing::info!("Tracking service initialized");
+
+    // Initialize Moderation Service (keyword lists only if TensorZero is not available)
+    let moderation_classifier = match ModerationClassifier::new(
+        &config.agent_gateway.tensorzero_url,
+        config.agent_gateway.openai_api_key.clone(),
+        config.agent_gateway.model_name.clone(),
+    ) {
+        Ok(classifier) => Some(classifier),
+        Err(e) => {
+            tracing::warn!(
+                "LLM moderation classifier not available: {}. Using keyword lists only.",
+                e
+            );
+            None
+        }
+    };
+    let moderation_service = Arc::new(ModerationService::new(pool.clone(), moderation_classifier));
+    tracing::info!("Moderation service initialized");
+
     // Initialize Rate Limit Services
     let rate_limit_config_service = Arc::new(RateLimitConfigService::new(pool.clone()));
     let rate_limit_service = Arc::new(RateLimitService::new(
         pool.clone(),
         Arc::clone(&rate_limit_config_service),
+        Arc::clone(&settings_service),
     ));
     tracing::info!("Rate limit services initialized");
 
     // Initialize Admin Service
     let admin_service = Arc::new(AdminService::new(pool.clone()));
-    tracing::info!("Admin service initialized");
+    let report_bulk_service = Arc::new(ReportBulkService::new(pool.clone()));
+    let report_export_service = Arc::new(ReportExportService::new(
+        pool.clone(),
+        Arc::clone(&minio_client),
+        Arc::clone(&notification_service),
+    ));
+    tracing::info!("Admin services initialized");
 
     // Initialize Citizen Report Agent Services
     // ADK uses a separate database for conversation storage
@@ -235,6 +323,13 @@ async fn async_main(worker_threads: usize) -> anyhow::Result<()> {
         .map_err(|e| anyhow::anyhow!("ADK migration failed: {}", e))?;
     tracing::info!("ADK database migrations completed successfully");
 
+    // Initialize Report Review Service (reads conversation excerpts from ADK storage)
+    let report_review_service = Arc::new(ReportReviewService::new(
+        pool.clone(),
+        Arc::clone(&adk_storage),
+    ));
+    tracing::info!("Report review service initialized");
+
     // Initialize Extraction Service (uses TensorZero + ADK for LLM calls)
     let extraction_service = match ExtractionService::new(
         pool.clone(),
@@ -271,6 +366,9 @@ async fn async_main(worker_threads: usize) -> anyhow::Result<()> {
             Arc::clone(&report_service),
             Arc::clone(&report_job_service),
             Arc::clone(&region_lookup_service),
+            Arc::clone(&agency_routing_service),
+            Arc::clone(&moderation_service),
+            Arc::clone(&settings_service),
         );
         tokio::spawn(async move {
             report_processor.run().await;
@@ -278,8 +376,39 @@ async fn async_main(worker_threads: usize) -> anyhow::Result<()> {
         tracing::info!("Report processor worker spawned");
     }
 
+    // Spawn SLA Escalation Worker (independent of extraction)
+    let sla_escalation_worker = SlaEscalationWorker::new(
+        Arc::clone(&sla_service),
+        Arc::clone(&agency_routing_service),
+    );
+    tokio::spawn(async move {
+        sla_escalation_worker.run().await;
+    });
+    tracing::info!("SLA escalation worker spawned");
+
+    // Spawn Webhook Dispatcher (independent of extraction)
+    let webhook_dispatcher = WebhookDispatcher::new(Arc::clone(&webhook_service));
+    tokio::spawn(async move {
+        webhook_dispatcher.run().await;
+    });
+    tracing::info!("Webhook dispatcher spawned");
+
+    // Spawn Report Export Worker (independent of extraction)
+    let report_export_worker = ReportExportWorker::new(Arc::clone(&report_export_service));
+    tokio::spawn(async move {
+        report_export_worker.run().await;
+    });
+    tracing::info!("Report export worker spawned");
+
+    // Spawn Open Data Snapshot Worker
+    let open_data_worker = OpenDataSnapshotWorker::new(Arc::clone(&open_data_service));
+    tokio::spawn(async move {
+        open_data_worker.run().await;
+    });
+    tracing::info!("Open data snapshot worker spawned");
+
     // Create tool registry with database pool for ticket creation
-    let tool_registry = create_tool_registry(Arc::new(pool.clone()));
+    let tool_registry = create_tool_registry(Arc::new(pool.clone()), Arc::clone(&settings_service));
     tracing::info!(
         "Agent tool registry initialized with {} tools",
         tool_registry.names().len()
@@ -291,12 +420,14 @@ async fn async_main(worker_threads: usize) -> anyhow::Result<()> {
         config.agent_gateway.openai_api_key.clone(),
         config.agent_gateway.model_name.clone(),
         tool_registry,
+        Arc::clone(&settings_service),
     ));
     let conversation_service = Arc::new(ConversationService::new(Arc::clone(&adk_storage)));
     let thread_attachment_service = Arc::new(ThreadAttachmentService::new(
         pool.clone(),
         Arc::clone(&minio_client),
         Arc::clone(&adk_storage),
+        Arc::clone(&settings_service),
     ));
     tracing::info!(
         "Citizen report agent services initialized (TensorZero: {})",
@@ -332,17 +463,52 @@ async fn async_main(worker_threads: usize) -> anyhow::Result<()> {
         .merge(users_routes::routes(user_profile_service))
         .merge(regions_routes::routes(region_service))
         .merge(files_routes::routes(file_service))
-        .merge(reports_routes::routes(Arc::clone(&report_service)))
+        .merge(reports_routes::routes(
+            Arc::clone(&report_service),
+            Arc::clone(&report_assignment_service),
+            Arc::clone(&report_update_service),
+            Arc::clone(&report_endorsement_service),
+        ))
+        .merge(reports_routes::admin_routes(
+            Arc::clone(&report_assignment_service),
+            Arc::clone(&report_duplicate_service),
+            Arc::clone(&report_endorsement_service),
+            Arc::clone(&report_review_service),
+            Arc::clone(&report_job_service),
+            Arc::clone(&report_service),
+            Arc::clone(&conversation_service),
+        ))
+        .merge(reports_routes::revision_routes(report_revision_service))
+        .merge(reports_routes::identity_routes(
+            report_identity_service,
+            Arc::clone(&conversation_service),
+        ))
         .merge(citizen_agent_routes::routes(
             Arc::clone(&agent_runtime_service),
             Arc::clone(&conversation_service),
             Arc::clone(&thread_attachment_service),
             Arc::clone(&rate_limit_service),
+            Arc::clone(&moderation_service),
         ))
+        .merge(moderation_routes::admin_routes(Arc::clone(
+            &moderation_service,
+        )))
         .merge(rate_limits_routes::admin_routes(Arc::clone(
             &rate_limit_config_service,
         )))
         .merge(prompts_routes::admin_routes(Arc::clone(&prompt_service)))
+        .merge(agencies_routes::admin_routes(
+            Arc::clone(&agency_service),
+            Arc::clone(&agency_routing_service),
+        ))
+        .merge(sla_routes::admin_routes(Arc::clone(&sla_service)))
+        .merge(notifications_routes::routes(Arc::clone(
+            &notification_service,
+        )))
+        .merge(webhooks_routes::admin_routes(Arc::clone(&webhook_service)))
+        .merge(settings_routes::admin_routes(Arc::clone(&settings_service)))
+        .merge(admin_routes::bulk_routes(report_bulk_service))
+        .merge(admin_routes::export_routes(report_export_service))
         .nest(
             "/api/admin",
             admin_routes::routes(Arc::clone(&admin_service)),
@@ -364,7 +530,9 @@ async fn async_main(worker_threads: usize) -> anyhow::Result<()> {
         .merge(expectations_routes::routes(expectation_service))
         .merge(contributors_routes::routes(contributor_service))
         .merge(categories_routes::routes(category_service))
-        .merge(dashboard_routes::routes(Arc::clone(&dashboard_service)));
+        .merge(dashboard_routes::routes(Arc::clone(&dashboard_service)))
+        .merge(tracking_routes::routes(tracking_service))
+        .merge(open_data_routes::routes(open_data_service));
 
     let app = Router::new()
         .merge(swagger)
@@ -431,7 +599,12 @@ async fn async_main(worker_threads: usize) -> anyhow::Result<()> {
         format!("http://{}/swagger-ui/", addr)
     );
 
-    axum::serve(listener, app).await?;
+    // Connect info provides the peer address for per-client rate limits
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
@@ -15,9 +15,19 @@ pub const MAX_PAGE_SIZE: i64 = 100;
 pub const ROLE_ADMIN_CURATOR: &str = "admin_curator";
 
 /// Citizen role - can report problems and track their reports
-#[allow(dead_code)]
 pub const ROLE_CITIZEN: &str = "citizen";
 
 /// Official role - can claim and resolve marketplace problems
 #[allow(dead_code)]
 pub const ROLE_OFFICIAL: &str = "official";
+
+/// System actor - used when recording changes made by background workers
+pub const ROLE_SYSTEM: &str = "system";
+
+// =============================================================================
+// FULL-TEXT SEARCH
+// =============================================================================
+
+/// `ts_headline` options for report search snippets
+pub const SEARCH_HEADLINE_OPTIONS: &str =
+    "StartSel=<mark>, StopSel=</mark>, MaxWords=30, MinWords=10, MaxFragments=2, FragmentDelimiter=\" … \"";
@@ -96,3 +96,14 @@ pub async fn render_extraction_prompt(
 
     render_template("citizen_report_extraction/system.jinja", &ctx).await
 }
+
+/// Render the content moderation classifier system prompt.
+///
+/// # Arguments
+/// * `json_schema` - JSON schema string for the expected output
+pub async fn render_moderation_prompt(json_schema: &str) -> Result<String, TemplateError> {
+    let mut ctx: HashMap<&str, Value> = HashMap::new();
+    ctx.insert("json_schema", Value::from(json_schema));
+
+    render_template("content_moderation/system.jinja", &ctx).await
+}
@@ -1,6 +1,10 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::de::{DeserializeOwned, Deserializer, Error as _, IntoDeserializer};
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
 
+use crate::core::error::{AppError, Result};
 use crate::shared::constants::{DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -15,6 +19,22 @@ pub struct ApiResponse<T> {
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct Meta {
     pub total: i64,
+    /// Cursor for the next page (keyset-paginated listings, absent on the last page)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+impl Meta {
+    pub fn new(total: i64) -> Self {
+        Self {
+            total,
+            next_cursor: None,
+        }
+    }
+
+    pub fn with_cursor(total: i64, next_cursor: Option<String>) -> Self {
+        Self { total, next_cursor }
+    }
 }
 
 // =============================================================================
@@ -88,3 +108,194 @@ impl<T> ApiResponse<T> {
         }
     }
 }
+
+// =============================================================================
+// FULL-TEXT SEARCH
+// =============================================================================
+
+/// Full-text search match of a listed report (only present when searching with `q`)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SearchMatch {
+    /// Relevance, higher is better; search results are ordered by it
+    pub rank: f32,
+    /// Matching excerpts with the search terms wrapped in `<mark>`
+    pub snippet: Option<String>,
+}
+
+impl SearchMatch {
+    /// Build from the `rank` and `snippet` columns of a listing query (rank is NULL without `q`)
+    pub fn from_columns(rank: Option<f32>, snippet: Option<String>) -> Option<Self> {
+        rank.map(|rank| Self { rank, snippet })
+    }
+}
+
+/// Trimmed search text, None when empty
+pub fn search_text(q: Option<&str>) -> Option<&str> {
+    q.map(str::trim).filter(|q| !q.is_empty())
+}
+
+// =============================================================================
+// SORTING
+// =============================================================================
+
+/// Sort direction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    #[default]
+    Desc,
+    Asc,
+}
+
+impl SortDirection {
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        }
+    }
+
+    /// Row comparison selecting the rows that come after a cursor in this direction
+    pub fn after_operator(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => ">",
+            SortDirection::Desc => "<",
+        }
+    }
+}
+
+// =============================================================================
+// KEYSET CURSORS
+// =============================================================================
+
+/// Opaque keyset pagination cursor: sort key and id of the last row of a page
+///
+/// Clients pass it back unchanged as `cursor`; it is only valid for the sort it was
+/// issued for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeysetCursor {
+    /// Sort the cursor was issued for, e.g. `created_at:desc`
+    #[serde(rename = "s")]
+    pub sort: String,
+    /// Sort key of the last row, as text
+    #[serde(rename = "k")]
+    pub key: String,
+    /// Id of the last row, breaking ties of the sort key
+    #[serde(rename = "i")]
+    pub id: Uuid,
+}
+
+impl KeysetCursor {
+    pub fn new(sort: impl Into<String>, key: impl Into<String>, id: Uuid) -> Self {
+        Self {
+            sort: sort.into(),
+            key: key.into(),
+            id,
+        }
+    }
+
+    pub fn encode(&self) -> Result<String> {
+        let json = serde_json::to_vec(self)
+            .map_err(|e| AppError::Internal(format!("Failed to encode cursor: {}", e)))?;
+        Ok(URL_SAFE_NO_PAD.encode(json))
+    }
+
+    /// Decode a cursor issued for the given sort
+    pub fn decode(cursor: &str, sort: &str) -> Result<Self> {
+        let cursor: Self = URL_SAFE_NO_PAD
+            .decode(cursor.trim())
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or_else(|| AppError::BadRequest("Invalid cursor".to_string()))?;
+
+        if cursor.sort != sort {
+            return Err(AppError::BadRequest(
+                "Cursor was issued for a different sort order".to_string(),
+            ));
+        }
+
+        Ok(cursor)
+    }
+}
+
+// =============================================================================
+// LIST PARAMETERS
+// =============================================================================
+
+/// Deserialize a list from a JSON array, a single value or a comma-separated string
+///
+/// Query strings carry lists as `status=verified,resolved`; stored JSON filters written
+/// before a field became a list hold a single value.
+pub fn deserialize_list<'de, D, T>(deserializer: D) -> std::result::Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum List<T> {
+        Many(Vec<T>),
+        Text(String),
+    }
+
+    match Option::<List<T>>::deserialize(deserializer)? {
+        None => Ok(Vec::new()),
+        Some(List::Many(items)) => Ok(items),
+        Some(List::Text(text)) => text
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| {
+                T::deserialize(item.into_deserializer())
+                    .map_err(|e: serde::de::value::Error| D::Error::custom(e))
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Params {
+        #[serde(default, deserialize_with = "deserialize_list")]
+        ids: Vec<Uuid>,
+    }
+
+    #[test]
+    fn test_keyset_cursor_round_trip() {
+        let cursor = KeysetCursor::new("created_at:desc", "2026-10-01 08:00:00+00", Uuid::nil());
+        let encoded = cursor.encode().unwrap();
+
+        assert_eq!(
+            KeysetCursor::decode(&encoded, "created_at:desc").unwrap(),
+            cursor
+        );
+        assert!(KeysetCursor::decode(&encoded, "created_at:asc").is_err());
+        assert!(KeysetCursor::decode("not a cursor", "created_at:desc").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_list_forms() {
+        let a = Uuid::from_u128(1);
+        let b = Uuid::from_u128(2);
+
+        let uri: axum::http::Uri = format!("/reports?ids={},{}", a, b).parse().unwrap();
+        let axum::extract::Query(params) =
+            axum::extract::Query::<Params>::try_from_uri(&uri).unwrap();
+        assert_eq!(params.ids, vec![a, b]);
+
+        let params: Params = serde_json::from_value(serde_json::json!({ "ids": [a] })).unwrap();
+        assert_eq!(params.ids, vec![a]);
+
+        let params: Params = serde_json::from_value(serde_json::json!({ "ids": b })).unwrap();
+        assert_eq!(params.ids, vec![b]);
+
+        let params: Params = serde_json::from_value(serde_json::json!({ "ids": null })).unwrap();
+        assert!(params.ids.is_empty());
+
+        let uri: axum::http::Uri = "/reports?ids=nope".parse().unwrap();
+        assert!(axum::extract::Query::<Params>::try_from_uri(&uri).is_err());
+    }
+}